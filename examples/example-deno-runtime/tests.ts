@@ -5,6 +5,12 @@ import {
   fail,
 } from "https://deno.land/std@0.135.0/testing/asserts.ts";
 import { loadPlugin } from "./loader.ts";
+import {
+  durationFromMillis,
+  durationToMillis,
+  systemTimeFromMillis,
+  systemTimeToMillis,
+} from "../example-protocol/bindings/ts-runtime/types.ts";
 import type { Exports, Imports } from "../example-protocol/bindings/ts-runtime/index.ts";
 import type {
   FpAdjacentlyTagged,
@@ -13,15 +19,25 @@ import type {
   FpPropertyRenaming,
   FpUntagged,
   FpVariantRenaming,
+  FrameInfo,
+  HttpRequest,
+  HttpResponse,
   HttpResult,
+  Label,
+  Meters,
+  Position,
   Request,
   SerdeAdjacentlyTagged,
+  SerdeAttributes,
   SerdeFlatten,
   SerdeInternallyTagged,
   SerdePropertyRenaming,
   SerdeUntagged,
   SerdeVariantRenaming,
   StructWithGenerics,
+  UserId,
+  Uuid,
+  Wrapper,
 } from "../example-protocol/bindings/ts-runtime/types.ts";
 
 let voidFunctionCalled = false;
@@ -194,11 +210,54 @@ const imports: Imports = {
     return "Hello, plugin!";
   },
 
+  importByValue: (position: Position, frame: FrameInfo): Position => {
+    assertEquals(position, { x: 1.5, y: -2 });
+    assertEquals(frame, {
+      frameNumber: 42,
+      deltaMs: 16.5,
+      offset: -3,
+      isPaused: true,
+    });
+    return { x: position.x + frame.offset, y: position.y * 2 };
+  },
+
+  importBorrowed: (
+    text: string,
+    data: ArrayBuffer,
+    values: Array<number>,
+    position: Position,
+  ): string => {
+    assertEquals(text, "Hello, host!");
+    assertEquals(new Uint8Array(data), new Uint8Array([3, 2, 1]));
+    assertEquals(values, [5, 6]);
+    assertEquals(position, { x: 1.5, y: -2 });
+    return "Hello, world!";
+  },
+
+  importBytes: (arg: ArrayBuffer): ArrayBuffer => {
+    const decoder = new TextDecoder();
+    assertEquals(decoder.decode(arg), "hello");
+    return new TextEncoder().encode("hello, host").buffer;
+  },
+
   importTimestamp: (arg: string): string => {
     assertEquals(arg, "2022-04-12T19:10:00Z");
     return "2022-04-13T12:37:00Z";
   },
 
+  fetch: (request: HttpRequest): HttpResponse => {
+    assertEquals(request, {
+      method: "GET",
+      uri: request.uri,
+      version: "HTTP/1.1",
+      headers: { "x-plugin": "example" },
+      body: new Uint8Array(),
+    });
+    return request.uri.endsWith("/missing")
+      ? { status: 404 }
+      : { status: 200, headers: { "content-type": "text/plain" } };
+  },
+
   importVoidFunction: (): void => {
     voidFunctionCalled = true;
   },
@@ -215,7 +274,7 @@ const imports: Imports = {
       url: "https://fiberplane.dev/",
       method: "POST",
       headers: {
-        "Content-Type": "application/json",
+        "content-type": "application/json",
       },
       body: encoder.encode(
         JSON.stringify({ "country": "🇳🇱", "type": "sign-up" }),
@@ -272,12 +331,292 @@ Deno.test("primitives", async () => {
   assertAlmostEquals(plugin.exportPrimitiveF64?.(2.718281828459) ?? 0, 2.718281828459);
 });
 
+Deno.test("wide primitives", async () => {
+  const plugin = await loadExamplePlugin();
+
+  assertEquals(plugin.exportPrimitiveChar?.("a"), "z");
+  assertEquals(plugin.exportPrimitiveI128?.(-(1n << 100n)), -(1n << 127n));
+  assertEquals(plugin.exportPrimitiveIsize?.(-32), -64);
+  assertEquals(plugin.exportPrimitiveU128?.(1n << 100n), (1n << 128n) - 1n);
+  assertEquals(plugin.exportPrimitiveUsize?.(32), 64);
+
+  assertEquals(plugin.exportStructWithWidePrimitives?.({
+    initial: "a",
+    signed_large: -1n,
+    unsigned_large: 1n << 64n,
+    offset: -8,
+    length: 8,
+  }), {
+    initial: "z",
+    signed_large: -(1n << 127n),
+    unsigned_large: (1n << 128n) - 1n,
+    offset: 8,
+    length: 16,
+  });
+});
+
 Deno.test("string", async () => {
   const plugin = await loadExamplePlugin();
 
   assertEquals(plugin.exportString?.("Hello, plugin!"), "Hello, world!");
 });
 
+Deno.test("binary data", async () => {
+  const plugin = await loadExamplePlugin();
+  const encoder = new TextEncoder();
+
+  const bytes = plugin.exportBytes?.(encoder.encode("hello"));
+  assertEquals(new TextDecoder().decode(bytes), "hello, host, plugin");
+
+  const byteBuf = plugin.exportByteBuf?.(new Uint8Array([1, 2, 3]));
+  assertEquals(new Uint8Array(byteBuf!), new Uint8Array([3, 2, 1]));
+});
+
+Deno.test("structs passed by value", async () => {
+  const plugin = await loadExamplePlugin();
+
+  const position = plugin.exportByValue?.({ x: 1.5, y: -2 }, {
+    frameNumber: 42,
+    deltaMs: 16.5,
+    offset: -3,
+    isPaused: true,
+  });
+  assertEquals(position, { x: -0.5, y: -3 });
+});
+
+Deno.test("borrowed arguments", async () => {
+  const plugin = await loadExamplePlugin();
+
+  assertEquals(
+    plugin.exportBorrowed?.(
+      "Hello, plugin!",
+      new Uint8Array([1, 2, 3]),
+      [4, 5, 6],
+      { x: 1.5, y: -2 },
+    ),
+    "Hello, world!",
+  );
+});
+
+Deno.test("serde attributes", async () => {
+  const plugin = await loadExamplePlugin();
+
+  // The title is passed using its alias, and the revision is ignored by the
+  // plugin. All other fields are omitted, so they get their default values:
+  assertEquals(plugin.exportSerdeAttributes?.({
+    name: "Hello, plugin!",
+    max_items: 5,
+    password: "secret",
+    revision: 1,
+    label: "important" as Label,
+  } as SerdeAttributes), {
+    title: "Hello, host!",
+    maxItems: 10,
+    revision: 2,
+    label: "important" as Label,
+    priority: "High",
+    range: { type: "range", start: 1, end: 3 },
+    email: "host@example.com",
+  });
+});
+
+Deno.test("collections", async () => {
+  const plugin = await loadExamplePlugin();
+
+  assertEquals(plugin.exportCollections?.({
+    shared: "shared",
+    label: "label",
+    queue: [1, 2, 3],
+    ordered: { b: 2, a: 1 },
+  }), {
+    shared: "shared by plugin",
+    label: "LABEL",
+    queue: [2, 3, 1],
+    ordered: { b: 2, a: 1, c: 3 },
+  });
+
+  // Binary heaps are serialized as arrays in heap order, so only their
+  // contents are verified:
+  const { priorities } = plugin.exportBinaryHeap?.({ priorities: [1, 3, 2] })!;
+  assertEquals(priorities.sort(), [1, 2, 3, 4]);
+});
+
+Deno.test("custom types", async () => {
+  const plugin = await loadExamplePlugin();
+
+  assertEquals(plugin.exportCustomTypes?.({
+    date: "2022-05-17",
+    weekday: "Tuesday",
+  }), {
+    date: "2022-05-18",
+    weekday: "Wednesday",
+  });
+});
+
+Deno.test("json", async () => {
+  const plugin = await loadExamplePlugin();
+
+  // Integral floats, such as `3.0`, come back as integers:
+  assertEquals(plugin.exportJson?.({
+    value: { count: 1, ratio: 0.5, tags: ["a", null, true] },
+    object: { nested: { list: [1, 2] } },
+  }), {
+    value: { count: 2, ratio: 1.5, float: 3 },
+    object: { nested: { list: [1, 2] }, seen: true },
+    optional_value: -7,
+  });
+});
+
+Deno.test("http exchange", async () => {
+  const plugin = await loadExamplePlugin();
+  const encoder = new TextEncoder();
+
+  assertEquals(plugin.exportHttpExchange?.({
+    request: {
+      method: "PUT",
+      uri: "https://fiberplane.dev/notebooks/1",
+      headers: { "Accept": "application/json" },
+      body: encoder.encode("{}"),
+    },
+    response: {
+      status: 201,
+      version: "HTTP/2.0",
+      headers: { "set-cookie": "a=1" },
+      body: encoder.encode("{}"),
+    },
+    content_type: "application/json",
+  }), {
+    request: {
+      method: "PUT",
+      uri: "https://fiberplane.dev/notebooks/1",
+      version: "HTTP/1.1",
+      headers: { "accept": "application/json", "x-plugin": "example" },
+      body: encoder.encode("{}"),
+    },
+    response: {
+      status: 202,
+      version: "HTTP/2.0",
+      headers: { "set-cookie": ["a=1", "b=2"] },
+      body: encoder.encode("{}"),
+    },
+    content_type: "text/plain",
+  });
+});
+
+Deno.test("http fetch", async () => {
+  const plugin = await loadExamplePlugin();
+
+  assertEquals(plugin.exportFetch?.({
+    method: "GET",
+    uri: "https://fiberplane.dev/",
+  }), {
+    status: 200,
+    version: "HTTP/1.1",
+    headers: { "content-type": "text/plain" },
+    body: new Uint8Array(),
+  });
+  assertEquals(plugin.exportFetch?.({
+    method: "GET",
+    uri: "https://fiberplane.dev/missing",
+  }), null);
+});
+
+Deno.test("remote types", async () => {
+  const plugin = await loadExamplePlugin();
+
+  assertEquals(plugin.exportRemoteType?.({
+    sequenceNumber: 41,
+    origin: "host",
+  }), {
+    sequenceNumber: 42,
+    origin: "host via plugin",
+  });
+});
+
+Deno.test("repr enums", async () => {
+  const plugin = await loadExamplePlugin();
+
+  // Enums with a `repr` are passed as their numeric discriminants:
+  assertEquals(plugin.exportReprEnum?.(10), 20);
+
+  assertEquals(plugin.exportStructWithReprEnums?.({
+    level: 30,
+    history: [10, 20],
+  }), {
+    level: 31,
+    history: [20, 10],
+  });
+});
+
+Deno.test("unknown variants", async () => {
+  const plugin = await loadExamplePlugin();
+
+  assertEquals(plugin.exportUnknownVariant?.("Point"), { Circle: { radius: 0 } });
+
+  // Variants the plugin doesn't know about are passed back unchanged:
+  assertEquals(plugin.exportUnknownVariant?.("Triangle"), "Triangle");
+  assertEquals(plugin.exportUnknownVariant?.({ Polygon: { corners: 5 } }), {
+    Polygon: { corners: 5 },
+  });
+  assertEquals(
+    plugin.exportTaggedUnknownVariant?.({ type: "Polygon", corners: 5 }),
+    { type: "Polygon", corners: 5 },
+  );
+});
+
+Deno.test("arrays", async () => {
+  const plugin = await loadExamplePlugin();
+
+  assertEquals(plugin.exportArray?.([1, 2, 3]), [3, 2, 1]);
+
+  assertEquals(plugin.exportStructWithArrays?.({
+    checksum: [0xde, 0xad, 0xbe, 0xef],
+    coordinates: [1.0, 2.0, 3.0],
+    segments: [[{ value: 0.0 }, { value: 1.0 }]],
+  }), {
+    checksum: [0xca, 0xfe, 0xba, 0xbe],
+    coordinates: [-1.0, -2.0, -3.0],
+    segments: [[{ value: 1.0 }, { value: 0.0 }]],
+  });
+});
+
+Deno.test("tuple structs", async () => {
+  const plugin = await loadExamplePlugin();
+
+  assertEquals(plugin.exportNewtype?.("user-1" as UserId), 42.5);
+
+  assertEquals(plugin.exportStructWithTupleStructs?.({
+    user_id: "user-1" as UserId,
+    distance: 1.5 as Meters,
+    wrapped: 32 as Wrapper<number>,
+    color: [255, 128, 0],
+    marker: null,
+  }), {
+    user_id: "user-2",
+    optional_user_id: "user-1",
+    distance: -1.5,
+    wrapped: 64,
+    color: [0, 128, 255],
+    marker: null,
+  });
+});
+
+Deno.test("tuples", async () => {
+  const plugin = await loadExamplePlugin();
+
+  assertEquals(plugin.exportTuple?.([32, "Hello, plugin!"]), ["Hello, host!", 64]);
+
+  assertEquals(plugin.exportStructWithTuples?.({
+    pair: [-1, 1],
+    nested: [["foo", [8, true]]],
+    single: ["bar"],
+  }), {
+    pair: [1, -1],
+    nested: [["baz", [16, false]]],
+    single: ["qux"],
+  });
+});
+
 // FIXME: Disabled pending https://github.com/fiberplane/fp-bindgen/issues/108
 /*Deno.test("timestamp", async () => {
   const plugin = await loadExamplePlugin();
@@ -285,6 +624,48 @@ Deno.test("string", async () => {
   assertEquals(plugin.exportTimestamp?.("2022-04-12T19:10:00Z"), "2022-04-13T12:37:00Z");
 });*/
 
+Deno.test("chrono types", async () => {
+  const plugin = await loadExamplePlugin();
+
+  assertEquals(plugin.exportChronoTypes?.({
+    utc: "2022-04-12T19:10:00Z",
+    fixed_offset: "2022-04-12T21:10:00+02:00",
+    date: "2022-04-12",
+    date_time: "2022-04-12T19:10:00",
+    delta: [90, 500],
+  }), {
+    utc: "2022-04-13T19:10:00Z",
+    fixed_offset: "2022-04-13T21:10:00+02:00",
+    date: "2022-04-13",
+    date_time: "2022-04-13T19:10:00",
+    delta: [86400, 0],
+  });
+});
+
+Deno.test("durations", async () => {
+  const plugin = await loadExamplePlugin();
+
+  const createdAt = Date.UTC(2022, 3, 12, 19, 10);
+  const result = plugin.exportDurations?.({
+    timeout: durationFromMillis(1500),
+    created_at: systemTimeFromMillis(createdAt),
+  });
+  assertEquals(result, {
+    timeout: { secs: 3, nanos: 0 },
+    interval: { secs: 0, nanos: 250_000 },
+    created_at: { secs_since_epoch: 1_649_790_601, nanos_since_epoch: 500_000_000 },
+  });
+  assertEquals(durationToMillis(result!.interval!), 0.25);
+  assertEquals(systemTimeToMillis(result!.created_at), createdAt + 1500);
+});
+
+Deno.test("uuids", async () => {
+  const plugin = await loadExamplePlugin();
+
+  const uuid = "f71b6a4d-3cb6-4d3f-a5c2-9c4bd4b4b1a4" as Uuid;
+  assertEquals(plugin.exportUuid?.(uuid), "f71b6a4d-3cb6-4d3f-a5c2-9c4bd4b4b1a5");
+});
+
 Deno.test("flattened structs", async () => {
   const plugin = await loadExamplePlugin();
 
@@ -321,6 +702,22 @@ Deno.test("flattened structs", async () => {
       qux_baz: 64.0,
     }
   });
+
+  assertEquals(plugin.exportKebabCaseStruct?.({
+    "foo-bar": "foo_bar",
+    "raw-struct": -32
+  }), {
+    "foo-bar": "foo-bar",
+    "raw-struct": 32,
+  });
+
+  // Names differ between serialization and deserialization:
+  assertEquals(plugin.exportDirectionalEnum?.("foobar"), {
+    QUXBAZ: { fooBar: "foo_bar" }
+  });
+  assertEquals(plugin.exportDirectionalEnum?.({
+    quxbaz: { "FOO-BAR": "foo_bar" }
+  }), "FOOBAR");
 });
 
 // FIXME: Disabled pending https://github.com/fiberplane/fp-bindgen/issues/108
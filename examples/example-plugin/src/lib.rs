@@ -1,9 +1,19 @@
-use ::http::{Method, Uri};
-use example_bindings::*;
+use ::http::{header::CONTENT_TYPE, HeaderMap, HeaderValue, Method, StatusCode, Uri};
+use bytes::Bytes;
+use example_bindings::{
+    http::{HttpRequest, HttpResponse},
+    *,
+};
+use indexmap::IndexMap;
 use serde_bytes::ByteBuf;
-use std::collections::{BTreeMap, HashMap};
+use serde_json::json;
+use std::borrow::Cow;
+use std::collections::{BTreeMap, BinaryHeap, VecDeque};
 use std::panic;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
 use time::{macros::datetime, OffsetDateTime};
+use uuid::Uuid;
 
 // This plugin contains implementations for all the functions it may export
 // according to the protocol. These functions are called during our integration
@@ -15,6 +25,12 @@ use time::{macros::datetime, OffsetDateTime};
 //                https://fiberplane.dev/blog/writing-redux-reducers-in-rust/
 mod reducer;
 
+// The bindings don't have to be referred to by their crate name, any path
+// that re-exports them will do. See `export_by_value()` below.
+mod protocol {
+    pub use example_bindings::*;
+}
+
 fn init_panic_hook() {
     use std::sync::Once;
     static SET_HOOK: Once = Once::new();
@@ -90,12 +106,117 @@ fn export_primitive_u64(arg: u64) -> u64 {
     64
 }
 
+#[fp_export_impl(example_bindings)]
+fn export_primitive_char(arg: char) -> char {
+    assert_eq!(arg, 'a');
+    'z'
+}
+
+#[fp_export_impl(example_bindings)]
+fn export_primitive_i128(arg: i128) -> i128 {
+    assert_eq!(arg, -(1 << 100));
+    i128::MIN
+}
+
+#[fp_export_impl(example_bindings)]
+fn export_primitive_isize(arg: isize) -> isize {
+    assert_eq!(arg, -32);
+    -64
+}
+
+#[fp_export_impl(example_bindings)]
+fn export_primitive_u128(arg: u128) -> u128 {
+    assert_eq!(arg, 1 << 100);
+    u128::MAX
+}
+
+#[fp_export_impl(example_bindings)]
+fn export_primitive_usize(arg: usize) -> usize {
+    assert_eq!(arg, 32);
+    64
+}
+
+#[fp_export_impl(example_bindings)]
+fn export_struct_with_wide_primitives(arg: StructWithWidePrimitives) -> StructWithWidePrimitives {
+    assert_eq!(
+        arg,
+        StructWithWidePrimitives {
+            initial: 'a',
+            signed_large: -1,
+            unsigned_large: 1 << 64,
+            offset: -8,
+            length: 8,
+        }
+    );
+    StructWithWidePrimitives {
+        initial: 'z',
+        signed_large: i128::MIN,
+        unsigned_large: u128::MAX,
+        offset: 8,
+        length: 16,
+    }
+}
+
 #[fp_export_impl(example_bindings)]
 fn export_string(arg: String) -> String {
     assert_eq!(arg, "Hello, plugin!");
     "Hello, world!".to_owned()
 }
 
+#[fp_export_impl(example_bindings)]
+fn export_bytes(arg: Bytes) -> Bytes {
+    assert_eq!(arg, "hello");
+
+    // Let the host append to it as well, so we test both directions:
+    let result = import_bytes(arg);
+    [result.as_ref(), b", plugin"].concat().into()
+}
+
+#[fp_export_impl(example_bindings)]
+fn export_byte_buf(arg: ByteBuf) -> ByteBuf {
+    assert_eq!(arg.as_slice(), &[1, 2, 3]);
+    ByteBuf::from(arg.into_iter().rev().collect::<Vec<_>>())
+}
+
+// The fields of arguments that are passed by value are taken from the
+// generated `export.rs`, so implementations don't need any annotations:
+#[fp_export_impl(crate::protocol)]
+fn export_by_value(position: Position, frame: FrameInfo) -> Position {
+    assert_eq!(
+        frame,
+        FrameInfo {
+            frame_number: 42,
+            delta_ms: 16.5,
+            offset: -3,
+            is_paused: true,
+        }
+    );
+
+    // Let the host move it as well, so we test both directions:
+    let position = import_by_value(position, frame);
+    Position {
+        x: position.x + 1.0,
+        y: position.y + 1.0,
+    }
+}
+
+#[fp_export_impl(example_bindings)]
+fn export_borrowed(
+    text: String,
+    data: ByteBuf,
+    values: Vec<u32>,
+    position: Position,
+) -> String {
+    assert_eq!(text, "Hello, plugin!");
+    assert_eq!(data.as_slice(), &[1, 2, 3]);
+    assert_eq!(values, vec![4, 5, 6]);
+    assert_eq!(position, Position { x: 1.5, y: -2.0 });
+
+    // Pass borrowed arguments to the host as well, so we test both directions:
+    let reversed = data.iter().rev().copied().collect::<Vec<_>>();
+    import_borrowed("Hello, host!", &reversed, &values[1..], &position)
+}
+
 #[fp_export_impl(example_bindings)]
 fn export_multiple_primitives(arg1: i8, arg2: String) -> i64 {
     assert_eq!(arg1, -8);
@@ -109,6 +230,46 @@ fn export_timestamp(arg: OffsetDateTime) -> OffsetDateTime {
     datetime!(2022-04-13 12:37 UTC)
 }
 
+#[fp_export_impl(example_bindings)]
+fn export_chrono_types(arg: StructWithChronoTypes) -> StructWithChronoTypes {
+    assert_eq!(arg.utc.to_rfc3339(), "2022-04-12T19:10:00+00:00");
+    assert_eq!(arg.fixed_offset, arg.utc);
+    assert_eq!(arg.date_time, arg.utc.naive_utc());
+    assert_eq!(arg.date, arg.date_time.date());
+    assert_eq!(arg.delta, chrono::TimeDelta::new(90, 500).unwrap());
+
+    let delta = chrono::TimeDelta::days(1);
+    StructWithChronoTypes {
+        utc: arg.utc + delta,
+        fixed_offset: arg.fixed_offset + delta,
+        date: arg.date + delta,
+        date_time: arg.date_time + delta,
+        delta,
+    }
+}
+
+#[fp_export_impl(example_bindings)]
+fn export_durations(arg: StructWithDurations) -> StructWithDurations {
+    assert_eq!(arg.timeout, Duration::from_millis(1500));
+    assert_eq!(arg.interval, None);
+    assert_eq!(
+        arg.created_at,
+        SystemTime::UNIX_EPOCH + Duration::from_secs(1_649_790_600)
+    );
+
+    StructWithDurations {
+        timeout: arg.timeout * 2,
+        interval: Some(Duration::from_micros(250)),
+        created_at: arg.created_at + arg.timeout,
+    }
+}
+
+#[fp_export_impl(example_bindings)]
+fn export_uuid(arg: Uuid) -> Uuid {
+    assert_eq!(arg.get_version_num(), 4);
+    Uuid::from_u128(arg.as_u128().wrapping_add(1))
+}
+
 #[fp_export_impl(example_bindings)]
 fn export_fp_flatten(arg: FpFlatten) -> FpFlatten {
     assert_eq!(
@@ -230,6 +391,288 @@ fn export_serde_enum(arg: SerdeVariantRenaming) -> SerdeVariantRenaming {
     }
 }
 
+#[fp_export_impl(example_bindings)]
+fn export_kebab_case_struct(arg: KebabCasePropertyRenaming) -> KebabCasePropertyRenaming {
+    assert_eq!(
+        arg,
+        KebabCasePropertyRenaming {
+            foo_bar: "foo_bar".to_owned(),
+            raw_struct: -32
+        }
+    );
+    KebabCasePropertyRenaming {
+        foo_bar: "foo-bar".to_owned(),
+        raw_struct: 32,
+    }
+}
+
+#[fp_export_impl(example_bindings)]
+fn export_directional_enum(arg: DirectionalVariantRenaming) -> DirectionalVariantRenaming {
+    match arg {
+        DirectionalVariantRenaming::FooBar => DirectionalVariantRenaming::QuxBaz {
+            foo_bar: "foo_bar".to_owned(),
+        },
+        DirectionalVariantRenaming::QuxBaz { .. } => DirectionalVariantRenaming::FooBar,
+    }
+}
+
+#[fp_export_impl(example_bindings)]
+fn export_directional_struct(arg: DirectionalStructRenaming) -> DirectionalStructRenaming {
+    arg
+}
+
+#[fp_export_impl(example_bindings)]
+fn export_serde_attributes(arg: SerdeAttributes) -> SerdeAttributes {
+    assert_eq!(
+        arg,
+        SerdeAttributes {
+            title: "Hello, plugin!".to_owned(),
+            max_items: 5,
+            password: "secret".to_owned(),
+            label: Label {
+                value: "important".to_owned()
+            },
+            ..SerdeAttributes::default()
+        }
+    );
+    SerdeAttributes {
+        title: "Hello, host!".to_owned(),
+        max_items: 10,
+        password: "hidden".to_owned(),
+        revision: 2,
+        label: arg.label,
+        priority: Priority::High,
+        range: Range { start: 1, end: 3 },
+        email: "host@example.com".to_owned(),
+    }
+}
+
+#[fp_export_impl(example_bindings)]
+fn export_collections(arg: StructWithCollections) -> StructWithCollections {
+    assert_eq!(
+        arg,
+        StructWithCollections {
+            shared: Arc::new("shared".to_owned()),
+            label: Cow::Borrowed("label"),
+            queue: VecDeque::from([1, 2, 3]),
+            ordered: IndexMap::from([("b".to_owned(), 2), ("a".to_owned(), 1)]),
+        }
+    );
+
+    let mut queue = arg.queue;
+    queue.rotate_left(1);
+    let mut ordered = arg.ordered;
+    ordered.insert("c".to_owned(), 3);
+    StructWithCollections {
+        shared: Arc::new(format!("{} by plugin", arg.shared)),
+        label: Cow::Owned(arg.label.to_uppercase()),
+        queue,
+        ordered,
+    }
+}
+
+#[fp_export_impl(example_bindings)]
+fn export_binary_heap(arg: StructWithBinaryHeap) -> StructWithBinaryHeap {
+    let mut priorities = arg.priorities;
+    assert_eq!(priorities.peek(), Some(&3));
+    priorities.push(4);
+    StructWithBinaryHeap {
+        priorities: BinaryHeap::from(priorities.into_sorted_vec()),
+    }
+}
+
+#[fp_export_impl(example_bindings)]
+fn export_custom_types(arg: Appointment) -> Appointment {
+    assert_eq!(arg.date.weekday(), arg.weekday);
+    let date = arg.date.next_day().unwrap();
+    Appointment {
+        date,
+        weekday: date.weekday(),
+    }
+}
+
+#[fp_export_impl(example_bindings)]
+fn export_json(arg: StructWithJson) -> StructWithJson {
+    assert_eq!(arg.value["count"], json!(1));
+    assert!(arg.value["count"].is_u64());
+    assert_eq!(arg.value["ratio"], json!(0.5));
+    assert_eq!(arg.value["tags"], json!(["a", null, true]));
+    assert_eq!(arg.optional_value, None);
+
+    let mut object = arg.object;
+    object.insert("seen".to_owned(), json!(true));
+    StructWithJson {
+        value: json!({ "count": 2, "ratio": 1.5, "float": 3.0 }),
+        object,
+        optional_value: Some(json!(-7)),
+    }
+}
+
+#[fp_export_impl(example_bindings)]
+fn export_http_exchange(arg: HttpExchange) -> HttpExchange {
+    let HttpExchange {
+        mut request,
+        mut response,
+        content_type,
+    } = arg;
+    assert_eq!(request.method(), Method::PUT);
+    assert_eq!(request.uri(), "https://fiberplane.dev/notebooks/1");
+    assert_eq!(request.version(), ::http::Version::HTTP_11);
+    assert_eq!(request.headers()["accept"], "application/json");
+    assert_eq!(request.body().as_slice(), b"{}");
+    assert_eq!(response.status(), StatusCode::CREATED);
+    assert_eq!(response.version(), ::http::Version::HTTP_2);
+    assert_eq!(response.body().as_slice(), b"{}");
+    assert_eq!(content_type, "application/json");
+
+    request
+        .headers_mut()
+        .append("x-plugin", HeaderValue::from_static("example"));
+    response
+        .headers_mut()
+        .append("set-cookie", HeaderValue::from_static("b=2"));
+    *response.status_mut() = StatusCode::ACCEPTED;
+    HttpExchange {
+        request,
+        response,
+        content_type: HeaderValue::from_static("text/plain"),
+    }
+}
+
+#[fp_export_impl(example_bindings)]
+fn export_fetch(mut request: HttpRequest) -> Option<HttpResponse> {
+    assert_eq!(request.method(), Method::GET);
+
+    request
+        .headers_mut()
+        .insert("x-plugin", HeaderValue::from_static("example"));
+    let response = fetch(request);
+    if response.status() == StatusCode::NOT_FOUND {
+        None
+    } else {
+        Some(response)
+    }
+}
+
+#[fp_export_impl(example_bindings)]
+fn export_remote_type(arg: ActionMetaDef) -> ActionMetaDef {
+    ActionMeta {
+        sequence_number: arg.sequence_number + 1,
+        origin: format!("{} via plugin", arg.origin),
+    }
+}
+
+#[fp_export_impl(example_bindings)]
+fn export_repr_enum(arg: Level) -> Level {
+    assert_eq!(arg, Level::Debug);
+    Level::Info
+}
+
+#[fp_export_impl(example_bindings)]
+fn export_struct_with_repr_enums(arg: StructWithReprEnums) -> StructWithReprEnums {
+    assert_eq!(
+        arg,
+        StructWithReprEnums {
+            level: Level::Warning,
+            history: vec![Level::Debug, Level::Info],
+        }
+    );
+    StructWithReprEnums {
+        level: Level::Error,
+        history: vec![Level::Info, Level::Debug],
+    }
+}
+
+#[fp_export_impl(example_bindings)]
+fn export_unknown_variant(arg: Shape) -> Shape {
+    match arg {
+        Shape::Point => Shape::Circle { radius: 0.0 },
+        // Variants we don't know about are passed back unchanged:
+        other => other,
+    }
+}
+
+#[fp_export_impl(example_bindings)]
+fn export_tagged_unknown_variant(arg: TaggedShape) -> TaggedShape {
+    assert!(matches!(arg, TaggedShape::Unknown(_)));
+    arg
+}
+
+#[fp_export_impl(example_bindings)]
+fn export_array(arg: [u32; 3]) -> [u32; 3] {
+    assert_eq!(arg, [1, 2, 3]);
+    [3, 2, 1]
+}
+
+#[fp_export_impl(example_bindings)]
+fn export_struct_with_arrays(arg: StructWithArrays) -> StructWithArrays {
+    assert_eq!(
+        arg,
+        StructWithArrays {
+            checksum: [0xde, 0xad, 0xbe, 0xef],
+            coordinates: [1.0, 2.0, 3.0],
+            segments: vec![[Point { value: 0.0 }, Point { value: 1.0 }]],
+        }
+    );
+    StructWithArrays {
+        checksum: [0xca, 0xfe, 0xba, 0xbe],
+        coordinates: [-1.0, -2.0, -3.0],
+        segments: vec![[Point { value: 1.0 }, Point { value: 0.0 }]],
+    }
+}
+
+#[fp_export_impl(example_bindings)]
+fn export_tuple(arg: (u32, String)) -> (String, u32) {
+    assert_eq!(arg, (32, "Hello, plugin!".to_owned()));
+    ("Hello, host!".to_owned(), 64)
+}
+
+#[fp_export_impl(example_bindings)]
+fn export_struct_with_tuples(arg: StructWithTuples) -> StructWithTuples {
+    assert_eq!(
+        arg,
+        StructWithTuples {
+            pair: (-1, 1),
+            nested: vec![("foo".to_owned(), (8, true))],
+            single: ("bar".to_owned(),),
+        }
+    );
+    StructWithTuples {
+        pair: (1, -1),
+        nested: vec![("baz".to_owned(), (16, false))],
+        single: ("qux".to_owned(),),
+    }
+}
+
+#[fp_export_impl(example_bindings)]
+fn export_newtype(arg: UserId) -> Meters {
+    assert_eq!(arg, UserId("user-1".to_owned()));
+    Meters(42.5)
+}
+
+#[fp_export_impl(example_bindings)]
+fn export_struct_with_tuple_structs(arg: StructWithTupleStructs) -> StructWithTupleStructs {
+    assert_eq!(
+        arg,
+        StructWithTupleStructs {
+            user_id: UserId("user-1".to_owned()),
+            optional_user_id: None,
+            distance: Meters(1.5),
+            wrapped: Wrapper(32),
+            color: Rgb(255, 128, 0),
+            marker: Marker,
+        }
+    );
+    StructWithTupleStructs {
+        user_id: UserId("user-2".to_owned()),
+        optional_user_id: Some(UserId("user-1".to_owned())),
+        distance: Meters(-1.5),
+        wrapped: Wrapper(64),
+        color: Rgb(0, 128, 255),
+        marker: Marker,
+    }
+}
+
 #[fp_export_impl(example_bindings)]
 fn export_fp_internally_tagged(arg: FpInternallyTagged) -> FpInternallyTagged {
     assert_eq!(arg, FpInternallyTagged::Foo);
@@ -289,7 +732,11 @@ async fn fetch_data(r#type: String) -> Result<String, String> {
     let result = make_http_request(Request {
         url: Uri::from_static("https://fiberplane.dev"),
         method: Method::POST,
-        headers: HashMap::from([("Content-Type".to_owned(), "application/json".to_owned())]),
+        headers: {
+            let mut headers = HeaderMap::new();
+            headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+            headers
+        },
         body: Some(ByteBuf::from(format!(
             r#"{{"country":"🇳🇱","type":"{}"}}"#,
             r#type
@@ -0,0 +1,996 @@
+// ============================================= //
+// Exported functions for AssemblyScript plugins //
+//                                               //
+// This file is generated. PLEASE DO NOT MODIFY. //
+// ============================================= //
+//
+// Plugins provide their implementation by calling the `implement*()` function
+// for every function they wish to export, and by re-exporting the matching
+// `__fp_gen_*()` wrapper from their entry file.
+
+import {
+    FatPtr,
+    exportBinaryToHost,
+    exportToHost,
+    importBinaryFromHost,
+    importFromHost,
+} from "./fp";
+import {
+    ActionMetaDef,
+    Appointment,
+    DirectionalStructRenaming,
+    DirectionalVariantRenaming,
+    FpAdjacentlyTagged,
+    FpFlatten,
+    FpInternallyTagged,
+    FpPropertyRenaming,
+    FpUntagged,
+    FpVariantRenaming,
+    FrameInfo,
+    HttpExchange,
+    KebabCasePropertyRenaming,
+    Level,
+    Meters,
+    Position,
+    ReduxAction,
+    RequestByteBuf,
+    SerdeAdjacentlyTagged,
+    SerdeAttributes,
+    SerdeFlatten,
+    SerdeInternallyTagged,
+    SerdePropertyRenaming,
+    SerdeUntagged,
+    SerdeVariantRenaming,
+    Shape,
+    StateUpdate,
+    StructWithArrays,
+    StructWithBinaryHeap,
+    StructWithChronoTypes,
+    StructWithCollections,
+    StructWithDurations,
+    StructWithGenericsU64,
+    StructWithJson,
+    StructWithReprEnums,
+    StructWithTupleStructs,
+    StructWithTuples,
+    StructWithWidePrimitives,
+    TaggedShape,
+    TupleStringU32,
+    TupleU32String,
+    UserId,
+    decodeActionMetaDef,
+    decodeAppointment,
+    decodeArray3U32,
+    decodeChar,
+    decodeDirectionalStructRenaming,
+    decodeDirectionalVariantRenaming,
+    decodeFpAdjacentlyTagged,
+    decodeFpFlatten,
+    decodeFpInternallyTagged,
+    decodeFpPropertyRenaming,
+    decodeFpUntagged,
+    decodeFpVariantRenaming,
+    decodeHttpExchange,
+    decodeI128,
+    decodeIsize,
+    decodeKebabCasePropertyRenaming,
+    decodeLevel,
+    decodeOffsetDateTime,
+    decodeReduxAction,
+    decodeRequestByteBuf,
+    decodeSerdeAdjacentlyTagged,
+    decodeSerdeAttributes,
+    decodeSerdeFlatten,
+    decodeSerdeInternallyTagged,
+    decodeSerdePropertyRenaming,
+    decodeSerdeUntagged,
+    decodeSerdeVariantRenaming,
+    decodeShape,
+    decodeString,
+    decodeStructWithArrays,
+    decodeStructWithBinaryHeap,
+    decodeStructWithChronoTypes,
+    decodeStructWithCollections,
+    decodeStructWithDurations,
+    decodeStructWithGenericsU64,
+    decodeStructWithJson,
+    decodeStructWithReprEnums,
+    decodeStructWithTupleStructs,
+    decodeStructWithTuples,
+    decodeStructWithWidePrimitives,
+    decodeTaggedShape,
+    decodeTupleU32String,
+    decodeU128,
+    decodeUserId,
+    decodeUsize,
+    decodeUuid,
+    decodeVecU32,
+    encodeActionMetaDef,
+    encodeAppointment,
+    encodeArray3U32,
+    encodeChar,
+    encodeDirectionalStructRenaming,
+    encodeDirectionalVariantRenaming,
+    encodeFpAdjacentlyTagged,
+    encodeFpFlatten,
+    encodeFpInternallyTagged,
+    encodeFpPropertyRenaming,
+    encodeFpUntagged,
+    encodeFpVariantRenaming,
+    encodeHttpExchange,
+    encodeI128,
+    encodeIsize,
+    encodeKebabCasePropertyRenaming,
+    encodeLevel,
+    encodeMeters,
+    encodeOffsetDateTime,
+    encodeOptionResponseByteBuf,
+    encodePosition,
+    encodeSerdeAdjacentlyTagged,
+    encodeSerdeAttributes,
+    encodeSerdeFlatten,
+    encodeSerdeInternallyTagged,
+    encodeSerdePropertyRenaming,
+    encodeSerdeUntagged,
+    encodeSerdeVariantRenaming,
+    encodeShape,
+    encodeStateUpdate,
+    encodeString,
+    encodeStructWithArrays,
+    encodeStructWithBinaryHeap,
+    encodeStructWithChronoTypes,
+    encodeStructWithCollections,
+    encodeStructWithDurations,
+    encodeStructWithGenericsU64,
+    encodeStructWithJson,
+    encodeStructWithReprEnums,
+    encodeStructWithTupleStructs,
+    encodeStructWithTuples,
+    encodeStructWithWidePrimitives,
+    encodeTaggedShape,
+    encodeTupleStringU32,
+    encodeU128,
+    encodeUsize,
+    encodeUuid,
+} from "./types";
+
+let exportArrayImpl: (arg: StaticArray<u32>) => StaticArray<u32> = (_arg: StaticArray<u32>): StaticArray<u32> => {
+    throw new Error("Exported function \"export_array\" has not been implemented");
+};
+
+export function implementExportArray(impl: (arg: StaticArray<u32>) => StaticArray<u32>): void {
+    exportArrayImpl = impl;
+}
+
+export function __fp_gen_export_array(arg_ptr: FatPtr): FatPtr {
+    const arg = importFromHost(arg_ptr, decodeArray3U32);
+    return exportToHost(exportArrayImpl(arg), encodeArray3U32);
+}
+
+// Async function `export_async_struct` is not supported by AssemblyScript plugins.
+
+let exportBinaryHeapImpl: (arg: StructWithBinaryHeap) => StructWithBinaryHeap = (_arg: StructWithBinaryHeap): StructWithBinaryHeap => {
+    throw new Error("Exported function \"export_binary_heap\" has not been implemented");
+};
+
+export function implementExportBinaryHeap(impl: (arg: StructWithBinaryHeap) => StructWithBinaryHeap): void {
+    exportBinaryHeapImpl = impl;
+}
+
+export function __fp_gen_export_binary_heap(arg_ptr: FatPtr): FatPtr {
+    const arg = importFromHost(arg_ptr, decodeStructWithBinaryHeap);
+    return exportToHost(exportBinaryHeapImpl(arg), encodeStructWithBinaryHeap);
+}
+
+let exportBorrowedImpl: (text: string, data: ArrayBuffer, values: Array<u32>, position: Position) => string = (_text: string, _data: ArrayBuffer, _values: Array<u32>, _position: Position): string => {
+    throw new Error("Exported function \"export_borrowed\" has not been implemented");
+};
+
+export function implementExportBorrowed(impl: (text: string, data: ArrayBuffer, values: Array<u32>, position: Position) => string): void {
+    exportBorrowedImpl = impl;
+}
+
+export function __fp_gen_export_borrowed(text_ptr: FatPtr, data_ptr: FatPtr, values_ptr: FatPtr, position_x: f64, position_y: f64): FatPtr {
+    const text = importFromHost(text_ptr, decodeString);
+    const data = importBinaryFromHost(data_ptr);
+    const values = importFromHost(values_ptr, decodeVecU32);
+    const position = new Position();
+    position.x = position_x;
+    position.y = position_y;
+    return exportToHost(exportBorrowedImpl(text, data, values, position), encodeString);
+}
+
+let exportByValueImpl: (position: Position, frame: FrameInfo) => Position = (_position: Position, _frame: FrameInfo): Position => {
+    throw new Error("Exported function \"export_by_value\" has not been implemented");
+};
+
+export function implementExportByValue(impl: (position: Position, frame: FrameInfo) => Position): void {
+    exportByValueImpl = impl;
+}
+
+export function __fp_gen_export_by_value(position_x: f64, position_y: f64, frame_frame_number: u64, frame_delta_ms: f32, frame_offset: i32, frame_is_paused: bool): FatPtr {
+    const position = new Position();
+    position.x = position_x;
+    position.y = position_y;
+    const frame = new FrameInfo();
+    frame.frameNumber = frame_frame_number;
+    frame.deltaMs = frame_delta_ms;
+    frame.offset = frame_offset;
+    frame.isPaused = frame_is_paused;
+    return exportToHost(exportByValueImpl(position, frame), encodePosition);
+}
+
+let exportByteBufImpl: (arg: ArrayBuffer) => ArrayBuffer = (_arg: ArrayBuffer): ArrayBuffer => {
+    throw new Error("Exported function \"export_byte_buf\" has not been implemented");
+};
+
+export function implementExportByteBuf(impl: (arg: ArrayBuffer) => ArrayBuffer): void {
+    exportByteBufImpl = impl;
+}
+
+export function __fp_gen_export_byte_buf(arg_ptr: FatPtr): FatPtr {
+    const arg = importBinaryFromHost(arg_ptr);
+    return exportBinaryToHost(exportByteBufImpl(arg));
+}
+
+let exportBytesImpl: (arg: ArrayBuffer) => ArrayBuffer = (_arg: ArrayBuffer): ArrayBuffer => {
+    throw new Error("Exported function \"export_bytes\" has not been implemented");
+};
+
+export function implementExportBytes(impl: (arg: ArrayBuffer) => ArrayBuffer): void {
+    exportBytesImpl = impl;
+}
+
+export function __fp_gen_export_bytes(arg_ptr: FatPtr): FatPtr {
+    const arg = importBinaryFromHost(arg_ptr);
+    return exportBinaryToHost(exportBytesImpl(arg));
+}
+
+let exportChronoTypesImpl: (arg: StructWithChronoTypes) => StructWithChronoTypes = (_arg: StructWithChronoTypes): StructWithChronoTypes => {
+    throw new Error("Exported function \"export_chrono_types\" has not been implemented");
+};
+
+export function implementExportChronoTypes(impl: (arg: StructWithChronoTypes) => StructWithChronoTypes): void {
+    exportChronoTypesImpl = impl;
+}
+
+export function __fp_gen_export_chrono_types(arg_ptr: FatPtr): FatPtr {
+    const arg = importFromHost(arg_ptr, decodeStructWithChronoTypes);
+    return exportToHost(exportChronoTypesImpl(arg), encodeStructWithChronoTypes);
+}
+
+let exportCollectionsImpl: (arg: StructWithCollections) => StructWithCollections = (_arg: StructWithCollections): StructWithCollections => {
+    throw new Error("Exported function \"export_collections\" has not been implemented");
+};
+
+export function implementExportCollections(impl: (arg: StructWithCollections) => StructWithCollections): void {
+    exportCollectionsImpl = impl;
+}
+
+export function __fp_gen_export_collections(arg_ptr: FatPtr): FatPtr {
+    const arg = importFromHost(arg_ptr, decodeStructWithCollections);
+    return exportToHost(exportCollectionsImpl(arg), encodeStructWithCollections);
+}
+
+let exportCustomTypesImpl: (arg: Appointment) => Appointment = (_arg: Appointment): Appointment => {
+    throw new Error("Exported function \"export_custom_types\" has not been implemented");
+};
+
+export function implementExportCustomTypes(impl: (arg: Appointment) => Appointment): void {
+    exportCustomTypesImpl = impl;
+}
+
+export function __fp_gen_export_custom_types(arg_ptr: FatPtr): FatPtr {
+    const arg = importFromHost(arg_ptr, decodeAppointment);
+    return exportToHost(exportCustomTypesImpl(arg), encodeAppointment);
+}
+
+let exportDirectionalEnumImpl: (arg: DirectionalVariantRenaming) => DirectionalVariantRenaming = (_arg: DirectionalVariantRenaming): DirectionalVariantRenaming => {
+    throw new Error("Exported function \"export_directional_enum\" has not been implemented");
+};
+
+export function implementExportDirectionalEnum(impl: (arg: DirectionalVariantRenaming) => DirectionalVariantRenaming): void {
+    exportDirectionalEnumImpl = impl;
+}
+
+export function __fp_gen_export_directional_enum(arg_ptr: FatPtr): FatPtr {
+    const arg = importFromHost(arg_ptr, decodeDirectionalVariantRenaming);
+    return exportToHost(exportDirectionalEnumImpl(arg), encodeDirectionalVariantRenaming);
+}
+
+let exportDirectionalStructImpl: (arg: DirectionalStructRenaming) => DirectionalStructRenaming = (_arg: DirectionalStructRenaming): DirectionalStructRenaming => {
+    throw new Error("Exported function \"export_directional_struct\" has not been implemented");
+};
+
+export function implementExportDirectionalStruct(impl: (arg: DirectionalStructRenaming) => DirectionalStructRenaming): void {
+    exportDirectionalStructImpl = impl;
+}
+
+export function __fp_gen_export_directional_struct(arg_ptr: FatPtr): FatPtr {
+    const arg = importFromHost(arg_ptr, decodeDirectionalStructRenaming);
+    return exportToHost(exportDirectionalStructImpl(arg), encodeDirectionalStructRenaming);
+}
+
+let exportDurationsImpl: (arg: StructWithDurations) => StructWithDurations = (_arg: StructWithDurations): StructWithDurations => {
+    throw new Error("Exported function \"export_durations\" has not been implemented");
+};
+
+export function implementExportDurations(impl: (arg: StructWithDurations) => StructWithDurations): void {
+    exportDurationsImpl = impl;
+}
+
+export function __fp_gen_export_durations(arg_ptr: FatPtr): FatPtr {
+    const arg = importFromHost(arg_ptr, decodeStructWithDurations);
+    return exportToHost(exportDurationsImpl(arg), encodeStructWithDurations);
+}
+
+let exportFetchImpl: (request: RequestByteBuf) => ResponseByteBuf | null = (_request: RequestByteBuf): ResponseByteBuf | null => {
+    throw new Error("Exported function \"export_fetch\" has not been implemented");
+};
+
+export function implementExportFetch(impl: (request: RequestByteBuf) => ResponseByteBuf | null): void {
+    exportFetchImpl = impl;
+}
+
+export function __fp_gen_export_fetch(request_ptr: FatPtr): FatPtr {
+    const request = importFromHost(request_ptr, decodeRequestByteBuf);
+    return exportToHost(exportFetchImpl(request), encodeOptionResponseByteBuf);
+}
+
+let exportFpAdjacentlyTaggedImpl: (arg: FpAdjacentlyTagged) => FpAdjacentlyTagged = (_arg: FpAdjacentlyTagged): FpAdjacentlyTagged => {
+    throw new Error("Exported function \"export_fp_adjacently_tagged\" has not been implemented");
+};
+
+export function implementExportFpAdjacentlyTagged(impl: (arg: FpAdjacentlyTagged) => FpAdjacentlyTagged): void {
+    exportFpAdjacentlyTaggedImpl = impl;
+}
+
+export function __fp_gen_export_fp_adjacently_tagged(arg_ptr: FatPtr): FatPtr {
+    const arg = importFromHost(arg_ptr, decodeFpAdjacentlyTagged);
+    return exportToHost(exportFpAdjacentlyTaggedImpl(arg), encodeFpAdjacentlyTagged);
+}
+
+let exportFpEnumImpl: (arg: FpVariantRenaming) => FpVariantRenaming = (_arg: FpVariantRenaming): FpVariantRenaming => {
+    throw new Error("Exported function \"export_fp_enum\" has not been implemented");
+};
+
+export function implementExportFpEnum(impl: (arg: FpVariantRenaming) => FpVariantRenaming): void {
+    exportFpEnumImpl = impl;
+}
+
+export function __fp_gen_export_fp_enum(arg_ptr: FatPtr): FatPtr {
+    const arg = importFromHost(arg_ptr, decodeFpVariantRenaming);
+    return exportToHost(exportFpEnumImpl(arg), encodeFpVariantRenaming);
+}
+
+let exportFpFlattenImpl: (arg: FpFlatten) => FpFlatten = (_arg: FpFlatten): FpFlatten => {
+    throw new Error("Exported function \"export_fp_flatten\" has not been implemented");
+};
+
+export function implementExportFpFlatten(impl: (arg: FpFlatten) => FpFlatten): void {
+    exportFpFlattenImpl = impl;
+}
+
+export function __fp_gen_export_fp_flatten(arg_ptr: FatPtr): FatPtr {
+    const arg = importFromHost(arg_ptr, decodeFpFlatten);
+    return exportToHost(exportFpFlattenImpl(arg), encodeFpFlatten);
+}
+
+let exportFpInternallyTaggedImpl: (arg: FpInternallyTagged) => FpInternallyTagged = (_arg: FpInternallyTagged): FpInternallyTagged => {
+    throw new Error("Exported function \"export_fp_internally_tagged\" has not been implemented");
+};
+
+export function implementExportFpInternallyTagged(impl: (arg: FpInternallyTagged) => FpInternallyTagged): void {
+    exportFpInternallyTaggedImpl = impl;
+}
+
+export function __fp_gen_export_fp_internally_tagged(arg_ptr: FatPtr): FatPtr {
+    const arg = importFromHost(arg_ptr, decodeFpInternallyTagged);
+    return exportToHost(exportFpInternallyTaggedImpl(arg), encodeFpInternallyTagged);
+}
+
+let exportFpStructImpl: (arg: FpPropertyRenaming) => FpPropertyRenaming = (_arg: FpPropertyRenaming): FpPropertyRenaming => {
+    throw new Error("Exported function \"export_fp_struct\" has not been implemented");
+};
+
+export function implementExportFpStruct(impl: (arg: FpPropertyRenaming) => FpPropertyRenaming): void {
+    exportFpStructImpl = impl;
+}
+
+export function __fp_gen_export_fp_struct(arg_ptr: FatPtr): FatPtr {
+    const arg = importFromHost(arg_ptr, decodeFpPropertyRenaming);
+    return exportToHost(exportFpStructImpl(arg), encodeFpPropertyRenaming);
+}
+
+let exportFpUntaggedImpl: (arg: FpUntagged) => FpUntagged = (_arg: FpUntagged): FpUntagged => {
+    throw new Error("Exported function \"export_fp_untagged\" has not been implemented");
+};
+
+export function implementExportFpUntagged(impl: (arg: FpUntagged) => FpUntagged): void {
+    exportFpUntaggedImpl = impl;
+}
+
+export function __fp_gen_export_fp_untagged(arg_ptr: FatPtr): FatPtr {
+    const arg = importFromHost(arg_ptr, decodeFpUntagged);
+    return exportToHost(exportFpUntaggedImpl(arg), encodeFpUntagged);
+}
+
+let exportGenericsImpl: (arg: StructWithGenericsU64) => StructWithGenericsU64 = (_arg: StructWithGenericsU64): StructWithGenericsU64 => {
+    throw new Error("Exported function \"export_generics\" has not been implemented");
+};
+
+export function implementExportGenerics(impl: (arg: StructWithGenericsU64) => StructWithGenericsU64): void {
+    exportGenericsImpl = impl;
+}
+
+export function __fp_gen_export_generics(arg_ptr: FatPtr): FatPtr {
+    const arg = importFromHost(arg_ptr, decodeStructWithGenericsU64);
+    return exportToHost(exportGenericsImpl(arg), encodeStructWithGenericsU64);
+}
+
+let exportHttpExchangeImpl: (arg: HttpExchange) => HttpExchange = (_arg: HttpExchange): HttpExchange => {
+    throw new Error("Exported function \"export_http_exchange\" has not been implemented");
+};
+
+export function implementExportHttpExchange(impl: (arg: HttpExchange) => HttpExchange): void {
+    exportHttpExchangeImpl = impl;
+}
+
+export function __fp_gen_export_http_exchange(arg_ptr: FatPtr): FatPtr {
+    const arg = importFromHost(arg_ptr, decodeHttpExchange);
+    return exportToHost(exportHttpExchangeImpl(arg), encodeHttpExchange);
+}
+
+let exportJsonImpl: (arg: StructWithJson) => StructWithJson = (_arg: StructWithJson): StructWithJson => {
+    throw new Error("Exported function \"export_json\" has not been implemented");
+};
+
+export function implementExportJson(impl: (arg: StructWithJson) => StructWithJson): void {
+    exportJsonImpl = impl;
+}
+
+export function __fp_gen_export_json(arg_ptr: FatPtr): FatPtr {
+    const arg = importFromHost(arg_ptr, decodeStructWithJson);
+    return exportToHost(exportJsonImpl(arg), encodeStructWithJson);
+}
+
+let exportKebabCaseStructImpl: (arg: KebabCasePropertyRenaming) => KebabCasePropertyRenaming = (_arg: KebabCasePropertyRenaming): KebabCasePropertyRenaming => {
+    throw new Error("Exported function \"export_kebab_case_struct\" has not been implemented");
+};
+
+export function implementExportKebabCaseStruct(impl: (arg: KebabCasePropertyRenaming) => KebabCasePropertyRenaming): void {
+    exportKebabCaseStructImpl = impl;
+}
+
+export function __fp_gen_export_kebab_case_struct(arg_ptr: FatPtr): FatPtr {
+    const arg = importFromHost(arg_ptr, decodeKebabCasePropertyRenaming);
+    return exportToHost(exportKebabCaseStructImpl(arg), encodeKebabCasePropertyRenaming);
+}
+
+let exportMultiplePrimitivesImpl: (arg1: i8, arg2: string) => i64 = (_arg1: i8, _arg2: string): i64 => {
+    throw new Error("Exported function \"export_multiple_primitives\" has not been implemented");
+};
+
+export function implementExportMultiplePrimitives(impl: (arg1: i8, arg2: string) => i64): void {
+    exportMultiplePrimitivesImpl = impl;
+}
+
+export function __fp_gen_export_multiple_primitives(arg1: i8, arg2_ptr: FatPtr): i64 {
+    const arg2 = importFromHost(arg2_ptr, decodeString);
+    return exportMultiplePrimitivesImpl(arg1, arg2);
+}
+
+let exportNewtypeImpl: (arg: UserId) => Meters = (_arg: UserId): Meters => {
+    throw new Error("Exported function \"export_newtype\" has not been implemented");
+};
+
+export function implementExportNewtype(impl: (arg: UserId) => Meters): void {
+    exportNewtypeImpl = impl;
+}
+
+export function __fp_gen_export_newtype(arg_ptr: FatPtr): FatPtr {
+    const arg = importFromHost(arg_ptr, decodeUserId);
+    return exportToHost(exportNewtypeImpl(arg), encodeMeters);
+}
+
+let exportPrimitiveBoolImpl: (arg: bool) => bool = (_arg: bool): bool => {
+    throw new Error("Exported function \"export_primitive_bool\" has not been implemented");
+};
+
+export function implementExportPrimitiveBool(impl: (arg: bool) => bool): void {
+    exportPrimitiveBoolImpl = impl;
+}
+
+export function __fp_gen_export_primitive_bool(arg: bool): bool {
+    return exportPrimitiveBoolImpl(arg);
+}
+
+let exportPrimitiveCharImpl: (arg: string) => string = (_arg: string): string => {
+    throw new Error("Exported function \"export_primitive_char\" has not been implemented");
+};
+
+export function implementExportPrimitiveChar(impl: (arg: string) => string): void {
+    exportPrimitiveCharImpl = impl;
+}
+
+export function __fp_gen_export_primitive_char(arg_ptr: FatPtr): FatPtr {
+    const arg = importFromHost(arg_ptr, decodeChar);
+    return exportToHost(exportPrimitiveCharImpl(arg), encodeChar);
+}
+
+let exportPrimitiveF32Impl: (arg: f32) => f32 = (_arg: f32): f32 => {
+    throw new Error("Exported function \"export_primitive_f32\" has not been implemented");
+};
+
+export function implementExportPrimitiveF32(impl: (arg: f32) => f32): void {
+    exportPrimitiveF32Impl = impl;
+}
+
+export function __fp_gen_export_primitive_f32(arg: f32): f32 {
+    return exportPrimitiveF32Impl(arg);
+}
+
+let exportPrimitiveF64Impl: (arg: f64) => f64 = (_arg: f64): f64 => {
+    throw new Error("Exported function \"export_primitive_f64\" has not been implemented");
+};
+
+export function implementExportPrimitiveF64(impl: (arg: f64) => f64): void {
+    exportPrimitiveF64Impl = impl;
+}
+
+export function __fp_gen_export_primitive_f64(arg: f64): f64 {
+    return exportPrimitiveF64Impl(arg);
+}
+
+let exportPrimitiveI128Impl: (arg: ArrayBuffer) => ArrayBuffer = (_arg: ArrayBuffer): ArrayBuffer => {
+    throw new Error("Exported function \"export_primitive_i128\" has not been implemented");
+};
+
+export function implementExportPrimitiveI128(impl: (arg: ArrayBuffer) => ArrayBuffer): void {
+    exportPrimitiveI128Impl = impl;
+}
+
+export function __fp_gen_export_primitive_i128(arg_ptr: FatPtr): FatPtr {
+    const arg = importFromHost(arg_ptr, decodeI128);
+    return exportToHost(exportPrimitiveI128Impl(arg), encodeI128);
+}
+
+let exportPrimitiveI16Impl: (arg: i16) => i16 = (_arg: i16): i16 => {
+    throw new Error("Exported function \"export_primitive_i16\" has not been implemented");
+};
+
+export function implementExportPrimitiveI16(impl: (arg: i16) => i16): void {
+    exportPrimitiveI16Impl = impl;
+}
+
+export function __fp_gen_export_primitive_i16(arg: i16): i16 {
+    return exportPrimitiveI16Impl(arg);
+}
+
+let exportPrimitiveI32Impl: (arg: i32) => i32 = (_arg: i32): i32 => {
+    throw new Error("Exported function \"export_primitive_i32\" has not been implemented");
+};
+
+export function implementExportPrimitiveI32(impl: (arg: i32) => i32): void {
+    exportPrimitiveI32Impl = impl;
+}
+
+export function __fp_gen_export_primitive_i32(arg: i32): i32 {
+    return exportPrimitiveI32Impl(arg);
+}
+
+let exportPrimitiveI64Impl: (arg: i64) => i64 = (_arg: i64): i64 => {
+    throw new Error("Exported function \"export_primitive_i64\" has not been implemented");
+};
+
+export function implementExportPrimitiveI64(impl: (arg: i64) => i64): void {
+    exportPrimitiveI64Impl = impl;
+}
+
+export function __fp_gen_export_primitive_i64(arg: i64): i64 {
+    return exportPrimitiveI64Impl(arg);
+}
+
+let exportPrimitiveI8Impl: (arg: i8) => i8 = (_arg: i8): i8 => {
+    throw new Error("Exported function \"export_primitive_i8\" has not been implemented");
+};
+
+export function implementExportPrimitiveI8(impl: (arg: i8) => i8): void {
+    exportPrimitiveI8Impl = impl;
+}
+
+export function __fp_gen_export_primitive_i8(arg: i8): i8 {
+    return exportPrimitiveI8Impl(arg);
+}
+
+let exportPrimitiveIsizeImpl: (arg: i64) => i64 = (_arg: i64): i64 => {
+    throw new Error("Exported function \"export_primitive_isize\" has not been implemented");
+};
+
+export function implementExportPrimitiveIsize(impl: (arg: i64) => i64): void {
+    exportPrimitiveIsizeImpl = impl;
+}
+
+export function __fp_gen_export_primitive_isize(arg_ptr: FatPtr): FatPtr {
+    const arg = importFromHost(arg_ptr, decodeIsize);
+    return exportToHost(exportPrimitiveIsizeImpl(arg), encodeIsize);
+}
+
+let exportPrimitiveU128Impl: (arg: ArrayBuffer) => ArrayBuffer = (_arg: ArrayBuffer): ArrayBuffer => {
+    throw new Error("Exported function \"export_primitive_u128\" has not been implemented");
+};
+
+export function implementExportPrimitiveU128(impl: (arg: ArrayBuffer) => ArrayBuffer): void {
+    exportPrimitiveU128Impl = impl;
+}
+
+export function __fp_gen_export_primitive_u128(arg_ptr: FatPtr): FatPtr {
+    const arg = importFromHost(arg_ptr, decodeU128);
+    return exportToHost(exportPrimitiveU128Impl(arg), encodeU128);
+}
+
+let exportPrimitiveU16Impl: (arg: u16) => u16 = (_arg: u16): u16 => {
+    throw new Error("Exported function \"export_primitive_u16\" has not been implemented");
+};
+
+export function implementExportPrimitiveU16(impl: (arg: u16) => u16): void {
+    exportPrimitiveU16Impl = impl;
+}
+
+export function __fp_gen_export_primitive_u16(arg: u16): u16 {
+    return exportPrimitiveU16Impl(arg);
+}
+
+let exportPrimitiveU32Impl: (arg: u32) => u32 = (_arg: u32): u32 => {
+    throw new Error("Exported function \"export_primitive_u32\" has not been implemented");
+};
+
+export function implementExportPrimitiveU32(impl: (arg: u32) => u32): void {
+    exportPrimitiveU32Impl = impl;
+}
+
+export function __fp_gen_export_primitive_u32(arg: u32): u32 {
+    return exportPrimitiveU32Impl(arg);
+}
+
+let exportPrimitiveU64Impl: (arg: u64) => u64 = (_arg: u64): u64 => {
+    throw new Error("Exported function \"export_primitive_u64\" has not been implemented");
+};
+
+export function implementExportPrimitiveU64(impl: (arg: u64) => u64): void {
+    exportPrimitiveU64Impl = impl;
+}
+
+export function __fp_gen_export_primitive_u64(arg: u64): u64 {
+    return exportPrimitiveU64Impl(arg);
+}
+
+let exportPrimitiveU8Impl: (arg: u8) => u8 = (_arg: u8): u8 => {
+    throw new Error("Exported function \"export_primitive_u8\" has not been implemented");
+};
+
+export function implementExportPrimitiveU8(impl: (arg: u8) => u8): void {
+    exportPrimitiveU8Impl = impl;
+}
+
+export function __fp_gen_export_primitive_u8(arg: u8): u8 {
+    return exportPrimitiveU8Impl(arg);
+}
+
+let exportPrimitiveUsizeImpl: (arg: u64) => u64 = (_arg: u64): u64 => {
+    throw new Error("Exported function \"export_primitive_usize\" has not been implemented");
+};
+
+export function implementExportPrimitiveUsize(impl: (arg: u64) => u64): void {
+    exportPrimitiveUsizeImpl = impl;
+}
+
+export function __fp_gen_export_primitive_usize(arg_ptr: FatPtr): FatPtr {
+    const arg = importFromHost(arg_ptr, decodeUsize);
+    return exportToHost(exportPrimitiveUsizeImpl(arg), encodeUsize);
+}
+
+let exportRemoteTypeImpl: (arg: ActionMetaDef) => ActionMetaDef = (_arg: ActionMetaDef): ActionMetaDef => {
+    throw new Error("Exported function \"export_remote_type\" has not been implemented");
+};
+
+export function implementExportRemoteType(impl: (arg: ActionMetaDef) => ActionMetaDef): void {
+    exportRemoteTypeImpl = impl;
+}
+
+export function __fp_gen_export_remote_type(arg_ptr: FatPtr): FatPtr {
+    const arg = importFromHost(arg_ptr, decodeActionMetaDef);
+    return exportToHost(exportRemoteTypeImpl(arg), encodeActionMetaDef);
+}
+
+let exportReprEnumImpl: (arg: Level) => Level = (_arg: Level): Level => {
+    throw new Error("Exported function \"export_repr_enum\" has not been implemented");
+};
+
+export function implementExportReprEnum(impl: (arg: Level) => Level): void {
+    exportReprEnumImpl = impl;
+}
+
+export function __fp_gen_export_repr_enum(arg_ptr: FatPtr): FatPtr {
+    const arg = importFromHost(arg_ptr, decodeLevel);
+    return exportToHost(exportReprEnumImpl(arg), encodeLevel);
+}
+
+let exportSerdeAdjacentlyTaggedImpl: (arg: SerdeAdjacentlyTagged) => SerdeAdjacentlyTagged = (_arg: SerdeAdjacentlyTagged): SerdeAdjacentlyTagged => {
+    throw new Error("Exported function \"export_serde_adjacently_tagged\" has not been implemented");
+};
+
+export function implementExportSerdeAdjacentlyTagged(impl: (arg: SerdeAdjacentlyTagged) => SerdeAdjacentlyTagged): void {
+    exportSerdeAdjacentlyTaggedImpl = impl;
+}
+
+export function __fp_gen_export_serde_adjacently_tagged(arg_ptr: FatPtr): FatPtr {
+    const arg = importFromHost(arg_ptr, decodeSerdeAdjacentlyTagged);
+    return exportToHost(exportSerdeAdjacentlyTaggedImpl(arg), encodeSerdeAdjacentlyTagged);
+}
+
+let exportSerdeAttributesImpl: (arg: SerdeAttributes) => SerdeAttributes = (_arg: SerdeAttributes): SerdeAttributes => {
+    throw new Error("Exported function \"export_serde_attributes\" has not been implemented");
+};
+
+export function implementExportSerdeAttributes(impl: (arg: SerdeAttributes) => SerdeAttributes): void {
+    exportSerdeAttributesImpl = impl;
+}
+
+export function __fp_gen_export_serde_attributes(arg_ptr: FatPtr): FatPtr {
+    const arg = importFromHost(arg_ptr, decodeSerdeAttributes);
+    return exportToHost(exportSerdeAttributesImpl(arg), encodeSerdeAttributes);
+}
+
+let exportSerdeEnumImpl: (arg: SerdeVariantRenaming) => SerdeVariantRenaming = (_arg: SerdeVariantRenaming): SerdeVariantRenaming => {
+    throw new Error("Exported function \"export_serde_enum\" has not been implemented");
+};
+
+export function implementExportSerdeEnum(impl: (arg: SerdeVariantRenaming) => SerdeVariantRenaming): void {
+    exportSerdeEnumImpl = impl;
+}
+
+export function __fp_gen_export_serde_enum(arg_ptr: FatPtr): FatPtr {
+    const arg = importFromHost(arg_ptr, decodeSerdeVariantRenaming);
+    return exportToHost(exportSerdeEnumImpl(arg), encodeSerdeVariantRenaming);
+}
+
+let exportSerdeFlattenImpl: (arg: SerdeFlatten) => SerdeFlatten = (_arg: SerdeFlatten): SerdeFlatten => {
+    throw new Error("Exported function \"export_serde_flatten\" has not been implemented");
+};
+
+export function implementExportSerdeFlatten(impl: (arg: SerdeFlatten) => SerdeFlatten): void {
+    exportSerdeFlattenImpl = impl;
+}
+
+export function __fp_gen_export_serde_flatten(arg_ptr: FatPtr): FatPtr {
+    const arg = importFromHost(arg_ptr, decodeSerdeFlatten);
+    return exportToHost(exportSerdeFlattenImpl(arg), encodeSerdeFlatten);
+}
+
+let exportSerdeInternallyTaggedImpl: (arg: SerdeInternallyTagged) => SerdeInternallyTagged = (_arg: SerdeInternallyTagged): SerdeInternallyTagged => {
+    throw new Error("Exported function \"export_serde_internally_tagged\" has not been implemented");
+};
+
+export function implementExportSerdeInternallyTagged(impl: (arg: SerdeInternallyTagged) => SerdeInternallyTagged): void {
+    exportSerdeInternallyTaggedImpl = impl;
+}
+
+export function __fp_gen_export_serde_internally_tagged(arg_ptr: FatPtr): FatPtr {
+    const arg = importFromHost(arg_ptr, decodeSerdeInternallyTagged);
+    return exportToHost(exportSerdeInternallyTaggedImpl(arg), encodeSerdeInternallyTagged);
+}
+
+let exportSerdeStructImpl: (arg: SerdePropertyRenaming) => SerdePropertyRenaming = (_arg: SerdePropertyRenaming): SerdePropertyRenaming => {
+    throw new Error("Exported function \"export_serde_struct\" has not been implemented");
+};
+
+export function implementExportSerdeStruct(impl: (arg: SerdePropertyRenaming) => SerdePropertyRenaming): void {
+    exportSerdeStructImpl = impl;
+}
+
+export function __fp_gen_export_serde_struct(arg_ptr: FatPtr): FatPtr {
+    const arg = importFromHost(arg_ptr, decodeSerdePropertyRenaming);
+    return exportToHost(exportSerdeStructImpl(arg), encodeSerdePropertyRenaming);
+}
+
+let exportSerdeUntaggedImpl: (arg: SerdeUntagged) => SerdeUntagged = (_arg: SerdeUntagged): SerdeUntagged => {
+    throw new Error("Exported function \"export_serde_untagged\" has not been implemented");
+};
+
+export function implementExportSerdeUntagged(impl: (arg: SerdeUntagged) => SerdeUntagged): void {
+    exportSerdeUntaggedImpl = impl;
+}
+
+export function __fp_gen_export_serde_untagged(arg_ptr: FatPtr): FatPtr {
+    const arg = importFromHost(arg_ptr, decodeSerdeUntagged);
+    return exportToHost(exportSerdeUntaggedImpl(arg), encodeSerdeUntagged);
+}
+
+let exportStringImpl: (arg: string) => string = (_arg: string): string => {
+    throw new Error("Exported function \"export_string\" has not been implemented");
+};
+
+export function implementExportString(impl: (arg: string) => string): void {
+    exportStringImpl = impl;
+}
+
+export function __fp_gen_export_string(arg_ptr: FatPtr): FatPtr {
+    const arg = importFromHost(arg_ptr, decodeString);
+    return exportToHost(exportStringImpl(arg), encodeString);
+}
+
+let exportStructWithArraysImpl: (arg: StructWithArrays) => StructWithArrays = (_arg: StructWithArrays): StructWithArrays => {
+    throw new Error("Exported function \"export_struct_with_arrays\" has not been implemented");
+};
+
+export function implementExportStructWithArrays(impl: (arg: StructWithArrays) => StructWithArrays): void {
+    exportStructWithArraysImpl = impl;
+}
+
+export function __fp_gen_export_struct_with_arrays(arg_ptr: FatPtr): FatPtr {
+    const arg = importFromHost(arg_ptr, decodeStructWithArrays);
+    return exportToHost(exportStructWithArraysImpl(arg), encodeStructWithArrays);
+}
+
+let exportStructWithReprEnumsImpl: (arg: StructWithReprEnums) => StructWithReprEnums = (_arg: StructWithReprEnums): StructWithReprEnums => {
+    throw new Error("Exported function \"export_struct_with_repr_enums\" has not been implemented");
+};
+
+export function implementExportStructWithReprEnums(impl: (arg: StructWithReprEnums) => StructWithReprEnums): void {
+    exportStructWithReprEnumsImpl = impl;
+}
+
+export function __fp_gen_export_struct_with_repr_enums(arg_ptr: FatPtr): FatPtr {
+    const arg = importFromHost(arg_ptr, decodeStructWithReprEnums);
+    return exportToHost(exportStructWithReprEnumsImpl(arg), encodeStructWithReprEnums);
+}
+
+let exportStructWithTupleStructsImpl: (arg: StructWithTupleStructs) => StructWithTupleStructs = (_arg: StructWithTupleStructs): StructWithTupleStructs => {
+    throw new Error("Exported function \"export_struct_with_tuple_structs\" has not been implemented");
+};
+
+export function implementExportStructWithTupleStructs(impl: (arg: StructWithTupleStructs) => StructWithTupleStructs): void {
+    exportStructWithTupleStructsImpl = impl;
+}
+
+export function __fp_gen_export_struct_with_tuple_structs(arg_ptr: FatPtr): FatPtr {
+    const arg = importFromHost(arg_ptr, decodeStructWithTupleStructs);
+    return exportToHost(exportStructWithTupleStructsImpl(arg), encodeStructWithTupleStructs);
+}
+
+let exportStructWithTuplesImpl: (arg: StructWithTuples) => StructWithTuples = (_arg: StructWithTuples): StructWithTuples => {
+    throw new Error("Exported function \"export_struct_with_tuples\" has not been implemented");
+};
+
+export function implementExportStructWithTuples(impl: (arg: StructWithTuples) => StructWithTuples): void {
+    exportStructWithTuplesImpl = impl;
+}
+
+export function __fp_gen_export_struct_with_tuples(arg_ptr: FatPtr): FatPtr {
+    const arg = importFromHost(arg_ptr, decodeStructWithTuples);
+    return exportToHost(exportStructWithTuplesImpl(arg), encodeStructWithTuples);
+}
+
+let exportStructWithWidePrimitivesImpl: (arg: StructWithWidePrimitives) => StructWithWidePrimitives = (_arg: StructWithWidePrimitives): StructWithWidePrimitives => {
+    throw new Error("Exported function \"export_struct_with_wide_primitives\" has not been implemented");
+};
+
+export function implementExportStructWithWidePrimitives(impl: (arg: StructWithWidePrimitives) => StructWithWidePrimitives): void {
+    exportStructWithWidePrimitivesImpl = impl;
+}
+
+export function __fp_gen_export_struct_with_wide_primitives(arg_ptr: FatPtr): FatPtr {
+    const arg = importFromHost(arg_ptr, decodeStructWithWidePrimitives);
+    return exportToHost(exportStructWithWidePrimitivesImpl(arg), encodeStructWithWidePrimitives);
+}
+
+let exportTaggedUnknownVariantImpl: (arg: TaggedShape) => TaggedShape = (_arg: TaggedShape): TaggedShape => {
+    throw new Error("Exported function \"export_tagged_unknown_variant\" has not been implemented");
+};
+
+export function implementExportTaggedUnknownVariant(impl: (arg: TaggedShape) => TaggedShape): void {
+    exportTaggedUnknownVariantImpl = impl;
+}
+
+export function __fp_gen_export_tagged_unknown_variant(arg_ptr: FatPtr): FatPtr {
+    const arg = importFromHost(arg_ptr, decodeTaggedShape);
+    return exportToHost(exportTaggedUnknownVariantImpl(arg), encodeTaggedShape);
+}
+
+let exportTimestampImpl: (arg: string) => string = (_arg: string): string => {
+    throw new Error("Exported function \"export_timestamp\" has not been implemented");
+};
+
+export function implementExportTimestamp(impl: (arg: string) => string): void {
+    exportTimestampImpl = impl;
+}
+
+export function __fp_gen_export_timestamp(arg_ptr: FatPtr): FatPtr {
+    const arg = importFromHost(arg_ptr, decodeOffsetDateTime);
+    return exportToHost(exportTimestampImpl(arg), encodeOffsetDateTime);
+}
+
+let exportTupleImpl: (arg: TupleU32String) => TupleStringU32 = (_arg: TupleU32String): TupleStringU32 => {
+    throw new Error("Exported function \"export_tuple\" has not been implemented");
+};
+
+export function implementExportTuple(impl: (arg: TupleU32String) => TupleStringU32): void {
+    exportTupleImpl = impl;
+}
+
+export function __fp_gen_export_tuple(arg_ptr: FatPtr): FatPtr {
+    const arg = importFromHost(arg_ptr, decodeTupleU32String);
+    return exportToHost(exportTupleImpl(arg), encodeTupleStringU32);
+}
+
+let exportUnknownVariantImpl: (arg: Shape) => Shape = (_arg: Shape): Shape => {
+    throw new Error("Exported function \"export_unknown_variant\" has not been implemented");
+};
+
+export function implementExportUnknownVariant(impl: (arg: Shape) => Shape): void {
+    exportUnknownVariantImpl = impl;
+}
+
+export function __fp_gen_export_unknown_variant(arg_ptr: FatPtr): FatPtr {
+    const arg = importFromHost(arg_ptr, decodeShape);
+    return exportToHost(exportUnknownVariantImpl(arg), encodeShape);
+}
+
+let exportUuidImpl: (arg: string) => string = (_arg: string): string => {
+    throw new Error("Exported function \"export_uuid\" has not been implemented");
+};
+
+export function implementExportUuid(impl: (arg: string) => string): void {
+    exportUuidImpl = impl;
+}
+
+export function __fp_gen_export_uuid(arg_ptr: FatPtr): FatPtr {
+    const arg = importFromHost(arg_ptr, decodeUuid);
+    return exportToHost(exportUuidImpl(arg), encodeUuid);
+}
+
+let exportVoidFunctionImpl: () => void = (): void => {
+    throw new Error("Exported function \"export_void_function\" has not been implemented");
+};
+
+export function implementExportVoidFunction(impl: () => void): void {
+    exportVoidFunctionImpl = impl;
+}
+
+export function __fp_gen_export_void_function(): void {
+    exportVoidFunctionImpl();
+}
+
+// Async function `fetch_data` is not supported by AssemblyScript plugins.
+
+let initImpl: () => void = (): void => {
+    throw new Error("Exported function \"init\" has not been implemented");
+};
+
+/**
+ * Called on the plugin to give it a chance to initialize.
+ */
+export function implementInit(impl: () => void): void {
+    initImpl = impl;
+}
+
+export function __fp_gen_init(): void {
+    initImpl();
+}
+
+let reducerBridgeImpl: (action: ReduxAction) => StateUpdate = (_action: ReduxAction): StateUpdate => {
+    throw new Error("Exported function \"reducer_bridge\" has not been implemented");
+};
+
+/**
+ * Example how plugin could expose a reducer.
+ */
+export function implementReducerBridge(impl: (action: ReduxAction) => StateUpdate): void {
+    reducerBridgeImpl = impl;
+}
+
+export function __fp_gen_reducer_bridge(action_ptr: FatPtr): FatPtr {
+    const action = importFromHost(action_ptr, decodeReduxAction);
+    return exportToHost(reducerBridgeImpl(action), encodeStateUpdate);
+}
@@ -0,0 +1,102 @@
+// ============================================= //
+// Runtime for AssemblyScript plugins            //
+//                                               //
+// This file is generated. PLEASE DO NOT MODIFY. //
+// ============================================= //
+
+import { Decoder, Encoder, Sizer, Writer } from "@wapc/as-msgpack";
+
+export type FatPtr = u64;
+
+/**
+ * Wraps a primitive value, so that it can be made nullable.
+ */
+export class Box<T> {
+    constructor(public value: T) {}
+}
+
+export function __fp_malloc(len: u32): FatPtr {
+    return toFatPtr(<u32>heap.alloc(len), len);
+}
+
+export function __fp_free(fatPtr: FatPtr): void {
+    heap.free(fatPtrAddr(fatPtr));
+}
+
+/**
+ * Decodes the given number of fields into the given value.
+ *
+ * Fields that are not known to the value are skipped.
+ */
+export function decodeFields<T>(reader: Decoder, value: T, numFields: u32): T {
+    for (let i: u32 = 0; i < numFields; i++) {
+        const key = reader.readString();
+        if (!value.decodeField(key, reader)) {
+            reader.skip();
+        }
+    }
+    return value;
+}
+
+/**
+ * Serializes the value and copies it into memory that is owned by the host.
+ */
+export function exportToHost<T>(value: T, encode: (writer: Writer, value: T) => void): FatPtr {
+    const sizer = new Sizer();
+    encode(sizer, value);
+
+    const buffer = new ArrayBuffer(sizer.length);
+    encode(new Encoder(buffer), value);
+    return exportBinaryToHost(buffer);
+}
+
+/**
+ * Deserializes the value from memory that was passed to us by the host.
+ *
+ * After this call, the pointer is no longer valid.
+ */
+export function importFromHost<T>(fatPtr: FatPtr, decode: (reader: Decoder) => T): T {
+    return decode(new Decoder(importBinaryFromHost(fatPtr)));
+}
+
+/**
+ * Copies the buffer as is into memory that is owned by the host.
+ */
+export function exportBinaryToHost(buffer: ArrayBuffer): FatPtr {
+    const len = <u32>buffer.byteLength;
+    const ptr = heap.alloc(len);
+    memory.copy(ptr, changetype<usize>(buffer), len);
+    return toFatPtr(<u32>ptr, len);
+}
+
+/**
+ * Copies the buffer from memory that was passed to us by the host.
+ *
+ * After this call, the pointer is no longer valid.
+ */
+export function importBinaryFromHost(fatPtr: FatPtr): ArrayBuffer {
+    const ptr = fatPtrAddr(fatPtr);
+    const len = fatPtrLen(fatPtr);
+    const buffer = new ArrayBuffer(len);
+    memory.copy(changetype<usize>(buffer), ptr, len);
+    __fp_free(fatPtr);
+    return buffer;
+}
+
+function fatPtrAddr(fatPtr: FatPtr): u32 {
+    return <u32>(fatPtr >> 32);
+}
+
+function fatPtrLen(fatPtr: FatPtr): u32 {
+    if (fatPtr & 0xff000000) {
+        throw new Error("Unknown extension bits");
+    }
+    return <u32>(fatPtr & 0xffffffff);
+}
+
+function toFatPtr(ptr: u32, len: u32): FatPtr {
+    if (len & 0xff000000) {
+        throw new Error("Buffer too large");
+    }
+    return (<u64>ptr << 32) | <u64>len;
+}
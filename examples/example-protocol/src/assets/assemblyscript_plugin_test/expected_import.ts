@@ -0,0 +1,363 @@
+// ============================================= //
+// Imported functions for AssemblyScript plugins //
+//                                               //
+// This file is generated. PLEASE DO NOT MODIFY. //
+// ============================================= //
+
+import {
+    FatPtr,
+    exportBinaryToHost,
+    exportToHost,
+    importBinaryFromHost,
+    importFromHost,
+} from "./fp";
+import {
+    FpAdjacentlyTagged,
+    FpFlatten,
+    FpInternallyTagged,
+    FpPropertyRenaming,
+    FpUntagged,
+    FpVariantRenaming,
+    FrameInfo,
+    Position,
+    RequestByteBuf,
+    ResponseByteBuf,
+    SerdeAdjacentlyTagged,
+    SerdeFlatten,
+    SerdeInternallyTagged,
+    SerdePropertyRenaming,
+    SerdeUntagged,
+    SerdeVariantRenaming,
+    StructWithGenericsU64,
+    decodeFpAdjacentlyTagged,
+    decodeFpFlatten,
+    decodeFpInternallyTagged,
+    decodeFpPropertyRenaming,
+    decodeFpUntagged,
+    decodeFpVariantRenaming,
+    decodeOffsetDateTime,
+    decodePosition,
+    decodeResponseByteBuf,
+    decodeSerdeAdjacentlyTagged,
+    decodeSerdeFlatten,
+    decodeSerdeInternallyTagged,
+    decodeSerdePropertyRenaming,
+    decodeSerdeUntagged,
+    decodeSerdeVariantRenaming,
+    decodeString,
+    decodeStructWithGenericsU64,
+    encodeFpAdjacentlyTagged,
+    encodeFpFlatten,
+    encodeFpInternallyTagged,
+    encodeFpPropertyRenaming,
+    encodeFpUntagged,
+    encodeFpVariantRenaming,
+    encodeOffsetDateTime,
+    encodeRequestByteBuf,
+    encodeSerdeAdjacentlyTagged,
+    encodeSerdeFlatten,
+    encodeSerdeInternallyTagged,
+    encodeSerdePropertyRenaming,
+    encodeSerdeUntagged,
+    encodeSerdeVariantRenaming,
+    encodeString,
+    encodeStructWithGenericsU64,
+    encodeVecU32,
+} from "./types";
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_fetch")
+declare function __fp_gen_fetch(request: FatPtr): FatPtr;
+
+/**
+ * Example how a runtime could expose a `fetch()` function using the
+ * request and response types from the `http` crate.
+ *
+ * See `types/http_exchange.rs` for more info.
+ */
+export function fetch(request: RequestByteBuf): ResponseByteBuf {
+    const request_ptr = exportToHost(request, encodeRequestByteBuf);
+    return importFromHost(__fp_gen_fetch(request_ptr), decodeResponseByteBuf);
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_borrowed")
+declare function __fp_gen_import_borrowed(text: FatPtr, data: FatPtr, values: FatPtr, position_x: f64, position_y: f64): FatPtr;
+
+export function importBorrowed(text: string, data: ArrayBuffer, values: Array<u32>, position: Position): string {
+    const text_ptr = exportToHost(text, encodeString);
+    const data_ptr = exportBinaryToHost(data);
+    const values_ptr = exportToHost(values, encodeVecU32);
+    return importFromHost(__fp_gen_import_borrowed(text_ptr, data_ptr, values_ptr, position.x, position.y), decodeString);
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_by_value")
+declare function __fp_gen_import_by_value(position_x: f64, position_y: f64, frame_frame_number: u64, frame_delta_ms: f32, frame_offset: i32, frame_is_paused: bool): FatPtr;
+
+export function importByValue(position: Position, frame: FrameInfo): Position {
+    return importFromHost(__fp_gen_import_by_value(position.x, position.y, frame.frameNumber, frame.deltaMs, frame.offset, frame.isPaused), decodePosition);
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_bytes")
+declare function __fp_gen_import_bytes(arg: FatPtr): FatPtr;
+
+export function importBytes(arg: ArrayBuffer): ArrayBuffer {
+    const arg_ptr = exportBinaryToHost(arg);
+    return importBinaryFromHost(__fp_gen_import_bytes(arg_ptr));
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_fp_adjacently_tagged")
+declare function __fp_gen_import_fp_adjacently_tagged(arg: FatPtr): FatPtr;
+
+export function importFpAdjacentlyTagged(arg: FpAdjacentlyTagged): FpAdjacentlyTagged {
+    const arg_ptr = exportToHost(arg, encodeFpAdjacentlyTagged);
+    return importFromHost(__fp_gen_import_fp_adjacently_tagged(arg_ptr), decodeFpAdjacentlyTagged);
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_fp_enum")
+declare function __fp_gen_import_fp_enum(arg: FatPtr): FatPtr;
+
+export function importFpEnum(arg: FpVariantRenaming): FpVariantRenaming {
+    const arg_ptr = exportToHost(arg, encodeFpVariantRenaming);
+    return importFromHost(__fp_gen_import_fp_enum(arg_ptr), decodeFpVariantRenaming);
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_fp_flatten")
+declare function __fp_gen_import_fp_flatten(arg: FatPtr): FatPtr;
+
+export function importFpFlatten(arg: FpFlatten): FpFlatten {
+    const arg_ptr = exportToHost(arg, encodeFpFlatten);
+    return importFromHost(__fp_gen_import_fp_flatten(arg_ptr), decodeFpFlatten);
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_fp_internally_tagged")
+declare function __fp_gen_import_fp_internally_tagged(arg: FatPtr): FatPtr;
+
+export function importFpInternallyTagged(arg: FpInternallyTagged): FpInternallyTagged {
+    const arg_ptr = exportToHost(arg, encodeFpInternallyTagged);
+    return importFromHost(__fp_gen_import_fp_internally_tagged(arg_ptr), decodeFpInternallyTagged);
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_fp_struct")
+declare function __fp_gen_import_fp_struct(arg: FatPtr): FatPtr;
+
+export function importFpStruct(arg: FpPropertyRenaming): FpPropertyRenaming {
+    const arg_ptr = exportToHost(arg, encodeFpPropertyRenaming);
+    return importFromHost(__fp_gen_import_fp_struct(arg_ptr), decodeFpPropertyRenaming);
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_fp_untagged")
+declare function __fp_gen_import_fp_untagged(arg: FatPtr): FatPtr;
+
+export function importFpUntagged(arg: FpUntagged): FpUntagged {
+    const arg_ptr = exportToHost(arg, encodeFpUntagged);
+    return importFromHost(__fp_gen_import_fp_untagged(arg_ptr), decodeFpUntagged);
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_generics")
+declare function __fp_gen_import_generics(arg: FatPtr): FatPtr;
+
+export function importGenerics(arg: StructWithGenericsU64): StructWithGenericsU64 {
+    const arg_ptr = exportToHost(arg, encodeStructWithGenericsU64);
+    return importFromHost(__fp_gen_import_generics(arg_ptr), decodeStructWithGenericsU64);
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_multiple_primitives")
+declare function __fp_gen_import_multiple_primitives(arg1: i8, arg2: FatPtr): i64;
+
+export function importMultiplePrimitives(arg1: i8, arg2: string): i64 {
+    const arg2_ptr = exportToHost(arg2, encodeString);
+    return __fp_gen_import_multiple_primitives(arg1, arg2_ptr);
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_primitive_bool")
+declare function __fp_gen_import_primitive_bool(arg: bool): bool;
+
+export function importPrimitiveBool(arg: bool): bool {
+    return __fp_gen_import_primitive_bool(arg);
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_primitive_f32")
+declare function __fp_gen_import_primitive_f32(arg: f32): f32;
+
+export function importPrimitiveF32(arg: f32): f32 {
+    return __fp_gen_import_primitive_f32(arg);
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_primitive_f64")
+declare function __fp_gen_import_primitive_f64(arg: f64): f64;
+
+export function importPrimitiveF64(arg: f64): f64 {
+    return __fp_gen_import_primitive_f64(arg);
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_primitive_i16")
+declare function __fp_gen_import_primitive_i16(arg: i16): i16;
+
+export function importPrimitiveI16(arg: i16): i16 {
+    return __fp_gen_import_primitive_i16(arg);
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_primitive_i32")
+declare function __fp_gen_import_primitive_i32(arg: i32): i32;
+
+export function importPrimitiveI32(arg: i32): i32 {
+    return __fp_gen_import_primitive_i32(arg);
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_primitive_i64")
+declare function __fp_gen_import_primitive_i64(arg: i64): i64;
+
+export function importPrimitiveI64(arg: i64): i64 {
+    return __fp_gen_import_primitive_i64(arg);
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_primitive_i8")
+declare function __fp_gen_import_primitive_i8(arg: i8): i8;
+
+export function importPrimitiveI8(arg: i8): i8 {
+    return __fp_gen_import_primitive_i8(arg);
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_primitive_u16")
+declare function __fp_gen_import_primitive_u16(arg: u16): u16;
+
+export function importPrimitiveU16(arg: u16): u16 {
+    return __fp_gen_import_primitive_u16(arg);
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_primitive_u32")
+declare function __fp_gen_import_primitive_u32(arg: u32): u32;
+
+export function importPrimitiveU32(arg: u32): u32 {
+    return __fp_gen_import_primitive_u32(arg);
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_primitive_u64")
+declare function __fp_gen_import_primitive_u64(arg: u64): u64;
+
+export function importPrimitiveU64(arg: u64): u64 {
+    return __fp_gen_import_primitive_u64(arg);
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_primitive_u8")
+declare function __fp_gen_import_primitive_u8(arg: u8): u8;
+
+export function importPrimitiveU8(arg: u8): u8 {
+    return __fp_gen_import_primitive_u8(arg);
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_serde_adjacently_tagged")
+declare function __fp_gen_import_serde_adjacently_tagged(arg: FatPtr): FatPtr;
+
+export function importSerdeAdjacentlyTagged(arg: SerdeAdjacentlyTagged): SerdeAdjacentlyTagged {
+    const arg_ptr = exportToHost(arg, encodeSerdeAdjacentlyTagged);
+    return importFromHost(__fp_gen_import_serde_adjacently_tagged(arg_ptr), decodeSerdeAdjacentlyTagged);
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_serde_enum")
+declare function __fp_gen_import_serde_enum(arg: FatPtr): FatPtr;
+
+export function importSerdeEnum(arg: SerdeVariantRenaming): SerdeVariantRenaming {
+    const arg_ptr = exportToHost(arg, encodeSerdeVariantRenaming);
+    return importFromHost(__fp_gen_import_serde_enum(arg_ptr), decodeSerdeVariantRenaming);
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_serde_flatten")
+declare function __fp_gen_import_serde_flatten(arg: FatPtr): FatPtr;
+
+export function importSerdeFlatten(arg: SerdeFlatten): SerdeFlatten {
+    const arg_ptr = exportToHost(arg, encodeSerdeFlatten);
+    return importFromHost(__fp_gen_import_serde_flatten(arg_ptr), decodeSerdeFlatten);
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_serde_internally_tagged")
+declare function __fp_gen_import_serde_internally_tagged(arg: FatPtr): FatPtr;
+
+export function importSerdeInternallyTagged(arg: SerdeInternallyTagged): SerdeInternallyTagged {
+    const arg_ptr = exportToHost(arg, encodeSerdeInternallyTagged);
+    return importFromHost(__fp_gen_import_serde_internally_tagged(arg_ptr), decodeSerdeInternallyTagged);
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_serde_struct")
+declare function __fp_gen_import_serde_struct(arg: FatPtr): FatPtr;
+
+export function importSerdeStruct(arg: SerdePropertyRenaming): SerdePropertyRenaming {
+    const arg_ptr = exportToHost(arg, encodeSerdePropertyRenaming);
+    return importFromHost(__fp_gen_import_serde_struct(arg_ptr), decodeSerdePropertyRenaming);
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_serde_untagged")
+declare function __fp_gen_import_serde_untagged(arg: FatPtr): FatPtr;
+
+export function importSerdeUntagged(arg: SerdeUntagged): SerdeUntagged {
+    const arg_ptr = exportToHost(arg, encodeSerdeUntagged);
+    return importFromHost(__fp_gen_import_serde_untagged(arg_ptr), decodeSerdeUntagged);
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_string")
+declare function __fp_gen_import_string(arg: FatPtr): FatPtr;
+
+export function importString(arg: string): string {
+    const arg_ptr = exportToHost(arg, encodeString);
+    return importFromHost(__fp_gen_import_string(arg_ptr), decodeString);
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_timestamp")
+declare function __fp_gen_import_timestamp(arg: FatPtr): FatPtr;
+
+export function importTimestamp(arg: string): string {
+    const arg_ptr = exportToHost(arg, encodeOffsetDateTime);
+    return importFromHost(__fp_gen_import_timestamp(arg_ptr), decodeOffsetDateTime);
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_import_void_function")
+declare function __fp_gen_import_void_function(): void;
+
+export function importVoidFunction(): void {
+    __fp_gen_import_void_function();
+}
+
+// @ts-ignore: decorator
+@external("fp", "__fp_gen_log")
+declare function __fp_gen_log(message: FatPtr): void;
+
+/**
+ * Logs a message to the (development) console.
+ */
+export function log(message: string): void {
+    const message_ptr = exportToHost(message, encodeString);
+    __fp_gen_log(message_ptr);
+}
+
+// Async function `make_http_request` is not supported by AssemblyScript plugins.
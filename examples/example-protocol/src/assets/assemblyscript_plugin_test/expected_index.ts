@@ -0,0 +1,4 @@
+export { __fp_free, __fp_malloc } from "./fp";
+export * from "./export";
+export * from "./import";
+export * from "./types";
@@ -0,0 +1,3967 @@
+// ============================================= //
+// Types for AssemblyScript plugins              //
+//                                               //
+// This file is generated. PLEASE DO NOT MODIFY. //
+// ============================================= //
+
+import { Decoder, Writer } from "@wapc/as-msgpack";
+import { Box, decodeFields } from "./fp";
+
+export class TupleString {
+    value: string = "";
+
+    static decode(reader: Decoder): TupleString {
+        reader.readArrayLength();
+        const result = new TupleString();
+        result.value = decodeString(reader);
+        return result;
+    }
+
+    encode(writer: Writer): void {
+        writer.writeArrayLength(1);
+        encodeString(writer, this.value);
+    }
+}
+
+export class TupleStringTupleU8Bool {
+    value0: string = "";
+    value1!: TupleU8Bool;
+
+    static decode(reader: Decoder): TupleStringTupleU8Bool {
+        reader.readArrayLength();
+        const result = new TupleStringTupleU8Bool();
+        result.value0 = decodeString(reader);
+        result.value1 = decodeTupleU8Bool(reader);
+        return result;
+    }
+
+    encode(writer: Writer): void {
+        writer.writeArrayLength(2);
+        encodeString(writer, this.value0);
+        encodeTupleU8Bool(writer, this.value1);
+    }
+}
+
+export class TupleStringU32 {
+    value0: string = "";
+    value1: u32 = 0;
+
+    static decode(reader: Decoder): TupleStringU32 {
+        reader.readArrayLength();
+        const result = new TupleStringU32();
+        result.value0 = decodeString(reader);
+        result.value1 = reader.readUInt32();
+        return result;
+    }
+
+    encode(writer: Writer): void {
+        writer.writeArrayLength(2);
+        encodeString(writer, this.value0);
+        writer.writeUInt32(this.value1);
+    }
+}
+
+export class TupleI64I64 {
+    value0: i64 = 0;
+    value1: i64 = 0;
+
+    static decode(reader: Decoder): TupleI64I64 {
+        reader.readArrayLength();
+        const result = new TupleI64I64();
+        result.value0 = reader.readInt64();
+        result.value1 = reader.readInt64();
+        return result;
+    }
+
+    encode(writer: Writer): void {
+        writer.writeArrayLength(2);
+        writer.writeInt64(this.value0);
+        writer.writeInt64(this.value1);
+    }
+}
+
+export class TupleU32String {
+    value0: u32 = 0;
+    value1: string = "";
+
+    static decode(reader: Decoder): TupleU32String {
+        reader.readArrayLength();
+        const result = new TupleU32String();
+        result.value0 = reader.readUInt32();
+        result.value1 = decodeString(reader);
+        return result;
+    }
+
+    encode(writer: Writer): void {
+        writer.writeArrayLength(2);
+        writer.writeUInt32(this.value0);
+        encodeString(writer, this.value1);
+    }
+}
+
+export class TupleU8Bool {
+    value0: u8 = 0;
+    value1: bool = false;
+
+    static decode(reader: Decoder): TupleU8Bool {
+        reader.readArrayLength();
+        const result = new TupleU8Bool();
+        result.value0 = reader.readUInt8();
+        result.value1 = reader.readBool();
+        return result;
+    }
+
+    encode(writer: Writer): void {
+        writer.writeArrayLength(2);
+        writer.writeUInt8(this.value0);
+        writer.writeBool(this.value1);
+    }
+}
+
+export class ActionMeta {
+    sequenceNumber: u64 = 0;
+    origin: string = "";
+
+    static decode(reader: Decoder): ActionMeta {
+        return decodeFields(reader, new ActionMeta(), reader.readMapLength());
+    }
+
+    decodeField(key: string, reader: Decoder): bool {
+        if (key == "sequenceNumber") {
+            this.sequenceNumber = reader.readUInt64();
+            return true;
+        }
+        if (key == "origin") {
+            this.origin = decodeString(reader);
+            return true;
+        }
+        return false;
+    }
+
+    encode(writer: Writer): void {
+        writer.writeMapLength(2);
+        this.encodeFields(writer);
+    }
+
+    encodeFields(writer: Writer): void {
+        writer.writeString("sequenceNumber");
+        writer.writeUInt64(this.sequenceNumber);
+        writer.writeString("origin");
+        encodeString(writer, this.origin);
+    }
+}
+
+export type ActionMetaDef = ActionMeta;
+
+export class Appointment {
+    date: string = "";
+    weekday: string = "";
+
+    static decode(reader: Decoder): Appointment {
+        return decodeFields(reader, new Appointment(), reader.readMapLength());
+    }
+
+    decodeField(key: string, reader: Decoder): bool {
+        if (key == "date") {
+            this.date = decodeCalendarDate(reader);
+            return true;
+        }
+        if (key == "weekday") {
+            this.weekday = decodeWeekday(reader);
+            return true;
+        }
+        return false;
+    }
+
+    encode(writer: Writer): void {
+        writer.writeMapLength(2);
+        this.encodeFields(writer);
+    }
+
+    encodeFields(writer: Writer): void {
+        writer.writeString("date");
+        encodeCalendarDate(writer, this.date);
+        writer.writeString("weekday");
+        encodeWeekday(writer, this.weekday);
+    }
+}
+
+export type Body = ArrayBuffer;
+
+export class DirectionalStructRenaming {
+    fooBar: string = "";
+
+    static decode(reader: Decoder): DirectionalStructRenaming {
+        return decodeFields(reader, new DirectionalStructRenaming(), reader.readMapLength());
+    }
+
+    decodeField(key: string, reader: Decoder): bool {
+        if (key == "foo_bar") {
+            this.fooBar = decodeString(reader);
+            return true;
+        }
+        return false;
+    }
+
+    encode(writer: Writer): void {
+        writer.writeMapLength(2);
+        writer.writeString("type");
+        writer.writeString("SerializedName");
+        this.encodeFields(writer);
+    }
+
+    encodeFields(writer: Writer): void {
+        writer.writeString("foo_bar");
+        encodeString(writer, this.fooBar);
+    }
+}
+
+export abstract class DirectionalVariantRenaming {
+    static decode(reader: Decoder): DirectionalVariantRenaming {
+        if (reader.isNextString()) {
+            const name = reader.readString();
+            if (name == "foobar") return new DirectionalVariantRenamingFooBar();
+            if (name == "baz_in") return new DirectionalVariantRenamingBaz();
+            throw new Error("Unknown variant for enum DirectionalVariantRenaming: " + name);
+        }
+        reader.readMapLength();
+        const name = reader.readString();
+        if (name == "quxbaz") {
+            return decodeFields(reader, new DirectionalVariantRenamingQuxBaz(), reader.readMapLength());
+        }
+        throw new Error("Unknown variant for enum DirectionalVariantRenaming: " + name);
+    }
+
+    abstract encode(writer: Writer): void;
+}
+
+export class DirectionalVariantRenamingFooBar extends DirectionalVariantRenaming {
+    encode(writer: Writer): void {
+        writer.writeString("FOOBAR");
+    }
+}
+
+export class DirectionalVariantRenamingQuxBaz extends DirectionalVariantRenaming {
+    /**
+     * Will be serialized as "fooBar", and deserialized from "FOO-BAR".
+     */
+    fooBar: string = "";
+
+    decodeField(key: string, reader: Decoder): bool {
+        if (key == "FOO-BAR") {
+            this.fooBar = decodeString(reader);
+            return true;
+        }
+        return false;
+    }
+
+    encodeFields(writer: Writer): void {
+        writer.writeString("fooBar");
+        encodeString(writer, this.fooBar);
+    }
+
+    encode(writer: Writer): void {
+        writer.writeMapLength(1);
+        writer.writeString("QUXBAZ");
+        writer.writeMapLength(1);
+        this.encodeFields(writer);
+    }
+}
+
+export class DirectionalVariantRenamingBaz extends DirectionalVariantRenaming {
+    encode(writer: Writer): void {
+        writer.writeString("BAZ_OUT");
+    }
+}
+
+/**
+ * # This is an enum with doc comments.
+ */
+export abstract class DocExampleEnum {
+    static decode(reader: Decoder): DocExampleEnum {
+        reader.readMapLength();
+        const name = reader.readString();
+        if (name == "Variant1") {
+            const variant = new DocExampleEnumVariant1();
+            variant.value = decodeString(reader);
+            return variant;
+        }
+        if (name == "Variant2") {
+            return decodeFields(reader, new DocExampleEnumVariant2(), reader.readMapLength());
+        }
+        throw new Error("Unknown variant for enum DocExampleEnum: " + name);
+    }
+
+    abstract encode(writer: Writer): void;
+}
+
+/**
+ * Multi-line doc comment with complex characters
+ * & " , \ ! '
+ */
+export class DocExampleEnumVariant1 extends DocExampleEnum {
+    value: string = "";
+
+    encode(writer: Writer): void {
+        writer.writeMapLength(1);
+        writer.writeString("Variant1");
+        encodeString(writer, this.value);
+    }
+}
+
+/**
+ * Raw identifiers are supported too.
+ */
+export class DocExampleEnumVariant2 extends DocExampleEnum {
+    /**
+     * Variant property.
+     */
+    inner: i8 = 0;
+
+    decodeField(key: string, reader: Decoder): bool {
+        if (key == "inner") {
+            this.inner = reader.readInt8();
+            return true;
+        }
+        return false;
+    }
+
+    encodeFields(writer: Writer): void {
+        writer.writeString("inner");
+        writer.writeInt8(this.inner);
+    }
+
+    encode(writer: Writer): void {
+        writer.writeMapLength(1);
+        writer.writeString("Variant2");
+        writer.writeMapLength(1);
+        this.encodeFields(writer);
+    }
+}
+
+/**
+ * # This is a struct with doc comments.
+ */
+export class DocExampleStruct {
+    /**
+     * Multi-line doc comment with complex characters
+     * & " , \ ! '
+     */
+    multiLine: string = "";
+
+    /**
+     * Raw identifiers are supported too.
+     */
+    type: string = "";
+
+    static decode(reader: Decoder): DocExampleStruct {
+        return decodeFields(reader, new DocExampleStruct(), reader.readMapLength());
+    }
+
+    decodeField(key: string, reader: Decoder): bool {
+        if (key == "multi_line") {
+            this.multiLine = decodeString(reader);
+            return true;
+        }
+        if (key == "type") {
+            this.type = decodeString(reader);
+            return true;
+        }
+        return false;
+    }
+
+    encode(writer: Writer): void {
+        writer.writeMapLength(2);
+        this.encodeFields(writer);
+    }
+
+    encodeFields(writer: Writer): void {
+        writer.writeString("multi_line");
+        encodeString(writer, this.multiLine);
+        writer.writeString("type");
+        encodeString(writer, this.type);
+    }
+}
+
+/**
+ * A value of type `Duration`, which cannot be represented in AssemblyScript.
+ *
+ * Its value is skipped when decoding, so it cannot be encoded again.
+ */
+export class Duration {}
+
+export type Email = string;
+
+/**
+ * This struct is also not referenced by any function or data structure, but
+ * it will show up because there is an explicit `use` statement for it in the
+ * `fp_import!` macro.
+ */
+export class ExplicitedlyImportedType {
+    youWillSeeThis: bool = false;
+
+    static decode(reader: Decoder): ExplicitedlyImportedType {
+        return decodeFields(reader, new ExplicitedlyImportedType(), reader.readMapLength());
+    }
+
+    decodeField(key: string, reader: Decoder): bool {
+        if (key == "you_will_see_this") {
+            this.youWillSeeThis = reader.readBool();
+            return true;
+        }
+        return false;
+    }
+
+    encode(writer: Writer): void {
+        writer.writeMapLength(1);
+        this.encodeFields(writer);
+    }
+
+    encodeFields(writer: Writer): void {
+        writer.writeString("you_will_see_this");
+        writer.writeBool(this.youWillSeeThis);
+    }
+}
+
+export class FlattenedStruct {
+    foo: string = "";
+    bar: i64 = 0;
+
+    static decode(reader: Decoder): FlattenedStruct {
+        return decodeFields(reader, new FlattenedStruct(), reader.readMapLength());
+    }
+
+    decodeField(key: string, reader: Decoder): bool {
+        if (key == "foo") {
+            this.foo = decodeString(reader);
+            return true;
+        }
+        if (key == "bar") {
+            this.bar = reader.readInt64();
+            return true;
+        }
+        return false;
+    }
+
+    encode(writer: Writer): void {
+        writer.writeMapLength(2);
+        this.encodeFields(writer);
+    }
+
+    encodeFields(writer: Writer): void {
+        writer.writeString("foo");
+        encodeString(writer, this.foo);
+        writer.writeString("bar");
+        writer.writeInt64(this.bar);
+    }
+}
+
+export type FloatingPoint = PointF64;
+
+export abstract class FpAdjacentlyTagged {
+    static decode(reader: Decoder): FpAdjacentlyTagged {
+        const numFields = reader.readMapLength();
+        if (reader.readString() != "type") {
+            throw new Error("Expected tag \"type\" for enum FpAdjacentlyTagged");
+        }
+        const name = reader.readString();
+        if (name == "Foo") {
+            return new FpAdjacentlyTaggedFoo();
+        }
+        if (name == "Bar") {
+            reader.readString();
+            const variant = new FpAdjacentlyTaggedBar();
+            variant.value = decodeString(reader);
+            return variant;
+        }
+        if (name == "Baz") {
+            reader.readString();
+            return decodeFields(reader, new FpAdjacentlyTaggedBaz(), reader.readMapLength());
+        }
+        throw new Error("Unknown variant for enum FpAdjacentlyTagged: " + name);
+    }
+
+    abstract encode(writer: Writer): void;
+}
+
+export class FpAdjacentlyTaggedFoo extends FpAdjacentlyTagged {
+    encode(writer: Writer): void {
+        writer.writeMapLength(1);
+        writer.writeString("type");
+        writer.writeString("Foo");
+    }
+}
+
+export class FpAdjacentlyTaggedBar extends FpAdjacentlyTagged {
+    value: string = "";
+
+    encode(writer: Writer): void {
+        writer.writeMapLength(2);
+        writer.writeString("type");
+        writer.writeString("Bar");
+        writer.writeString("payload");
+        encodeString(writer, this.value);
+    }
+}
+
+export class FpAdjacentlyTaggedBaz extends FpAdjacentlyTagged {
+    a: i8 = 0;
+    b: u64 = 0;
+
+    decodeField(key: string, reader: Decoder): bool {
+        if (key == "a") {
+            this.a = reader.readInt8();
+            return true;
+        }
+        if (key == "b") {
+            this.b = reader.readUInt64();
+            return true;
+        }
+        return false;
+    }
+
+    encodeFields(writer: Writer): void {
+        writer.writeString("a");
+        writer.writeInt8(this.a);
+        writer.writeString("b");
+        writer.writeUInt64(this.b);
+    }
+
+    encode(writer: Writer): void {
+        writer.writeMapLength(2);
+        writer.writeString("type");
+        writer.writeString("Baz");
+        writer.writeString("payload");
+        writer.writeMapLength(2);
+        this.encodeFields(writer);
+    }
+}
+
+export class FpFlatten {
+    flattened: FlattenedStruct = new FlattenedStruct();
+
+    static decode(reader: Decoder): FpFlatten {
+        return decodeFields(reader, new FpFlatten(), reader.readMapLength());
+    }
+
+    decodeField(key: string, reader: Decoder): bool {
+        return this.flattened.decodeField(key, reader);
+    }
+
+    encode(writer: Writer): void {
+        writer.writeMapLength(2);
+        this.encodeFields(writer);
+    }
+
+    encodeFields(writer: Writer): void {
+        this.flattened.encodeFields(writer);
+    }
+}
+
+export abstract class FpInternallyTagged {
+    static decode(reader: Decoder): FpInternallyTagged {
+        const numFields = reader.readMapLength();
+        if (reader.readString() != "type") {
+            throw new Error("Expected tag \"type\" for enum FpInternallyTagged");
+        }
+        const name = reader.readString();
+        if (name == "Foo") {
+            return new FpInternallyTaggedFoo();
+        }
+        if (name == "Baz") {
+            return decodeFields(reader, new FpInternallyTaggedBaz(), numFields - 1);
+        }
+        throw new Error("Unknown variant for enum FpInternallyTagged: " + name);
+    }
+
+    abstract encode(writer: Writer): void;
+}
+
+export class FpInternallyTaggedFoo extends FpInternallyTagged {
+    encode(writer: Writer): void {
+        writer.writeMapLength(1);
+        writer.writeString("type");
+        writer.writeString("Foo");
+    }
+}
+
+export class FpInternallyTaggedBaz extends FpInternallyTagged {
+    a: i8 = 0;
+    b: u64 = 0;
+
+    decodeField(key: string, reader: Decoder): bool {
+        if (key == "a") {
+            this.a = reader.readInt8();
+            return true;
+        }
+        if (key == "b") {
+            this.b = reader.readUInt64();
+            return true;
+        }
+        return false;
+    }
+
+    encodeFields(writer: Writer): void {
+        writer.writeString("a");
+        writer.writeInt8(this.a);
+        writer.writeString("b");
+        writer.writeUInt64(this.b);
+    }
+
+    encode(writer: Writer): void {
+        writer.writeMapLength(3);
+        writer.writeString("type");
+        writer.writeString("Baz");
+        this.encodeFields(writer);
+    }
+}
+
+export class FpPropertyRenaming {
+    fooBar: string = "";
+    quxBaz: f64 = 0;
+    rawStruct: i32 = 0;
+
+    static decode(reader: Decoder): FpPropertyRenaming {
+        return decodeFields(reader, new FpPropertyRenaming(), reader.readMapLength());
+    }
+
+    decodeField(key: string, reader: Decoder): bool {
+        if (key == "fooBar") {
+            this.fooBar = decodeString(reader);
+            return true;
+        }
+        if (key == "QUX_BAZ") {
+            this.quxBaz = reader.readFloat64();
+            return true;
+        }
+        if (key == "rawStruct") {
+            this.rawStruct = reader.readInt32();
+            return true;
+        }
+        return false;
+    }
+
+    encode(writer: Writer): void {
+        writer.writeMapLength(3);
+        this.encodeFields(writer);
+    }
+
+    encodeFields(writer: Writer): void {
+        writer.writeString("fooBar");
+        encodeString(writer, this.fooBar);
+        writer.writeString("QUX_BAZ");
+        writer.writeFloat64(this.quxBaz);
+        writer.writeString("rawStruct");
+        writer.writeInt32(this.rawStruct);
+    }
+}
+
+export abstract class FpUntagged {
+    static decode(reader: Decoder): FpUntagged {
+        throw new Error("Untagged enum FpUntagged cannot be decoded by AssemblyScript plugins");
+    }
+
+    abstract encode(writer: Writer): void;
+}
+
+export class FpUntaggedBar extends FpUntagged {
+    value: string = "";
+
+    encode(writer: Writer): void {
+        encodeString(writer, this.value);
+    }
+}
+
+export class FpUntaggedBaz extends FpUntagged {
+    a: i8 = 0;
+    b: u64 = 0;
+
+    decodeField(key: string, reader: Decoder): bool {
+        if (key == "a") {
+            this.a = reader.readInt8();
+            return true;
+        }
+        if (key == "b") {
+            this.b = reader.readUInt64();
+            return true;
+        }
+        return false;
+    }
+
+    encodeFields(writer: Writer): void {
+        writer.writeString("a");
+        writer.writeInt8(this.a);
+        writer.writeString("b");
+        writer.writeUInt64(this.b);
+    }
+
+    encode(writer: Writer): void {
+        writer.writeMapLength(2);
+        this.encodeFields(writer);
+    }
+}
+
+export abstract class FpVariantRenaming {
+    static decode(reader: Decoder): FpVariantRenaming {
+        if (reader.isNextString()) {
+            const name = reader.readString();
+            if (name == "foo_bar") return new FpVariantRenamingFooBar();
+            throw new Error("Unknown variant for enum FpVariantRenaming: " + name);
+        }
+        reader.readMapLength();
+        const name = reader.readString();
+        if (name == "QUX_BAZ") {
+            return decodeFields(reader, new FpVariantRenamingQuxBaz(), reader.readMapLength());
+        }
+        throw new Error("Unknown variant for enum FpVariantRenaming: " + name);
+    }
+
+    abstract encode(writer: Writer): void;
+}
+
+export class FpVariantRenamingFooBar extends FpVariantRenaming {
+    encode(writer: Writer): void {
+        writer.writeString("foo_bar");
+    }
+}
+
+export class FpVariantRenamingQuxBaz extends FpVariantRenaming {
+    /**
+     * Will be renamed to "FOO_BAR" because of the `rename_all` on the
+     * variant.
+     */
+    fooBar: string = "";
+    quxBaz: f64 = 0;
+
+    decodeField(key: string, reader: Decoder): bool {
+        if (key == "FOO_BAR") {
+            this.fooBar = decodeString(reader);
+            return true;
+        }
+        if (key == "qux_baz") {
+            this.quxBaz = reader.readFloat64();
+            return true;
+        }
+        return false;
+    }
+
+    encodeFields(writer: Writer): void {
+        writer.writeString("FOO_BAR");
+        encodeString(writer, this.fooBar);
+        writer.writeString("qux_baz");
+        writer.writeFloat64(this.quxBaz);
+    }
+
+    encode(writer: Writer): void {
+        writer.writeMapLength(1);
+        writer.writeString("QUX_BAZ");
+        writer.writeMapLength(2);
+        this.encodeFields(writer);
+    }
+}
+
+/**
+ * Information about the frame that is being rendered.
+ */
+export class FrameInfo {
+    frameNumber: u64 = 0;
+    deltaMs: f32 = 0;
+    offset: i32 = 0;
+    isPaused: bool = false;
+
+    static decode(reader: Decoder): FrameInfo {
+        return decodeFields(reader, new FrameInfo(), reader.readMapLength());
+    }
+
+    decodeField(key: string, reader: Decoder): bool {
+        if (key == "frameNumber") {
+            this.frameNumber = reader.readUInt64();
+            return true;
+        }
+        if (key == "deltaMs") {
+            this.deltaMs = reader.readFloat32();
+            return true;
+        }
+        if (key == "offset") {
+            this.offset = reader.readInt32();
+            return true;
+        }
+        if (key == "isPaused") {
+            this.isPaused = reader.readBool();
+            return true;
+        }
+        return false;
+    }
+
+    encode(writer: Writer): void {
+        writer.writeMapLength(4);
+        this.encodeFields(writer);
+    }
+
+    encodeFields(writer: Writer): void {
+        writer.writeString("frameNumber");
+        writer.writeUInt64(this.frameNumber);
+        writer.writeString("deltaMs");
+        writer.writeFloat32(this.deltaMs);
+        writer.writeString("offset");
+        writer.writeInt32(this.offset);
+        writer.writeString("isPaused");
+        writer.writeBool(this.isPaused);
+    }
+}
+
+export class GroupImportedType1 {
+    youWillSeeThis: bool = false;
+
+    static decode(reader: Decoder): GroupImportedType1 {
+        return decodeFields(reader, new GroupImportedType1(), reader.readMapLength());
+    }
+
+    decodeField(key: string, reader: Decoder): bool {
+        if (key == "you_will_see_this") {
+            this.youWillSeeThis = reader.readBool();
+            return true;
+        }
+        return false;
+    }
+
+    encode(writer: Writer): void {
+        writer.writeMapLength(1);
+        this.encodeFields(writer);
+    }
+
+    encodeFields(writer: Writer): void {
+        writer.writeString("you_will_see_this");
+        writer.writeBool(this.youWillSeeThis);
+    }
+}
+
+export class GroupImportedType2 {
+    youWillSeeThis: bool = false;
+
+    static decode(reader: Decoder): GroupImportedType2 {
+        return decodeFields(reader, new GroupImportedType2(), reader.readMapLength());
+    }
+
+    decodeField(key: string, reader: Decoder): bool {
+        if (key == "you_will_see_this") {
+            this.youWillSeeThis = reader.readBool();
+            return true;
+        }
+        return false;
+    }
+
+    encode(writer: Writer): void {
+        writer.writeMapLength(1);
+        this.encodeFields(writer);
+    }
+
+    encodeFields(writer: Writer): void {
+        writer.writeString("you_will_see_this");
+        writer.writeBool(this.youWillSeeThis);
+    }
+}
+
+/**
+ * A value of type `HeaderMap`, which cannot be represented in AssemblyScript.
+ *
+ * Its value is skipped when decoding, so it cannot be encoded again.
+ */
+export class HeaderMap {}
+
+/**
+ * A value of type `string | ArrayBuffer`, which cannot be represented in AssemblyScript.
+ *
+ * Its value is skipped when decoding, so it cannot be encoded again.
+ */
+export class HeaderValue {}
+
+/**
+ * A request that was sent, together with the response that was received.
+ */
+export class HttpExchange {
+    request: RequestByteBuf = new RequestByteBuf();
+    response: ResponseByteBuf = new ResponseByteBuf();
+
+    /**
+     * The content type of the response body.
+     */
+    contentType: HeaderValue = new HeaderValue();
+
+    static decode(reader: Decoder): HttpExchange {
+        return decodeFields(reader, new HttpExchange(), reader.readMapLength());
+    }
+
+    decodeField(key: string, reader: Decoder): bool {
+        if (key == "request") {
+            this.request = decodeRequestByteBuf(reader);
+            return true;
+        }
+        if (key == "response") {
+            this.response = decodeResponseByteBuf(reader);
+            return true;
+        }
+        if (key == "content_type") {
+            this.contentType = decodeHeaderValue(reader);
+            return true;
+        }
+        return false;
+    }
+
+    encode(writer: Writer): void {
+        writer.writeMapLength(3);
+        this.encodeFields(writer);
+    }
+
+    encodeFields(writer: Writer): void {
+        writer.writeString("request");
+        encodeRequestByteBuf(writer, this.request);
+        writer.writeString("response");
+        encodeResponseByteBuf(writer, this.response);
+        writer.writeString("content_type");
+        encodeHeaderValue(writer, this.contentType);
+    }
+}
+
+export type HttpResult = ResultResponseRequestError;
+
+export type Int64 = u64;
+
+/**
+ * A value of type `JsonObject`, which cannot be represented in AssemblyScript.
+ *
+ * Its value is skipped when decoding, so it cannot be encoded again.
+ */
+export class JsonMapStringJsonValue {}
+
+/**
+ * A value of type `JsonValue`, which cannot be represented in AssemblyScript.
+ *
+ * Its value is skipped when decoding, so it cannot be encoded again.
+ */
+export class JsonValue {}
+
+export class KebabCasePropertyRenaming {
+    fooBar: string = "";
+    rawStruct: i32 = 0;
+
+    static decode(reader: Decoder): KebabCasePropertyRenaming {
+        return decodeFields(reader, new KebabCasePropertyRenaming(), reader.readMapLength());
+    }
+
+    decodeField(key: string, reader: Decoder): bool {
+        if (key == "foo-bar") {
+            this.fooBar = decodeString(reader);
+            return true;
+        }
+        if (key == "raw-struct") {
+            this.rawStruct = reader.readInt32();
+            return true;
+        }
+        return false;
+    }
+
+    encode(writer: Writer): void {
+        writer.writeMapLength(2);
+        this.encodeFields(writer);
+    }
+
+    encodeFields(writer: Writer): void {
+        writer.writeString("foo-bar");
+        encodeString(writer, this.fooBar);
+        writer.writeString("raw-struct");
+        writer.writeInt32(this.rawStruct);
+    }
+}
+
+export type Label = string;
+
+/**
+ * Log level, serialized as a single byte.
+ */
+export enum Level {
+    Debug = 10,
+    Info = 20,
+    /**
+     * Something may be wrong.
+     */
+    Warning = 30,
+    Error = 31,
+}
+
+/**
+ * Marker without any data.
+ */
+export class Marker {
+    static decode(reader: Decoder): Marker {
+        reader.isNextNil();
+        return new Marker();
+    }
+
+    encode(writer: Writer): void {
+        writer.writeNil();
+    }
+}
+
+/**
+ * Newtypes may also wrap primitives.
+ */
+export type Meters = f64;
+
+/**
+ * A point of an arbitrary type.
+ */
+export class PointPointU64 {
+    value!: PointU64;
+
+    static decode(reader: Decoder): PointPointU64 {
+        return decodeFields(reader, new PointPointU64(), reader.readMapLength());
+    }
+
+    decodeField(key: string, reader: Decoder): bool {
+        if (key == "value") {
+            this.value = decodePointU64(reader);
+            return true;
+        }
+        return false;
+    }
+
+    encode(writer: Writer): void {
+        writer.writeMapLength(1);
+        this.encodeFields(writer);
+    }
+
+    encodeFields(writer: Writer): void {
+        writer.writeString("value");
+        encodePointU64(writer, this.value);
+    }
+}
+
+/**
+ * A point of an arbitrary type.
+ */
+export class PointF64 {
+    value: f64 = 0;
+
+    static decode(reader: Decoder): PointF64 {
+        return decodeFields(reader, new PointF64(), reader.readMapLength());
+    }
+
+    decodeField(key: string, reader: Decoder): bool {
+        if (key == "value") {
+            this.value = reader.readFloat64();
+            return true;
+        }
+        return false;
+    }
+
+    encode(writer: Writer): void {
+        writer.writeMapLength(1);
+        this.encodeFields(writer);
+    }
+
+    encodeFields(writer: Writer): void {
+        writer.writeString("value");
+        writer.writeFloat64(this.value);
+    }
+}
+
+/**
+ * A point of an arbitrary type.
+ */
+export class PointU64 {
+    value: u64 = 0;
+
+    static decode(reader: Decoder): PointU64 {
+        return decodeFields(reader, new PointU64(), reader.readMapLength());
+    }
+
+    decodeField(key: string, reader: Decoder): bool {
+        if (key == "value") {
+            this.value = reader.readUInt64();
+            return true;
+        }
+        return false;
+    }
+
+    encode(writer: Writer): void {
+        writer.writeMapLength(1);
+        this.encodeFields(writer);
+    }
+
+    encodeFields(writer: Writer): void {
+        writer.writeString("value");
+        writer.writeUInt64(this.value);
+    }
+}
+
+/**
+ * A position in 2D space.
+ */
+export class Position {
+    x: f64 = 0;
+    y: f64 = 0;
+
+    static decode(reader: Decoder): Position {
+        return decodeFields(reader, new Position(), reader.readMapLength());
+    }
+
+    decodeField(key: string, reader: Decoder): bool {
+        if (key == "x") {
+            this.x = reader.readFloat64();
+            return true;
+        }
+        if (key == "y") {
+            this.y = reader.readFloat64();
+            return true;
+        }
+        return false;
+    }
+
+    encode(writer: Writer): void {
+        writer.writeMapLength(2);
+        this.encodeFields(writer);
+    }
+
+    encodeFields(writer: Writer): void {
+        writer.writeString("x");
+        writer.writeFloat64(this.x);
+        writer.writeString("y");
+        writer.writeFloat64(this.y);
+    }
+}
+
+export abstract class Priority {
+    static decode(reader: Decoder): Priority {
+        if (reader.isNextString()) {
+            const name = reader.readString();
+            if (name == "Low") return new PriorityLow();
+            if (name == "High" || name == "Urgent") return new PriorityHigh();
+            if (name == "Legacy") return new PriorityLegacy();
+            if (name == "Unknown") return new PriorityUnknown();
+            return new PriorityUnknown();
+        }
+        reader.readMapLength();
+        const name = reader.readString();
+        throw new Error("Unknown variant for enum Priority: " + name);
+    }
+
+    abstract encode(writer: Writer): void;
+}
+
+export class PriorityLow extends Priority {
+    encode(writer: Writer): void {
+        writer.writeString("Low");
+    }
+}
+
+export class PriorityHigh extends Priority {
+    encode(writer: Writer): void {
+        writer.writeString("High");
+    }
+}
+
+export class PriorityLegacy extends Priority {
+    encode(writer: Writer): void {
+        throw new Error("Variant Legacy of enum Priority cannot be encoded");
+    }
+}
+
+export class PriorityPending extends Priority {
+    encode(writer: Writer): void {
+        writer.writeString("Pending");
+    }
+}
+
+export class PriorityUnknown extends Priority {
+    encode(writer: Writer): void {
+        writer.writeString("Unknown");
+    }
+}
+
+export class Range {
+    start: u32 = 0;
+    end: u32 = 0;
+
+    static decode(reader: Decoder): Range {
+        return decodeFields(reader, new Range(), reader.readMapLength());
+    }
+
+    decodeField(key: string, reader: Decoder): bool {
+        if (key == "start") {
+            this.start = reader.readUInt32();
+            return true;
+        }
+        if (key == "end") {
+            this.end = reader.readUInt32();
+            return true;
+        }
+        return false;
+    }
+
+    encode(writer: Writer): void {
+        writer.writeMapLength(3);
+        writer.writeString("type");
+        writer.writeString("range");
+        this.encodeFields(writer);
+    }
+
+    encodeFields(writer: Writer): void {
+        writer.writeString("start");
+        writer.writeUInt32(this.start);
+        writer.writeString("end");
+        writer.writeUInt32(this.end);
+    }
+}
+
+/**
+ * Example for representing Redux actions.
+ */
+export abstract class ReduxAction {
+    static decode(reader: Decoder): ReduxAction {
+        const numFields = reader.readMapLength();
+        if (reader.readString() != "type") {
+            throw new Error("Expected tag \"type\" for enum ReduxAction");
+        }
+        const name = reader.readString();
+        if (name == "clear_title") {
+            return new ReduxActionClearTitle();
+        }
+        if (name == "update_title") {
+            reader.readString();
+            return decodeFields(reader, new ReduxActionUpdateTitle(), reader.readMapLength());
+        }
+        throw new Error("Unknown variant for enum ReduxAction: " + name);
+    }
+
+    abstract encode(writer: Writer): void;
+}
+
+export class ReduxActionClearTitle extends ReduxAction {
+    encode(writer: Writer): void {
+        writer.writeMapLength(1);
+        writer.writeString("type");
+        writer.writeString("clear_title");
+    }
+}
+
+export class ReduxActionUpdateTitle extends ReduxAction {
+    title: string = "";
+
+    decodeField(key: string, reader: Decoder): bool {
+        if (key == "title") {
+            this.title = decodeString(reader);
+            return true;
+        }
+        return false;
+    }
+
+    encodeFields(writer: Writer): void {
+        writer.writeString("title");
+        encodeString(writer, this.title);
+    }
+
+    encode(writer: Writer): void {
+        writer.writeMapLength(2);
+        writer.writeString("type");
+        writer.writeString("update_title");
+        writer.writeString("payload");
+        writer.writeMapLength(1);
+        this.encodeFields(writer);
+    }
+}
+
+/**
+ * Represents an HTTP request to be sent.
+ */
+export class Request {
+    /**
+     * The URI to submit the request to.
+     */
+    url: string = "";
+
+    /**
+     * HTTP method to use for the request.
+     */
+    method: string = "";
+
+    /**
+     * HTTP headers to submit with the request.
+     */
+    headers: HeaderMap = new HeaderMap();
+
+    /**
+     * The body to submit with the request.
+     */
+    body: Body | null = null;
+
+    static decode(reader: Decoder): Request {
+        return decodeFields(reader, new Request(), reader.readMapLength());
+    }
+
+    decodeField(key: string, reader: Decoder): bool {
+        if (key == "url") {
+            this.url = decodeUri(reader);
+            return true;
+        }
+        if (key == "method") {
+            this.method = decodeMethod(reader);
+            return true;
+        }
+        if (key == "headers") {
+            this.headers = decodeHeaderMap(reader);
+            return true;
+        }
+        if (key == "body") {
+            this.body = decodeOptionBody(reader);
+            return true;
+        }
+        return false;
+    }
+
+    encode(writer: Writer): void {
+        writer.writeMapLength(4);
+        this.encodeFields(writer);
+    }
+
+    encodeFields(writer: Writer): void {
+        writer.writeString("url");
+        encodeUri(writer, this.url);
+        writer.writeString("method");
+        encodeMethod(writer, this.method);
+        writer.writeString("headers");
+        encodeHeaderMap(writer, this.headers);
+        writer.writeString("body");
+        encodeOptionBody(writer, this.body);
+    }
+}
+
+/**
+ * A value of type `HttpRequest`, which cannot be represented in AssemblyScript.
+ *
+ * Its value is skipped when decoding, so it cannot be encoded again.
+ */
+export class RequestByteBuf {}
+
+/**
+ * Represents an error that occurred while attempting to submit the request.
+ */
+export abstract class RequestError {
+    static decode(reader: Decoder): RequestError {
+        const numFields = reader.readMapLength();
+        if (reader.readString() != "type") {
+            throw new Error("Expected tag \"type\" for enum RequestError");
+        }
+        const name = reader.readString();
+        if (name == "offline") {
+            return new RequestErrorOffline();
+        }
+        if (name == "no_route") {
+            return new RequestErrorNoRoute();
+        }
+        if (name == "connection_refused") {
+            return new RequestErrorConnectionRefused();
+        }
+        if (name == "timeout") {
+            return new RequestErrorTimeout();
+        }
+        if (name == "server_error") {
+            return decodeFields(reader, new RequestErrorServerError(), numFields - 1);
+        }
+        if (name == "other/misc") {
+            return decodeFields(reader, new RequestErrorOther(), numFields - 1);
+        }
+        throw new Error("Unknown variant for enum RequestError: " + name);
+    }
+
+    abstract encode(writer: Writer): void;
+}
+
+/**
+ * Used when we know we don't have an active network connection.
+ */
+export class RequestErrorOffline extends RequestError {
+    encode(writer: Writer): void {
+        writer.writeMapLength(1);
+        writer.writeString("type");
+        writer.writeString("offline");
+    }
+}
+
+export class RequestErrorNoRoute extends RequestError {
+    encode(writer: Writer): void {
+        writer.writeMapLength(1);
+        writer.writeString("type");
+        writer.writeString("no_route");
+    }
+}
+
+export class RequestErrorConnectionRefused extends RequestError {
+    encode(writer: Writer): void {
+        writer.writeMapLength(1);
+        writer.writeString("type");
+        writer.writeString("connection_refused");
+    }
+}
+
+export class RequestErrorTimeout extends RequestError {
+    encode(writer: Writer): void {
+        writer.writeMapLength(1);
+        writer.writeString("type");
+        writer.writeString("timeout");
+    }
+}
+
+export class RequestErrorServerError extends RequestError {
+    /**
+     * HTTP status code.
+     */
+    statusCode: u16 = 0;
+
+    /**
+     * Response body.
+     */
+    response: Body = new ArrayBuffer(0);
+
+    decodeField(key: string, reader: Decoder): bool {
+        if (key == "status_code") {
+            this.statusCode = reader.readUInt16();
+            return true;
+        }
+        if (key == "response") {
+            this.response = decodeBody(reader);
+            return true;
+        }
+        return false;
+    }
+
+    encodeFields(writer: Writer): void {
+        writer.writeString("status_code");
+        writer.writeUInt16(this.statusCode);
+        writer.writeString("response");
+        encodeBody(writer, this.response);
+    }
+
+    encode(writer: Writer): void {
+        writer.writeMapLength(3);
+        writer.writeString("type");
+        writer.writeString("server_error");
+        this.encodeFields(writer);
+    }
+}
+
+/**
+ * Misc.
+ */
+export class RequestErrorOther extends RequestError {
+    reason: string = "";
+
+    decodeField(key: string, reader: Decoder): bool {
+        if (key == "reason") {
+            this.reason = decodeString(reader);
+            return true;
+        }
+        return false;
+    }
+
+    encodeFields(writer: Writer): void {
+        writer.writeString("reason");
+        encodeString(writer, this.reason);
+    }
+
+    encode(writer: Writer): void {
+        writer.writeMapLength(2);
+        writer.writeString("type");
+        writer.writeString("other/misc");
+        this.encodeFields(writer);
+    }
+}
+
+/**
+ * Represents an HTTP response we received.
+ *
+ * Please note we currently do not support streaming responses.
+ */
+export class Response {
+    /**
+     * The response body. May be empty.
+     */
+    body: Body = new ArrayBuffer(0);
+
+    /**
+     * HTTP headers that were part of the response.
+     */
+    headers: HeaderMap = new HeaderMap();
+
+    /**
+     * HTTP status code.
+     */
+    statusCode: StatusCode = new StatusCode();
+
+    static decode(reader: Decoder): Response {
+        return decodeFields(reader, new Response(), reader.readMapLength());
+    }
+
+    decodeField(key: string, reader: Decoder): bool {
+        if (key == "body") {
+            this.body = decodeBody(reader);
+            return true;
+        }
+        if (key == "headers") {
+            this.headers = decodeHeaderMap(reader);
+            return true;
+        }
+        if (key == "status_code") {
+            this.statusCode = decodeStatusCode(reader);
+            return true;
+        }
+        return false;
+    }
+
+    encode(writer: Writer): void {
+        writer.writeMapLength(3);
+        this.encodeFields(writer);
+    }
+
+    encodeFields(writer: Writer): void {
+        writer.writeString("body");
+        encodeBody(writer, this.body);
+        writer.writeString("headers");
+        encodeHeaderMap(writer, this.headers);
+        writer.writeString("status_code");
+        encodeStatusCode(writer, this.statusCode);
+    }
+}
+
+/**
+ * A value of type `HttpResponse`, which cannot be represented in AssemblyScript.
+ *
+ * Its value is skipped when decoding, so it cannot be encoded again.
+ */
+export class ResponseByteBuf {}
+
+/**
+ * A result that can be either successful (`Ok)` or represent an error (`Err`).
+ */
+export abstract class ResultResponseRequestError {
+    static decode(reader: Decoder): ResultResponseRequestError {
+        reader.readMapLength();
+        const name = reader.readString();
+        if (name == "Ok") {
+            const variant = new ResultResponseRequestErrorOk();
+            variant.value = decodeResponse(reader);
+            return variant;
+        }
+        if (name == "Err") {
+            const variant = new ResultResponseRequestErrorErr();
+            variant.value = decodeRequestError(reader);
+            return variant;
+        }
+        throw new Error("Unknown variant for enum ResultResponseRequestError: " + name);
+    }
+
+    abstract encode(writer: Writer): void;
+}
+
+/**
+ * Represents a succesful result.
+ */
+export class ResultResponseRequestErrorOk extends ResultResponseRequestError {
+    value!: Response;
+
+    encode(writer: Writer): void {
+        writer.writeMapLength(1);
+        writer.writeString("Ok");
+        encodeResponse(writer, this.value);
+    }
+}
+
+/**
+ * Represents an error.
+ */
+export class ResultResponseRequestErrorErr extends ResultResponseRequestError {
+    value!: RequestError;
+
+    encode(writer: Writer): void {
+        writer.writeMapLength(1);
+        writer.writeString("Err");
+        encodeRequestError(writer, this.value);
+    }
+}
+
+/**
+ * A result that can be either successful (`Ok)` or represent an error (`Err`).
+ */
+export abstract class ResultStringString {
+    static decode(reader: Decoder): ResultStringString {
+        reader.readMapLength();
+        const name = reader.readString();
+        if (name == "Ok") {
+            const variant = new ResultStringStringOk();
+            variant.value = decodeString(reader);
+            return variant;
+        }
+        if (name == "Err") {
+            const variant = new ResultStringStringErr();
+            variant.value = decodeString(reader);
+            return variant;
+        }
+        throw new Error("Unknown variant for enum ResultStringString: " + name);
+    }
+
+    abstract encode(writer: Writer): void;
+}
+
+/**
+ * Represents a succesful result.
+ */
+export class ResultStringStringOk extends ResultStringString {
+    value: string = "";
+
+    encode(writer: Writer): void {
+        writer.writeMapLength(1);
+        writer.writeString("Ok");
+        encodeString(writer, this.value);
+    }
+}
+
+/**
+ * Represents an error.
+ */
+export class ResultStringStringErr extends ResultStringString {
+    value: string = "";
+
+    encode(writer: Writer): void {
+        writer.writeMapLength(1);
+        writer.writeString("Err");
+        encodeString(writer, this.value);
+    }
+}
+
+/**
+ * Color in RGB format.
+ */
+export class Rgb {
+    value0: u8 = 0;
+    value1: u8 = 0;
+    value2: u8 = 0;
+
+    static decode(reader: Decoder): Rgb {
+        reader.readArrayLength();
+        const result = new Rgb();
+        result.value0 = reader.readUInt8();
+        result.value1 = reader.readUInt8();
+        result.value2 = reader.readUInt8();
+        return result;
+    }
+
+    encode(writer: Writer): void {
+        writer.writeArrayLength(3);
+        writer.writeUInt8(this.value0);
+        writer.writeUInt8(this.value1);
+        writer.writeUInt8(this.value2);
+    }
+}
+
+export abstract class SerdeAdjacentlyTagged {
+    static decode(reader: Decoder): SerdeAdjacentlyTagged {
+        const numFields = reader.readMapLength();
+        if (reader.readString() != "type") {
+            throw new Error("Expected tag \"type\" for enum SerdeAdjacentlyTagged");
+        }
+        const name = reader.readString();
+        if (name == "Foo") {
+            return new SerdeAdjacentlyTaggedFoo();
+        }
+        if (name == "Bar") {
+            reader.readString();
+            const variant = new SerdeAdjacentlyTaggedBar();
+            variant.value = decodeString(reader);
+            return variant;
+        }
+        if (name == "Baz") {
+            reader.readString();
+            return decodeFields(reader, new SerdeAdjacentlyTaggedBaz(), reader.readMapLength());
+        }
+        throw new Error("Unknown variant for enum SerdeAdjacentlyTagged: " + name);
+    }
+
+    abstract encode(writer: Writer): void;
+}
+
+export class SerdeAdjacentlyTaggedFoo extends SerdeAdjacentlyTagged {
+    encode(writer: Writer): void {
+        writer.writeMapLength(1);
+        writer.writeString("type");
+        writer.writeString("Foo");
+    }
+}
+
+export class SerdeAdjacentlyTaggedBar extends SerdeAdjacentlyTagged {
+    value: string = "";
+
+    encode(writer: Writer): void {
+        writer.writeMapLength(2);
+        writer.writeString("type");
+        writer.writeString("Bar");
+        writer.writeString("payload");
+        encodeString(writer, this.value);
+    }
+}
+
+export class SerdeAdjacentlyTaggedBaz extends SerdeAdjacentlyTagged {
+    a: i8 = 0;
+    b: u64 = 0;
+
+    decodeField(key: string, reader: Decoder): bool {
+        if (key == "a") {
+            this.a = reader.readInt8();
+            return true;
+        }
+        if (key == "b") {
+            this.b = reader.readUInt64();
+            return true;
+        }
+        return false;
+    }
+
+    encodeFields(writer: Writer): void {
+        writer.writeString("a");
+        writer.writeInt8(this.a);
+        writer.writeString("b");
+        writer.writeUInt64(this.b);
+    }
+
+    encode(writer: Writer): void {
+        writer.writeMapLength(2);
+        writer.writeString("type");
+        writer.writeString("Baz");
+        writer.writeString("payload");
+        writer.writeMapLength(2);
+        this.encodeFields(writer);
+    }
+}
+
+export class SerdeAttributes {
+    title: string = "";
+    maxItems: u32 = 0;
+    password: string = "";
+    revision: u32 = 0;
+    label: Label = "";
+    priority!: Priority;
+    range!: Range;
+    email: Email = "";
+
+    static decode(reader: Decoder): SerdeAttributes {
+        return decodeFields(reader, new SerdeAttributes(), reader.readMapLength());
+    }
+
+    decodeField(key: string, reader: Decoder): bool {
+        if (key == "title" || key == "name") {
+            this.title = decodeString(reader);
+            return true;
+        }
+        if (key == "max_items") {
+            this.maxItems = reader.readUInt32();
+            return true;
+        }
+        if (key == "password") {
+            this.password = decodeString(reader);
+            return true;
+        }
+        if (key == "label") {
+            this.label = decodeLabel(reader);
+            return true;
+        }
+        if (key == "priority") {
+            this.priority = decodePriority(reader);
+            return true;
+        }
+        if (key == "range") {
+            this.range = decodeRange(reader);
+            return true;
+        }
+        if (key == "email") {
+            this.email = decodeEmail(reader);
+            return true;
+        }
+        return false;
+    }
+
+    encode(writer: Writer): void {
+        writer.writeMapLength(7);
+        this.encodeFields(writer);
+    }
+
+    encodeFields(writer: Writer): void {
+        writer.writeString("title");
+        encodeString(writer, this.title);
+        writer.writeString("maxItems");
+        writer.writeUInt32(this.maxItems);
+        writer.writeString("revision");
+        writer.writeUInt32(this.revision);
+        writer.writeString("label");
+        encodeLabel(writer, this.label);
+        writer.writeString("priority");
+        encodePriority(writer, this.priority);
+        writer.writeString("range");
+        encodeRange(writer, this.range);
+        writer.writeString("email");
+        encodeEmail(writer, this.email);
+    }
+}
+
+export class SerdeFlatten {
+    flattened: FlattenedStruct = new FlattenedStruct();
+
+    static decode(reader: Decoder): SerdeFlatten {
+        return decodeFields(reader, new SerdeFlatten(), reader.readMapLength());
+    }
+
+    decodeField(key: string, reader: Decoder): bool {
+        return this.flattened.decodeField(key, reader);
+    }
+
+    encode(writer: Writer): void {
+        writer.writeMapLength(2);
+        this.encodeFields(writer);
+    }
+
+    encodeFields(writer: Writer): void {
+        this.flattened.encodeFields(writer);
+    }
+}
+
+export abstract class SerdeInternallyTagged {
+    static decode(reader: Decoder): SerdeInternallyTagged {
+        const numFields = reader.readMapLength();
+        if (reader.readString() != "type") {
+            throw new Error("Expected tag \"type\" for enum SerdeInternallyTagged");
+        }
+        const name = reader.readString();
+        if (name == "Foo") {
+            return new SerdeInternallyTaggedFoo();
+        }
+        if (name == "Baz") {
+            return decodeFields(reader, new SerdeInternallyTaggedBaz(), numFields - 1);
+        }
+        throw new Error("Unknown variant for enum SerdeInternallyTagged: " + name);
+    }
+
+    abstract encode(writer: Writer): void;
+}
+
+export class SerdeInternallyTaggedFoo extends SerdeInternallyTagged {
+    encode(writer: Writer): void {
+        writer.writeMapLength(1);
+        writer.writeString("type");
+        writer.writeString("Foo");
+    }
+}
+
+export class SerdeInternallyTaggedBaz extends SerdeInternallyTagged {
+    a: i8 = 0;
+    b: u64 = 0;
+
+    decodeField(key: string, reader: Decoder): bool {
+        if (key == "a") {
+            this.a = reader.readInt8();
+            return true;
+        }
+        if (key == "b") {
+            this.b = reader.readUInt64();
+            return true;
+        }
+        return false;
+    }
+
+    encodeFields(writer: Writer): void {
+        writer.writeString("a");
+        writer.writeInt8(this.a);
+        writer.writeString("b");
+        writer.writeUInt64(this.b);
+    }
+
+    encode(writer: Writer): void {
+        writer.writeMapLength(3);
+        writer.writeString("type");
+        writer.writeString("Baz");
+        this.encodeFields(writer);
+    }
+}
+
+export class SerdePropertyRenaming {
+    fooBar: string = "";
+    quxBaz: f64 = 0;
+    rawStruct: i32 = 0;
+
+    static decode(reader: Decoder): SerdePropertyRenaming {
+        return decodeFields(reader, new SerdePropertyRenaming(), reader.readMapLength());
+    }
+
+    decodeField(key: string, reader: Decoder): bool {
+        if (key == "fooBar") {
+            this.fooBar = decodeString(reader);
+            return true;
+        }
+        if (key == "QUX_BAZ") {
+            this.quxBaz = reader.readFloat64();
+            return true;
+        }
+        if (key == "rawStruct") {
+            this.rawStruct = reader.readInt32();
+            return true;
+        }
+        return false;
+    }
+
+    encode(writer: Writer): void {
+        writer.writeMapLength(3);
+        this.encodeFields(writer);
+    }
+
+    encodeFields(writer: Writer): void {
+        writer.writeString("fooBar");
+        encodeString(writer, this.fooBar);
+        writer.writeString("QUX_BAZ");
+        writer.writeFloat64(this.quxBaz);
+        writer.writeString("rawStruct");
+        writer.writeInt32(this.rawStruct);
+    }
+}
+
+export abstract class SerdeUntagged {
+    static decode(reader: Decoder): SerdeUntagged {
+        throw new Error("Untagged enum SerdeUntagged cannot be decoded by AssemblyScript plugins");
+    }
+
+    abstract encode(writer: Writer): void;
+}
+
+export class SerdeUntaggedBar extends SerdeUntagged {
+    value: string = "";
+
+    encode(writer: Writer): void {
+        encodeString(writer, this.value);
+    }
+}
+
+export class SerdeUntaggedBaz extends SerdeUntagged {
+    a: i8 = 0;
+    b: u64 = 0;
+
+    decodeField(key: string, reader: Decoder): bool {
+        if (key == "a") {
+            this.a = reader.readInt8();
+            return true;
+        }
+        if (key == "b") {
+            this.b = reader.readUInt64();
+            return true;
+        }
+        return false;
+    }
+
+    encodeFields(writer: Writer): void {
+        writer.writeString("a");
+        writer.writeInt8(this.a);
+        writer.writeString("b");
+        writer.writeUInt64(this.b);
+    }
+
+    encode(writer: Writer): void {
+        writer.writeMapLength(2);
+        this.encodeFields(writer);
+    }
+}
+
+export abstract class SerdeVariantRenaming {
+    static decode(reader: Decoder): SerdeVariantRenaming {
+        if (reader.isNextString()) {
+            const name = reader.readString();
+            if (name == "foo_bar") return new SerdeVariantRenamingFooBar();
+            throw new Error("Unknown variant for enum SerdeVariantRenaming: " + name);
+        }
+        reader.readMapLength();
+        const name = reader.readString();
+        if (name == "QUX_BAZ") {
+            return decodeFields(reader, new SerdeVariantRenamingQuxBaz(), reader.readMapLength());
+        }
+        throw new Error("Unknown variant for enum SerdeVariantRenaming: " + name);
+    }
+
+    abstract encode(writer: Writer): void;
+}
+
+export class SerdeVariantRenamingFooBar extends SerdeVariantRenaming {
+    encode(writer: Writer): void {
+        writer.writeString("foo_bar");
+    }
+}
+
+export class SerdeVariantRenamingQuxBaz extends SerdeVariantRenaming {
+    /**
+     * Will be renamed to "FooBar" because of the `rename_all` on the
+     * variant.
+     */
+    fooBar: string = "";
+    quxBaz: f64 = 0;
+
+    decodeField(key: string, reader: Decoder): bool {
+        if (key == "FooBar") {
+            this.fooBar = decodeString(reader);
+            return true;
+        }
+        if (key == "qux_baz") {
+            this.quxBaz = reader.readFloat64();
+            return true;
+        }
+        return false;
+    }
+
+    encodeFields(writer: Writer): void {
+        writer.writeString("FooBar");
+        encodeString(writer, this.fooBar);
+        writer.writeString("qux_baz");
+        writer.writeFloat64(this.quxBaz);
+    }
+
+    encode(writer: Writer): void {
+        writer.writeMapLength(1);
+        writer.writeString("QUX_BAZ");
+        writer.writeMapLength(2);
+        this.encodeFields(writer);
+    }
+}
+
+/**
+ * A shape, which may be one we don't know about yet.
+ */
+export abstract class Shape {
+    static decode(reader: Decoder): Shape {
+        if (reader.isNextString()) {
+            const name = reader.readString();
+            if (name == "Point") return new ShapePoint();
+            return new ShapeUnknown();
+        }
+        reader.readMapLength();
+        const name = reader.readString();
+        if (name == "Circle") {
+            return decodeFields(reader, new ShapeCircle(), reader.readMapLength());
+        }
+        if (name == "Square") {
+            const variant = new ShapeSquare();
+            variant.value = reader.readFloat64();
+            return variant;
+        }
+        reader.skip();
+        return new ShapeUnknown();
+    }
+
+    abstract encode(writer: Writer): void;
+}
+
+export class ShapeCircle extends Shape {
+    radius: f64 = 0;
+
+    decodeField(key: string, reader: Decoder): bool {
+        if (key == "radius") {
+            this.radius = reader.readFloat64();
+            return true;
+        }
+        return false;
+    }
+
+    encodeFields(writer: Writer): void {
+        writer.writeString("radius");
+        writer.writeFloat64(this.radius);
+    }
+
+    encode(writer: Writer): void {
+        writer.writeMapLength(1);
+        writer.writeString("Circle");
+        writer.writeMapLength(1);
+        this.encodeFields(writer);
+    }
+}
+
+export class ShapePoint extends Shape {
+    encode(writer: Writer): void {
+        writer.writeString("Point");
+    }
+}
+
+export class ShapeSquare extends Shape {
+    value: f64 = 0;
+
+    encode(writer: Writer): void {
+        writer.writeMapLength(1);
+        writer.writeString("Square");
+        writer.writeFloat64(this.value);
+    }
+}
+
+/**
+ * A variant that is not known to this version of the protocol.
+ *
+ * Its value is skipped when decoding, so it cannot be encoded again.
+ */
+export class ShapeUnknown extends Shape {
+    encode(writer: Writer): void {
+        throw new Error("Unknown variant of enum Shape cannot be encoded");
+    }
+}
+
+/**
+ * A state update to communicate to the Redux host.
+ *
+ * Fields are wrapped in `Option`. If any field is `None` it means it hasn't
+ * changed.
+ */
+export class StateUpdate {
+    title: string | null = null;
+    revision: Box<u16> | null = null;
+
+    static decode(reader: Decoder): StateUpdate {
+        return decodeFields(reader, new StateUpdate(), reader.readMapLength());
+    }
+
+    decodeField(key: string, reader: Decoder): bool {
+        if (key == "title") {
+            this.title = decodeOptionRcString(reader);
+            return true;
+        }
+        if (key == "revision") {
+            this.revision = decodeOptionU16(reader);
+            return true;
+        }
+        return false;
+    }
+
+    encode(writer: Writer): void {
+        writer.writeMapLength(2);
+        this.encodeFields(writer);
+    }
+
+    encodeFields(writer: Writer): void {
+        writer.writeString("title");
+        encodeOptionRcString(writer, this.title);
+        writer.writeString("revision");
+        encodeOptionU16(writer, this.revision);
+    }
+}
+
+/**
+ * A value of type `number`, which cannot be represented in AssemblyScript.
+ *
+ * Its value is skipped when decoding, so it cannot be encoded again.
+ */
+export class StatusCode {}
+
+export class StructWithArrays {
+    checksum!: StaticArray<u8>;
+    coordinates!: StaticArray<f64>;
+    segments: Array<StaticArray<PointF64>> = new Array<StaticArray<PointF64>>();
+
+    static decode(reader: Decoder): StructWithArrays {
+        return decodeFields(reader, new StructWithArrays(), reader.readMapLength());
+    }
+
+    decodeField(key: string, reader: Decoder): bool {
+        if (key == "checksum") {
+            this.checksum = decodeArray4U8(reader);
+            return true;
+        }
+        if (key == "coordinates") {
+            this.coordinates = decodeArray3F64(reader);
+            return true;
+        }
+        if (key == "segments") {
+            this.segments = decodeVecArray2PointF64(reader);
+            return true;
+        }
+        return false;
+    }
+
+    encode(writer: Writer): void {
+        writer.writeMapLength(3);
+        this.encodeFields(writer);
+    }
+
+    encodeFields(writer: Writer): void {
+        writer.writeString("checksum");
+        encodeArray4U8(writer, this.checksum);
+        writer.writeString("coordinates");
+        encodeArray3F64(writer, this.coordinates);
+        writer.writeString("segments");
+        encodeVecArray2PointF64(writer, this.segments);
+    }
+}
+
+export class StructWithBinaryHeap {
+    priorities: Array<u32> = new Array<u32>();
+
+    static decode(reader: Decoder): StructWithBinaryHeap {
+        return decodeFields(reader, new StructWithBinaryHeap(), reader.readMapLength());
+    }
+
+    decodeField(key: string, reader: Decoder): bool {
+        if (key == "priorities") {
+            this.priorities = decodeBinaryHeapU32(reader);
+            return true;
+        }
+        return false;
+    }
+
+    encode(writer: Writer): void {
+        writer.writeMapLength(1);
+        this.encodeFields(writer);
+    }
+
+    encodeFields(writer: Writer): void {
+        writer.writeString("priorities");
+        encodeBinaryHeapU32(writer, this.priorities);
+    }
+}
+
+export class StructWithChronoTypes {
+    utc: string = "";
+    fixedOffset: string = "";
+    date: string = "";
+    dateTime: string = "";
+    delta: TimeDelta = new TimeDelta();
+
+    static decode(reader: Decoder): StructWithChronoTypes {
+        return decodeFields(reader, new StructWithChronoTypes(), reader.readMapLength());
+    }
+
+    decodeField(key: string, reader: Decoder): bool {
+        if (key == "utc") {
+            this.utc = decodeDateTimeUtc(reader);
+            return true;
+        }
+        if (key == "fixed_offset") {
+            this.fixedOffset = decodeDateTimeFixedOffset(reader);
+            return true;
+        }
+        if (key == "date") {
+            this.date = decodeNaiveDate(reader);
+            return true;
+        }
+        if (key == "date_time") {
+            this.dateTime = decodeNaiveDateTime(reader);
+            return true;
+        }
+        if (key == "delta") {
+            this.delta = decodeTimeDelta(reader);
+            return true;
+        }
+        return false;
+    }
+
+    encode(writer: Writer): void {
+        writer.writeMapLength(5);
+        this.encodeFields(writer);
+    }
+
+    encodeFields(writer: Writer): void {
+        writer.writeString("utc");
+        encodeDateTimeUtc(writer, this.utc);
+        writer.writeString("fixed_offset");
+        encodeDateTimeFixedOffset(writer, this.fixedOffset);
+        writer.writeString("date");
+        encodeNaiveDate(writer, this.date);
+        writer.writeString("date_time");
+        encodeNaiveDateTime(writer, this.dateTime);
+        writer.writeString("delta");
+        encodeTimeDelta(writer, this.delta);
+    }
+}
+
+export class StructWithCollections {
+    shared: string = "";
+    label: string = "";
+    queue: Array<u32> = new Array<u32>();
+    ordered: Map<string, u32> = new Map<string, u32>();
+
+    static decode(reader: Decoder): StructWithCollections {
+        return decodeFields(reader, new StructWithCollections(), reader.readMapLength());
+    }
+
+    decodeField(key: string, reader: Decoder): bool {
+        if (key == "shared") {
+            this.shared = decodeArcString(reader);
+            return true;
+        }
+        if (key == "label") {
+            this.label = decodeCowstr(reader);
+            return true;
+        }
+        if (key == "queue") {
+            this.queue = decodeVecDequeU32(reader);
+            return true;
+        }
+        if (key == "ordered") {
+            this.ordered = decodeIndexMapStringU32(reader);
+            return true;
+        }
+        return false;
+    }
+
+    encode(writer: Writer): void {
+        writer.writeMapLength(4);
+        this.encodeFields(writer);
+    }
+
+    encodeFields(writer: Writer): void {
+        writer.writeString("shared");
+        encodeArcString(writer, this.shared);
+        writer.writeString("label");
+        encodeCowstr(writer, this.label);
+        writer.writeString("queue");
+        encodeVecDequeU32(writer, this.queue);
+        writer.writeString("ordered");
+        encodeIndexMapStringU32(writer, this.ordered);
+    }
+}
+
+export class StructWithDurations {
+    timeout: Duration = new Duration();
+    interval: Duration | null = null;
+    createdAt: SystemTime = new SystemTime();
+
+    static decode(reader: Decoder): StructWithDurations {
+        return decodeFields(reader, new StructWithDurations(), reader.readMapLength());
+    }
+
+    decodeField(key: string, reader: Decoder): bool {
+        if (key == "timeout") {
+            this.timeout = decodeDuration(reader);
+            return true;
+        }
+        if (key == "interval") {
+            this.interval = decodeOptionDuration(reader);
+            return true;
+        }
+        if (key == "created_at") {
+            this.createdAt = decodeSystemTime(reader);
+            return true;
+        }
+        return false;
+    }
+
+    encode(writer: Writer): void {
+        writer.writeMapLength(3);
+        this.encodeFields(writer);
+    }
+
+    encodeFields(writer: Writer): void {
+        writer.writeString("timeout");
+        encodeDuration(writer, this.timeout);
+        writer.writeString("interval");
+        encodeOptionDuration(writer, this.interval);
+        writer.writeString("created_at");
+        encodeSystemTime(writer, this.createdAt);
+    }
+}
+
+export class StructWithGenericsU64 {
+    list: Array<u64> = new Array<u64>();
+    points: Array<PointU64> = new Array<PointU64>();
+    recursive: Array<PointPointU64> = new Array<PointPointU64>();
+    complexNested: Map<string, Array<FloatingPoint>> | null = null;
+    optionalTimestamp: string | null = null;
+
+    static decode(reader: Decoder): StructWithGenericsU64 {
+        return decodeFields(reader, new StructWithGenericsU64(), reader.readMapLength());
+    }
+
+    decodeField(key: string, reader: Decoder): bool {
+        if (key == "list") {
+            this.list = decodeVecU64(reader);
+            return true;
+        }
+        if (key == "points") {
+            this.points = decodeVecPointU64(reader);
+            return true;
+        }
+        if (key == "recursive") {
+            this.recursive = decodeVecPointPointU64(reader);
+            return true;
+        }
+        if (key == "complex_nested") {
+            this.complexNested = decodeOptionBTreeMapStringVecFloatingPoint(reader);
+            return true;
+        }
+        if (key == "optional_timestamp") {
+            this.optionalTimestamp = decodeOptionOffsetDateTime(reader);
+            return true;
+        }
+        return false;
+    }
+
+    encode(writer: Writer): void {
+        writer.writeMapLength(5);
+        this.encodeFields(writer);
+    }
+
+    encodeFields(writer: Writer): void {
+        writer.writeString("list");
+        encodeVecU64(writer, this.list);
+        writer.writeString("points");
+        encodeVecPointU64(writer, this.points);
+        writer.writeString("recursive");
+        encodeVecPointPointU64(writer, this.recursive);
+        writer.writeString("complex_nested");
+        encodeOptionBTreeMapStringVecFloatingPoint(writer, this.complexNested);
+        writer.writeString("optional_timestamp");
+        encodeOptionOffsetDateTime(writer, this.optionalTimestamp);
+    }
+}
+
+export class StructWithJson {
+    value: JsonValue = new JsonValue();
+    object: JsonMapStringJsonValue = new JsonMapStringJsonValue();
+    optionalValue: JsonValue | null = null;
+
+    static decode(reader: Decoder): StructWithJson {
+        return decodeFields(reader, new StructWithJson(), reader.readMapLength());
+    }
+
+    decodeField(key: string, reader: Decoder): bool {
+        if (key == "value") {
+            this.value = decodeJsonValue(reader);
+            return true;
+        }
+        if (key == "object") {
+            this.object = decodeJsonMapStringJsonValue(reader);
+            return true;
+        }
+        if (key == "optional_value") {
+            this.optionalValue = decodeOptionJsonValue(reader);
+            return true;
+        }
+        return false;
+    }
+
+    encode(writer: Writer): void {
+        writer.writeMapLength(3);
+        this.encodeFields(writer);
+    }
+
+    encodeFields(writer: Writer): void {
+        writer.writeString("value");
+        encodeJsonValue(writer, this.value);
+        writer.writeString("object");
+        encodeJsonMapStringJsonValue(writer, this.object);
+        writer.writeString("optional_value");
+        encodeOptionJsonValue(writer, this.optionalValue);
+    }
+}
+
+export class StructWithReprEnums {
+    level: Level = Level.Debug;
+    history: Array<Level> = new Array<Level>();
+
+    static decode(reader: Decoder): StructWithReprEnums {
+        return decodeFields(reader, new StructWithReprEnums(), reader.readMapLength());
+    }
+
+    decodeField(key: string, reader: Decoder): bool {
+        if (key == "level") {
+            this.level = decodeLevel(reader);
+            return true;
+        }
+        if (key == "history") {
+            this.history = decodeVecLevel(reader);
+            return true;
+        }
+        return false;
+    }
+
+    encode(writer: Writer): void {
+        writer.writeMapLength(2);
+        this.encodeFields(writer);
+    }
+
+    encodeFields(writer: Writer): void {
+        writer.writeString("level");
+        encodeLevel(writer, this.level);
+        writer.writeString("history");
+        encodeVecLevel(writer, this.history);
+    }
+}
+
+export class StructWithTupleStructs {
+    userId: UserId = "";
+    optionalUserId: UserId | null = null;
+    distance: Meters = 0;
+    wrapped: WrapperU32 = 0;
+    color!: Rgb;
+    marker!: Marker;
+
+    static decode(reader: Decoder): StructWithTupleStructs {
+        return decodeFields(reader, new StructWithTupleStructs(), reader.readMapLength());
+    }
+
+    decodeField(key: string, reader: Decoder): bool {
+        if (key == "user_id") {
+            this.userId = decodeUserId(reader);
+            return true;
+        }
+        if (key == "optional_user_id") {
+            this.optionalUserId = decodeOptionUserId(reader);
+            return true;
+        }
+        if (key == "distance") {
+            this.distance = reader.readFloat64();
+            return true;
+        }
+        if (key == "wrapped") {
+            this.wrapped = reader.readUInt32();
+            return true;
+        }
+        if (key == "color") {
+            this.color = decodeRgb(reader);
+            return true;
+        }
+        if (key == "marker") {
+            this.marker = decodeMarker(reader);
+            return true;
+        }
+        return false;
+    }
+
+    encode(writer: Writer): void {
+        writer.writeMapLength(6);
+        this.encodeFields(writer);
+    }
+
+    encodeFields(writer: Writer): void {
+        writer.writeString("user_id");
+        encodeUserId(writer, this.userId);
+        writer.writeString("optional_user_id");
+        encodeOptionUserId(writer, this.optionalUserId);
+        writer.writeString("distance");
+        writer.writeFloat64(this.distance);
+        writer.writeString("wrapped");
+        writer.writeUInt32(this.wrapped);
+        writer.writeString("color");
+        encodeRgb(writer, this.color);
+        writer.writeString("marker");
+        encodeMarker(writer, this.marker);
+    }
+}
+
+export class StructWithTuples {
+    pair!: TupleI64I64;
+    nested: Array<TupleStringTupleU8Bool> = new Array<TupleStringTupleU8Bool>();
+    single!: TupleString;
+
+    static decode(reader: Decoder): StructWithTuples {
+        return decodeFields(reader, new StructWithTuples(), reader.readMapLength());
+    }
+
+    decodeField(key: string, reader: Decoder): bool {
+        if (key == "pair") {
+            this.pair = decodeTupleI64I64(reader);
+            return true;
+        }
+        if (key == "nested") {
+            this.nested = decodeVecTupleStringTupleU8Bool(reader);
+            return true;
+        }
+        if (key == "single") {
+            this.single = decodeTupleString(reader);
+            return true;
+        }
+        return false;
+    }
+
+    encode(writer: Writer): void {
+        writer.writeMapLength(3);
+        this.encodeFields(writer);
+    }
+
+    encodeFields(writer: Writer): void {
+        writer.writeString("pair");
+        encodeTupleI64I64(writer, this.pair);
+        writer.writeString("nested");
+        encodeVecTupleStringTupleU8Bool(writer, this.nested);
+        writer.writeString("single");
+        encodeTupleString(writer, this.single);
+    }
+}
+
+export class StructWithWidePrimitives {
+    initial: string = "";
+    signedLarge: ArrayBuffer = new ArrayBuffer(16);
+    unsignedLarge: ArrayBuffer = new ArrayBuffer(16);
+    offset: i64 = 0;
+    length: u64 = 0;
+
+    static decode(reader: Decoder): StructWithWidePrimitives {
+        return decodeFields(reader, new StructWithWidePrimitives(), reader.readMapLength());
+    }
+
+    decodeField(key: string, reader: Decoder): bool {
+        if (key == "initial") {
+            this.initial = reader.readString();
+            return true;
+        }
+        if (key == "signed_large") {
+            this.signedLarge = reader.readByteArray();
+            return true;
+        }
+        if (key == "unsigned_large") {
+            this.unsignedLarge = reader.readByteArray();
+            return true;
+        }
+        if (key == "offset") {
+            this.offset = reader.readInt64();
+            return true;
+        }
+        if (key == "length") {
+            this.length = reader.readUInt64();
+            return true;
+        }
+        return false;
+    }
+
+    encode(writer: Writer): void {
+        writer.writeMapLength(5);
+        this.encodeFields(writer);
+    }
+
+    encodeFields(writer: Writer): void {
+        writer.writeString("initial");
+        writer.writeString(this.initial);
+        writer.writeString("signed_large");
+        writer.writeByteArray(this.signedLarge);
+        writer.writeString("unsigned_large");
+        writer.writeByteArray(this.unsignedLarge);
+        writer.writeString("offset");
+        writer.writeInt64(this.offset);
+        writer.writeString("length");
+        writer.writeUInt64(this.length);
+    }
+}
+
+/**
+ * A value of type `SystemTime`, which cannot be represented in AssemblyScript.
+ *
+ * Its value is skipped when decoding, so it cannot be encoded again.
+ */
+export class SystemTime {}
+
+/**
+ * Internally tagged equivalent of `Shape`.
+ */
+export abstract class TaggedShape {
+    static decode(reader: Decoder): TaggedShape {
+        const numFields = reader.readMapLength();
+        if (reader.readString() != "type") {
+            throw new Error("Expected tag \"type\" for enum TaggedShape");
+        }
+        const name = reader.readString();
+        if (name == "Circle") {
+            return decodeFields(reader, new TaggedShapeCircle(), numFields - 1);
+        }
+        if (name == "Point") {
+            return new TaggedShapePoint();
+        }
+        for (let i: u32 = 1; i < numFields; i++) {
+            reader.skip();
+            reader.skip();
+        }
+        return new TaggedShapeUnknown();
+    }
+
+    abstract encode(writer: Writer): void;
+}
+
+export class TaggedShapeCircle extends TaggedShape {
+    radius: f64 = 0;
+
+    decodeField(key: string, reader: Decoder): bool {
+        if (key == "radius") {
+            this.radius = reader.readFloat64();
+            return true;
+        }
+        return false;
+    }
+
+    encodeFields(writer: Writer): void {
+        writer.writeString("radius");
+        writer.writeFloat64(this.radius);
+    }
+
+    encode(writer: Writer): void {
+        writer.writeMapLength(2);
+        writer.writeString("type");
+        writer.writeString("Circle");
+        this.encodeFields(writer);
+    }
+}
+
+export class TaggedShapePoint extends TaggedShape {
+    encode(writer: Writer): void {
+        writer.writeMapLength(1);
+        writer.writeString("type");
+        writer.writeString("Point");
+    }
+}
+
+/**
+ * A variant that is not known to this version of the protocol.
+ *
+ * Its value is skipped when decoding, so it cannot be encoded again.
+ */
+export class TaggedShapeUnknown extends TaggedShape {
+    encode(writer: Writer): void {
+        throw new Error("Unknown variant of enum TaggedShape cannot be encoded");
+    }
+}
+
+/**
+ * A value of type `TimeDelta`, which cannot be represented in AssemblyScript.
+ *
+ * Its value is skipped when decoding, so it cannot be encoded again.
+ */
+export class TimeDelta {}
+
+/**
+ * Identifier of a user.
+ *
+ * In TypeScript, this becomes a branded type, so it cannot be mixed up with
+ * other strings by accident.
+ */
+export type UserId = string;
+
+/**
+ * Newtypes may be generic too.
+ */
+export type WrapperU32 = u32;
+
+export function encodeTupleString(writer: Writer, value: TupleString): void {
+    value.encode(writer);
+}
+
+export function decodeTupleString(reader: Decoder): TupleString {
+    return TupleString.decode(reader);
+}
+
+export function encodeTupleStringTupleU8Bool(writer: Writer, value: TupleStringTupleU8Bool): void {
+    value.encode(writer);
+}
+
+export function decodeTupleStringTupleU8Bool(reader: Decoder): TupleStringTupleU8Bool {
+    return TupleStringTupleU8Bool.decode(reader);
+}
+
+export function encodeTupleStringU32(writer: Writer, value: TupleStringU32): void {
+    value.encode(writer);
+}
+
+export function decodeTupleStringU32(reader: Decoder): TupleStringU32 {
+    return TupleStringU32.decode(reader);
+}
+
+export function encodeTupleI64I64(writer: Writer, value: TupleI64I64): void {
+    value.encode(writer);
+}
+
+export function decodeTupleI64I64(reader: Decoder): TupleI64I64 {
+    return TupleI64I64.decode(reader);
+}
+
+export function encodeTupleU32String(writer: Writer, value: TupleU32String): void {
+    value.encode(writer);
+}
+
+export function decodeTupleU32String(reader: Decoder): TupleU32String {
+    return TupleU32String.decode(reader);
+}
+
+export function encodeTupleU8Bool(writer: Writer, value: TupleU8Bool): void {
+    value.encode(writer);
+}
+
+export function decodeTupleU8Bool(reader: Decoder): TupleU8Bool {
+    return TupleU8Bool.decode(reader);
+}
+
+export function encodeActionMeta(writer: Writer, value: ActionMeta): void {
+    value.encode(writer);
+}
+
+export function decodeActionMeta(reader: Decoder): ActionMeta {
+    return ActionMeta.decode(reader);
+}
+
+export function encodeActionMetaDef(writer: Writer, value: ActionMetaDef): void {
+    encodeActionMeta(writer, value);
+}
+
+export function decodeActionMetaDef(reader: Decoder): ActionMetaDef {
+    return decodeActionMeta(reader);
+}
+
+export function encodeAppointment(writer: Writer, value: Appointment): void {
+    value.encode(writer);
+}
+
+export function decodeAppointment(reader: Decoder): Appointment {
+    return Appointment.decode(reader);
+}
+
+export function encodeArcString(writer: Writer, value: string): void {
+    encodeString(writer, value);
+}
+
+export function decodeArcString(reader: Decoder): string {
+    return decodeString(reader);
+}
+
+export function encodeBTreeMapStringVecFloatingPoint(writer: Writer, value: Map<string, Array<FloatingPoint>>): void {
+    const keys = value.keys();
+    writer.writeMapLength(keys.length);
+    for (let i = 0; i < keys.length; i++) {
+        encodeString(writer, keys[i]);
+        encodeVecFloatingPoint(writer, value.get(keys[i]));
+    }
+}
+
+export function decodeBTreeMapStringVecFloatingPoint(reader: Decoder): Map<string, Array<FloatingPoint>> {
+    const size = reader.readMapLength();
+    const result = new Map<string, Array<FloatingPoint>>();
+    for (let i: u32 = 0; i < size; i++) {
+        const key = decodeString(reader);
+        result.set(key, decodeVecFloatingPoint(reader));
+    }
+    return result;
+}
+
+export function encodeBinaryHeapU32(writer: Writer, value: Array<u32>): void {
+    writer.writeArrayLength(value.length);
+    for (let i = 0; i < value.length; i++) {
+        writer.writeUInt32(value[i]);
+    }
+}
+
+export function decodeBinaryHeapU32(reader: Decoder): Array<u32> {
+    const length = reader.readArrayLength();
+    const result = new Array<u32>();
+    for (let i: u32 = 0; i < length; i++) {
+        result.push(reader.readUInt32());
+    }
+    return result;
+}
+
+export function encodeBody(writer: Writer, value: Body): void {
+    encodeByteBuf(writer, value);
+}
+
+export function decodeBody(reader: Decoder): Body {
+    return decodeByteBuf(reader);
+}
+
+export function encodeByteBuf(writer: Writer, value: ArrayBuffer): void {
+    writer.writeByteArray(value);
+}
+
+export function decodeByteBuf(reader: Decoder): ArrayBuffer {
+    return reader.readByteArray();
+}
+
+export function encodeBytes(writer: Writer, value: ArrayBuffer): void {
+    writer.writeByteArray(value);
+}
+
+export function decodeBytes(reader: Decoder): ArrayBuffer {
+    return reader.readByteArray();
+}
+
+export function encodeCalendarDate(writer: Writer, value: string): void {
+    writer.writeString(value);
+}
+
+export function decodeCalendarDate(reader: Decoder): string {
+    return reader.readString();
+}
+
+export function encodeCowstr(writer: Writer, value: string): void {
+    writer.writeString(value);
+}
+
+export function decodeCowstr(reader: Decoder): string {
+    return reader.readString();
+}
+
+export function encodeDateTimeFixedOffset(writer: Writer, value: string): void {
+    writer.writeString(value);
+}
+
+export function decodeDateTimeFixedOffset(reader: Decoder): string {
+    return reader.readString();
+}
+
+export function encodeDateTimeUtc(writer: Writer, value: string): void {
+    writer.writeString(value);
+}
+
+export function decodeDateTimeUtc(reader: Decoder): string {
+    return reader.readString();
+}
+
+export function encodeDirectionalStructRenaming(writer: Writer, value: DirectionalStructRenaming): void {
+    value.encode(writer);
+}
+
+export function decodeDirectionalStructRenaming(reader: Decoder): DirectionalStructRenaming {
+    return DirectionalStructRenaming.decode(reader);
+}
+
+export function encodeDirectionalVariantRenaming(writer: Writer, value: DirectionalVariantRenaming): void {
+    value.encode(writer);
+}
+
+export function decodeDirectionalVariantRenaming(reader: Decoder): DirectionalVariantRenaming {
+    return DirectionalVariantRenaming.decode(reader);
+}
+
+export function encodeDocExampleEnum(writer: Writer, value: DocExampleEnum): void {
+    value.encode(writer);
+}
+
+export function decodeDocExampleEnum(reader: Decoder): DocExampleEnum {
+    return DocExampleEnum.decode(reader);
+}
+
+export function encodeDocExampleStruct(writer: Writer, value: DocExampleStruct): void {
+    value.encode(writer);
+}
+
+export function decodeDocExampleStruct(reader: Decoder): DocExampleStruct {
+    return DocExampleStruct.decode(reader);
+}
+
+export function encodeDuration(writer: Writer, value: Duration): void {
+    throw new Error("Value of type Duration cannot be encoded");
+}
+
+export function decodeDuration(reader: Decoder): Duration {
+    reader.skip();
+    return new Duration();
+}
+
+export function encodeEmail(writer: Writer, value: Email): void {
+    encodeString(writer, value);
+}
+
+export function decodeEmail(reader: Decoder): Email {
+    return decodeString(reader);
+}
+
+export function encodeExplicitedlyImportedType(writer: Writer, value: ExplicitedlyImportedType): void {
+    value.encode(writer);
+}
+
+export function decodeExplicitedlyImportedType(reader: Decoder): ExplicitedlyImportedType {
+    return ExplicitedlyImportedType.decode(reader);
+}
+
+export function encodeFlattenedStruct(writer: Writer, value: FlattenedStruct): void {
+    value.encode(writer);
+}
+
+export function decodeFlattenedStruct(reader: Decoder): FlattenedStruct {
+    return FlattenedStruct.decode(reader);
+}
+
+export function encodeFloatingPoint(writer: Writer, value: FloatingPoint): void {
+    encodePointF64(writer, value);
+}
+
+export function decodeFloatingPoint(reader: Decoder): FloatingPoint {
+    return decodePointF64(reader);
+}
+
+export function encodeFpAdjacentlyTagged(writer: Writer, value: FpAdjacentlyTagged): void {
+    value.encode(writer);
+}
+
+export function decodeFpAdjacentlyTagged(reader: Decoder): FpAdjacentlyTagged {
+    return FpAdjacentlyTagged.decode(reader);
+}
+
+export function encodeFpFlatten(writer: Writer, value: FpFlatten): void {
+    value.encode(writer);
+}
+
+export function decodeFpFlatten(reader: Decoder): FpFlatten {
+    return FpFlatten.decode(reader);
+}
+
+export function encodeFpInternallyTagged(writer: Writer, value: FpInternallyTagged): void {
+    value.encode(writer);
+}
+
+export function decodeFpInternallyTagged(reader: Decoder): FpInternallyTagged {
+    return FpInternallyTagged.decode(reader);
+}
+
+export function encodeFpPropertyRenaming(writer: Writer, value: FpPropertyRenaming): void {
+    value.encode(writer);
+}
+
+export function decodeFpPropertyRenaming(reader: Decoder): FpPropertyRenaming {
+    return FpPropertyRenaming.decode(reader);
+}
+
+export function encodeFpUntagged(writer: Writer, value: FpUntagged): void {
+    value.encode(writer);
+}
+
+export function decodeFpUntagged(reader: Decoder): FpUntagged {
+    return FpUntagged.decode(reader);
+}
+
+export function encodeFpVariantRenaming(writer: Writer, value: FpVariantRenaming): void {
+    value.encode(writer);
+}
+
+export function decodeFpVariantRenaming(reader: Decoder): FpVariantRenaming {
+    return FpVariantRenaming.decode(reader);
+}
+
+export function encodeFrameInfo(writer: Writer, value: FrameInfo): void {
+    value.encode(writer);
+}
+
+export function decodeFrameInfo(reader: Decoder): FrameInfo {
+    return FrameInfo.decode(reader);
+}
+
+export function encodeGroupImportedType1(writer: Writer, value: GroupImportedType1): void {
+    value.encode(writer);
+}
+
+export function decodeGroupImportedType1(reader: Decoder): GroupImportedType1 {
+    return GroupImportedType1.decode(reader);
+}
+
+export function encodeGroupImportedType2(writer: Writer, value: GroupImportedType2): void {
+    value.encode(writer);
+}
+
+export function decodeGroupImportedType2(reader: Decoder): GroupImportedType2 {
+    return GroupImportedType2.decode(reader);
+}
+
+export function encodeHeaderMap(writer: Writer, value: HeaderMap): void {
+    throw new Error("Value of type HeaderMap cannot be encoded");
+}
+
+export function decodeHeaderMap(reader: Decoder): HeaderMap {
+    reader.skip();
+    return new HeaderMap();
+}
+
+export function encodeHeaderValue(writer: Writer, value: HeaderValue): void {
+    throw new Error("Value of type string | ArrayBuffer cannot be encoded");
+}
+
+export function decodeHeaderValue(reader: Decoder): HeaderValue {
+    reader.skip();
+    return new HeaderValue();
+}
+
+export function encodeHttpExchange(writer: Writer, value: HttpExchange): void {
+    value.encode(writer);
+}
+
+export function decodeHttpExchange(reader: Decoder): HttpExchange {
+    return HttpExchange.decode(reader);
+}
+
+export function encodeHttpResult(writer: Writer, value: HttpResult): void {
+    encodeResultResponseRequestError(writer, value);
+}
+
+export function decodeHttpResult(reader: Decoder): HttpResult {
+    return decodeResultResponseRequestError(reader);
+}
+
+export function encodeIndexMapStringU32(writer: Writer, value: Map<string, u32>): void {
+    const keys = value.keys();
+    writer.writeMapLength(keys.length);
+    for (let i = 0; i < keys.length; i++) {
+        encodeString(writer, keys[i]);
+        writer.writeUInt32(value.get(keys[i]));
+    }
+}
+
+export function decodeIndexMapStringU32(reader: Decoder): Map<string, u32> {
+    const size = reader.readMapLength();
+    const result = new Map<string, u32>();
+    for (let i: u32 = 0; i < size; i++) {
+        const key = decodeString(reader);
+        result.set(key, reader.readUInt32());
+    }
+    return result;
+}
+
+export function encodeInt64(writer: Writer, value: Int64): void {
+    writer.writeUInt64(value);
+}
+
+export function decodeInt64(reader: Decoder): Int64 {
+    return reader.readUInt64();
+}
+
+export function encodeJsonMapStringJsonValue(writer: Writer, value: JsonMapStringJsonValue): void {
+    throw new Error("Value of type JsonObject cannot be encoded");
+}
+
+export function decodeJsonMapStringJsonValue(reader: Decoder): JsonMapStringJsonValue {
+    reader.skip();
+    return new JsonMapStringJsonValue();
+}
+
+export function encodeJsonValue(writer: Writer, value: JsonValue): void {
+    throw new Error("Value of type JsonValue cannot be encoded");
+}
+
+export function decodeJsonValue(reader: Decoder): JsonValue {
+    reader.skip();
+    return new JsonValue();
+}
+
+export function encodeKebabCasePropertyRenaming(writer: Writer, value: KebabCasePropertyRenaming): void {
+    value.encode(writer);
+}
+
+export function decodeKebabCasePropertyRenaming(reader: Decoder): KebabCasePropertyRenaming {
+    return KebabCasePropertyRenaming.decode(reader);
+}
+
+export function encodeLabel(writer: Writer, value: Label): void {
+    encodeString(writer, value);
+}
+
+export function decodeLabel(reader: Decoder): Label {
+    return decodeString(reader);
+}
+
+export function encodeLevel(writer: Writer, value: Level): void {
+    writer.writeUInt8(<u8>value);
+}
+
+export function decodeLevel(reader: Decoder): Level {
+    return <Level>reader.readUInt8();
+}
+
+export function encodeMarker(writer: Writer, value: Marker): void {
+    value.encode(writer);
+}
+
+export function decodeMarker(reader: Decoder): Marker {
+    return Marker.decode(reader);
+}
+
+export function encodeMeters(writer: Writer, value: Meters): void {
+    writer.writeFloat64(value);
+}
+
+export function decodeMeters(reader: Decoder): Meters {
+    return reader.readFloat64();
+}
+
+export function encodeMethod(writer: Writer, value: string): void {
+    writer.writeString(value);
+}
+
+export function decodeMethod(reader: Decoder): string {
+    return reader.readString();
+}
+
+export function encodeNaiveDate(writer: Writer, value: string): void {
+    writer.writeString(value);
+}
+
+export function decodeNaiveDate(reader: Decoder): string {
+    return reader.readString();
+}
+
+export function encodeNaiveDateTime(writer: Writer, value: string): void {
+    writer.writeString(value);
+}
+
+export function decodeNaiveDateTime(reader: Decoder): string {
+    return reader.readString();
+}
+
+export function encodeOffsetDateTime(writer: Writer, value: string): void {
+    writer.writeString(value);
+}
+
+export function decodeOffsetDateTime(reader: Decoder): string {
+    return reader.readString();
+}
+
+export function encodeOptionBTreeMapStringVecFloatingPoint(writer: Writer, value: Map<string, Array<FloatingPoint>> | null): void {
+    if (value === null) {
+        writer.writeNil();
+    } else {
+        encodeBTreeMapStringVecFloatingPoint(writer, value!);
+    }
+}
+
+export function decodeOptionBTreeMapStringVecFloatingPoint(reader: Decoder): Map<string, Array<FloatingPoint>> | null {
+    if (reader.isNextNil()) {
+        return null;
+    }
+    return decodeBTreeMapStringVecFloatingPoint(reader);
+}
+
+export function encodeOptionBody(writer: Writer, value: Body | null): void {
+    if (value === null) {
+        writer.writeNil();
+    } else {
+        encodeBody(writer, value!);
+    }
+}
+
+export function decodeOptionBody(reader: Decoder): Body | null {
+    if (reader.isNextNil()) {
+        return null;
+    }
+    return decodeBody(reader);
+}
+
+export function encodeOptionDuration(writer: Writer, value: Duration | null): void {
+    if (value === null) {
+        writer.writeNil();
+    } else {
+        encodeDuration(writer, value!);
+    }
+}
+
+export function decodeOptionDuration(reader: Decoder): Duration | null {
+    if (reader.isNextNil()) {
+        return null;
+    }
+    return decodeDuration(reader);
+}
+
+export function encodeOptionJsonValue(writer: Writer, value: JsonValue | null): void {
+    if (value === null) {
+        writer.writeNil();
+    } else {
+        encodeJsonValue(writer, value!);
+    }
+}
+
+export function decodeOptionJsonValue(reader: Decoder): JsonValue | null {
+    if (reader.isNextNil()) {
+        return null;
+    }
+    return decodeJsonValue(reader);
+}
+
+export function encodeOptionOffsetDateTime(writer: Writer, value: string | null): void {
+    if (value === null) {
+        writer.writeNil();
+    } else {
+        encodeOffsetDateTime(writer, value!);
+    }
+}
+
+export function decodeOptionOffsetDateTime(reader: Decoder): string | null {
+    if (reader.isNextNil()) {
+        return null;
+    }
+    return decodeOffsetDateTime(reader);
+}
+
+export function encodeOptionRcString(writer: Writer, value: string | null): void {
+    if (value === null) {
+        writer.writeNil();
+    } else {
+        encodeRcString(writer, value!);
+    }
+}
+
+export function decodeOptionRcString(reader: Decoder): string | null {
+    if (reader.isNextNil()) {
+        return null;
+    }
+    return decodeRcString(reader);
+}
+
+export function encodeOptionResponseByteBuf(writer: Writer, value: ResponseByteBuf | null): void {
+    if (value === null) {
+        writer.writeNil();
+    } else {
+        encodeResponseByteBuf(writer, value!);
+    }
+}
+
+export function decodeOptionResponseByteBuf(reader: Decoder): ResponseByteBuf | null {
+    if (reader.isNextNil()) {
+        return null;
+    }
+    return decodeResponseByteBuf(reader);
+}
+
+export function encodeOptionUserId(writer: Writer, value: UserId | null): void {
+    if (value === null) {
+        writer.writeNil();
+    } else {
+        encodeUserId(writer, value!);
+    }
+}
+
+export function decodeOptionUserId(reader: Decoder): UserId | null {
+    if (reader.isNextNil()) {
+        return null;
+    }
+    return decodeUserId(reader);
+}
+
+export function encodeOptionU16(writer: Writer, value: Box<u16> | null): void {
+    if (value === null) {
+        writer.writeNil();
+    } else {
+        writer.writeUInt16(value!.value);
+    }
+}
+
+export function decodeOptionU16(reader: Decoder): Box<u16> | null {
+    if (reader.isNextNil()) {
+        return null;
+    }
+    return new Box(reader.readUInt16());
+}
+
+export function encodePointPointU64(writer: Writer, value: PointPointU64): void {
+    value.encode(writer);
+}
+
+export function decodePointPointU64(reader: Decoder): PointPointU64 {
+    return PointPointU64.decode(reader);
+}
+
+export function encodePointF64(writer: Writer, value: PointF64): void {
+    value.encode(writer);
+}
+
+export function decodePointF64(reader: Decoder): PointF64 {
+    return PointF64.decode(reader);
+}
+
+export function encodePointU64(writer: Writer, value: PointU64): void {
+    value.encode(writer);
+}
+
+export function decodePointU64(reader: Decoder): PointU64 {
+    return PointU64.decode(reader);
+}
+
+export function encodePosition(writer: Writer, value: Position): void {
+    value.encode(writer);
+}
+
+export function decodePosition(reader: Decoder): Position {
+    return Position.decode(reader);
+}
+
+export function encodePriority(writer: Writer, value: Priority): void {
+    value.encode(writer);
+}
+
+export function decodePriority(reader: Decoder): Priority {
+    return Priority.decode(reader);
+}
+
+export function encodeRange(writer: Writer, value: Range): void {
+    value.encode(writer);
+}
+
+export function decodeRange(reader: Decoder): Range {
+    return Range.decode(reader);
+}
+
+export function encodeRcString(writer: Writer, value: string): void {
+    encodeString(writer, value);
+}
+
+export function decodeRcString(reader: Decoder): string {
+    return decodeString(reader);
+}
+
+export function encodeReduxAction(writer: Writer, value: ReduxAction): void {
+    value.encode(writer);
+}
+
+export function decodeReduxAction(reader: Decoder): ReduxAction {
+    return ReduxAction.decode(reader);
+}
+
+export function encodeRequest(writer: Writer, value: Request): void {
+    value.encode(writer);
+}
+
+export function decodeRequest(reader: Decoder): Request {
+    return Request.decode(reader);
+}
+
+export function encodeRequestByteBuf(writer: Writer, value: RequestByteBuf): void {
+    throw new Error("Value of type HttpRequest cannot be encoded");
+}
+
+export function decodeRequestByteBuf(reader: Decoder): RequestByteBuf {
+    reader.skip();
+    return new RequestByteBuf();
+}
+
+export function encodeRequestError(writer: Writer, value: RequestError): void {
+    value.encode(writer);
+}
+
+export function decodeRequestError(reader: Decoder): RequestError {
+    return RequestError.decode(reader);
+}
+
+export function encodeResponse(writer: Writer, value: Response): void {
+    value.encode(writer);
+}
+
+export function decodeResponse(reader: Decoder): Response {
+    return Response.decode(reader);
+}
+
+export function encodeResponseByteBuf(writer: Writer, value: ResponseByteBuf): void {
+    throw new Error("Value of type HttpResponse cannot be encoded");
+}
+
+export function decodeResponseByteBuf(reader: Decoder): ResponseByteBuf {
+    reader.skip();
+    return new ResponseByteBuf();
+}
+
+export function encodeResultResponseRequestError(writer: Writer, value: ResultResponseRequestError): void {
+    value.encode(writer);
+}
+
+export function decodeResultResponseRequestError(reader: Decoder): ResultResponseRequestError {
+    return ResultResponseRequestError.decode(reader);
+}
+
+export function encodeResultStringString(writer: Writer, value: ResultStringString): void {
+    value.encode(writer);
+}
+
+export function decodeResultStringString(reader: Decoder): ResultStringString {
+    return ResultStringString.decode(reader);
+}
+
+export function encodeRgb(writer: Writer, value: Rgb): void {
+    value.encode(writer);
+}
+
+export function decodeRgb(reader: Decoder): Rgb {
+    return Rgb.decode(reader);
+}
+
+export function encodeSerdeAdjacentlyTagged(writer: Writer, value: SerdeAdjacentlyTagged): void {
+    value.encode(writer);
+}
+
+export function decodeSerdeAdjacentlyTagged(reader: Decoder): SerdeAdjacentlyTagged {
+    return SerdeAdjacentlyTagged.decode(reader);
+}
+
+export function encodeSerdeAttributes(writer: Writer, value: SerdeAttributes): void {
+    value.encode(writer);
+}
+
+export function decodeSerdeAttributes(reader: Decoder): SerdeAttributes {
+    return SerdeAttributes.decode(reader);
+}
+
+export function encodeSerdeFlatten(writer: Writer, value: SerdeFlatten): void {
+    value.encode(writer);
+}
+
+export function decodeSerdeFlatten(reader: Decoder): SerdeFlatten {
+    return SerdeFlatten.decode(reader);
+}
+
+export function encodeSerdeInternallyTagged(writer: Writer, value: SerdeInternallyTagged): void {
+    value.encode(writer);
+}
+
+export function decodeSerdeInternallyTagged(reader: Decoder): SerdeInternallyTagged {
+    return SerdeInternallyTagged.decode(reader);
+}
+
+export function encodeSerdePropertyRenaming(writer: Writer, value: SerdePropertyRenaming): void {
+    value.encode(writer);
+}
+
+export function decodeSerdePropertyRenaming(reader: Decoder): SerdePropertyRenaming {
+    return SerdePropertyRenaming.decode(reader);
+}
+
+export function encodeSerdeUntagged(writer: Writer, value: SerdeUntagged): void {
+    value.encode(writer);
+}
+
+export function decodeSerdeUntagged(reader: Decoder): SerdeUntagged {
+    return SerdeUntagged.decode(reader);
+}
+
+export function encodeSerdeVariantRenaming(writer: Writer, value: SerdeVariantRenaming): void {
+    value.encode(writer);
+}
+
+export function decodeSerdeVariantRenaming(reader: Decoder): SerdeVariantRenaming {
+    return SerdeVariantRenaming.decode(reader);
+}
+
+export function encodeShape(writer: Writer, value: Shape): void {
+    value.encode(writer);
+}
+
+export function decodeShape(reader: Decoder): Shape {
+    return Shape.decode(reader);
+}
+
+export function encodeStateUpdate(writer: Writer, value: StateUpdate): void {
+    value.encode(writer);
+}
+
+export function decodeStateUpdate(reader: Decoder): StateUpdate {
+    return StateUpdate.decode(reader);
+}
+
+export function encodeStatusCode(writer: Writer, value: StatusCode): void {
+    throw new Error("Value of type number cannot be encoded");
+}
+
+export function decodeStatusCode(reader: Decoder): StatusCode {
+    reader.skip();
+    return new StatusCode();
+}
+
+export function encodeString(writer: Writer, value: string): void {
+    writer.writeString(value);
+}
+
+export function decodeString(reader: Decoder): string {
+    return reader.readString();
+}
+
+export function encodeStructWithArrays(writer: Writer, value: StructWithArrays): void {
+    value.encode(writer);
+}
+
+export function decodeStructWithArrays(reader: Decoder): StructWithArrays {
+    return StructWithArrays.decode(reader);
+}
+
+export function encodeStructWithBinaryHeap(writer: Writer, value: StructWithBinaryHeap): void {
+    value.encode(writer);
+}
+
+export function decodeStructWithBinaryHeap(reader: Decoder): StructWithBinaryHeap {
+    return StructWithBinaryHeap.decode(reader);
+}
+
+export function encodeStructWithChronoTypes(writer: Writer, value: StructWithChronoTypes): void {
+    value.encode(writer);
+}
+
+export function decodeStructWithChronoTypes(reader: Decoder): StructWithChronoTypes {
+    return StructWithChronoTypes.decode(reader);
+}
+
+export function encodeStructWithCollections(writer: Writer, value: StructWithCollections): void {
+    value.encode(writer);
+}
+
+export function decodeStructWithCollections(reader: Decoder): StructWithCollections {
+    return StructWithCollections.decode(reader);
+}
+
+export function encodeStructWithDurations(writer: Writer, value: StructWithDurations): void {
+    value.encode(writer);
+}
+
+export function decodeStructWithDurations(reader: Decoder): StructWithDurations {
+    return StructWithDurations.decode(reader);
+}
+
+export function encodeStructWithGenericsU64(writer: Writer, value: StructWithGenericsU64): void {
+    value.encode(writer);
+}
+
+export function decodeStructWithGenericsU64(reader: Decoder): StructWithGenericsU64 {
+    return StructWithGenericsU64.decode(reader);
+}
+
+export function encodeStructWithJson(writer: Writer, value: StructWithJson): void {
+    value.encode(writer);
+}
+
+export function decodeStructWithJson(reader: Decoder): StructWithJson {
+    return StructWithJson.decode(reader);
+}
+
+export function encodeStructWithReprEnums(writer: Writer, value: StructWithReprEnums): void {
+    value.encode(writer);
+}
+
+export function decodeStructWithReprEnums(reader: Decoder): StructWithReprEnums {
+    return StructWithReprEnums.decode(reader);
+}
+
+export function encodeStructWithTupleStructs(writer: Writer, value: StructWithTupleStructs): void {
+    value.encode(writer);
+}
+
+export function decodeStructWithTupleStructs(reader: Decoder): StructWithTupleStructs {
+    return StructWithTupleStructs.decode(reader);
+}
+
+export function encodeStructWithTuples(writer: Writer, value: StructWithTuples): void {
+    value.encode(writer);
+}
+
+export function decodeStructWithTuples(reader: Decoder): StructWithTuples {
+    return StructWithTuples.decode(reader);
+}
+
+export function encodeStructWithWidePrimitives(writer: Writer, value: StructWithWidePrimitives): void {
+    value.encode(writer);
+}
+
+export function decodeStructWithWidePrimitives(reader: Decoder): StructWithWidePrimitives {
+    return StructWithWidePrimitives.decode(reader);
+}
+
+export function encodeSystemTime(writer: Writer, value: SystemTime): void {
+    throw new Error("Value of type SystemTime cannot be encoded");
+}
+
+export function decodeSystemTime(reader: Decoder): SystemTime {
+    reader.skip();
+    return new SystemTime();
+}
+
+export function encodeTaggedShape(writer: Writer, value: TaggedShape): void {
+    value.encode(writer);
+}
+
+export function decodeTaggedShape(reader: Decoder): TaggedShape {
+    return TaggedShape.decode(reader);
+}
+
+export function encodeTimeDelta(writer: Writer, value: TimeDelta): void {
+    throw new Error("Value of type TimeDelta cannot be encoded");
+}
+
+export function decodeTimeDelta(reader: Decoder): TimeDelta {
+    reader.skip();
+    return new TimeDelta();
+}
+
+export function encodeUri(writer: Writer, value: string): void {
+    writer.writeString(value);
+}
+
+export function decodeUri(reader: Decoder): string {
+    return reader.readString();
+}
+
+export function encodeUserId(writer: Writer, value: UserId): void {
+    encodeString(writer, value);
+}
+
+export function decodeUserId(reader: Decoder): UserId {
+    return decodeString(reader);
+}
+
+export function encodeUuid(writer: Writer, value: string): void {
+    writer.writeString(value);
+}
+
+export function decodeUuid(reader: Decoder): string {
+    return reader.readString();
+}
+
+export function encodeVecTupleStringTupleU8Bool(writer: Writer, value: Array<TupleStringTupleU8Bool>): void {
+    writer.writeArrayLength(value.length);
+    for (let i = 0; i < value.length; i++) {
+        encodeTupleStringTupleU8Bool(writer, value[i]);
+    }
+}
+
+export function decodeVecTupleStringTupleU8Bool(reader: Decoder): Array<TupleStringTupleU8Bool> {
+    const length = reader.readArrayLength();
+    const result = new Array<TupleStringTupleU8Bool>();
+    for (let i: u32 = 0; i < length; i++) {
+        result.push(decodeTupleStringTupleU8Bool(reader));
+    }
+    return result;
+}
+
+export function encodeVecFloatingPoint(writer: Writer, value: Array<FloatingPoint>): void {
+    writer.writeArrayLength(value.length);
+    for (let i = 0; i < value.length; i++) {
+        encodeFloatingPoint(writer, value[i]);
+    }
+}
+
+export function decodeVecFloatingPoint(reader: Decoder): Array<FloatingPoint> {
+    const length = reader.readArrayLength();
+    const result = new Array<FloatingPoint>();
+    for (let i: u32 = 0; i < length; i++) {
+        result.push(decodeFloatingPoint(reader));
+    }
+    return result;
+}
+
+export function encodeVecLevel(writer: Writer, value: Array<Level>): void {
+    writer.writeArrayLength(value.length);
+    for (let i = 0; i < value.length; i++) {
+        encodeLevel(writer, value[i]);
+    }
+}
+
+export function decodeVecLevel(reader: Decoder): Array<Level> {
+    const length = reader.readArrayLength();
+    const result = new Array<Level>();
+    for (let i: u32 = 0; i < length; i++) {
+        result.push(decodeLevel(reader));
+    }
+    return result;
+}
+
+export function encodeVecPointPointU64(writer: Writer, value: Array<PointPointU64>): void {
+    writer.writeArrayLength(value.length);
+    for (let i = 0; i < value.length; i++) {
+        encodePointPointU64(writer, value[i]);
+    }
+}
+
+export function decodeVecPointPointU64(reader: Decoder): Array<PointPointU64> {
+    const length = reader.readArrayLength();
+    const result = new Array<PointPointU64>();
+    for (let i: u32 = 0; i < length; i++) {
+        result.push(decodePointPointU64(reader));
+    }
+    return result;
+}
+
+export function encodeVecPointU64(writer: Writer, value: Array<PointU64>): void {
+    writer.writeArrayLength(value.length);
+    for (let i = 0; i < value.length; i++) {
+        encodePointU64(writer, value[i]);
+    }
+}
+
+export function decodeVecPointU64(reader: Decoder): Array<PointU64> {
+    const length = reader.readArrayLength();
+    const result = new Array<PointU64>();
+    for (let i: u32 = 0; i < length; i++) {
+        result.push(decodePointU64(reader));
+    }
+    return result;
+}
+
+export function encodeVecArray2PointF64(writer: Writer, value: Array<StaticArray<PointF64>>): void {
+    writer.writeArrayLength(value.length);
+    for (let i = 0; i < value.length; i++) {
+        encodeArray2PointF64(writer, value[i]);
+    }
+}
+
+export function decodeVecArray2PointF64(reader: Decoder): Array<StaticArray<PointF64>> {
+    const length = reader.readArrayLength();
+    const result = new Array<StaticArray<PointF64>>();
+    for (let i: u32 = 0; i < length; i++) {
+        result.push(decodeArray2PointF64(reader));
+    }
+    return result;
+}
+
+export function encodeVecU32(writer: Writer, value: Array<u32>): void {
+    writer.writeArrayLength(value.length);
+    for (let i = 0; i < value.length; i++) {
+        writer.writeUInt32(value[i]);
+    }
+}
+
+export function decodeVecU32(reader: Decoder): Array<u32> {
+    const length = reader.readArrayLength();
+    const result = new Array<u32>();
+    for (let i: u32 = 0; i < length; i++) {
+        result.push(reader.readUInt32());
+    }
+    return result;
+}
+
+export function encodeVecU64(writer: Writer, value: Array<u64>): void {
+    writer.writeArrayLength(value.length);
+    for (let i = 0; i < value.length; i++) {
+        writer.writeUInt64(value[i]);
+    }
+}
+
+export function decodeVecU64(reader: Decoder): Array<u64> {
+    const length = reader.readArrayLength();
+    const result = new Array<u64>();
+    for (let i: u32 = 0; i < length; i++) {
+        result.push(reader.readUInt64());
+    }
+    return result;
+}
+
+export function encodeVecDequeU32(writer: Writer, value: Array<u32>): void {
+    writer.writeArrayLength(value.length);
+    for (let i = 0; i < value.length; i++) {
+        writer.writeUInt32(value[i]);
+    }
+}
+
+export function decodeVecDequeU32(reader: Decoder): Array<u32> {
+    const length = reader.readArrayLength();
+    const result = new Array<u32>();
+    for (let i: u32 = 0; i < length; i++) {
+        result.push(reader.readUInt32());
+    }
+    return result;
+}
+
+export function encodeWeekday(writer: Writer, value: string): void {
+    writer.writeString(value);
+}
+
+export function decodeWeekday(reader: Decoder): string {
+    return reader.readString();
+}
+
+export function encodeWrapperU32(writer: Writer, value: WrapperU32): void {
+    writer.writeUInt32(value);
+}
+
+export function decodeWrapperU32(reader: Decoder): WrapperU32 {
+    return reader.readUInt32();
+}
+
+export function encodeArray2PointF64(writer: Writer, value: StaticArray<PointF64>): void {
+    writer.writeArrayLength(2);
+    for (let i = 0; i < value.length; i++) {
+        encodePointF64(writer, value[i]);
+    }
+}
+
+export function decodeArray2PointF64(reader: Decoder): StaticArray<PointF64> {
+    const length = reader.readArrayLength();
+    const result = new Array<PointF64>();
+    for (let i: u32 = 0; i < length; i++) {
+        result.push(decodePointF64(reader));
+    }
+    return StaticArray.fromArray(result);
+}
+
+export function encodeArray3F64(writer: Writer, value: StaticArray<f64>): void {
+    writer.writeArrayLength(3);
+    for (let i = 0; i < value.length; i++) {
+        writer.writeFloat64(value[i]);
+    }
+}
+
+export function decodeArray3F64(reader: Decoder): StaticArray<f64> {
+    const length = reader.readArrayLength();
+    const result = new Array<f64>();
+    for (let i: u32 = 0; i < length; i++) {
+        result.push(reader.readFloat64());
+    }
+    return StaticArray.fromArray(result);
+}
+
+export function encodeArray3U32(writer: Writer, value: StaticArray<u32>): void {
+    writer.writeArrayLength(3);
+    for (let i = 0; i < value.length; i++) {
+        writer.writeUInt32(value[i]);
+    }
+}
+
+export function decodeArray3U32(reader: Decoder): StaticArray<u32> {
+    const length = reader.readArrayLength();
+    const result = new Array<u32>();
+    for (let i: u32 = 0; i < length; i++) {
+        result.push(reader.readUInt32());
+    }
+    return StaticArray.fromArray(result);
+}
+
+export function encodeArray4U8(writer: Writer, value: StaticArray<u8>): void {
+    writer.writeArrayLength(4);
+    for (let i = 0; i < value.length; i++) {
+        writer.writeUInt8(value[i]);
+    }
+}
+
+export function decodeArray4U8(reader: Decoder): StaticArray<u8> {
+    const length = reader.readArrayLength();
+    const result = new Array<u8>();
+    for (let i: u32 = 0; i < length; i++) {
+        result.push(reader.readUInt8());
+    }
+    return StaticArray.fromArray(result);
+}
+
+export function encodeChar(writer: Writer, value: string): void {
+    writer.writeString(value);
+}
+
+export function decodeChar(reader: Decoder): string {
+    return reader.readString();
+}
+
+export function encodeI128(writer: Writer, value: ArrayBuffer): void {
+    writer.writeByteArray(value);
+}
+
+export function decodeI128(reader: Decoder): ArrayBuffer {
+    return reader.readByteArray();
+}
+
+export function encodeIsize(writer: Writer, value: i64): void {
+    writer.writeInt64(value);
+}
+
+export function decodeIsize(reader: Decoder): i64 {
+    return reader.readInt64();
+}
+
+export function encodeU128(writer: Writer, value: ArrayBuffer): void {
+    writer.writeByteArray(value);
+}
+
+export function decodeU128(reader: Decoder): ArrayBuffer {
+    return reader.readByteArray();
+}
+
+export function encodeUsize(writer: Writer, value: u64): void {
+    writer.writeUInt64(value);
+}
+
+export function decodeUsize(reader: Decoder): u64 {
+    return reader.readUInt64();
+}
@@ -1,8 +1,47 @@
 use crate::types::*;
 
+#[fp_bindgen_support::fp_export_signature]
+pub fn export_array(arg: [u32; 3]) -> [u32; 3];
+
 #[fp_bindgen_support::fp_export_signature]
 pub async fn export_async_struct(arg1: FpPropertyRenaming, arg2: u64) -> FpPropertyRenaming;
 
+#[fp_bindgen_support::fp_export_signature]
+pub fn export_binary_heap(arg: StructWithBinaryHeap) -> StructWithBinaryHeap;
+
+#[fp_bindgen_support::fp_export_signature]
+pub fn export_borrowed(text: String, #[fp(binary)] data: serde_bytes::ByteBuf, values: Vec<u32>, #[fp(by_value(x: f64, y: f64))] position: Position) -> String;
+
+#[fp_bindgen_support::fp_export_signature]
+pub fn export_by_value(#[fp(by_value(x: f64, y: f64))] position: Position, #[fp(by_value(frame_number: u64, delta_ms: f32, offset: i32, is_paused: bool))] frame: FrameInfo) -> Position;
+
+#[fp_bindgen_support::fp_export_signature(binary_return)]
+pub fn export_byte_buf(#[fp(binary)] arg: serde_bytes::ByteBuf) -> serde_bytes::ByteBuf;
+
+#[fp_bindgen_support::fp_export_signature(binary_return)]
+pub fn export_bytes(#[fp(binary)] arg: bytes::Bytes) -> bytes::Bytes;
+
+#[fp_bindgen_support::fp_export_signature]
+pub fn export_chrono_types(arg: StructWithChronoTypes) -> StructWithChronoTypes;
+
+#[fp_bindgen_support::fp_export_signature]
+pub fn export_collections(arg: StructWithCollections) -> StructWithCollections;
+
+#[fp_bindgen_support::fp_export_signature]
+pub fn export_custom_types(arg: Appointment) -> Appointment;
+
+#[fp_bindgen_support::fp_export_signature]
+pub fn export_directional_enum(arg: DirectionalVariantRenaming) -> DirectionalVariantRenaming;
+
+#[fp_bindgen_support::fp_export_signature]
+pub fn export_directional_struct(arg: DirectionalStructRenaming) -> DirectionalStructRenaming;
+
+#[fp_bindgen_support::fp_export_signature]
+pub fn export_durations(arg: StructWithDurations) -> StructWithDurations;
+
+#[fp_bindgen_support::fp_export_signature]
+pub fn export_fetch(request: fp_bindgen_support::http::HttpRequest) -> Option<fp_bindgen_support::http::HttpResponse>;
+
 #[fp_bindgen_support::fp_export_signature]
 pub fn export_fp_adjacently_tagged(arg: FpAdjacentlyTagged) -> FpAdjacentlyTagged;
 
@@ -24,18 +63,36 @@ pub fn export_fp_untagged(arg: FpUntagged) -> FpUntagged;
 #[fp_bindgen_support::fp_export_signature]
 pub fn export_generics(arg: StructWithGenerics<u64>) -> StructWithGenerics<u64>;
 
+#[fp_bindgen_support::fp_export_signature]
+pub fn export_http_exchange(arg: HttpExchange) -> HttpExchange;
+
+#[fp_bindgen_support::fp_export_signature]
+pub fn export_json(arg: StructWithJson) -> StructWithJson;
+
+#[fp_bindgen_support::fp_export_signature]
+pub fn export_kebab_case_struct(arg: KebabCasePropertyRenaming) -> KebabCasePropertyRenaming;
+
 #[fp_bindgen_support::fp_export_signature]
 pub fn export_multiple_primitives(arg1: i8, arg2: String) -> i64;
 
+#[fp_bindgen_support::fp_export_signature]
+pub fn export_newtype(arg: UserId) -> Meters;
+
 #[fp_bindgen_support::fp_export_signature]
 pub fn export_primitive_bool(arg: bool) -> bool;
 
+#[fp_bindgen_support::fp_export_signature]
+pub fn export_primitive_char(arg: char) -> char;
+
 #[fp_bindgen_support::fp_export_signature]
 pub fn export_primitive_f32(arg: f32) -> f32;
 
 #[fp_bindgen_support::fp_export_signature]
 pub fn export_primitive_f64(arg: f64) -> f64;
 
+#[fp_bindgen_support::fp_export_signature]
+pub fn export_primitive_i128(arg: i128) -> i128;
+
 #[fp_bindgen_support::fp_export_signature]
 pub fn export_primitive_i16(arg: i16) -> i16;
 
@@ -48,6 +105,12 @@ pub fn export_primitive_i64(arg: i64) -> i64;
 #[fp_bindgen_support::fp_export_signature]
 pub fn export_primitive_i8(arg: i8) -> i8;
 
+#[fp_bindgen_support::fp_export_signature]
+pub fn export_primitive_isize(arg: isize) -> isize;
+
+#[fp_bindgen_support::fp_export_signature]
+pub fn export_primitive_u128(arg: u128) -> u128;
+
 #[fp_bindgen_support::fp_export_signature]
 pub fn export_primitive_u16(arg: u16) -> u16;
 
@@ -60,9 +123,21 @@ pub fn export_primitive_u64(arg: u64) -> u64;
 #[fp_bindgen_support::fp_export_signature]
 pub fn export_primitive_u8(arg: u8) -> u8;
 
+#[fp_bindgen_support::fp_export_signature]
+pub fn export_primitive_usize(arg: usize) -> usize;
+
+#[fp_bindgen_support::fp_export_signature]
+pub fn export_remote_type(arg: ActionMetaDef) -> ActionMetaDef;
+
+#[fp_bindgen_support::fp_export_signature]
+pub fn export_repr_enum(arg: Level) -> Level;
+
 #[fp_bindgen_support::fp_export_signature]
 pub fn export_serde_adjacently_tagged(arg: SerdeAdjacentlyTagged) -> SerdeAdjacentlyTagged;
 
+#[fp_bindgen_support::fp_export_signature]
+pub fn export_serde_attributes(arg: SerdeAttributes) -> SerdeAttributes;
+
 #[fp_bindgen_support::fp_export_signature]
 pub fn export_serde_enum(arg: SerdeVariantRenaming) -> SerdeVariantRenaming;
 
@@ -81,9 +156,36 @@ pub fn export_serde_untagged(arg: SerdeUntagged) -> SerdeUntagged;
 #[fp_bindgen_support::fp_export_signature]
 pub fn export_string(arg: String) -> String;
 
+#[fp_bindgen_support::fp_export_signature]
+pub fn export_struct_with_arrays(arg: StructWithArrays) -> StructWithArrays;
+
+#[fp_bindgen_support::fp_export_signature]
+pub fn export_struct_with_repr_enums(arg: StructWithReprEnums) -> StructWithReprEnums;
+
+#[fp_bindgen_support::fp_export_signature]
+pub fn export_struct_with_tuple_structs(arg: StructWithTupleStructs) -> StructWithTupleStructs;
+
+#[fp_bindgen_support::fp_export_signature]
+pub fn export_struct_with_tuples(arg: StructWithTuples) -> StructWithTuples;
+
+#[fp_bindgen_support::fp_export_signature]
+pub fn export_struct_with_wide_primitives(arg: StructWithWidePrimitives) -> StructWithWidePrimitives;
+
+#[fp_bindgen_support::fp_export_signature]
+pub fn export_tagged_unknown_variant(arg: TaggedShape) -> TaggedShape;
+
 #[fp_bindgen_support::fp_export_signature]
 pub fn export_timestamp(arg: time::OffsetDateTime) -> time::OffsetDateTime;
 
+#[fp_bindgen_support::fp_export_signature]
+pub fn export_tuple(arg: (u32, String)) -> (String, u32);
+
+#[fp_bindgen_support::fp_export_signature]
+pub fn export_unknown_variant(arg: Shape) -> Shape;
+
+#[fp_bindgen_support::fp_export_signature]
+pub fn export_uuid(arg: uuid::Uuid) -> uuid::Uuid;
+
 #[fp_bindgen_support::fp_export_signature]
 pub fn export_void_function();
 
@@ -1,5 +1,21 @@
 use crate::types::*;
 
+/// Example how a runtime could expose a `fetch()` function using the
+/// request and response types from the `http` crate.
+///
+/// See `types/http_exchange.rs` for more info.
+#[fp_bindgen_support::fp_import_signature]
+pub fn fetch(request: fp_bindgen_support::http::HttpRequest) -> fp_bindgen_support::http::HttpResponse;
+
+#[fp_bindgen_support::fp_import_signature]
+pub fn import_borrowed(text: &str, #[fp(binary)] data: &[u8], values: &[u32], #[fp(by_value(x: f64, y: f64))] position: &Position) -> String;
+
+#[fp_bindgen_support::fp_import_signature]
+pub fn import_by_value(#[fp(by_value(x: f64, y: f64))] position: Position, #[fp(by_value(frame_number: u64, delta_ms: f32, offset: i32, is_paused: bool))] frame: FrameInfo) -> Position;
+
+#[fp_bindgen_support::fp_import_signature(binary_return)]
+pub fn import_bytes(#[fp(binary)] arg: bytes::Bytes) -> bytes::Bytes;
+
 #[fp_bindgen_support::fp_import_signature]
 pub fn import_fp_adjacently_tagged(arg: FpAdjacentlyTagged) -> FpAdjacentlyTagged;
 
@@ -1,11 +1,11 @@
 use super::types::*;
 use fp_bindgen_support::{
-    common::mem::FatPtr,
+    common::{binary::BinaryValue, mem::FatPtr},
     host::{
         errors::{InvocationError, RuntimeError},
         mem::{
-            deserialize_from_slice, export_to_guest, export_to_guest_raw, import_from_guest,
-            import_from_guest_raw, serialize_to_vec,
+            deserialize_from_slice, export_binary_to_guest, export_to_guest, export_to_guest_raw,
+            import_binary_from_guest, import_from_guest, import_from_guest_raw, serialize_to_vec,
         },
         r#async::{create_future_value, future::ModuleRawFuture, resolve_async_value},
         runtime::RuntimeInstanceData,
@@ -38,6 +38,27 @@ impl Runtime {
         Store::new(&engine)
     }
 
+    pub fn export_array(&self, arg: [u32; 3]) -> Result<[u32; 3], InvocationError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_array_raw(arg);
+        let result = result.map(|ref data| deserialize_from_slice(data));
+        result
+    }
+    pub fn export_array_raw(&self, arg: Vec<u8>) -> Result<Vec<u8>, InvocationError> {
+        let mut env = RuntimeInstanceData::default();
+        let import_object = create_import_object(self.module.store(), &env);
+        let instance = Instance::new(&self.module, &import_object).unwrap();
+        env.init_with_instance(&instance).unwrap();
+        let arg = export_to_guest_raw(&env, arg);
+        let function = instance
+            .exports
+            .get_native_function::<(FatPtr), FatPtr>("__fp_gen_export_array")
+            .map_err(|_| InvocationError::FunctionNotExported)?;
+        let result = function.call(arg)?;
+        let result = import_from_guest_raw(&env, result);
+        Ok(result)
+    }
+
     pub async fn export_async_struct(
         &self,
         arg1: FpPropertyRenaming,
@@ -68,6 +89,315 @@ impl Runtime {
         Ok(result)
     }
 
+    pub fn export_binary_heap(
+        &self,
+        arg: StructWithBinaryHeap,
+    ) -> Result<StructWithBinaryHeap, InvocationError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_binary_heap_raw(arg);
+        let result = result.map(|ref data| deserialize_from_slice(data));
+        result
+    }
+    pub fn export_binary_heap_raw(&self, arg: Vec<u8>) -> Result<Vec<u8>, InvocationError> {
+        let mut env = RuntimeInstanceData::default();
+        let import_object = create_import_object(self.module.store(), &env);
+        let instance = Instance::new(&self.module, &import_object).unwrap();
+        env.init_with_instance(&instance).unwrap();
+        let arg = export_to_guest_raw(&env, arg);
+        let function = instance
+            .exports
+            .get_native_function::<(FatPtr), FatPtr>("__fp_gen_export_binary_heap")
+            .map_err(|_| InvocationError::FunctionNotExported)?;
+        let result = function.call(arg)?;
+        let result = import_from_guest_raw(&env, result);
+        Ok(result)
+    }
+
+    pub fn export_borrowed(
+        &self,
+        text: &str,
+        data: &[u8],
+        values: &[u32],
+        position: &Position,
+    ) -> Result<String, InvocationError> {
+        let text = serialize_to_vec(&text);
+        let data = data.to_vec();
+        let values = serialize_to_vec(&values);
+        let result = self.export_borrowed_raw(text, data, values, position);
+        let result = result.map(|ref data| deserialize_from_slice(data));
+        result
+    }
+    pub fn export_borrowed_raw(
+        &self,
+        text: Vec<u8>,
+        data: Vec<u8>,
+        values: Vec<u8>,
+        position: &Position,
+    ) -> Result<Vec<u8>, InvocationError> {
+        let mut env = RuntimeInstanceData::default();
+        let import_object = create_import_object(self.module.store(), &env);
+        let instance = Instance::new(&self.module, &import_object).unwrap();
+        env.init_with_instance(&instance).unwrap();
+        let text = export_to_guest_raw(&env, text);
+        let data = export_to_guest_raw(&env, data);
+        let values = export_to_guest_raw(&env, values);
+        let function = instance
+            .exports
+            .get_native_function::<(FatPtr, FatPtr, FatPtr, f64, f64), FatPtr>(
+                "__fp_gen_export_borrowed",
+            )
+            .map_err(|_| InvocationError::FunctionNotExported)?;
+        let result = function.call(text, data, values, position.x, position.y)?;
+        let result = import_from_guest_raw(&env, result);
+        Ok(result)
+    }
+
+    pub fn export_by_value(
+        &self,
+        position: Position,
+        frame: FrameInfo,
+    ) -> Result<Position, InvocationError> {
+        let result = self.export_by_value_raw(position, frame);
+        let result = result.map(|ref data| deserialize_from_slice(data));
+        result
+    }
+    pub fn export_by_value_raw(
+        &self,
+        position: Position,
+        frame: FrameInfo,
+    ) -> Result<Vec<u8>, InvocationError> {
+        let mut env = RuntimeInstanceData::default();
+        let import_object = create_import_object(self.module.store(), &env);
+        let instance = Instance::new(&self.module, &import_object).unwrap();
+        env.init_with_instance(&instance).unwrap();
+        let function = instance
+            .exports
+            .get_native_function::<(f64, f64, u64, f32, i32, bool), FatPtr>(
+                "__fp_gen_export_by_value",
+            )
+            .map_err(|_| InvocationError::FunctionNotExported)?;
+        let result = function.call(
+            position.x,
+            position.y,
+            frame.frame_number,
+            frame.delta_ms,
+            frame.offset,
+            frame.is_paused,
+        )?;
+        let result = import_from_guest_raw(&env, result);
+        Ok(result)
+    }
+
+    pub fn export_byte_buf(
+        &self,
+        arg: serde_bytes::ByteBuf,
+    ) -> Result<serde_bytes::ByteBuf, InvocationError> {
+        let arg = BinaryValue::into_vec(arg);
+        let result = self.export_byte_buf_raw(arg);
+        let result = result.map(BinaryValue::from_vec);
+        result
+    }
+    pub fn export_byte_buf_raw(&self, arg: Vec<u8>) -> Result<Vec<u8>, InvocationError> {
+        let mut env = RuntimeInstanceData::default();
+        let import_object = create_import_object(self.module.store(), &env);
+        let instance = Instance::new(&self.module, &import_object).unwrap();
+        env.init_with_instance(&instance).unwrap();
+        let arg = export_to_guest_raw(&env, arg);
+        let function = instance
+            .exports
+            .get_native_function::<(FatPtr), FatPtr>("__fp_gen_export_byte_buf")
+            .map_err(|_| InvocationError::FunctionNotExported)?;
+        let result = function.call(arg)?;
+        let result = import_from_guest_raw(&env, result);
+        Ok(result)
+    }
+
+    pub fn export_bytes(&self, arg: bytes::Bytes) -> Result<bytes::Bytes, InvocationError> {
+        let arg = BinaryValue::into_vec(arg);
+        let result = self.export_bytes_raw(arg);
+        let result = result.map(BinaryValue::from_vec);
+        result
+    }
+    pub fn export_bytes_raw(&self, arg: Vec<u8>) -> Result<Vec<u8>, InvocationError> {
+        let mut env = RuntimeInstanceData::default();
+        let import_object = create_import_object(self.module.store(), &env);
+        let instance = Instance::new(&self.module, &import_object).unwrap();
+        env.init_with_instance(&instance).unwrap();
+        let arg = export_to_guest_raw(&env, arg);
+        let function = instance
+            .exports
+            .get_native_function::<(FatPtr), FatPtr>("__fp_gen_export_bytes")
+            .map_err(|_| InvocationError::FunctionNotExported)?;
+        let result = function.call(arg)?;
+        let result = import_from_guest_raw(&env, result);
+        Ok(result)
+    }
+
+    pub fn export_chrono_types(
+        &self,
+        arg: StructWithChronoTypes,
+    ) -> Result<StructWithChronoTypes, InvocationError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_chrono_types_raw(arg);
+        let result = result.map(|ref data| deserialize_from_slice(data));
+        result
+    }
+    pub fn export_chrono_types_raw(&self, arg: Vec<u8>) -> Result<Vec<u8>, InvocationError> {
+        let mut env = RuntimeInstanceData::default();
+        let import_object = create_import_object(self.module.store(), &env);
+        let instance = Instance::new(&self.module, &import_object).unwrap();
+        env.init_with_instance(&instance).unwrap();
+        let arg = export_to_guest_raw(&env, arg);
+        let function = instance
+            .exports
+            .get_native_function::<(FatPtr), FatPtr>("__fp_gen_export_chrono_types")
+            .map_err(|_| InvocationError::FunctionNotExported)?;
+        let result = function.call(arg)?;
+        let result = import_from_guest_raw(&env, result);
+        Ok(result)
+    }
+
+    pub fn export_collections(
+        &self,
+        arg: StructWithCollections,
+    ) -> Result<StructWithCollections, InvocationError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_collections_raw(arg);
+        let result = result.map(|ref data| deserialize_from_slice(data));
+        result
+    }
+    pub fn export_collections_raw(&self, arg: Vec<u8>) -> Result<Vec<u8>, InvocationError> {
+        let mut env = RuntimeInstanceData::default();
+        let import_object = create_import_object(self.module.store(), &env);
+        let instance = Instance::new(&self.module, &import_object).unwrap();
+        env.init_with_instance(&instance).unwrap();
+        let arg = export_to_guest_raw(&env, arg);
+        let function = instance
+            .exports
+            .get_native_function::<(FatPtr), FatPtr>("__fp_gen_export_collections")
+            .map_err(|_| InvocationError::FunctionNotExported)?;
+        let result = function.call(arg)?;
+        let result = import_from_guest_raw(&env, result);
+        Ok(result)
+    }
+
+    pub fn export_custom_types(&self, arg: Appointment) -> Result<Appointment, InvocationError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_custom_types_raw(arg);
+        let result = result.map(|ref data| deserialize_from_slice(data));
+        result
+    }
+    pub fn export_custom_types_raw(&self, arg: Vec<u8>) -> Result<Vec<u8>, InvocationError> {
+        let mut env = RuntimeInstanceData::default();
+        let import_object = create_import_object(self.module.store(), &env);
+        let instance = Instance::new(&self.module, &import_object).unwrap();
+        env.init_with_instance(&instance).unwrap();
+        let arg = export_to_guest_raw(&env, arg);
+        let function = instance
+            .exports
+            .get_native_function::<(FatPtr), FatPtr>("__fp_gen_export_custom_types")
+            .map_err(|_| InvocationError::FunctionNotExported)?;
+        let result = function.call(arg)?;
+        let result = import_from_guest_raw(&env, result);
+        Ok(result)
+    }
+
+    pub fn export_directional_enum(
+        &self,
+        arg: DirectionalVariantRenaming,
+    ) -> Result<DirectionalVariantRenaming, InvocationError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_directional_enum_raw(arg);
+        let result = result.map(|ref data| deserialize_from_slice(data));
+        result
+    }
+    pub fn export_directional_enum_raw(&self, arg: Vec<u8>) -> Result<Vec<u8>, InvocationError> {
+        let mut env = RuntimeInstanceData::default();
+        let import_object = create_import_object(self.module.store(), &env);
+        let instance = Instance::new(&self.module, &import_object).unwrap();
+        env.init_with_instance(&instance).unwrap();
+        let arg = export_to_guest_raw(&env, arg);
+        let function = instance
+            .exports
+            .get_native_function::<(FatPtr), FatPtr>("__fp_gen_export_directional_enum")
+            .map_err(|_| InvocationError::FunctionNotExported)?;
+        let result = function.call(arg)?;
+        let result = import_from_guest_raw(&env, result);
+        Ok(result)
+    }
+
+    pub fn export_directional_struct(
+        &self,
+        arg: DirectionalStructRenaming,
+    ) -> Result<DirectionalStructRenaming, InvocationError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_directional_struct_raw(arg);
+        let result = result.map(|ref data| deserialize_from_slice(data));
+        result
+    }
+    pub fn export_directional_struct_raw(&self, arg: Vec<u8>) -> Result<Vec<u8>, InvocationError> {
+        let mut env = RuntimeInstanceData::default();
+        let import_object = create_import_object(self.module.store(), &env);
+        let instance = Instance::new(&self.module, &import_object).unwrap();
+        env.init_with_instance(&instance).unwrap();
+        let arg = export_to_guest_raw(&env, arg);
+        let function = instance
+            .exports
+            .get_native_function::<(FatPtr), FatPtr>("__fp_gen_export_directional_struct")
+            .map_err(|_| InvocationError::FunctionNotExported)?;
+        let result = function.call(arg)?;
+        let result = import_from_guest_raw(&env, result);
+        Ok(result)
+    }
+
+    pub fn export_durations(
+        &self,
+        arg: StructWithDurations,
+    ) -> Result<StructWithDurations, InvocationError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_durations_raw(arg);
+        let result = result.map(|ref data| deserialize_from_slice(data));
+        result
+    }
+    pub fn export_durations_raw(&self, arg: Vec<u8>) -> Result<Vec<u8>, InvocationError> {
+        let mut env = RuntimeInstanceData::default();
+        let import_object = create_import_object(self.module.store(), &env);
+        let instance = Instance::new(&self.module, &import_object).unwrap();
+        env.init_with_instance(&instance).unwrap();
+        let arg = export_to_guest_raw(&env, arg);
+        let function = instance
+            .exports
+            .get_native_function::<(FatPtr), FatPtr>("__fp_gen_export_durations")
+            .map_err(|_| InvocationError::FunctionNotExported)?;
+        let result = function.call(arg)?;
+        let result = import_from_guest_raw(&env, result);
+        Ok(result)
+    }
+
+    pub fn export_fetch(
+        &self,
+        request: fp_bindgen_support::http::HttpRequest,
+    ) -> Result<Option<fp_bindgen_support::http::HttpResponse>, InvocationError> {
+        let request = serialize_to_vec(&request);
+        let result = self.export_fetch_raw(request);
+        let result = result.map(|ref data| deserialize_from_slice(data));
+        result
+    }
+    pub fn export_fetch_raw(&self, request: Vec<u8>) -> Result<Vec<u8>, InvocationError> {
+        let mut env = RuntimeInstanceData::default();
+        let import_object = create_import_object(self.module.store(), &env);
+        let instance = Instance::new(&self.module, &import_object).unwrap();
+        env.init_with_instance(&instance).unwrap();
+        let request = export_to_guest_raw(&env, request);
+        let function = instance
+            .exports
+            .get_native_function::<(FatPtr), FatPtr>("__fp_gen_export_fetch")
+            .map_err(|_| InvocationError::FunctionNotExported)?;
+        let result = function.call(request)?;
+        let result = import_from_guest_raw(&env, result);
+        Ok(result)
+    }
+
     pub fn export_fp_adjacently_tagged(
         &self,
         arg: FpAdjacentlyTagged,
@@ -176,52 +506,458 @@ impl Runtime {
         let result = result.map(|ref data| deserialize_from_slice(data));
         result
     }
-    pub fn export_fp_struct_raw(&self, arg: Vec<u8>) -> Result<Vec<u8>, InvocationError> {
+    pub fn export_fp_struct_raw(&self, arg: Vec<u8>) -> Result<Vec<u8>, InvocationError> {
+        let mut env = RuntimeInstanceData::default();
+        let import_object = create_import_object(self.module.store(), &env);
+        let instance = Instance::new(&self.module, &import_object).unwrap();
+        env.init_with_instance(&instance).unwrap();
+        let arg = export_to_guest_raw(&env, arg);
+        let function = instance
+            .exports
+            .get_native_function::<(FatPtr), FatPtr>("__fp_gen_export_fp_struct")
+            .map_err(|_| InvocationError::FunctionNotExported)?;
+        let result = function.call(arg)?;
+        let result = import_from_guest_raw(&env, result);
+        Ok(result)
+    }
+
+    pub fn export_fp_untagged(&self, arg: FpUntagged) -> Result<FpUntagged, InvocationError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_fp_untagged_raw(arg);
+        let result = result.map(|ref data| deserialize_from_slice(data));
+        result
+    }
+    pub fn export_fp_untagged_raw(&self, arg: Vec<u8>) -> Result<Vec<u8>, InvocationError> {
+        let mut env = RuntimeInstanceData::default();
+        let import_object = create_import_object(self.module.store(), &env);
+        let instance = Instance::new(&self.module, &import_object).unwrap();
+        env.init_with_instance(&instance).unwrap();
+        let arg = export_to_guest_raw(&env, arg);
+        let function = instance
+            .exports
+            .get_native_function::<(FatPtr), FatPtr>("__fp_gen_export_fp_untagged")
+            .map_err(|_| InvocationError::FunctionNotExported)?;
+        let result = function.call(arg)?;
+        let result = import_from_guest_raw(&env, result);
+        Ok(result)
+    }
+
+    pub fn export_generics(
+        &self,
+        arg: StructWithGenerics<u64>,
+    ) -> Result<StructWithGenerics<u64>, InvocationError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_generics_raw(arg);
+        let result = result.map(|ref data| deserialize_from_slice(data));
+        result
+    }
+    pub fn export_generics_raw(&self, arg: Vec<u8>) -> Result<Vec<u8>, InvocationError> {
+        let mut env = RuntimeInstanceData::default();
+        let import_object = create_import_object(self.module.store(), &env);
+        let instance = Instance::new(&self.module, &import_object).unwrap();
+        env.init_with_instance(&instance).unwrap();
+        let arg = export_to_guest_raw(&env, arg);
+        let function = instance
+            .exports
+            .get_native_function::<(FatPtr), FatPtr>("__fp_gen_export_generics")
+            .map_err(|_| InvocationError::FunctionNotExported)?;
+        let result = function.call(arg)?;
+        let result = import_from_guest_raw(&env, result);
+        Ok(result)
+    }
+
+    pub fn export_http_exchange(&self, arg: HttpExchange) -> Result<HttpExchange, InvocationError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_http_exchange_raw(arg);
+        let result = result.map(|ref data| deserialize_from_slice(data));
+        result
+    }
+    pub fn export_http_exchange_raw(&self, arg: Vec<u8>) -> Result<Vec<u8>, InvocationError> {
+        let mut env = RuntimeInstanceData::default();
+        let import_object = create_import_object(self.module.store(), &env);
+        let instance = Instance::new(&self.module, &import_object).unwrap();
+        env.init_with_instance(&instance).unwrap();
+        let arg = export_to_guest_raw(&env, arg);
+        let function = instance
+            .exports
+            .get_native_function::<(FatPtr), FatPtr>("__fp_gen_export_http_exchange")
+            .map_err(|_| InvocationError::FunctionNotExported)?;
+        let result = function.call(arg)?;
+        let result = import_from_guest_raw(&env, result);
+        Ok(result)
+    }
+
+    pub fn export_json(&self, arg: StructWithJson) -> Result<StructWithJson, InvocationError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_json_raw(arg);
+        let result = result.map(|ref data| deserialize_from_slice(data));
+        result
+    }
+    pub fn export_json_raw(&self, arg: Vec<u8>) -> Result<Vec<u8>, InvocationError> {
+        let mut env = RuntimeInstanceData::default();
+        let import_object = create_import_object(self.module.store(), &env);
+        let instance = Instance::new(&self.module, &import_object).unwrap();
+        env.init_with_instance(&instance).unwrap();
+        let arg = export_to_guest_raw(&env, arg);
+        let function = instance
+            .exports
+            .get_native_function::<(FatPtr), FatPtr>("__fp_gen_export_json")
+            .map_err(|_| InvocationError::FunctionNotExported)?;
+        let result = function.call(arg)?;
+        let result = import_from_guest_raw(&env, result);
+        Ok(result)
+    }
+
+    pub fn export_kebab_case_struct(
+        &self,
+        arg: KebabCasePropertyRenaming,
+    ) -> Result<KebabCasePropertyRenaming, InvocationError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_kebab_case_struct_raw(arg);
+        let result = result.map(|ref data| deserialize_from_slice(data));
+        result
+    }
+    pub fn export_kebab_case_struct_raw(&self, arg: Vec<u8>) -> Result<Vec<u8>, InvocationError> {
+        let mut env = RuntimeInstanceData::default();
+        let import_object = create_import_object(self.module.store(), &env);
+        let instance = Instance::new(&self.module, &import_object).unwrap();
+        env.init_with_instance(&instance).unwrap();
+        let arg = export_to_guest_raw(&env, arg);
+        let function = instance
+            .exports
+            .get_native_function::<(FatPtr), FatPtr>("__fp_gen_export_kebab_case_struct")
+            .map_err(|_| InvocationError::FunctionNotExported)?;
+        let result = function.call(arg)?;
+        let result = import_from_guest_raw(&env, result);
+        Ok(result)
+    }
+
+    pub fn export_multiple_primitives(
+        &self,
+        arg1: i8,
+        arg2: String,
+    ) -> Result<i64, InvocationError> {
+        let arg2 = serialize_to_vec(&arg2);
+        let result = self.export_multiple_primitives_raw(arg1, arg2);
+        result
+    }
+    pub fn export_multiple_primitives_raw(
+        &self,
+        arg1: i8,
+        arg2: Vec<u8>,
+    ) -> Result<i64, InvocationError> {
+        let mut env = RuntimeInstanceData::default();
+        let import_object = create_import_object(self.module.store(), &env);
+        let instance = Instance::new(&self.module, &import_object).unwrap();
+        env.init_with_instance(&instance).unwrap();
+        let arg2 = export_to_guest_raw(&env, arg2);
+        let function = instance
+            .exports
+            .get_native_function::<(i8, FatPtr), i64>("__fp_gen_export_multiple_primitives")
+            .map_err(|_| InvocationError::FunctionNotExported)?;
+        let result = function.call(arg1, arg2)?;
+        Ok(result)
+    }
+
+    pub fn export_newtype(&self, arg: UserId) -> Result<Meters, InvocationError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_newtype_raw(arg);
+        let result = result.map(|ref data| deserialize_from_slice(data));
+        result
+    }
+    pub fn export_newtype_raw(&self, arg: Vec<u8>) -> Result<Vec<u8>, InvocationError> {
+        let mut env = RuntimeInstanceData::default();
+        let import_object = create_import_object(self.module.store(), &env);
+        let instance = Instance::new(&self.module, &import_object).unwrap();
+        env.init_with_instance(&instance).unwrap();
+        let arg = export_to_guest_raw(&env, arg);
+        let function = instance
+            .exports
+            .get_native_function::<(FatPtr), FatPtr>("__fp_gen_export_newtype")
+            .map_err(|_| InvocationError::FunctionNotExported)?;
+        let result = function.call(arg)?;
+        let result = import_from_guest_raw(&env, result);
+        Ok(result)
+    }
+
+    pub fn export_primitive_bool(&self, arg: bool) -> Result<bool, InvocationError> {
+        let result = self.export_primitive_bool_raw(arg);
+        result
+    }
+    pub fn export_primitive_bool_raw(&self, arg: bool) -> Result<bool, InvocationError> {
+        let mut env = RuntimeInstanceData::default();
+        let import_object = create_import_object(self.module.store(), &env);
+        let instance = Instance::new(&self.module, &import_object).unwrap();
+        env.init_with_instance(&instance).unwrap();
+        let function = instance
+            .exports
+            .get_native_function::<(bool), bool>("__fp_gen_export_primitive_bool")
+            .map_err(|_| InvocationError::FunctionNotExported)?;
+        let result = function.call(arg)?;
+        Ok(result)
+    }
+
+    pub fn export_primitive_char(&self, arg: char) -> Result<char, InvocationError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_primitive_char_raw(arg);
+        let result = result.map(|ref data| deserialize_from_slice(data));
+        result
+    }
+    pub fn export_primitive_char_raw(&self, arg: Vec<u8>) -> Result<Vec<u8>, InvocationError> {
+        let mut env = RuntimeInstanceData::default();
+        let import_object = create_import_object(self.module.store(), &env);
+        let instance = Instance::new(&self.module, &import_object).unwrap();
+        env.init_with_instance(&instance).unwrap();
+        let arg = export_to_guest_raw(&env, arg);
+        let function = instance
+            .exports
+            .get_native_function::<(FatPtr), FatPtr>("__fp_gen_export_primitive_char")
+            .map_err(|_| InvocationError::FunctionNotExported)?;
+        let result = function.call(arg)?;
+        let result = import_from_guest_raw(&env, result);
+        Ok(result)
+    }
+
+    pub fn export_primitive_f32(&self, arg: f32) -> Result<f32, InvocationError> {
+        let result = self.export_primitive_f32_raw(arg);
+        result
+    }
+    pub fn export_primitive_f32_raw(&self, arg: f32) -> Result<f32, InvocationError> {
+        let mut env = RuntimeInstanceData::default();
+        let import_object = create_import_object(self.module.store(), &env);
+        let instance = Instance::new(&self.module, &import_object).unwrap();
+        env.init_with_instance(&instance).unwrap();
+        let function = instance
+            .exports
+            .get_native_function::<(f32), f32>("__fp_gen_export_primitive_f32")
+            .map_err(|_| InvocationError::FunctionNotExported)?;
+        let result = function.call(arg)?;
+        Ok(result)
+    }
+
+    pub fn export_primitive_f64(&self, arg: f64) -> Result<f64, InvocationError> {
+        let result = self.export_primitive_f64_raw(arg);
+        result
+    }
+    pub fn export_primitive_f64_raw(&self, arg: f64) -> Result<f64, InvocationError> {
+        let mut env = RuntimeInstanceData::default();
+        let import_object = create_import_object(self.module.store(), &env);
+        let instance = Instance::new(&self.module, &import_object).unwrap();
+        env.init_with_instance(&instance).unwrap();
+        let function = instance
+            .exports
+            .get_native_function::<(f64), f64>("__fp_gen_export_primitive_f64")
+            .map_err(|_| InvocationError::FunctionNotExported)?;
+        let result = function.call(arg)?;
+        Ok(result)
+    }
+
+    pub fn export_primitive_i128(&self, arg: i128) -> Result<i128, InvocationError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_primitive_i128_raw(arg);
+        let result = result.map(|ref data| deserialize_from_slice(data));
+        result
+    }
+    pub fn export_primitive_i128_raw(&self, arg: Vec<u8>) -> Result<Vec<u8>, InvocationError> {
+        let mut env = RuntimeInstanceData::default();
+        let import_object = create_import_object(self.module.store(), &env);
+        let instance = Instance::new(&self.module, &import_object).unwrap();
+        env.init_with_instance(&instance).unwrap();
+        let arg = export_to_guest_raw(&env, arg);
+        let function = instance
+            .exports
+            .get_native_function::<(FatPtr), FatPtr>("__fp_gen_export_primitive_i128")
+            .map_err(|_| InvocationError::FunctionNotExported)?;
+        let result = function.call(arg)?;
+        let result = import_from_guest_raw(&env, result);
+        Ok(result)
+    }
+
+    pub fn export_primitive_i16(&self, arg: i16) -> Result<i16, InvocationError> {
+        let result = self.export_primitive_i16_raw(arg);
+        result
+    }
+    pub fn export_primitive_i16_raw(&self, arg: i16) -> Result<i16, InvocationError> {
+        let mut env = RuntimeInstanceData::default();
+        let import_object = create_import_object(self.module.store(), &env);
+        let instance = Instance::new(&self.module, &import_object).unwrap();
+        env.init_with_instance(&instance).unwrap();
+        let function = instance
+            .exports
+            .get_native_function::<(i16), i16>("__fp_gen_export_primitive_i16")
+            .map_err(|_| InvocationError::FunctionNotExported)?;
+        let result = function.call(arg)?;
+        Ok(result)
+    }
+
+    pub fn export_primitive_i32(&self, arg: i32) -> Result<i32, InvocationError> {
+        let result = self.export_primitive_i32_raw(arg);
+        result
+    }
+    pub fn export_primitive_i32_raw(&self, arg: i32) -> Result<i32, InvocationError> {
+        let mut env = RuntimeInstanceData::default();
+        let import_object = create_import_object(self.module.store(), &env);
+        let instance = Instance::new(&self.module, &import_object).unwrap();
+        env.init_with_instance(&instance).unwrap();
+        let function = instance
+            .exports
+            .get_native_function::<(i32), i32>("__fp_gen_export_primitive_i32")
+            .map_err(|_| InvocationError::FunctionNotExported)?;
+        let result = function.call(arg)?;
+        Ok(result)
+    }
+
+    pub fn export_primitive_i64(&self, arg: i64) -> Result<i64, InvocationError> {
+        let result = self.export_primitive_i64_raw(arg);
+        result
+    }
+    pub fn export_primitive_i64_raw(&self, arg: i64) -> Result<i64, InvocationError> {
+        let mut env = RuntimeInstanceData::default();
+        let import_object = create_import_object(self.module.store(), &env);
+        let instance = Instance::new(&self.module, &import_object).unwrap();
+        env.init_with_instance(&instance).unwrap();
+        let function = instance
+            .exports
+            .get_native_function::<(i64), i64>("__fp_gen_export_primitive_i64")
+            .map_err(|_| InvocationError::FunctionNotExported)?;
+        let result = function.call(arg)?;
+        Ok(result)
+    }
+
+    pub fn export_primitive_i8(&self, arg: i8) -> Result<i8, InvocationError> {
+        let result = self.export_primitive_i8_raw(arg);
+        result
+    }
+    pub fn export_primitive_i8_raw(&self, arg: i8) -> Result<i8, InvocationError> {
+        let mut env = RuntimeInstanceData::default();
+        let import_object = create_import_object(self.module.store(), &env);
+        let instance = Instance::new(&self.module, &import_object).unwrap();
+        env.init_with_instance(&instance).unwrap();
+        let function = instance
+            .exports
+            .get_native_function::<(i8), i8>("__fp_gen_export_primitive_i8")
+            .map_err(|_| InvocationError::FunctionNotExported)?;
+        let result = function.call(arg)?;
+        Ok(result)
+    }
+
+    pub fn export_primitive_isize(&self, arg: isize) -> Result<isize, InvocationError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_primitive_isize_raw(arg);
+        let result = result.map(|ref data| deserialize_from_slice(data));
+        result
+    }
+    pub fn export_primitive_isize_raw(&self, arg: Vec<u8>) -> Result<Vec<u8>, InvocationError> {
+        let mut env = RuntimeInstanceData::default();
+        let import_object = create_import_object(self.module.store(), &env);
+        let instance = Instance::new(&self.module, &import_object).unwrap();
+        env.init_with_instance(&instance).unwrap();
+        let arg = export_to_guest_raw(&env, arg);
+        let function = instance
+            .exports
+            .get_native_function::<(FatPtr), FatPtr>("__fp_gen_export_primitive_isize")
+            .map_err(|_| InvocationError::FunctionNotExported)?;
+        let result = function.call(arg)?;
+        let result = import_from_guest_raw(&env, result);
+        Ok(result)
+    }
+
+    pub fn export_primitive_u128(&self, arg: u128) -> Result<u128, InvocationError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_primitive_u128_raw(arg);
+        let result = result.map(|ref data| deserialize_from_slice(data));
+        result
+    }
+    pub fn export_primitive_u128_raw(&self, arg: Vec<u8>) -> Result<Vec<u8>, InvocationError> {
+        let mut env = RuntimeInstanceData::default();
+        let import_object = create_import_object(self.module.store(), &env);
+        let instance = Instance::new(&self.module, &import_object).unwrap();
+        env.init_with_instance(&instance).unwrap();
+        let arg = export_to_guest_raw(&env, arg);
+        let function = instance
+            .exports
+            .get_native_function::<(FatPtr), FatPtr>("__fp_gen_export_primitive_u128")
+            .map_err(|_| InvocationError::FunctionNotExported)?;
+        let result = function.call(arg)?;
+        let result = import_from_guest_raw(&env, result);
+        Ok(result)
+    }
+
+    pub fn export_primitive_u16(&self, arg: u16) -> Result<u16, InvocationError> {
+        let result = self.export_primitive_u16_raw(arg);
+        result
+    }
+    pub fn export_primitive_u16_raw(&self, arg: u16) -> Result<u16, InvocationError> {
+        let mut env = RuntimeInstanceData::default();
+        let import_object = create_import_object(self.module.store(), &env);
+        let instance = Instance::new(&self.module, &import_object).unwrap();
+        env.init_with_instance(&instance).unwrap();
+        let function = instance
+            .exports
+            .get_native_function::<(u16), u16>("__fp_gen_export_primitive_u16")
+            .map_err(|_| InvocationError::FunctionNotExported)?;
+        let result = function.call(arg)?;
+        Ok(result)
+    }
+
+    pub fn export_primitive_u32(&self, arg: u32) -> Result<u32, InvocationError> {
+        let result = self.export_primitive_u32_raw(arg);
+        result
+    }
+    pub fn export_primitive_u32_raw(&self, arg: u32) -> Result<u32, InvocationError> {
+        let mut env = RuntimeInstanceData::default();
+        let import_object = create_import_object(self.module.store(), &env);
+        let instance = Instance::new(&self.module, &import_object).unwrap();
+        env.init_with_instance(&instance).unwrap();
+        let function = instance
+            .exports
+            .get_native_function::<(u32), u32>("__fp_gen_export_primitive_u32")
+            .map_err(|_| InvocationError::FunctionNotExported)?;
+        let result = function.call(arg)?;
+        Ok(result)
+    }
+
+    pub fn export_primitive_u64(&self, arg: u64) -> Result<u64, InvocationError> {
+        let result = self.export_primitive_u64_raw(arg);
+        result
+    }
+    pub fn export_primitive_u64_raw(&self, arg: u64) -> Result<u64, InvocationError> {
         let mut env = RuntimeInstanceData::default();
         let import_object = create_import_object(self.module.store(), &env);
         let instance = Instance::new(&self.module, &import_object).unwrap();
         env.init_with_instance(&instance).unwrap();
-        let arg = export_to_guest_raw(&env, arg);
         let function = instance
             .exports
-            .get_native_function::<(FatPtr), FatPtr>("__fp_gen_export_fp_struct")
+            .get_native_function::<(u64), u64>("__fp_gen_export_primitive_u64")
             .map_err(|_| InvocationError::FunctionNotExported)?;
         let result = function.call(arg)?;
-        let result = import_from_guest_raw(&env, result);
         Ok(result)
     }
 
-    pub fn export_fp_untagged(&self, arg: FpUntagged) -> Result<FpUntagged, InvocationError> {
-        let arg = serialize_to_vec(&arg);
-        let result = self.export_fp_untagged_raw(arg);
-        let result = result.map(|ref data| deserialize_from_slice(data));
+    pub fn export_primitive_u8(&self, arg: u8) -> Result<u8, InvocationError> {
+        let result = self.export_primitive_u8_raw(arg);
         result
     }
-    pub fn export_fp_untagged_raw(&self, arg: Vec<u8>) -> Result<Vec<u8>, InvocationError> {
+    pub fn export_primitive_u8_raw(&self, arg: u8) -> Result<u8, InvocationError> {
         let mut env = RuntimeInstanceData::default();
         let import_object = create_import_object(self.module.store(), &env);
         let instance = Instance::new(&self.module, &import_object).unwrap();
         env.init_with_instance(&instance).unwrap();
-        let arg = export_to_guest_raw(&env, arg);
         let function = instance
             .exports
-            .get_native_function::<(FatPtr), FatPtr>("__fp_gen_export_fp_untagged")
+            .get_native_function::<(u8), u8>("__fp_gen_export_primitive_u8")
             .map_err(|_| InvocationError::FunctionNotExported)?;
         let result = function.call(arg)?;
-        let result = import_from_guest_raw(&env, result);
         Ok(result)
     }
 
-    pub fn export_generics(
-        &self,
-        arg: StructWithGenerics<u64>,
-    ) -> Result<StructWithGenerics<u64>, InvocationError> {
+    pub fn export_primitive_usize(&self, arg: usize) -> Result<usize, InvocationError> {
         let arg = serialize_to_vec(&arg);
-        let result = self.export_generics_raw(arg);
+        let result = self.export_primitive_usize_raw(arg);
         let result = result.map(|ref data| deserialize_from_slice(data));
         result
     }
-    pub fn export_generics_raw(&self, arg: Vec<u8>) -> Result<Vec<u8>, InvocationError> {
+    pub fn export_primitive_usize_raw(&self, arg: Vec<u8>) -> Result<Vec<u8>, InvocationError> {
         let mut env = RuntimeInstanceData::default();
         let import_object = create_import_object(self.module.store(), &env);
         let instance = Instance::new(&self.module, &import_object).unwrap();
@@ -229,237 +965,308 @@ impl Runtime {
         let arg = export_to_guest_raw(&env, arg);
         let function = instance
             .exports
-            .get_native_function::<(FatPtr), FatPtr>("__fp_gen_export_generics")
+            .get_native_function::<(FatPtr), FatPtr>("__fp_gen_export_primitive_usize")
             .map_err(|_| InvocationError::FunctionNotExported)?;
         let result = function.call(arg)?;
         let result = import_from_guest_raw(&env, result);
         Ok(result)
     }
 
-    pub fn export_multiple_primitives(
-        &self,
-        arg1: i8,
-        arg2: String,
-    ) -> Result<i64, InvocationError> {
-        let arg2 = serialize_to_vec(&arg2);
-        let result = self.export_multiple_primitives_raw(arg1, arg2);
+    pub fn export_remote_type(&self, arg: ActionMetaDef) -> Result<ActionMetaDef, InvocationError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_remote_type_raw(arg);
+        let result = result.map(|ref data| deserialize_from_slice(data));
         result
     }
-    pub fn export_multiple_primitives_raw(
-        &self,
-        arg1: i8,
-        arg2: Vec<u8>,
-    ) -> Result<i64, InvocationError> {
+    pub fn export_remote_type_raw(&self, arg: Vec<u8>) -> Result<Vec<u8>, InvocationError> {
         let mut env = RuntimeInstanceData::default();
         let import_object = create_import_object(self.module.store(), &env);
         let instance = Instance::new(&self.module, &import_object).unwrap();
         env.init_with_instance(&instance).unwrap();
-        let arg2 = export_to_guest_raw(&env, arg2);
+        let arg = export_to_guest_raw(&env, arg);
         let function = instance
             .exports
-            .get_native_function::<(i8, FatPtr), i64>("__fp_gen_export_multiple_primitives")
+            .get_native_function::<(FatPtr), FatPtr>("__fp_gen_export_remote_type")
             .map_err(|_| InvocationError::FunctionNotExported)?;
-        let result = function.call(arg1, arg2)?;
+        let result = function.call(arg)?;
+        let result = import_from_guest_raw(&env, result);
         Ok(result)
     }
 
-    pub fn export_primitive_bool(&self, arg: bool) -> Result<bool, InvocationError> {
-        let result = self.export_primitive_bool_raw(arg);
+    pub fn export_repr_enum(&self, arg: Level) -> Result<Level, InvocationError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_repr_enum_raw(arg);
+        let result = result.map(|ref data| deserialize_from_slice(data));
         result
     }
-    pub fn export_primitive_bool_raw(&self, arg: bool) -> Result<bool, InvocationError> {
+    pub fn export_repr_enum_raw(&self, arg: Vec<u8>) -> Result<Vec<u8>, InvocationError> {
         let mut env = RuntimeInstanceData::default();
         let import_object = create_import_object(self.module.store(), &env);
         let instance = Instance::new(&self.module, &import_object).unwrap();
         env.init_with_instance(&instance).unwrap();
+        let arg = export_to_guest_raw(&env, arg);
         let function = instance
             .exports
-            .get_native_function::<(bool), bool>("__fp_gen_export_primitive_bool")
+            .get_native_function::<(FatPtr), FatPtr>("__fp_gen_export_repr_enum")
             .map_err(|_| InvocationError::FunctionNotExported)?;
         let result = function.call(arg)?;
+        let result = import_from_guest_raw(&env, result);
         Ok(result)
     }
 
-    pub fn export_primitive_f32(&self, arg: f32) -> Result<f32, InvocationError> {
-        let result = self.export_primitive_f32_raw(arg);
+    pub fn export_serde_adjacently_tagged(
+        &self,
+        arg: SerdeAdjacentlyTagged,
+    ) -> Result<SerdeAdjacentlyTagged, InvocationError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_serde_adjacently_tagged_raw(arg);
+        let result = result.map(|ref data| deserialize_from_slice(data));
         result
     }
-    pub fn export_primitive_f32_raw(&self, arg: f32) -> Result<f32, InvocationError> {
+    pub fn export_serde_adjacently_tagged_raw(
+        &self,
+        arg: Vec<u8>,
+    ) -> Result<Vec<u8>, InvocationError> {
         let mut env = RuntimeInstanceData::default();
         let import_object = create_import_object(self.module.store(), &env);
         let instance = Instance::new(&self.module, &import_object).unwrap();
         env.init_with_instance(&instance).unwrap();
+        let arg = export_to_guest_raw(&env, arg);
         let function = instance
             .exports
-            .get_native_function::<(f32), f32>("__fp_gen_export_primitive_f32")
+            .get_native_function::<(FatPtr), FatPtr>("__fp_gen_export_serde_adjacently_tagged")
             .map_err(|_| InvocationError::FunctionNotExported)?;
         let result = function.call(arg)?;
+        let result = import_from_guest_raw(&env, result);
         Ok(result)
     }
 
-    pub fn export_primitive_f64(&self, arg: f64) -> Result<f64, InvocationError> {
-        let result = self.export_primitive_f64_raw(arg);
+    pub fn export_serde_attributes(
+        &self,
+        arg: SerdeAttributes,
+    ) -> Result<SerdeAttributes, InvocationError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_serde_attributes_raw(arg);
+        let result = result.map(|ref data| deserialize_from_slice(data));
         result
     }
-    pub fn export_primitive_f64_raw(&self, arg: f64) -> Result<f64, InvocationError> {
+    pub fn export_serde_attributes_raw(&self, arg: Vec<u8>) -> Result<Vec<u8>, InvocationError> {
         let mut env = RuntimeInstanceData::default();
         let import_object = create_import_object(self.module.store(), &env);
         let instance = Instance::new(&self.module, &import_object).unwrap();
         env.init_with_instance(&instance).unwrap();
+        let arg = export_to_guest_raw(&env, arg);
         let function = instance
             .exports
-            .get_native_function::<(f64), f64>("__fp_gen_export_primitive_f64")
+            .get_native_function::<(FatPtr), FatPtr>("__fp_gen_export_serde_attributes")
             .map_err(|_| InvocationError::FunctionNotExported)?;
         let result = function.call(arg)?;
+        let result = import_from_guest_raw(&env, result);
         Ok(result)
     }
 
-    pub fn export_primitive_i16(&self, arg: i16) -> Result<i16, InvocationError> {
-        let result = self.export_primitive_i16_raw(arg);
+    pub fn export_serde_enum(
+        &self,
+        arg: SerdeVariantRenaming,
+    ) -> Result<SerdeVariantRenaming, InvocationError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_serde_enum_raw(arg);
+        let result = result.map(|ref data| deserialize_from_slice(data));
         result
     }
-    pub fn export_primitive_i16_raw(&self, arg: i16) -> Result<i16, InvocationError> {
+    pub fn export_serde_enum_raw(&self, arg: Vec<u8>) -> Result<Vec<u8>, InvocationError> {
         let mut env = RuntimeInstanceData::default();
         let import_object = create_import_object(self.module.store(), &env);
         let instance = Instance::new(&self.module, &import_object).unwrap();
         env.init_with_instance(&instance).unwrap();
+        let arg = export_to_guest_raw(&env, arg);
         let function = instance
             .exports
-            .get_native_function::<(i16), i16>("__fp_gen_export_primitive_i16")
+            .get_native_function::<(FatPtr), FatPtr>("__fp_gen_export_serde_enum")
             .map_err(|_| InvocationError::FunctionNotExported)?;
         let result = function.call(arg)?;
+        let result = import_from_guest_raw(&env, result);
         Ok(result)
     }
 
-    pub fn export_primitive_i32(&self, arg: i32) -> Result<i32, InvocationError> {
-        let result = self.export_primitive_i32_raw(arg);
+    pub fn export_serde_flatten(&self, arg: SerdeFlatten) -> Result<SerdeFlatten, InvocationError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_serde_flatten_raw(arg);
+        let result = result.map(|ref data| deserialize_from_slice(data));
         result
     }
-    pub fn export_primitive_i32_raw(&self, arg: i32) -> Result<i32, InvocationError> {
+    pub fn export_serde_flatten_raw(&self, arg: Vec<u8>) -> Result<Vec<u8>, InvocationError> {
         let mut env = RuntimeInstanceData::default();
         let import_object = create_import_object(self.module.store(), &env);
         let instance = Instance::new(&self.module, &import_object).unwrap();
         env.init_with_instance(&instance).unwrap();
+        let arg = export_to_guest_raw(&env, arg);
         let function = instance
             .exports
-            .get_native_function::<(i32), i32>("__fp_gen_export_primitive_i32")
+            .get_native_function::<(FatPtr), FatPtr>("__fp_gen_export_serde_flatten")
             .map_err(|_| InvocationError::FunctionNotExported)?;
         let result = function.call(arg)?;
+        let result = import_from_guest_raw(&env, result);
         Ok(result)
     }
 
-    pub fn export_primitive_i64(&self, arg: i64) -> Result<i64, InvocationError> {
-        let result = self.export_primitive_i64_raw(arg);
+    pub fn export_serde_internally_tagged(
+        &self,
+        arg: SerdeInternallyTagged,
+    ) -> Result<SerdeInternallyTagged, InvocationError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_serde_internally_tagged_raw(arg);
+        let result = result.map(|ref data| deserialize_from_slice(data));
         result
     }
-    pub fn export_primitive_i64_raw(&self, arg: i64) -> Result<i64, InvocationError> {
+    pub fn export_serde_internally_tagged_raw(
+        &self,
+        arg: Vec<u8>,
+    ) -> Result<Vec<u8>, InvocationError> {
         let mut env = RuntimeInstanceData::default();
         let import_object = create_import_object(self.module.store(), &env);
         let instance = Instance::new(&self.module, &import_object).unwrap();
         env.init_with_instance(&instance).unwrap();
+        let arg = export_to_guest_raw(&env, arg);
         let function = instance
             .exports
-            .get_native_function::<(i64), i64>("__fp_gen_export_primitive_i64")
+            .get_native_function::<(FatPtr), FatPtr>("__fp_gen_export_serde_internally_tagged")
             .map_err(|_| InvocationError::FunctionNotExported)?;
         let result = function.call(arg)?;
+        let result = import_from_guest_raw(&env, result);
         Ok(result)
     }
 
-    pub fn export_primitive_i8(&self, arg: i8) -> Result<i8, InvocationError> {
-        let result = self.export_primitive_i8_raw(arg);
+    pub fn export_serde_struct(
+        &self,
+        arg: SerdePropertyRenaming,
+    ) -> Result<SerdePropertyRenaming, InvocationError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_serde_struct_raw(arg);
+        let result = result.map(|ref data| deserialize_from_slice(data));
         result
     }
-    pub fn export_primitive_i8_raw(&self, arg: i8) -> Result<i8, InvocationError> {
+    pub fn export_serde_struct_raw(&self, arg: Vec<u8>) -> Result<Vec<u8>, InvocationError> {
         let mut env = RuntimeInstanceData::default();
         let import_object = create_import_object(self.module.store(), &env);
         let instance = Instance::new(&self.module, &import_object).unwrap();
         env.init_with_instance(&instance).unwrap();
+        let arg = export_to_guest_raw(&env, arg);
         let function = instance
             .exports
-            .get_native_function::<(i8), i8>("__fp_gen_export_primitive_i8")
+            .get_native_function::<(FatPtr), FatPtr>("__fp_gen_export_serde_struct")
             .map_err(|_| InvocationError::FunctionNotExported)?;
         let result = function.call(arg)?;
+        let result = import_from_guest_raw(&env, result);
         Ok(result)
     }
 
-    pub fn export_primitive_u16(&self, arg: u16) -> Result<u16, InvocationError> {
-        let result = self.export_primitive_u16_raw(arg);
+    pub fn export_serde_untagged(
+        &self,
+        arg: SerdeUntagged,
+    ) -> Result<SerdeUntagged, InvocationError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_serde_untagged_raw(arg);
+        let result = result.map(|ref data| deserialize_from_slice(data));
         result
     }
-    pub fn export_primitive_u16_raw(&self, arg: u16) -> Result<u16, InvocationError> {
+    pub fn export_serde_untagged_raw(&self, arg: Vec<u8>) -> Result<Vec<u8>, InvocationError> {
         let mut env = RuntimeInstanceData::default();
         let import_object = create_import_object(self.module.store(), &env);
         let instance = Instance::new(&self.module, &import_object).unwrap();
         env.init_with_instance(&instance).unwrap();
+        let arg = export_to_guest_raw(&env, arg);
         let function = instance
             .exports
-            .get_native_function::<(u16), u16>("__fp_gen_export_primitive_u16")
+            .get_native_function::<(FatPtr), FatPtr>("__fp_gen_export_serde_untagged")
             .map_err(|_| InvocationError::FunctionNotExported)?;
         let result = function.call(arg)?;
+        let result = import_from_guest_raw(&env, result);
         Ok(result)
     }
 
-    pub fn export_primitive_u32(&self, arg: u32) -> Result<u32, InvocationError> {
-        let result = self.export_primitive_u32_raw(arg);
+    pub fn export_string(&self, arg: String) -> Result<String, InvocationError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_string_raw(arg);
+        let result = result.map(|ref data| deserialize_from_slice(data));
         result
     }
-    pub fn export_primitive_u32_raw(&self, arg: u32) -> Result<u32, InvocationError> {
+    pub fn export_string_raw(&self, arg: Vec<u8>) -> Result<Vec<u8>, InvocationError> {
         let mut env = RuntimeInstanceData::default();
         let import_object = create_import_object(self.module.store(), &env);
         let instance = Instance::new(&self.module, &import_object).unwrap();
         env.init_with_instance(&instance).unwrap();
+        let arg = export_to_guest_raw(&env, arg);
         let function = instance
             .exports
-            .get_native_function::<(u32), u32>("__fp_gen_export_primitive_u32")
+            .get_native_function::<(FatPtr), FatPtr>("__fp_gen_export_string")
             .map_err(|_| InvocationError::FunctionNotExported)?;
         let result = function.call(arg)?;
+        let result = import_from_guest_raw(&env, result);
         Ok(result)
     }
 
-    pub fn export_primitive_u64(&self, arg: u64) -> Result<u64, InvocationError> {
-        let result = self.export_primitive_u64_raw(arg);
+    pub fn export_struct_with_arrays(
+        &self,
+        arg: StructWithArrays,
+    ) -> Result<StructWithArrays, InvocationError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_struct_with_arrays_raw(arg);
+        let result = result.map(|ref data| deserialize_from_slice(data));
         result
     }
-    pub fn export_primitive_u64_raw(&self, arg: u64) -> Result<u64, InvocationError> {
+    pub fn export_struct_with_arrays_raw(&self, arg: Vec<u8>) -> Result<Vec<u8>, InvocationError> {
         let mut env = RuntimeInstanceData::default();
         let import_object = create_import_object(self.module.store(), &env);
         let instance = Instance::new(&self.module, &import_object).unwrap();
         env.init_with_instance(&instance).unwrap();
+        let arg = export_to_guest_raw(&env, arg);
         let function = instance
             .exports
-            .get_native_function::<(u64), u64>("__fp_gen_export_primitive_u64")
+            .get_native_function::<(FatPtr), FatPtr>("__fp_gen_export_struct_with_arrays")
             .map_err(|_| InvocationError::FunctionNotExported)?;
         let result = function.call(arg)?;
+        let result = import_from_guest_raw(&env, result);
         Ok(result)
     }
 
-    pub fn export_primitive_u8(&self, arg: u8) -> Result<u8, InvocationError> {
-        let result = self.export_primitive_u8_raw(arg);
+    pub fn export_struct_with_repr_enums(
+        &self,
+        arg: StructWithReprEnums,
+    ) -> Result<StructWithReprEnums, InvocationError> {
+        let arg = serialize_to_vec(&arg);
+        let result = self.export_struct_with_repr_enums_raw(arg);
+        let result = result.map(|ref data| deserialize_from_slice(data));
         result
     }
-    pub fn export_primitive_u8_raw(&self, arg: u8) -> Result<u8, InvocationError> {
+    pub fn export_struct_with_repr_enums_raw(
+        &self,
+        arg: Vec<u8>,
+    ) -> Result<Vec<u8>, InvocationError> {
         let mut env = RuntimeInstanceData::default();
         let import_object = create_import_object(self.module.store(), &env);
         let instance = Instance::new(&self.module, &import_object).unwrap();
         env.init_with_instance(&instance).unwrap();
+        let arg = export_to_guest_raw(&env, arg);
         let function = instance
             .exports
-            .get_native_function::<(u8), u8>("__fp_gen_export_primitive_u8")
+            .get_native_function::<(FatPtr), FatPtr>("__fp_gen_export_struct_with_repr_enums")
             .map_err(|_| InvocationError::FunctionNotExported)?;
         let result = function.call(arg)?;
+        let result = import_from_guest_raw(&env, result);
         Ok(result)
     }
 
-    pub fn export_serde_adjacently_tagged(
+    pub fn export_struct_with_tuple_structs(
         &self,
-        arg: SerdeAdjacentlyTagged,
-    ) -> Result<SerdeAdjacentlyTagged, InvocationError> {
+        arg: StructWithTupleStructs,
+    ) -> Result<StructWithTupleStructs, InvocationError> {
         let arg = serialize_to_vec(&arg);
-        let result = self.export_serde_adjacently_tagged_raw(arg);
+        let result = self.export_struct_with_tuple_structs_raw(arg);
         let result = result.map(|ref data| deserialize_from_slice(data));
         result
     }
-    pub fn export_serde_adjacently_tagged_raw(
+    pub fn export_struct_with_tuple_structs_raw(
         &self,
         arg: Vec<u8>,
     ) -> Result<Vec<u8>, InvocationError> {
@@ -470,23 +1277,23 @@ impl Runtime {
         let arg = export_to_guest_raw(&env, arg);
         let function = instance
             .exports
-            .get_native_function::<(FatPtr), FatPtr>("__fp_gen_export_serde_adjacently_tagged")
+            .get_native_function::<(FatPtr), FatPtr>("__fp_gen_export_struct_with_tuple_structs")
             .map_err(|_| InvocationError::FunctionNotExported)?;
         let result = function.call(arg)?;
         let result = import_from_guest_raw(&env, result);
         Ok(result)
     }
 
-    pub fn export_serde_enum(
+    pub fn export_struct_with_tuples(
         &self,
-        arg: SerdeVariantRenaming,
-    ) -> Result<SerdeVariantRenaming, InvocationError> {
+        arg: StructWithTuples,
+    ) -> Result<StructWithTuples, InvocationError> {
         let arg = serialize_to_vec(&arg);
-        let result = self.export_serde_enum_raw(arg);
+        let result = self.export_struct_with_tuples_raw(arg);
         let result = result.map(|ref data| deserialize_from_slice(data));
         result
     }
-    pub fn export_serde_enum_raw(&self, arg: Vec<u8>) -> Result<Vec<u8>, InvocationError> {
+    pub fn export_struct_with_tuples_raw(&self, arg: Vec<u8>) -> Result<Vec<u8>, InvocationError> {
         let mut env = RuntimeInstanceData::default();
         let import_object = create_import_object(self.module.store(), &env);
         let instance = Instance::new(&self.module, &import_object).unwrap();
@@ -494,20 +1301,26 @@ impl Runtime {
         let arg = export_to_guest_raw(&env, arg);
         let function = instance
             .exports
-            .get_native_function::<(FatPtr), FatPtr>("__fp_gen_export_serde_enum")
+            .get_native_function::<(FatPtr), FatPtr>("__fp_gen_export_struct_with_tuples")
             .map_err(|_| InvocationError::FunctionNotExported)?;
         let result = function.call(arg)?;
         let result = import_from_guest_raw(&env, result);
         Ok(result)
     }
 
-    pub fn export_serde_flatten(&self, arg: SerdeFlatten) -> Result<SerdeFlatten, InvocationError> {
+    pub fn export_struct_with_wide_primitives(
+        &self,
+        arg: StructWithWidePrimitives,
+    ) -> Result<StructWithWidePrimitives, InvocationError> {
         let arg = serialize_to_vec(&arg);
-        let result = self.export_serde_flatten_raw(arg);
+        let result = self.export_struct_with_wide_primitives_raw(arg);
         let result = result.map(|ref data| deserialize_from_slice(data));
         result
     }
-    pub fn export_serde_flatten_raw(&self, arg: Vec<u8>) -> Result<Vec<u8>, InvocationError> {
+    pub fn export_struct_with_wide_primitives_raw(
+        &self,
+        arg: Vec<u8>,
+    ) -> Result<Vec<u8>, InvocationError> {
         let mut env = RuntimeInstanceData::default();
         let import_object = create_import_object(self.module.store(), &env);
         let instance = Instance::new(&self.module, &import_object).unwrap();
@@ -515,23 +1328,23 @@ impl Runtime {
         let arg = export_to_guest_raw(&env, arg);
         let function = instance
             .exports
-            .get_native_function::<(FatPtr), FatPtr>("__fp_gen_export_serde_flatten")
+            .get_native_function::<(FatPtr), FatPtr>("__fp_gen_export_struct_with_wide_primitives")
             .map_err(|_| InvocationError::FunctionNotExported)?;
         let result = function.call(arg)?;
         let result = import_from_guest_raw(&env, result);
         Ok(result)
     }
 
-    pub fn export_serde_internally_tagged(
+    pub fn export_tagged_unknown_variant(
         &self,
-        arg: SerdeInternallyTagged,
-    ) -> Result<SerdeInternallyTagged, InvocationError> {
+        arg: TaggedShape,
+    ) -> Result<TaggedShape, InvocationError> {
         let arg = serialize_to_vec(&arg);
-        let result = self.export_serde_internally_tagged_raw(arg);
+        let result = self.export_tagged_unknown_variant_raw(arg);
         let result = result.map(|ref data| deserialize_from_slice(data));
         result
     }
-    pub fn export_serde_internally_tagged_raw(
+    pub fn export_tagged_unknown_variant_raw(
         &self,
         arg: Vec<u8>,
     ) -> Result<Vec<u8>, InvocationError> {
@@ -542,23 +1355,23 @@ impl Runtime {
         let arg = export_to_guest_raw(&env, arg);
         let function = instance
             .exports
-            .get_native_function::<(FatPtr), FatPtr>("__fp_gen_export_serde_internally_tagged")
+            .get_native_function::<(FatPtr), FatPtr>("__fp_gen_export_tagged_unknown_variant")
             .map_err(|_| InvocationError::FunctionNotExported)?;
         let result = function.call(arg)?;
         let result = import_from_guest_raw(&env, result);
         Ok(result)
     }
 
-    pub fn export_serde_struct(
+    pub fn export_timestamp(
         &self,
-        arg: SerdePropertyRenaming,
-    ) -> Result<SerdePropertyRenaming, InvocationError> {
+        arg: time::OffsetDateTime,
+    ) -> Result<time::OffsetDateTime, InvocationError> {
         let arg = serialize_to_vec(&arg);
-        let result = self.export_serde_struct_raw(arg);
+        let result = self.export_timestamp_raw(arg);
         let result = result.map(|ref data| deserialize_from_slice(data));
         result
     }
-    pub fn export_serde_struct_raw(&self, arg: Vec<u8>) -> Result<Vec<u8>, InvocationError> {
+    pub fn export_timestamp_raw(&self, arg: Vec<u8>) -> Result<Vec<u8>, InvocationError> {
         let mut env = RuntimeInstanceData::default();
         let import_object = create_import_object(self.module.store(), &env);
         let instance = Instance::new(&self.module, &import_object).unwrap();
@@ -566,23 +1379,20 @@ impl Runtime {
         let arg = export_to_guest_raw(&env, arg);
         let function = instance
             .exports
-            .get_native_function::<(FatPtr), FatPtr>("__fp_gen_export_serde_struct")
+            .get_native_function::<(FatPtr), FatPtr>("__fp_gen_export_timestamp")
             .map_err(|_| InvocationError::FunctionNotExported)?;
         let result = function.call(arg)?;
         let result = import_from_guest_raw(&env, result);
         Ok(result)
     }
 
-    pub fn export_serde_untagged(
-        &self,
-        arg: SerdeUntagged,
-    ) -> Result<SerdeUntagged, InvocationError> {
+    pub fn export_tuple(&self, arg: (u32, String)) -> Result<(String, u32), InvocationError> {
         let arg = serialize_to_vec(&arg);
-        let result = self.export_serde_untagged_raw(arg);
+        let result = self.export_tuple_raw(arg);
         let result = result.map(|ref data| deserialize_from_slice(data));
         result
     }
-    pub fn export_serde_untagged_raw(&self, arg: Vec<u8>) -> Result<Vec<u8>, InvocationError> {
+    pub fn export_tuple_raw(&self, arg: Vec<u8>) -> Result<Vec<u8>, InvocationError> {
         let mut env = RuntimeInstanceData::default();
         let import_object = create_import_object(self.module.store(), &env);
         let instance = Instance::new(&self.module, &import_object).unwrap();
@@ -590,20 +1400,20 @@ impl Runtime {
         let arg = export_to_guest_raw(&env, arg);
         let function = instance
             .exports
-            .get_native_function::<(FatPtr), FatPtr>("__fp_gen_export_serde_untagged")
+            .get_native_function::<(FatPtr), FatPtr>("__fp_gen_export_tuple")
             .map_err(|_| InvocationError::FunctionNotExported)?;
         let result = function.call(arg)?;
         let result = import_from_guest_raw(&env, result);
         Ok(result)
     }
 
-    pub fn export_string(&self, arg: String) -> Result<String, InvocationError> {
+    pub fn export_unknown_variant(&self, arg: Shape) -> Result<Shape, InvocationError> {
         let arg = serialize_to_vec(&arg);
-        let result = self.export_string_raw(arg);
+        let result = self.export_unknown_variant_raw(arg);
         let result = result.map(|ref data| deserialize_from_slice(data));
         result
     }
-    pub fn export_string_raw(&self, arg: Vec<u8>) -> Result<Vec<u8>, InvocationError> {
+    pub fn export_unknown_variant_raw(&self, arg: Vec<u8>) -> Result<Vec<u8>, InvocationError> {
         let mut env = RuntimeInstanceData::default();
         let import_object = create_import_object(self.module.store(), &env);
         let instance = Instance::new(&self.module, &import_object).unwrap();
@@ -611,20 +1421,20 @@ impl Runtime {
         let arg = export_to_guest_raw(&env, arg);
         let function = instance
             .exports
-            .get_native_function::<(FatPtr), FatPtr>("__fp_gen_export_string")
+            .get_native_function::<(FatPtr), FatPtr>("__fp_gen_export_unknown_variant")
             .map_err(|_| InvocationError::FunctionNotExported)?;
         let result = function.call(arg)?;
         let result = import_from_guest_raw(&env, result);
         Ok(result)
     }
 
-    pub fn export_timestamp(&self, arg: OffsetDateTime) -> Result<OffsetDateTime, InvocationError> {
+    pub fn export_uuid(&self, arg: uuid::Uuid) -> Result<uuid::Uuid, InvocationError> {
         let arg = serialize_to_vec(&arg);
-        let result = self.export_timestamp_raw(arg);
+        let result = self.export_uuid_raw(arg);
         let result = result.map(|ref data| deserialize_from_slice(data));
         result
     }
-    pub fn export_timestamp_raw(&self, arg: Vec<u8>) -> Result<Vec<u8>, InvocationError> {
+    pub fn export_uuid_raw(&self, arg: Vec<u8>) -> Result<Vec<u8>, InvocationError> {
         let mut env = RuntimeInstanceData::default();
         let import_object = create_import_object(self.module.store(), &env);
         let instance = Instance::new(&self.module, &import_object).unwrap();
@@ -632,7 +1442,7 @@ impl Runtime {
         let arg = export_to_guest_raw(&env, arg);
         let function = instance
             .exports
-            .get_native_function::<(FatPtr), FatPtr>("__fp_gen_export_timestamp")
+            .get_native_function::<(FatPtr), FatPtr>("__fp_gen_export_uuid")
             .map_err(|_| InvocationError::FunctionNotExported)?;
         let result = function.call(arg)?;
         let result = import_from_guest_raw(&env, result);
@@ -727,6 +1537,10 @@ fn create_import_object(store: &Store, env: &RuntimeInstanceData) -> ImportObjec
     imports! {
        "fp" => {
            "__fp_host_resolve_async_value" => Function :: new_native_with_env (store , env . clone () , resolve_async_value) ,
+           "__fp_gen_fetch" => Function :: new_native_with_env (store , env . clone () , _fetch) ,
+           "__fp_gen_import_borrowed" => Function :: new_native_with_env (store , env . clone () , _import_borrowed) ,
+           "__fp_gen_import_by_value" => Function :: new_native_with_env (store , env . clone () , _import_by_value) ,
+           "__fp_gen_import_bytes" => Function :: new_native_with_env (store , env . clone () , _import_bytes) ,
            "__fp_gen_import_fp_adjacently_tagged" => Function :: new_native_with_env (store , env . clone () , _import_fp_adjacently_tagged) ,
            "__fp_gen_import_fp_enum" => Function :: new_native_with_env (store , env . clone () , _import_fp_enum) ,
            "__fp_gen_import_fp_flatten" => Function :: new_native_with_env (store , env . clone () , _import_fp_flatten) ,
@@ -761,6 +1575,60 @@ fn create_import_object(store: &Store, env: &RuntimeInstanceData) -> ImportObjec
     }
 }
 
+pub fn _fetch(env: &RuntimeInstanceData, request: FatPtr) -> FatPtr {
+    let request = import_from_guest::<fp_bindgen_support::http::HttpRequest>(env, request);
+    let result = super::fetch(request);
+    export_to_guest(env, &result)
+}
+
+pub fn _import_borrowed(
+    env: &RuntimeInstanceData,
+    text: FatPtr,
+    data: FatPtr,
+    values: FatPtr,
+    position_x: f64,
+    position_y: f64,
+) -> FatPtr {
+    let position = Position {
+        x: position_x,
+        y: position_y,
+    };
+    let text = import_from_guest::<String>(env, text);
+    let data = import_binary_from_guest::<serde_bytes::ByteBuf>(env, data);
+    let values = import_from_guest::<Vec<u32>>(env, values);
+    let result = super::import_borrowed(text, data, values, position);
+    export_to_guest(env, &result)
+}
+
+pub fn _import_by_value(
+    env: &RuntimeInstanceData,
+    position_x: f64,
+    position_y: f64,
+    frame_frame_number: u64,
+    frame_delta_ms: f32,
+    frame_offset: i32,
+    frame_is_paused: bool,
+) -> FatPtr {
+    let position = Position {
+        x: position_x,
+        y: position_y,
+    };
+    let frame = FrameInfo {
+        frame_number: frame_frame_number,
+        delta_ms: frame_delta_ms,
+        offset: frame_offset,
+        is_paused: frame_is_paused,
+    };
+    let result = super::import_by_value(position, frame);
+    export_to_guest(env, &result)
+}
+
+pub fn _import_bytes(env: &RuntimeInstanceData, arg: FatPtr) -> FatPtr {
+    let arg = import_binary_from_guest::<bytes::Bytes>(env, arg);
+    let result = super::import_bytes(arg);
+    export_binary_to_guest(env, result)
+}
+
 pub fn _import_fp_adjacently_tagged(env: &RuntimeInstanceData, arg: FatPtr) -> FatPtr {
     let arg = import_from_guest::<FpAdjacentlyTagged>(env, arg);
     let result = super::import_fp_adjacently_tagged(arg);
@@ -907,7 +1775,7 @@ pub fn _import_string(env: &RuntimeInstanceData, arg: FatPtr) -> FatPtr {
 }
 
 pub fn _import_timestamp(env: &RuntimeInstanceData, arg: FatPtr) -> FatPtr {
-    let arg = import_from_guest::<OffsetDateTime>(env, arg);
+    let arg = import_from_guest::<time::OffsetDateTime>(env, arg);
     let result = super::import_timestamp(arg);
     export_to_guest(env, &result)
 }
@@ -1,12 +1,48 @@
 #![allow(unused_imports)]
 use serde::{Deserialize, Serialize};
-use std::{collections::BTreeMap, collections::HashMap, rc::Rc};
+use indexmap::IndexMap;
+use serde_repr::{Deserialize_repr, Serialize_repr};
+use std::{collections::BTreeMap, collections::BinaryHeap, collections::VecDeque, rc::Rc, sync::Arc};
 
 pub use redux_example::ReduxAction;
 pub use redux_example::StateUpdate;
 
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ActionMeta {
+    pub sequence_number: u64,
+    pub origin: String,
+}
+
+pub type ActionMetaDef = ActionMeta;
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Appointment {
+    pub date: time::Date,
+    pub weekday: time::Weekday,
+}
+
 pub type Body = serde_bytes::ByteBuf;
 
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename(serialize = "SerializedName", deserialize = "DeserializedName"), tag = "type")]
+pub struct DirectionalStructRenaming {
+    pub foo_bar: String,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all(serialize = "UPPERCASE", deserialize = "lowercase"))]
+pub enum DirectionalVariantRenaming {
+    FooBar,
+    #[serde(rename_all(serialize = "camelCase", deserialize = "SCREAMING-KEBAB-CASE"))]
+    QuxBaz {
+        /// Will be serialized as "fooBar", and deserialized from "FOO-BAR".
+        foo_bar: String,
+    },
+    #[serde(rename(serialize = "BAZ_OUT", deserialize = "baz_in"))]
+    Baz,
+}
+
 /// # This is an enum with doc comments.
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub enum DocExampleEnum {
@@ -31,6 +67,8 @@ pub struct DocExampleStruct {
     pub r#type: String,
 }
 
+pub type Email = String;
+
 /// This struct is also not referenced by any function or data structure, but
 /// it will show up because there is an explicit `use` statement for it in the
 /// `fp_import!` macro.
@@ -98,6 +136,16 @@ pub enum FpVariantRenaming {
     },
 }
 
+/// Information about the frame that is being rendered.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FrameInfo {
+    pub frame_number: u64,
+    pub delta_ms: f32,
+    pub offset: i32,
+    pub is_paused: bool,
+}
+
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct GroupImportedType1 {
     pub you_will_see_this: bool,
@@ -108,16 +156,89 @@ pub struct GroupImportedType2 {
     pub you_will_see_this: bool,
 }
 
+/// A request that was sent, together with the response that was received.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct HttpExchange {
+    pub request: fp_bindgen_support::http::HttpRequest,
+    pub response: fp_bindgen_support::http::HttpResponse,
+
+    /// The content type of the response body.
+    #[serde(deserialize_with = "fp_bindgen_support::http::deserialize_header_value", serialize_with = "fp_bindgen_support::http::serialize_header_value")]
+    pub content_type: http::HeaderValue,
+}
+
 pub type HttpResult = Result<Response, RequestError>;
 
 pub type Int64 = u64;
 
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct KebabCasePropertyRenaming {
+    pub foo_bar: String,
+    pub r#raw_struct: i32,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(transparent)]
+pub struct Label {
+    pub value: String,
+}
+
+/// Log level, serialized as a single byte.
+#[derive(Clone, Copy, Debug, Deserialize_repr, PartialEq, Serialize_repr)]
+#[repr(u8)]
+pub enum Level {
+    Debug = 10,
+    Info = 20,
+    /// Something may be wrong.
+    Warning = 30,
+    Error = 31,
+}
+
+/// Marker without any data.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Marker;
+
+/// Newtypes may also wrap primitives.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Meters(pub f64);
+
 /// A point of an arbitrary type.
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct Point<T> {
     pub value: T,
 }
 
+/// A position in 2D space.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Position {
+    pub x: f64,
+    pub y: f64,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub enum Priority {
+    #[default]
+    Low,
+    #[serde(alias = "Urgent")]
+    High,
+    #[serde(skip)]
+    Internal,
+    #[serde(skip_serializing)]
+    Legacy,
+    #[serde(skip_deserializing)]
+    Pending,
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(rename = "range", tag = "type")]
+pub struct Range {
+    pub start: u32,
+    pub end: u32,
+}
+
 /// Represents an HTTP request to be sent.
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct Request {
@@ -130,10 +251,8 @@ pub struct Request {
     pub method: http::Method,
 
     /// HTTP headers to submit with the request.
-    ///
-    /// Note: We currently do not support the `Headers` type from the `http`
-    ///       crate. See: https://github.com/fiberplane/fp-bindgen/issues/102
-    pub headers: HashMap<String, String>,
+    #[serde(deserialize_with = "fp_bindgen_support::http::deserialize_header_map", serialize_with = "fp_bindgen_support::http::serialize_header_map")]
+    pub headers: http::HeaderMap,
 
     /// The body to submit with the request.
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -171,15 +290,18 @@ pub struct Response {
     pub body: Body,
 
     /// HTTP headers that were part of the response.
-    ///
-    /// Note: We currently do not support the `Headers` type from the `http`
-    ///       crate. See: https://github.com/fiberplane/fp-bindgen/issues/102
-    pub headers: HashMap<String, String>,
+    #[serde(deserialize_with = "fp_bindgen_support::http::deserialize_header_map", serialize_with = "fp_bindgen_support::http::serialize_header_map")]
+    pub headers: http::HeaderMap,
 
     /// HTTP status code.
-    pub status_code: u16,
+    #[serde(deserialize_with = "fp_bindgen_support::http::deserialize_status_code", serialize_with = "fp_bindgen_support::http::serialize_status_code")]
+    pub status_code: http::StatusCode,
 }
 
+/// Color in RGB format.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 #[serde(tag = "type", content = "payload")]
 pub enum SerdeAdjacentlyTagged {
@@ -188,6 +310,23 @@ pub enum SerdeAdjacentlyTagged {
     Baz { a: i8, b: u64 },
 }
 
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct SerdeAttributes {
+    #[serde(alias = "name")]
+    pub title: String,
+    #[serde(rename(serialize = "maxItems", deserialize = "max_items"))]
+    pub max_items: u32,
+    #[serde(skip_serializing)]
+    pub password: String,
+    #[serde(skip_deserializing)]
+    pub revision: u32,
+    pub label: Label,
+    pub priority: Priority,
+    pub range: Range,
+    pub email: Email,
+}
+
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct SerdeFlatten {
     #[serde(flatten)]
@@ -231,6 +370,54 @@ pub enum SerdeVariantRenaming {
     },
 }
 
+/// A shape, which may be one we don't know about yet.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub enum Shape {
+    Circle { radius: f64 },
+    Point,
+    Square(f64),
+    /// A variant that is not known to this version of the protocol.
+    #[serde(untagged)]
+    Unknown(rmpv::Value),
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct StructWithArrays {
+    pub checksum: [u8; 4],
+    pub coordinates: [f64; 3],
+    pub segments: Vec<[Point<f64>; 2]>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct StructWithBinaryHeap {
+    pub priorities: BinaryHeap<u32>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct StructWithChronoTypes {
+    pub utc: chrono::DateTime<chrono::Utc>,
+    pub fixed_offset: chrono::DateTime<chrono::FixedOffset>,
+    pub date: chrono::NaiveDate,
+    pub date_time: chrono::NaiveDateTime,
+    pub delta: chrono::TimeDelta,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct StructWithCollections {
+    pub shared: Arc<String>,
+    pub label: std::borrow::Cow<'static, str>,
+    pub queue: VecDeque<u32>,
+    pub ordered: IndexMap<String, u32>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct StructWithDurations {
+    pub timeout: std::time::Duration,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub interval: Option<std::time::Duration>,
+    pub created_at: std::time::SystemTime,
+}
+
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct StructWithGenerics<T> {
     pub list: Vec<T>,
@@ -241,3 +428,66 @@ pub struct StructWithGenerics<T> {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub optional_timestamp: Option<time::OffsetDateTime>,
 }
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct StructWithJson {
+    pub value: serde_json::Value,
+    pub object: serde_json::Map<String, serde_json::Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub optional_value: Option<serde_json::Value>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct StructWithReprEnums {
+    pub level: Level,
+    pub history: Vec<Level>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct StructWithTupleStructs {
+    pub user_id: UserId,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub optional_user_id: Option<UserId>,
+    pub distance: Meters,
+    pub wrapped: Wrapper<u32>,
+    pub color: Rgb,
+    pub marker: Marker,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct StructWithTuples {
+    pub pair: (i64, i64),
+    pub nested: Vec<(String, (u8, bool))>,
+    pub single: (String,),
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct StructWithWidePrimitives {
+    pub initial: char,
+    pub signed_large: i128,
+    pub unsigned_large: u128,
+    pub offset: isize,
+    pub length: usize,
+}
+
+/// Internally tagged equivalent of `Shape`.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub enum TaggedShape {
+    Circle { radius: f64 },
+    Point,
+    /// A variant that is not known to this version of the protocol.
+    #[serde(untagged)]
+    Unknown(rmpv::Value),
+}
+
+/// Identifier of a user.
+///
+/// In TypeScript, this becomes a branded type, so it cannot be mixed up with
+/// other strings by accident.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct UserId(pub String);
+
+/// Newtypes may be generic too.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Wrapper<T>(pub T);
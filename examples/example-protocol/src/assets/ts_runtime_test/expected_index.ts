@@ -8,9 +8,16 @@
 import { encode, decode } from "https://unpkg.com/@msgpack/msgpack@2.7.2/mod.ts";
 
 import type {
+    Int128,
+    ActionMeta,
+    ActionMetaDef,
+    Appointment,
     Body,
+    DirectionalStructRenaming,
+    DirectionalVariantRenaming,
     DocExampleEnum,
     DocExampleStruct,
+    Email,
     ExplicitedlyImportedType,
     FlattenedStruct,
     FloatingPoint,
@@ -20,29 +27,59 @@ import type {
     FpPropertyRenaming,
     FpUntagged,
     FpVariantRenaming,
+    FrameInfo,
     GroupImportedType1,
     GroupImportedType2,
+    HttpExchange,
     HttpResult,
     Int64,
+    KebabCasePropertyRenaming,
+    Label,
+    Level,
+    Marker,
+    Meters,
     Point,
+    Position,
+    Priority,
+    Range,
     ReduxAction,
     Request,
     RequestError,
     Response,
     Result,
+    Rgb,
     SerdeAdjacentlyTagged,
+    SerdeAttributes,
     SerdeFlatten,
     SerdeInternallyTagged,
     SerdePropertyRenaming,
     SerdeUntagged,
     SerdeVariantRenaming,
+    Shape,
     StateUpdate,
+    StructWithArrays,
+    StructWithBinaryHeap,
+    StructWithChronoTypes,
+    StructWithCollections,
+    StructWithDurations,
     StructWithGenerics,
+    StructWithJson,
+    StructWithReprEnums,
+    StructWithTupleStructs,
+    StructWithTuples,
+    StructWithWidePrimitives,
+    TaggedShape,
+    UserId,
+    Wrapper,
 } from "./types.ts";
 
 type FatPtr = bigint;
 
 export type Imports = {
+    fetch: (request: HttpRequest) => HttpResponse;
+    importBorrowed: (text: string, data: ArrayBuffer, values: Array<number>, position: Position) => string;
+    importByValue: (position: Position, frame: FrameInfo) => Position;
+    importBytes: (arg: ArrayBuffer) => ArrayBuffer;
     importFpAdjacentlyTagged: (arg: FpAdjacentlyTagged) => FpAdjacentlyTagged;
     importFpEnum: (arg: FpVariantRenaming) => FpVariantRenaming;
     importFpFlatten: (arg: FpFlatten) => FpFlatten;
@@ -76,7 +113,20 @@ export type Imports = {
 };
 
 export type Exports = {
+    exportArray?: (arg: [number, number, number]) => [number, number, number];
     exportAsyncStruct?: (arg1: FpPropertyRenaming, arg2: bigint) => Promise<FpPropertyRenaming>;
+    exportBinaryHeap?: (arg: StructWithBinaryHeap) => StructWithBinaryHeap;
+    exportBorrowed?: (text: string, data: ArrayBuffer, values: Array<number>, position: Position) => string;
+    exportByValue?: (position: Position, frame: FrameInfo) => Position;
+    exportByteBuf?: (arg: ArrayBuffer) => ArrayBuffer;
+    exportBytes?: (arg: ArrayBuffer) => ArrayBuffer;
+    exportChronoTypes?: (arg: StructWithChronoTypes) => StructWithChronoTypes;
+    exportCollections?: (arg: StructWithCollections) => StructWithCollections;
+    exportCustomTypes?: (arg: Appointment) => Appointment;
+    exportDirectionalEnum?: (arg: DirectionalVariantRenaming) => DirectionalVariantRenaming;
+    exportDirectionalStruct?: (arg: DirectionalStructRenaming) => DirectionalStructRenaming;
+    exportDurations?: (arg: StructWithDurations) => StructWithDurations;
+    exportFetch?: (request: HttpRequest) => HttpResponse | null;
     exportFpAdjacentlyTagged?: (arg: FpAdjacentlyTagged) => FpAdjacentlyTagged;
     exportFpEnum?: (arg: FpVariantRenaming) => FpVariantRenaming;
     exportFpFlatten?: (arg: FpFlatten) => FpFlatten;
@@ -84,31 +134,65 @@ export type Exports = {
     exportFpStruct?: (arg: FpPropertyRenaming) => FpPropertyRenaming;
     exportFpUntagged?: (arg: FpUntagged) => FpUntagged;
     exportGenerics?: (arg: StructWithGenerics<number>) => StructWithGenerics<number>;
+    exportHttpExchange?: (arg: HttpExchange) => HttpExchange;
+    exportJson?: (arg: StructWithJson) => StructWithJson;
+    exportKebabCaseStruct?: (arg: KebabCasePropertyRenaming) => KebabCasePropertyRenaming;
     exportMultiplePrimitives?: (arg1: number, arg2: string) => bigint;
+    exportNewtype?: (arg: UserId) => Meters;
     exportPrimitiveBool?: (arg: boolean) => boolean;
+    exportPrimitiveChar?: (arg: string) => string;
     exportPrimitiveF32?: (arg: number) => number;
     exportPrimitiveF64?: (arg: number) => number;
+    exportPrimitiveI128?: (arg: bigint) => bigint;
     exportPrimitiveI16?: (arg: number) => number;
     exportPrimitiveI32?: (arg: number) => number;
     exportPrimitiveI64?: (arg: bigint) => bigint;
     exportPrimitiveI8?: (arg: number) => number;
+    exportPrimitiveIsize?: (arg: number) => number;
+    exportPrimitiveU128?: (arg: bigint) => bigint;
     exportPrimitiveU16?: (arg: number) => number;
     exportPrimitiveU32?: (arg: number) => number;
     exportPrimitiveU64?: (arg: bigint) => bigint;
     exportPrimitiveU8?: (arg: number) => number;
+    exportPrimitiveUsize?: (arg: number) => number;
+    exportRemoteType?: (arg: ActionMetaDef) => ActionMetaDef;
+    exportReprEnum?: (arg: Level) => Level;
     exportSerdeAdjacentlyTagged?: (arg: SerdeAdjacentlyTagged) => SerdeAdjacentlyTagged;
+    exportSerdeAttributes?: (arg: SerdeAttributes) => SerdeAttributes;
     exportSerdeEnum?: (arg: SerdeVariantRenaming) => SerdeVariantRenaming;
     exportSerdeFlatten?: (arg: SerdeFlatten) => SerdeFlatten;
     exportSerdeInternallyTagged?: (arg: SerdeInternallyTagged) => SerdeInternallyTagged;
     exportSerdeStruct?: (arg: SerdePropertyRenaming) => SerdePropertyRenaming;
     exportSerdeUntagged?: (arg: SerdeUntagged) => SerdeUntagged;
     exportString?: (arg: string) => string;
+    exportStructWithArrays?: (arg: StructWithArrays) => StructWithArrays;
+    exportStructWithReprEnums?: (arg: StructWithReprEnums) => StructWithReprEnums;
+    exportStructWithTupleStructs?: (arg: StructWithTupleStructs) => StructWithTupleStructs;
+    exportStructWithTuples?: (arg: StructWithTuples) => StructWithTuples;
+    exportStructWithWidePrimitives?: (arg: StructWithWidePrimitives) => StructWithWidePrimitives;
+    exportTaggedUnknownVariant?: (arg: TaggedShape) => TaggedShape;
     exportTimestamp?: (arg: string) => string;
+    exportTuple?: (arg: [number, string]) => [string, number];
+    exportUnknownVariant?: (arg: Shape) => Shape;
+    exportUuid?: (arg: Uuid) => Uuid;
     exportVoidFunction?: () => void;
     fetchData?: (rType: string) => Promise<Result<string, string>>;
     init?: () => void;
     reducerBridge?: (action: ReduxAction) => StateUpdate;
+    exportArrayRaw?: (arg: Uint8Array) => Uint8Array;
     exportAsyncStructRaw?: (arg1: Uint8Array, arg2: bigint) => Promise<Uint8Array>;
+    exportBinaryHeapRaw?: (arg: Uint8Array) => Uint8Array;
+    exportBorrowedRaw?: (text: Uint8Array, data: Uint8Array, values: Uint8Array, position: Position) => Uint8Array;
+    exportByValueRaw?: (position: Position, frame: FrameInfo) => Uint8Array;
+    exportByteBufRaw?: (arg: Uint8Array) => Uint8Array;
+    exportBytesRaw?: (arg: Uint8Array) => Uint8Array;
+    exportChronoTypesRaw?: (arg: Uint8Array) => Uint8Array;
+    exportCollectionsRaw?: (arg: Uint8Array) => Uint8Array;
+    exportCustomTypesRaw?: (arg: Uint8Array) => Uint8Array;
+    exportDirectionalEnumRaw?: (arg: Uint8Array) => Uint8Array;
+    exportDirectionalStructRaw?: (arg: Uint8Array) => Uint8Array;
+    exportDurationsRaw?: (arg: Uint8Array) => Uint8Array;
+    exportFetchRaw?: (request: Uint8Array) => Uint8Array;
     exportFpAdjacentlyTaggedRaw?: (arg: Uint8Array) => Uint8Array;
     exportFpEnumRaw?: (arg: Uint8Array) => Uint8Array;
     exportFpFlattenRaw?: (arg: Uint8Array) => Uint8Array;
@@ -116,20 +200,41 @@ export type Exports = {
     exportFpStructRaw?: (arg: Uint8Array) => Uint8Array;
     exportFpUntaggedRaw?: (arg: Uint8Array) => Uint8Array;
     exportGenericsRaw?: (arg: Uint8Array) => Uint8Array;
+    exportHttpExchangeRaw?: (arg: Uint8Array) => Uint8Array;
+    exportJsonRaw?: (arg: Uint8Array) => Uint8Array;
+    exportKebabCaseStructRaw?: (arg: Uint8Array) => Uint8Array;
     exportMultiplePrimitivesRaw?: (arg1: number, arg2: Uint8Array) => bigint;
+    exportNewtypeRaw?: (arg: Uint8Array) => Uint8Array;
     exportPrimitiveBoolRaw?: (arg: boolean) => boolean;
+    exportPrimitiveCharRaw?: (arg: Uint8Array) => Uint8Array;
+    exportPrimitiveI128Raw?: (arg: Uint8Array) => Uint8Array;
     exportPrimitiveI16Raw?: (arg: number) => number;
     exportPrimitiveI32Raw?: (arg: number) => number;
     exportPrimitiveI64Raw?: (arg: bigint) => bigint;
     exportPrimitiveI8Raw?: (arg: number) => number;
+    exportPrimitiveIsizeRaw?: (arg: Uint8Array) => Uint8Array;
+    exportPrimitiveU128Raw?: (arg: Uint8Array) => Uint8Array;
+    exportPrimitiveUsizeRaw?: (arg: Uint8Array) => Uint8Array;
+    exportRemoteTypeRaw?: (arg: Uint8Array) => Uint8Array;
+    exportReprEnumRaw?: (arg: Uint8Array) => Uint8Array;
     exportSerdeAdjacentlyTaggedRaw?: (arg: Uint8Array) => Uint8Array;
+    exportSerdeAttributesRaw?: (arg: Uint8Array) => Uint8Array;
     exportSerdeEnumRaw?: (arg: Uint8Array) => Uint8Array;
     exportSerdeFlattenRaw?: (arg: Uint8Array) => Uint8Array;
     exportSerdeInternallyTaggedRaw?: (arg: Uint8Array) => Uint8Array;
     exportSerdeStructRaw?: (arg: Uint8Array) => Uint8Array;
     exportSerdeUntaggedRaw?: (arg: Uint8Array) => Uint8Array;
     exportStringRaw?: (arg: Uint8Array) => Uint8Array;
+    exportStructWithArraysRaw?: (arg: Uint8Array) => Uint8Array;
+    exportStructWithReprEnumsRaw?: (arg: Uint8Array) => Uint8Array;
+    exportStructWithTupleStructsRaw?: (arg: Uint8Array) => Uint8Array;
+    exportStructWithTuplesRaw?: (arg: Uint8Array) => Uint8Array;
+    exportStructWithWidePrimitivesRaw?: (arg: Uint8Array) => Uint8Array;
+    exportTaggedUnknownVariantRaw?: (arg: Uint8Array) => Uint8Array;
     exportTimestampRaw?: (arg: Uint8Array) => Uint8Array;
+    exportTupleRaw?: (arg: Uint8Array) => Uint8Array;
+    exportUnknownVariantRaw?: (arg: Uint8Array) => Uint8Array;
+    exportUuidRaw?: (arg: Uint8Array) => Uint8Array;
     fetchDataRaw?: (rType: Uint8Array) => Promise<Uint8Array>;
     reducerBridgeRaw?: (action: Uint8Array) => Uint8Array;
 };
@@ -225,6 +330,14 @@ export async function createRuntime(
         return exportToMemory(encode(object));
     }
 
+    function exportBinaryToMemory(binary: ArrayBuffer | ArrayBufferView): FatPtr {
+        return exportToMemory(
+            ArrayBuffer.isView(binary)
+                ? new Uint8Array(binary.buffer, binary.byteOffset, binary.byteLength)
+                : new Uint8Array(binary)
+        );
+    }
+
     function exportToMemory(serialized: Uint8Array): FatPtr {
         const fatPtr = malloc(serialized.length);
         const [ptr, len] = fromFatPtr(fatPtr);
@@ -244,6 +357,26 @@ export async function createRuntime(
 
     const { instance } = await WebAssembly.instantiate(plugin, {
         fp: {
+            __fp_gen_fetch: (request_ptr: FatPtr): FatPtr => {
+                const request = parseObject<HttpRequest>(request_ptr);
+                return serializeObject(importFunctions.fetch(request));
+            },
+            __fp_gen_import_borrowed: (text_ptr: FatPtr, data_ptr: FatPtr, values_ptr: FatPtr, positionX: number, positionY: number): FatPtr => {
+                const text = parseObject<string>(text_ptr);
+                const data = importFromMemory(data_ptr).buffer;
+                const values = parseObject<Array<number>>(values_ptr);
+                const position = { x: positionX, y: positionY };
+                return serializeObject(importFunctions.importBorrowed(text, data, values, position));
+            },
+            __fp_gen_import_by_value: (positionX: number, positionY: number, frameFrameNumber: bigint, frameDeltaMs: number, frameOffset: number, frameIsPaused: boolean): FatPtr => {
+                const position = { x: positionX, y: positionY };
+                const frame = { frameNumber: Number(frameFrameNumber), deltaMs: frameDeltaMs, offset: interpretSign(frameOffset, 2147483648), isPaused: !!frameIsPaused };
+                return serializeObject(importFunctions.importByValue(position, frame));
+            },
+            __fp_gen_import_bytes: (arg_ptr: FatPtr): FatPtr => {
+                const arg = importFromMemory(arg_ptr).buffer;
+                return exportBinaryToMemory(importFunctions.importBytes(arg));
+            },
             __fp_gen_import_fp_adjacently_tagged: (arg_ptr: FatPtr): FatPtr => {
                 const arg = parseObject<FpAdjacentlyTagged>(arg_ptr);
                 return serializeObject(importFunctions.importFpAdjacentlyTagged(arg));
@@ -381,6 +514,15 @@ export async function createRuntime(
     const resolveFuture = getExport<(asyncValuePtr: FatPtr, resultPtr: FatPtr) => void>("__fp_guest_resolve_async_value");
 
     return {
+        exportArray: (() => {
+            const export_fn = instance.exports.__fp_gen_export_array as any;
+            if (!export_fn) return;
+
+            return (arg: [number, number, number]) => {
+                const arg_ptr = serializeObject(arg);
+                return parseObject<[number, number, number]>(export_fn(arg_ptr));
+            };
+        })(),
         exportAsyncStruct: (() => {
             const export_fn = instance.exports.__fp_gen_export_async_struct as any;
             if (!export_fn) return;
@@ -390,6 +532,113 @@ export async function createRuntime(
                 return promiseFromPtr(export_fn(arg1_ptr, arg2)).then((ptr) => parseObject<FpPropertyRenaming>(ptr));
             };
         })(),
+        exportBinaryHeap: (() => {
+            const export_fn = instance.exports.__fp_gen_export_binary_heap as any;
+            if (!export_fn) return;
+
+            return (arg: StructWithBinaryHeap) => {
+                const arg_ptr = serializeObject(arg);
+                return parseObject<StructWithBinaryHeap>(export_fn(arg_ptr));
+            };
+        })(),
+        exportBorrowed: (() => {
+            const export_fn = instance.exports.__fp_gen_export_borrowed as any;
+            if (!export_fn) return;
+
+            return (text: string, data: ArrayBuffer, values: Array<number>, position: Position) => {
+                const text_ptr = serializeObject(text);
+                const data_ptr = exportBinaryToMemory(data);
+                const values_ptr = serializeObject(values);
+                return parseObject<string>(export_fn(text_ptr, data_ptr, values_ptr, position.x, position.y));
+            };
+        })(),
+        exportByValue: (() => {
+            const export_fn = instance.exports.__fp_gen_export_by_value as any;
+            if (!export_fn) return;
+
+            return (position: Position, frame: FrameInfo) => parseObject<Position>(export_fn(position.x, position.y, BigInt(frame.frameNumber), frame.deltaMs, frame.offset, frame.isPaused));
+        })(),
+        exportByteBuf: (() => {
+            const export_fn = instance.exports.__fp_gen_export_byte_buf as any;
+            if (!export_fn) return;
+
+            return (arg: ArrayBuffer) => {
+                const arg_ptr = exportBinaryToMemory(arg);
+                return importFromMemory(export_fn(arg_ptr)).buffer;
+            };
+        })(),
+        exportBytes: (() => {
+            const export_fn = instance.exports.__fp_gen_export_bytes as any;
+            if (!export_fn) return;
+
+            return (arg: ArrayBuffer) => {
+                const arg_ptr = exportBinaryToMemory(arg);
+                return importFromMemory(export_fn(arg_ptr)).buffer;
+            };
+        })(),
+        exportChronoTypes: (() => {
+            const export_fn = instance.exports.__fp_gen_export_chrono_types as any;
+            if (!export_fn) return;
+
+            return (arg: StructWithChronoTypes) => {
+                const arg_ptr = serializeObject(arg);
+                return parseObject<StructWithChronoTypes>(export_fn(arg_ptr));
+            };
+        })(),
+        exportCollections: (() => {
+            const export_fn = instance.exports.__fp_gen_export_collections as any;
+            if (!export_fn) return;
+
+            return (arg: StructWithCollections) => {
+                const arg_ptr = serializeObject(arg);
+                return parseObject<StructWithCollections>(export_fn(arg_ptr));
+            };
+        })(),
+        exportCustomTypes: (() => {
+            const export_fn = instance.exports.__fp_gen_export_custom_types as any;
+            if (!export_fn) return;
+
+            return (arg: Appointment) => {
+                const arg_ptr = serializeObject(arg);
+                return parseObject<Appointment>(export_fn(arg_ptr));
+            };
+        })(),
+        exportDirectionalEnum: (() => {
+            const export_fn = instance.exports.__fp_gen_export_directional_enum as any;
+            if (!export_fn) return;
+
+            return (arg: DirectionalVariantRenaming) => {
+                const arg_ptr = serializeObject(arg);
+                return parseObject<DirectionalVariantRenaming>(export_fn(arg_ptr));
+            };
+        })(),
+        exportDirectionalStruct: (() => {
+            const export_fn = instance.exports.__fp_gen_export_directional_struct as any;
+            if (!export_fn) return;
+
+            return (arg: DirectionalStructRenaming) => {
+                const arg_ptr = serializeObject(arg);
+                return parseObject<DirectionalStructRenaming>(export_fn(arg_ptr));
+            };
+        })(),
+        exportDurations: (() => {
+            const export_fn = instance.exports.__fp_gen_export_durations as any;
+            if (!export_fn) return;
+
+            return (arg: StructWithDurations) => {
+                const arg_ptr = serializeObject(arg);
+                return parseObject<StructWithDurations>(export_fn(arg_ptr));
+            };
+        })(),
+        exportFetch: (() => {
+            const export_fn = instance.exports.__fp_gen_export_fetch as any;
+            if (!export_fn) return;
+
+            return (request: HttpRequest) => {
+                const request_ptr = serializeObject(request);
+                return parseObject<HttpResponse | null>(export_fn(request_ptr));
+            };
+        })(),
         exportFpAdjacentlyTagged: (() => {
             const export_fn = instance.exports.__fp_gen_export_fp_adjacently_tagged as any;
             if (!export_fn) return;
@@ -453,6 +702,33 @@ export async function createRuntime(
                 return parseObject<StructWithGenerics<number>>(export_fn(arg_ptr));
             };
         })(),
+        exportHttpExchange: (() => {
+            const export_fn = instance.exports.__fp_gen_export_http_exchange as any;
+            if (!export_fn) return;
+
+            return (arg: HttpExchange) => {
+                const arg_ptr = serializeObject(arg);
+                return parseObject<HttpExchange>(export_fn(arg_ptr));
+            };
+        })(),
+        exportJson: (() => {
+            const export_fn = instance.exports.__fp_gen_export_json as any;
+            if (!export_fn) return;
+
+            return (arg: StructWithJson) => {
+                const arg_ptr = serializeObject(arg);
+                return parseObject<StructWithJson>(export_fn(arg_ptr));
+            };
+        })(),
+        exportKebabCaseStruct: (() => {
+            const export_fn = instance.exports.__fp_gen_export_kebab_case_struct as any;
+            if (!export_fn) return;
+
+            return (arg: KebabCasePropertyRenaming) => {
+                const arg_ptr = serializeObject(arg);
+                return parseObject<KebabCasePropertyRenaming>(export_fn(arg_ptr));
+            };
+        })(),
         exportMultiplePrimitives: (() => {
             const export_fn = instance.exports.__fp_gen_export_multiple_primitives as any;
             if (!export_fn) return;
@@ -462,14 +738,41 @@ export async function createRuntime(
                 return interpretBigSign(export_fn(arg1, arg2_ptr), 9223372036854775808n);
             };
         })(),
+        exportNewtype: (() => {
+            const export_fn = instance.exports.__fp_gen_export_newtype as any;
+            if (!export_fn) return;
+
+            return (arg: UserId) => {
+                const arg_ptr = serializeObject(arg);
+                return parseObject<Meters>(export_fn(arg_ptr));
+            };
+        })(),
         exportPrimitiveBool: (() => {
             const export_fn = instance.exports.__fp_gen_export_primitive_bool as any;
             if (!export_fn) return;
 
             return (arg: boolean) => !!export_fn(arg);
         })(),
+        exportPrimitiveChar: (() => {
+            const export_fn = instance.exports.__fp_gen_export_primitive_char as any;
+            if (!export_fn) return;
+
+            return (arg: string) => {
+                const arg_ptr = serializeObject(arg);
+                return parseObject<string>(export_fn(arg_ptr));
+            };
+        })(),
         exportPrimitiveF32: instance.exports.__fp_gen_export_primitive_f32 as any,
         exportPrimitiveF64: instance.exports.__fp_gen_export_primitive_f64 as any,
+        exportPrimitiveI128: (() => {
+            const export_fn = instance.exports.__fp_gen_export_primitive_i128 as any;
+            if (!export_fn) return;
+
+            return (arg: bigint) => {
+                const arg_ptr = serializeObject(int128ToBytes(arg));
+                return int128FromBytes(parseObject<Uint8Array>(export_fn(arg_ptr)), true);
+            };
+        })(),
         exportPrimitiveI16: (() => {
             const export_fn = instance.exports.__fp_gen_export_primitive_i16 as any;
             if (!export_fn) return;
@@ -494,10 +797,55 @@ export async function createRuntime(
 
             return (arg: number) => interpretSign(export_fn(arg), 128);
         })(),
+        exportPrimitiveIsize: (() => {
+            const export_fn = instance.exports.__fp_gen_export_primitive_isize as any;
+            if (!export_fn) return;
+
+            return (arg: number) => {
+                const arg_ptr = serializeObject(arg);
+                return parseObject<number>(export_fn(arg_ptr));
+            };
+        })(),
+        exportPrimitiveU128: (() => {
+            const export_fn = instance.exports.__fp_gen_export_primitive_u128 as any;
+            if (!export_fn) return;
+
+            return (arg: bigint) => {
+                const arg_ptr = serializeObject(int128ToBytes(arg));
+                return int128FromBytes(parseObject<Uint8Array>(export_fn(arg_ptr)), false);
+            };
+        })(),
         exportPrimitiveU16: instance.exports.__fp_gen_export_primitive_u16 as any,
         exportPrimitiveU32: instance.exports.__fp_gen_export_primitive_u32 as any,
         exportPrimitiveU64: instance.exports.__fp_gen_export_primitive_u64 as any,
         exportPrimitiveU8: instance.exports.__fp_gen_export_primitive_u8 as any,
+        exportPrimitiveUsize: (() => {
+            const export_fn = instance.exports.__fp_gen_export_primitive_usize as any;
+            if (!export_fn) return;
+
+            return (arg: number) => {
+                const arg_ptr = serializeObject(arg);
+                return parseObject<number>(export_fn(arg_ptr));
+            };
+        })(),
+        exportRemoteType: (() => {
+            const export_fn = instance.exports.__fp_gen_export_remote_type as any;
+            if (!export_fn) return;
+
+            return (arg: ActionMetaDef) => {
+                const arg_ptr = serializeObject(arg);
+                return parseObject<ActionMetaDef>(export_fn(arg_ptr));
+            };
+        })(),
+        exportReprEnum: (() => {
+            const export_fn = instance.exports.__fp_gen_export_repr_enum as any;
+            if (!export_fn) return;
+
+            return (arg: Level) => {
+                const arg_ptr = serializeObject(arg);
+                return parseObject<Level>(export_fn(arg_ptr));
+            };
+        })(),
         exportSerdeAdjacentlyTagged: (() => {
             const export_fn = instance.exports.__fp_gen_export_serde_adjacently_tagged as any;
             if (!export_fn) return;
@@ -507,6 +855,15 @@ export async function createRuntime(
                 return parseObject<SerdeAdjacentlyTagged>(export_fn(arg_ptr));
             };
         })(),
+        exportSerdeAttributes: (() => {
+            const export_fn = instance.exports.__fp_gen_export_serde_attributes as any;
+            if (!export_fn) return;
+
+            return (arg: SerdeAttributes) => {
+                const arg_ptr = serializeObject(arg);
+                return parseObject<SerdeAttributes>(export_fn(arg_ptr));
+            };
+        })(),
         exportSerdeEnum: (() => {
             const export_fn = instance.exports.__fp_gen_export_serde_enum as any;
             if (!export_fn) return;
@@ -561,6 +918,60 @@ export async function createRuntime(
                 return parseObject<string>(export_fn(arg_ptr));
             };
         })(),
+        exportStructWithArrays: (() => {
+            const export_fn = instance.exports.__fp_gen_export_struct_with_arrays as any;
+            if (!export_fn) return;
+
+            return (arg: StructWithArrays) => {
+                const arg_ptr = serializeObject(arg);
+                return parseObject<StructWithArrays>(export_fn(arg_ptr));
+            };
+        })(),
+        exportStructWithReprEnums: (() => {
+            const export_fn = instance.exports.__fp_gen_export_struct_with_repr_enums as any;
+            if (!export_fn) return;
+
+            return (arg: StructWithReprEnums) => {
+                const arg_ptr = serializeObject(arg);
+                return parseObject<StructWithReprEnums>(export_fn(arg_ptr));
+            };
+        })(),
+        exportStructWithTupleStructs: (() => {
+            const export_fn = instance.exports.__fp_gen_export_struct_with_tuple_structs as any;
+            if (!export_fn) return;
+
+            return (arg: StructWithTupleStructs) => {
+                const arg_ptr = serializeObject(arg);
+                return parseObject<StructWithTupleStructs>(export_fn(arg_ptr));
+            };
+        })(),
+        exportStructWithTuples: (() => {
+            const export_fn = instance.exports.__fp_gen_export_struct_with_tuples as any;
+            if (!export_fn) return;
+
+            return (arg: StructWithTuples) => {
+                const arg_ptr = serializeObject(arg);
+                return parseObject<StructWithTuples>(export_fn(arg_ptr));
+            };
+        })(),
+        exportStructWithWidePrimitives: (() => {
+            const export_fn = instance.exports.__fp_gen_export_struct_with_wide_primitives as any;
+            if (!export_fn) return;
+
+            return (arg: StructWithWidePrimitives) => {
+                const arg_ptr = serializeObject(encodeStructWithWidePrimitives(arg));
+                return decodeStructWithWidePrimitives(parseObject<any>(export_fn(arg_ptr)));
+            };
+        })(),
+        exportTaggedUnknownVariant: (() => {
+            const export_fn = instance.exports.__fp_gen_export_tagged_unknown_variant as any;
+            if (!export_fn) return;
+
+            return (arg: TaggedShape) => {
+                const arg_ptr = serializeObject(arg);
+                return parseObject<TaggedShape>(export_fn(arg_ptr));
+            };
+        })(),
         exportTimestamp: (() => {
             const export_fn = instance.exports.__fp_gen_export_timestamp as any;
             if (!export_fn) return;
@@ -570,6 +981,33 @@ export async function createRuntime(
                 return parseObject<string>(export_fn(arg_ptr));
             };
         })(),
+        exportTuple: (() => {
+            const export_fn = instance.exports.__fp_gen_export_tuple as any;
+            if (!export_fn) return;
+
+            return (arg: [number, string]) => {
+                const arg_ptr = serializeObject(arg);
+                return parseObject<[string, number]>(export_fn(arg_ptr));
+            };
+        })(),
+        exportUnknownVariant: (() => {
+            const export_fn = instance.exports.__fp_gen_export_unknown_variant as any;
+            if (!export_fn) return;
+
+            return (arg: Shape) => {
+                const arg_ptr = serializeObject(arg);
+                return parseObject<Shape>(export_fn(arg_ptr));
+            };
+        })(),
+        exportUuid: (() => {
+            const export_fn = instance.exports.__fp_gen_export_uuid as any;
+            if (!export_fn) return;
+
+            return (arg: Uuid) => {
+                const arg_ptr = serializeObject(arg);
+                return parseObject<Uuid>(export_fn(arg_ptr));
+            };
+        })(),
         exportVoidFunction: instance.exports.__fp_gen_export_void_function as any,
         fetchData: (() => {
             const export_fn = instance.exports.__fp_gen_fetch_data as any;
@@ -590,6 +1028,15 @@ export async function createRuntime(
                 return parseObject<StateUpdate>(export_fn(action_ptr));
             };
         })(),
+        exportArrayRaw: (() => {
+            const export_fn = instance.exports.__fp_gen_export_array as any;
+            if (!export_fn) return;
+
+            return (arg: Uint8Array) => {
+                const arg_ptr = exportToMemory(arg);
+                return importFromMemory(export_fn(arg_ptr));
+            };
+        })(),
         exportAsyncStructRaw: (() => {
             const export_fn = instance.exports.__fp_gen_export_async_struct as any;
             if (!export_fn) return;
@@ -599,6 +1046,113 @@ export async function createRuntime(
                 return promiseFromPtr(export_fn(arg1_ptr, arg2)).then(importFromMemory);
             };
         })(),
+        exportBinaryHeapRaw: (() => {
+            const export_fn = instance.exports.__fp_gen_export_binary_heap as any;
+            if (!export_fn) return;
+
+            return (arg: Uint8Array) => {
+                const arg_ptr = exportToMemory(arg);
+                return importFromMemory(export_fn(arg_ptr));
+            };
+        })(),
+        exportBorrowedRaw: (() => {
+            const export_fn = instance.exports.__fp_gen_export_borrowed as any;
+            if (!export_fn) return;
+
+            return (text: Uint8Array, data: Uint8Array, values: Uint8Array, position: Position) => {
+                const text_ptr = exportToMemory(text);
+                const data_ptr = exportToMemory(data);
+                const values_ptr = exportToMemory(values);
+                return importFromMemory(export_fn(text_ptr, data_ptr, values_ptr, position.x, position.y));
+            };
+        })(),
+        exportByValueRaw: (() => {
+            const export_fn = instance.exports.__fp_gen_export_by_value as any;
+            if (!export_fn) return;
+
+            return (position: Position, frame: FrameInfo) => importFromMemory(export_fn(position.x, position.y, BigInt(frame.frameNumber), frame.deltaMs, frame.offset, frame.isPaused));
+        })(),
+        exportByteBufRaw: (() => {
+            const export_fn = instance.exports.__fp_gen_export_byte_buf as any;
+            if (!export_fn) return;
+
+            return (arg: Uint8Array) => {
+                const arg_ptr = exportToMemory(arg);
+                return importFromMemory(export_fn(arg_ptr));
+            };
+        })(),
+        exportBytesRaw: (() => {
+            const export_fn = instance.exports.__fp_gen_export_bytes as any;
+            if (!export_fn) return;
+
+            return (arg: Uint8Array) => {
+                const arg_ptr = exportToMemory(arg);
+                return importFromMemory(export_fn(arg_ptr));
+            };
+        })(),
+        exportChronoTypesRaw: (() => {
+            const export_fn = instance.exports.__fp_gen_export_chrono_types as any;
+            if (!export_fn) return;
+
+            return (arg: Uint8Array) => {
+                const arg_ptr = exportToMemory(arg);
+                return importFromMemory(export_fn(arg_ptr));
+            };
+        })(),
+        exportCollectionsRaw: (() => {
+            const export_fn = instance.exports.__fp_gen_export_collections as any;
+            if (!export_fn) return;
+
+            return (arg: Uint8Array) => {
+                const arg_ptr = exportToMemory(arg);
+                return importFromMemory(export_fn(arg_ptr));
+            };
+        })(),
+        exportCustomTypesRaw: (() => {
+            const export_fn = instance.exports.__fp_gen_export_custom_types as any;
+            if (!export_fn) return;
+
+            return (arg: Uint8Array) => {
+                const arg_ptr = exportToMemory(arg);
+                return importFromMemory(export_fn(arg_ptr));
+            };
+        })(),
+        exportDirectionalEnumRaw: (() => {
+            const export_fn = instance.exports.__fp_gen_export_directional_enum as any;
+            if (!export_fn) return;
+
+            return (arg: Uint8Array) => {
+                const arg_ptr = exportToMemory(arg);
+                return importFromMemory(export_fn(arg_ptr));
+            };
+        })(),
+        exportDirectionalStructRaw: (() => {
+            const export_fn = instance.exports.__fp_gen_export_directional_struct as any;
+            if (!export_fn) return;
+
+            return (arg: Uint8Array) => {
+                const arg_ptr = exportToMemory(arg);
+                return importFromMemory(export_fn(arg_ptr));
+            };
+        })(),
+        exportDurationsRaw: (() => {
+            const export_fn = instance.exports.__fp_gen_export_durations as any;
+            if (!export_fn) return;
+
+            return (arg: Uint8Array) => {
+                const arg_ptr = exportToMemory(arg);
+                return importFromMemory(export_fn(arg_ptr));
+            };
+        })(),
+        exportFetchRaw: (() => {
+            const export_fn = instance.exports.__fp_gen_export_fetch as any;
+            if (!export_fn) return;
+
+            return (request: Uint8Array) => {
+                const request_ptr = exportToMemory(request);
+                return importFromMemory(export_fn(request_ptr));
+            };
+        })(),
         exportFpAdjacentlyTaggedRaw: (() => {
             const export_fn = instance.exports.__fp_gen_export_fp_adjacently_tagged as any;
             if (!export_fn) return;
@@ -662,6 +1216,33 @@ export async function createRuntime(
                 return importFromMemory(export_fn(arg_ptr));
             };
         })(),
+        exportHttpExchangeRaw: (() => {
+            const export_fn = instance.exports.__fp_gen_export_http_exchange as any;
+            if (!export_fn) return;
+
+            return (arg: Uint8Array) => {
+                const arg_ptr = exportToMemory(arg);
+                return importFromMemory(export_fn(arg_ptr));
+            };
+        })(),
+        exportJsonRaw: (() => {
+            const export_fn = instance.exports.__fp_gen_export_json as any;
+            if (!export_fn) return;
+
+            return (arg: Uint8Array) => {
+                const arg_ptr = exportToMemory(arg);
+                return importFromMemory(export_fn(arg_ptr));
+            };
+        })(),
+        exportKebabCaseStructRaw: (() => {
+            const export_fn = instance.exports.__fp_gen_export_kebab_case_struct as any;
+            if (!export_fn) return;
+
+            return (arg: Uint8Array) => {
+                const arg_ptr = exportToMemory(arg);
+                return importFromMemory(export_fn(arg_ptr));
+            };
+        })(),
         exportMultiplePrimitivesRaw: (() => {
             const export_fn = instance.exports.__fp_gen_export_multiple_primitives as any;
             if (!export_fn) return;
@@ -671,12 +1252,39 @@ export async function createRuntime(
                 return interpretBigSign(export_fn(arg1, arg2_ptr), 9223372036854775808n);
             };
         })(),
+        exportNewtypeRaw: (() => {
+            const export_fn = instance.exports.__fp_gen_export_newtype as any;
+            if (!export_fn) return;
+
+            return (arg: Uint8Array) => {
+                const arg_ptr = exportToMemory(arg);
+                return importFromMemory(export_fn(arg_ptr));
+            };
+        })(),
         exportPrimitiveBoolRaw: (() => {
             const export_fn = instance.exports.__fp_gen_export_primitive_bool as any;
             if (!export_fn) return;
 
             return (arg: boolean) => !!export_fn(arg);
         })(),
+        exportPrimitiveCharRaw: (() => {
+            const export_fn = instance.exports.__fp_gen_export_primitive_char as any;
+            if (!export_fn) return;
+
+            return (arg: Uint8Array) => {
+                const arg_ptr = exportToMemory(arg);
+                return importFromMemory(export_fn(arg_ptr));
+            };
+        })(),
+        exportPrimitiveI128Raw: (() => {
+            const export_fn = instance.exports.__fp_gen_export_primitive_i128 as any;
+            if (!export_fn) return;
+
+            return (arg: Uint8Array) => {
+                const arg_ptr = exportToMemory(arg);
+                return importFromMemory(export_fn(arg_ptr));
+            };
+        })(),
         exportPrimitiveI16Raw: (() => {
             const export_fn = instance.exports.__fp_gen_export_primitive_i16 as any;
             if (!export_fn) return;
@@ -701,6 +1309,51 @@ export async function createRuntime(
 
             return (arg: number) => interpretSign(export_fn(arg), 128);
         })(),
+        exportPrimitiveIsizeRaw: (() => {
+            const export_fn = instance.exports.__fp_gen_export_primitive_isize as any;
+            if (!export_fn) return;
+
+            return (arg: Uint8Array) => {
+                const arg_ptr = exportToMemory(arg);
+                return importFromMemory(export_fn(arg_ptr));
+            };
+        })(),
+        exportPrimitiveU128Raw: (() => {
+            const export_fn = instance.exports.__fp_gen_export_primitive_u128 as any;
+            if (!export_fn) return;
+
+            return (arg: Uint8Array) => {
+                const arg_ptr = exportToMemory(arg);
+                return importFromMemory(export_fn(arg_ptr));
+            };
+        })(),
+        exportPrimitiveUsizeRaw: (() => {
+            const export_fn = instance.exports.__fp_gen_export_primitive_usize as any;
+            if (!export_fn) return;
+
+            return (arg: Uint8Array) => {
+                const arg_ptr = exportToMemory(arg);
+                return importFromMemory(export_fn(arg_ptr));
+            };
+        })(),
+        exportRemoteTypeRaw: (() => {
+            const export_fn = instance.exports.__fp_gen_export_remote_type as any;
+            if (!export_fn) return;
+
+            return (arg: Uint8Array) => {
+                const arg_ptr = exportToMemory(arg);
+                return importFromMemory(export_fn(arg_ptr));
+            };
+        })(),
+        exportReprEnumRaw: (() => {
+            const export_fn = instance.exports.__fp_gen_export_repr_enum as any;
+            if (!export_fn) return;
+
+            return (arg: Uint8Array) => {
+                const arg_ptr = exportToMemory(arg);
+                return importFromMemory(export_fn(arg_ptr));
+            };
+        })(),
         exportSerdeAdjacentlyTaggedRaw: (() => {
             const export_fn = instance.exports.__fp_gen_export_serde_adjacently_tagged as any;
             if (!export_fn) return;
@@ -710,6 +1363,15 @@ export async function createRuntime(
                 return importFromMemory(export_fn(arg_ptr));
             };
         })(),
+        exportSerdeAttributesRaw: (() => {
+            const export_fn = instance.exports.__fp_gen_export_serde_attributes as any;
+            if (!export_fn) return;
+
+            return (arg: Uint8Array) => {
+                const arg_ptr = exportToMemory(arg);
+                return importFromMemory(export_fn(arg_ptr));
+            };
+        })(),
         exportSerdeEnumRaw: (() => {
             const export_fn = instance.exports.__fp_gen_export_serde_enum as any;
             if (!export_fn) return;
@@ -764,6 +1426,60 @@ export async function createRuntime(
                 return importFromMemory(export_fn(arg_ptr));
             };
         })(),
+        exportStructWithArraysRaw: (() => {
+            const export_fn = instance.exports.__fp_gen_export_struct_with_arrays as any;
+            if (!export_fn) return;
+
+            return (arg: Uint8Array) => {
+                const arg_ptr = exportToMemory(arg);
+                return importFromMemory(export_fn(arg_ptr));
+            };
+        })(),
+        exportStructWithReprEnumsRaw: (() => {
+            const export_fn = instance.exports.__fp_gen_export_struct_with_repr_enums as any;
+            if (!export_fn) return;
+
+            return (arg: Uint8Array) => {
+                const arg_ptr = exportToMemory(arg);
+                return importFromMemory(export_fn(arg_ptr));
+            };
+        })(),
+        exportStructWithTupleStructsRaw: (() => {
+            const export_fn = instance.exports.__fp_gen_export_struct_with_tuple_structs as any;
+            if (!export_fn) return;
+
+            return (arg: Uint8Array) => {
+                const arg_ptr = exportToMemory(arg);
+                return importFromMemory(export_fn(arg_ptr));
+            };
+        })(),
+        exportStructWithTuplesRaw: (() => {
+            const export_fn = instance.exports.__fp_gen_export_struct_with_tuples as any;
+            if (!export_fn) return;
+
+            return (arg: Uint8Array) => {
+                const arg_ptr = exportToMemory(arg);
+                return importFromMemory(export_fn(arg_ptr));
+            };
+        })(),
+        exportStructWithWidePrimitivesRaw: (() => {
+            const export_fn = instance.exports.__fp_gen_export_struct_with_wide_primitives as any;
+            if (!export_fn) return;
+
+            return (arg: Uint8Array) => {
+                const arg_ptr = exportToMemory(arg);
+                return importFromMemory(export_fn(arg_ptr));
+            };
+        })(),
+        exportTaggedUnknownVariantRaw: (() => {
+            const export_fn = instance.exports.__fp_gen_export_tagged_unknown_variant as any;
+            if (!export_fn) return;
+
+            return (arg: Uint8Array) => {
+                const arg_ptr = exportToMemory(arg);
+                return importFromMemory(export_fn(arg_ptr));
+            };
+        })(),
         exportTimestampRaw: (() => {
             const export_fn = instance.exports.__fp_gen_export_timestamp as any;
             if (!export_fn) return;
@@ -773,6 +1489,33 @@ export async function createRuntime(
                 return importFromMemory(export_fn(arg_ptr));
             };
         })(),
+        exportTupleRaw: (() => {
+            const export_fn = instance.exports.__fp_gen_export_tuple as any;
+            if (!export_fn) return;
+
+            return (arg: Uint8Array) => {
+                const arg_ptr = exportToMemory(arg);
+                return importFromMemory(export_fn(arg_ptr));
+            };
+        })(),
+        exportUnknownVariantRaw: (() => {
+            const export_fn = instance.exports.__fp_gen_export_unknown_variant as any;
+            if (!export_fn) return;
+
+            return (arg: Uint8Array) => {
+                const arg_ptr = exportToMemory(arg);
+                return importFromMemory(export_fn(arg_ptr));
+            };
+        })(),
+        exportUuidRaw: (() => {
+            const export_fn = instance.exports.__fp_gen_export_uuid as any;
+            if (!export_fn) return;
+
+            return (arg: Uint8Array) => {
+                const arg_ptr = exportToMemory(arg);
+                return importFromMemory(export_fn(arg_ptr));
+            };
+        })(),
         fetchDataRaw: (() => {
             const export_fn = instance.exports.__fp_gen_fetch_data as any;
             if (!export_fn) return;
@@ -804,3 +1547,46 @@ function fromFatPtr(fatPtr: FatPtr): [ptr: number, len: number] {
 function toFatPtr(ptr: number, len: number): FatPtr {
     return (BigInt(ptr) << 32n) | BigInt(len);
 }
+
+function int128FromBytes(bytes: Uint8Array, signed: boolean): bigint {
+    let value = 0n;
+    for (const byte of bytes) {
+        value = (value << 8n) | BigInt(byte);
+    }
+    if (signed) {
+        value = BigInt.asIntN(128, value);
+    }
+    return value;
+}
+
+function int128ToBytes(value: bigint): Uint8Array {
+    const bytes = new Uint8Array(16);
+    let remaining = BigInt.asUintN(128, value);
+    for (let i = 15; i >= 0; i--) {
+        bytes[i] = Number(remaining & 0xffn);
+        remaining >>= 8n;
+    }
+    return bytes;
+}
+
+function decodeStructWithWidePrimitives(value: any): any {
+    const result = { ...value };
+    if (result.signed_large != null) {
+        result.signed_large = int128FromBytes(result.signed_large, true);
+    }
+    if (result.unsigned_large != null) {
+        result.unsigned_large = int128FromBytes(result.unsigned_large, false);
+    }
+    return result;
+}
+
+function encodeStructWithWidePrimitives(value: any): any {
+    const result = { ...value };
+    if (result.signed_large != null) {
+        result.signed_large = int128ToBytes(result.signed_large);
+    }
+    if (result.unsigned_large != null) {
+        result.unsigned_large = int128ToBytes(result.unsigned_large);
+    }
+    return result;
+}
@@ -4,8 +4,49 @@
 // This file is generated. PLEASE DO NOT MODIFY. //
 // ============================================= //
 
+export type Int128 = bigint;
+
+export type ActionMeta = {
+    sequenceNumber: number;
+    origin: string;
+};
+
+export type ActionMetaDef = ActionMeta;
+
+export type Appointment = {
+    date: string;
+    weekday: Weekday;
+};
+
 export type Body = ArrayBuffer;
 
+export type DirectionalStructRenaming = {
+    type?: "SerializedName";
+    foo_bar: string;
+};
+
+export type DirectionalVariantRenaming =
+    | "FOOBAR"
+    | "foobar"
+    | { QUXBAZ: {
+
+        /**
+         * Will be serialized as "fooBar", and deserialized from "FOO-BAR".
+         */
+        fooBar?: string;
+        "FOO-BAR"?: string;
+    } }
+    | { quxbaz: {
+
+        /**
+         * Will be serialized as "fooBar", and deserialized from "FOO-BAR".
+         */
+        fooBar?: string;
+        "FOO-BAR"?: string;
+    } }
+    | "BAZ_OUT"
+    | "baz_in";
+
 /**
  * # This is an enum with doc comments.
  */
@@ -42,6 +83,10 @@ export type DocExampleStruct = {
     type: string;
 };
 
+export type Duration = { secs: number; nanos: number };
+
+export type Email = string;
+
 /**
  * This struct is also not referenced by any function or data structure, but
  * it will show up because there is an explicit `use` statement for it in the
@@ -92,6 +137,16 @@ export type FpVariantRenaming =
         qux_baz: number;
     } };
 
+/**
+ * Information about the frame that is being rendered.
+ */
+export type FrameInfo = {
+    frameNumber: number;
+    deltaMs: number;
+    offset: number;
+    isPaused: boolean;
+};
+
 export type GroupImportedType1 = {
     you_will_see_this: boolean;
 };
@@ -100,10 +155,59 @@ export type GroupImportedType2 = {
     you_will_see_this: boolean;
 };
 
+export type HeaderMap = { [name: string]: string | ArrayBuffer | Array<string | ArrayBuffer> };
+
+/**
+ * A request that was sent, together with the response that was received.
+ */
+export type HttpExchange = {
+    request: HttpRequest;
+    response: HttpResponse;
+
+    /**
+     * The content type of the response body.
+     */
+    content_type: string | ArrayBuffer;
+};
+
 export type HttpResult = Result<Response, RequestError>;
 
 export type Int64 = number | bigint;
 
+export type JsonObject = { [key: string]: JsonValue };
+
+export type JsonValue = null | boolean | number | string | JsonValue[] | { [key: string]: JsonValue };
+
+export type KebabCasePropertyRenaming = {
+    "foo-bar": string;
+    "raw-struct": number;
+};
+
+export type Label = string & { readonly __brand: "Label" };
+
+/**
+ * Log level, serialized as a single byte.
+ */
+export const enum Level {
+    Debug = 10,
+    Info = 20,
+    /**
+     * Something may be wrong.
+     */
+    Warning = 30,
+    Error = 31,
+}
+
+/**
+ * Marker without any data.
+ */
+export type Marker = null;
+
+/**
+ * Newtypes may also wrap primitives.
+ */
+export type Meters = number & { readonly __brand: "Meters" };
+
 export type Method = 
     | "GET"
     | "POST"
@@ -122,6 +226,27 @@ export type Point<T> = {
     value: T;
 };
 
+/**
+ * A position in 2D space.
+ */
+export type Position = {
+    x: number;
+    y: number;
+};
+
+export type Priority =
+    | "Low"
+    | "High"
+    | "Legacy"
+    | "Pending"
+    | "Unknown";
+
+export type Range = {
+    type?: "range";
+    start: number;
+    end: number;
+};
+
 /**
  * Example for representing Redux actions.
  */
@@ -145,11 +270,8 @@ export type Request = {
 
     /**
      * HTTP headers to submit with the request.
-     *
-     * Note: We currently do not support the `Headers` type from the `http`
-     *       crate. See: https://github.com/fiberplane/fp-bindgen/issues/102
      */
-    headers: Record<string, string>;
+    headers: HeaderMap;
 
     /**
      * The body to submit with the request.
@@ -157,6 +279,14 @@ export type Request = {
     body?: Body;
 };
 
+export type HttpRequest = {
+    method: Method;
+    uri: string;
+    version?: HttpVersion;
+    headers?: HeaderMap;
+    body?: ArrayBuffer;
+};
+
 /**
  * Represents an error that occurred while attempting to submit the request.
  */
@@ -199,11 +329,8 @@ export type Response = {
 
     /**
      * HTTP headers that were part of the response.
-     *
-     * Note: We currently do not support the `Headers` type from the `http`
-     *       crate. See: https://github.com/fiberplane/fp-bindgen/issues/102
      */
-    headers: Record<string, string>;
+    headers: HeaderMap;
 
     /**
      * HTTP status code.
@@ -211,6 +338,13 @@ export type Response = {
     status_code: number;
 };
 
+export type HttpResponse = {
+    status: number;
+    version?: HttpVersion;
+    headers?: HeaderMap;
+    body?: ArrayBuffer;
+};
+
 /**
  * A result that can be either successful (`Ok)` or represent an error (`Err`).
  */
@@ -224,11 +358,28 @@ export type Result<T, E> =
      */
     | { Err: E };
 
+/**
+ * Color in RGB format.
+ */
+export type Rgb = [number, number, number];
+
 export type SerdeAdjacentlyTagged =
     | { type: "Foo" }
     | { type: "Bar"; payload: string }
     | { type: "Baz"; payload: { a: number; b: number } };
 
+export type SerdeAttributes = {
+    title?: string;
+    maxItems?: number;
+    max_items?: number;
+    password?: string;
+    revision?: number;
+    label?: Label;
+    priority?: Priority;
+    range?: Range;
+    email?: Email;
+};
+
 export type SerdeFlatten = {
 } & FlattenedStruct;
 
@@ -258,6 +409,19 @@ export type SerdeVariantRenaming =
         qux_baz: number;
     } };
 
+/**
+ * A shape, which may be one we don't know about yet.
+ */
+export type Shape =
+    | { Circle: { radius: number } }
+    | "Point"
+    | { Square: number }
+    /**
+     * A variant that is not known to this version of the protocol.
+     */
+    | (string & {})
+    | { [variant: string]: unknown };
+
 /**
  * A state update to communicate to the Redux host.
  *
@@ -269,6 +433,37 @@ export type StateUpdate = {
     revision?: number;
 };
 
+export type StructWithArrays = {
+    checksum: [number, number, number, number];
+    coordinates: [number, number, number];
+    segments: Array<[Point<number>, Point<number>]>;
+};
+
+export type StructWithBinaryHeap = {
+    priorities: Array<number>;
+};
+
+export type StructWithChronoTypes = {
+    utc: string;
+    fixed_offset: string;
+    date: string;
+    date_time: string;
+    delta: TimeDelta;
+};
+
+export type StructWithCollections = {
+    shared: string;
+    label: string;
+    queue: Array<number>;
+    ordered: Record<string, number>;
+};
+
+export type StructWithDurations = {
+    timeout: Duration;
+    interval?: Duration;
+    created_at: SystemTime;
+};
+
 export type StructWithGenerics<T> = {
     list: Array<T>;
     points: Array<Point<T>>;
@@ -276,3 +471,105 @@ export type StructWithGenerics<T> = {
     complex_nested?: Record<string, Array<FloatingPoint>>;
     optional_timestamp?: string;
 };
+
+export type StructWithJson = {
+    value: JsonValue;
+    object: JsonObject;
+    optional_value?: JsonValue;
+};
+
+export type StructWithReprEnums = {
+    level: Level;
+    history: Array<Level>;
+};
+
+export type StructWithTupleStructs = {
+    user_id: UserId;
+    optional_user_id?: UserId;
+    distance: Meters;
+    wrapped: Wrapper<number>;
+    color: Rgb;
+    marker: Marker;
+};
+
+export type StructWithTuples = {
+    pair: [number, number];
+    nested: Array<[string, [number, boolean]]>;
+    single: [string];
+};
+
+export type StructWithWidePrimitives = {
+    initial: string;
+    signed_large: Int128;
+    unsigned_large: Int128;
+    offset: number;
+    length: number;
+};
+
+export type SystemTime = { secs_since_epoch: number; nanos_since_epoch: number };
+
+/**
+ * Internally tagged equivalent of `Shape`.
+ */
+export type TaggedShape =
+    | { type: "Circle"; radius: number }
+    | { type: "Point" }
+    /**
+     * A variant that is not known to this version of the protocol.
+     */
+    | { type: string & {}; [key: string]: unknown };
+
+export type TimeDelta = [secs: number, nanos: number];
+
+/**
+ * Identifier of a user.
+ *
+ * In TypeScript, this becomes a branded type, so it cannot be mixed up with
+ * other strings by accident.
+ */
+export type UserId = string & { readonly __brand: "UserId" };
+
+export type Uuid = string & { readonly __brand: "Uuid" };
+
+export type HttpVersion = "HTTP/0.9" | "HTTP/1.0" | "HTTP/1.1" | "HTTP/2.0" | "HTTP/3.0";
+
+export type Weekday = "Monday" | "Tuesday" | "Wednesday" | "Thursday" | "Friday" | "Saturday" | "Sunday";
+
+/**
+ * Newtypes may be generic too.
+ */
+export type Wrapper<T> = T & { readonly __brand: "Wrapper" };
+
+/**
+ * Converts a `Duration` to a (fractional) number of milliseconds.
+ */
+export function durationToMillis(duration: Duration): number {
+    return duration.secs * 1000 + duration.nanos / 1_000_000;
+}
+
+/**
+ * Converts a non-negative number of milliseconds to a `Duration`.
+ */
+export function durationFromMillis(millis: number): Duration {
+    const secs = Math.floor(millis / 1000);
+    const nanos = Math.round((millis - secs * 1000) * 1_000_000);
+    return { secs, nanos: Math.min(nanos, 999_999_999) };
+}
+
+/**
+ * Converts a `SystemTime` to a (fractional) number of milliseconds since the
+ * Unix epoch, such as can be passed to the `Date` constructor.
+ */
+export function systemTimeToMillis(time: SystemTime): number {
+    return time.secs_since_epoch * 1000 + time.nanos_since_epoch / 1_000_000;
+}
+
+/**
+ * Converts a number of milliseconds since the Unix epoch, such as returned by
+ * `Date.now()`, to a `SystemTime`. Times before the epoch are not supported.
+ */
+export function systemTimeFromMillis(millis: number): SystemTime {
+    const secs = Math.floor(millis / 1000);
+    const nanos = Math.round((millis - secs * 1000) * 1_000_000);
+    return { secs_since_epoch: secs, nanos_since_epoch: Math.min(nanos, 999_999_999) };
+}
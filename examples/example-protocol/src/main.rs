@@ -1,10 +1,13 @@
 #![allow(dead_code)]
 
+use bytes::Bytes;
 use fp_bindgen::{prelude::*, types::CargoDependency};
 use once_cell::sync::Lazy;
 use redux_example::{ReduxAction, StateUpdate};
+use serde_bytes::ByteBuf;
 use std::collections::{BTreeMap, BTreeSet};
 use time::OffsetDateTime;
+use uuid::Uuid;
 
 // Referencing types using their full module path can be problematic in some
 // edge cases. If you want to use types from other modules in your protocol,
@@ -56,6 +59,20 @@ fp_import! {
     // Passing strings:
     fn import_string(arg: String) -> String;
 
+    // Passing binary data. `Bytes` and `ByteBuf` are passed as raw buffers,
+    // without MessagePack encoding, when used directly as function arguments
+    // or return values:
+    fn import_bytes(arg: Bytes) -> Bytes;
+
+    // Passing structs by value.
+    //
+    // See `types/by_value.rs` for more info.
+    fn import_by_value(position: Position, frame: FrameInfo) -> Position;
+
+    // Passing borrowed arguments. The receiving side gets owned values
+    // (`String`, `ByteBuf`, `Vec<T>` and `T` respectively):
+    fn import_borrowed(text: &str, data: &[u8], values: &[u32], position: &Position) -> String;
+
     // Multiple arguments:
     fn import_multiple_primitives(arg1: i8, arg2: String) -> i64;
 
@@ -101,6 +118,12 @@ fp_import! {
     ///
     /// See `types/http.rs` for more info.
     async fn make_http_request(request: Request) -> HttpResult;
+
+    /// Example how a runtime could expose a `fetch()` function using the
+    /// request and response types from the `http` crate.
+    ///
+    /// See `types/http_exchange.rs` for more info.
+    fn fetch(request: http::Request<ByteBuf>) -> http::Response<ByteBuf>;
 }
 
 fp_export! {
@@ -124,15 +147,52 @@ fp_export! {
     fn export_primitive_u32(arg: u32) -> u32;
     fn export_primitive_u64(arg: u64) -> u64;
 
+    // Passing primitives that don't fit in a WebAssembly value.
+    //
+    // See `types/primitives.rs` for more info.
+    fn export_primitive_char(arg: char) -> char;
+    fn export_primitive_i128(arg: i128) -> i128;
+    fn export_primitive_isize(arg: isize) -> isize;
+    fn export_primitive_u128(arg: u128) -> u128;
+    fn export_primitive_usize(arg: usize) -> usize;
+    fn export_struct_with_wide_primitives(
+        arg: StructWithWidePrimitives,
+    ) -> StructWithWidePrimitives;
+
     // Passing strings:
     fn export_string(arg: String) -> String;
 
+    // Passing binary data:
+    fn export_bytes(arg: Bytes) -> Bytes;
+    fn export_byte_buf(arg: ByteBuf) -> ByteBuf;
+
+    // Passing structs by value.
+    //
+    // See `types/by_value.rs` for more info.
+    fn export_by_value(position: Position, frame: FrameInfo) -> Position;
+
+    // Passing borrowed arguments:
+    fn export_borrowed(text: &str, data: &[u8], values: &[u32], position: &Position) -> String;
+
     // Multiple arguments:
     fn export_multiple_primitives(arg1: i8, arg2: String) -> i64;
 
     // Integration with the `time` crate:
     fn export_timestamp(arg: OffsetDateTime) -> OffsetDateTime;
 
+    // Integration with the `chrono` crate.
+    //
+    // See `types/chrono.rs` for more info.
+    fn export_chrono_types(arg: StructWithChronoTypes) -> StructWithChronoTypes;
+
+    // Passing durations and system times.
+    //
+    // See `types/durations.rs` for more info.
+    fn export_durations(arg: StructWithDurations) -> StructWithDurations;
+
+    // Integration with the `uuid` crate:
+    fn export_uuid(arg: Uuid) -> Uuid;
+
     // Passing custom types with flattened properties.
     //
     // See `types/flattening.rs` for more info.
@@ -151,6 +211,71 @@ fp_export! {
     fn export_fp_enum(arg: FpVariantRenaming) -> FpVariantRenaming;
     fn export_serde_struct(arg: SerdePropertyRenaming) -> SerdePropertyRenaming;
     fn export_serde_enum(arg: SerdeVariantRenaming) -> SerdeVariantRenaming;
+    fn export_kebab_case_struct(arg: KebabCasePropertyRenaming) -> KebabCasePropertyRenaming;
+    fn export_directional_enum(arg: DirectionalVariantRenaming) -> DirectionalVariantRenaming;
+    fn export_directional_struct(arg: DirectionalStructRenaming) -> DirectionalStructRenaming;
+
+    // Passing custom types with other Serde attributes.
+    //
+    // See `types/serde_attributes.rs` for more info.
+    fn export_serde_attributes(arg: SerdeAttributes) -> SerdeAttributes;
+
+    // Passing standard containers.
+    //
+    // See `types/collections.rs` for more info.
+    fn export_collections(arg: StructWithCollections) -> StructWithCollections;
+    fn export_binary_heap(arg: StructWithBinaryHeap) -> StructWithBinaryHeap;
+
+    // Passing types that are mapped to custom types.
+    //
+    // See `types/custom_types.rs` for more info.
+    fn export_custom_types(arg: Appointment) -> Appointment;
+
+    // Passing dynamic JSON data.
+    //
+    // See `types/json.rs` for more info.
+    fn export_json(arg: StructWithJson) -> StructWithJson;
+
+    // Passing requests and responses from the `http` crate.
+    //
+    // See `types/http_exchange.rs` for more info.
+    fn export_http_exchange(arg: HttpExchange) -> HttpExchange;
+    fn export_fetch(request: http::Request<ByteBuf>) -> Option<http::Response<ByteBuf>>;
+
+    // Passing types from other crates through a mirror.
+    //
+    // See `types/remote.rs` for more info.
+    fn export_remote_type(arg: ActionMetaDef) -> ActionMetaDef;
+
+    // Passing enums that are serialized as integers.
+    //
+    // See `types/repr_enums.rs` for more info.
+    fn export_repr_enum(arg: Level) -> Level;
+    fn export_struct_with_repr_enums(arg: StructWithReprEnums) -> StructWithReprEnums;
+
+    // Passing enums that capture variants that are not known to them.
+    //
+    // See `types/unknown_variants.rs` for more info.
+    fn export_unknown_variant(arg: Shape) -> Shape;
+    fn export_tagged_unknown_variant(arg: TaggedShape) -> TaggedShape;
+
+    // Passing fixed-size arrays.
+    //
+    // See `types/arrays.rs` for more info.
+    fn export_array(arg: [u32; 3]) -> [u32; 3];
+    fn export_struct_with_arrays(arg: StructWithArrays) -> StructWithArrays;
+
+    // Passing anonymous tuples.
+    //
+    // See `types/tuples.rs` for more info.
+    fn export_tuple(arg: (u32, String)) -> (String, u32);
+    fn export_struct_with_tuples(arg: StructWithTuples) -> StructWithTuples;
+
+    // Passing tuple structs, newtypes and unit structs.
+    //
+    // See `types/tuple_structs.rs` for more info.
+    fn export_newtype(arg: UserId) -> Meters;
+    fn export_struct_with_tuple_structs(arg: StructWithTupleStructs) -> StructWithTupleStructs;
 
     // Passing custom enums with different tagging options.
     //
@@ -210,6 +335,16 @@ static PLUGIN_DEPENDENCIES: Lazy<BTreeMap<&str, CargoDependency>> = Lazy::new(||
 
 fn main() {
     for bindings_type in [
+        BindingsType::AssemblyScriptPlugin(AssemblyScriptPluginConfig {
+            name: NAME,
+            authors: AUTHORS,
+            version: VERSION,
+            dependencies: BTreeMap::new(),
+            allow_opaque_types: true,
+        }),
+        BindingsType::Html,
+        BindingsType::JsonSchema,
+        BindingsType::Markdown,
         BindingsType::RustPlugin(RustPluginConfig {
             name: NAME,
             authors: AUTHORS,
@@ -220,7 +355,8 @@ fn main() {
         BindingsType::TsRuntimeWithExtendedConfig(
             TsExtendedRuntimeConfig::new()
                 .with_msgpack_module("https://unpkg.com/@msgpack/msgpack@2.7.2/mod.ts")
-                .with_raw_export_wrappers(),
+                .with_raw_export_wrappers()
+                .with_time_helpers(),
         ),
     ] {
         let output_path = format!("bindings/{}", bindings_type);
@@ -233,6 +369,89 @@ fn main() {
     }
 }
 
+#[test]
+fn test_generate_assemblyscript_plugin() {
+    static FILES: &[(&str, &[u8])] = &[
+        (
+            "bindings/assemblyscript-plugin/assembly/types.ts",
+            include_bytes!("assets/assemblyscript_plugin_test/expected_types.ts"),
+        ),
+        (
+            "bindings/assemblyscript-plugin/assembly/fp.ts",
+            include_bytes!("assets/assemblyscript_plugin_test/expected_fp.ts"),
+        ),
+        (
+            "bindings/assemblyscript-plugin/assembly/index.ts",
+            include_bytes!("assets/assemblyscript_plugin_test/expected_index.ts"),
+        ),
+        (
+            "bindings/assemblyscript-plugin/assembly/tsconfig.json",
+            include_bytes!("assets/assemblyscript_plugin_test/expected_tsconfig.json"),
+        ),
+        (
+            "bindings/assemblyscript-plugin/assembly/export.ts",
+            include_bytes!("assets/assemblyscript_plugin_test/expected_export.ts"),
+        ),
+        (
+            "bindings/assemblyscript-plugin/assembly/import.ts",
+            include_bytes!("assets/assemblyscript_plugin_test/expected_import.ts"),
+        ),
+        (
+            "bindings/assemblyscript-plugin/package.json",
+            include_bytes!("assets/assemblyscript_plugin_test/expected_package.json"),
+        ),
+    ];
+
+    fp_bindgen!(BindingConfig {
+        bindings_type: BindingsType::AssemblyScriptPlugin(AssemblyScriptPluginConfig {
+            name: NAME,
+            authors: AUTHORS,
+            version: VERSION,
+            dependencies: BTreeMap::new(),
+            allow_opaque_types: true,
+        }),
+        path: "bindings/assemblyscript-plugin",
+    });
+
+    for (path, expected) in FILES {
+        tests::assert_file_eq(path, expected)
+    }
+}
+
+#[test]
+fn test_generate_json_schema() {
+    static FILES: &[(&str, &[u8])] = &[(
+        "bindings/json-schema/schema.json",
+        include_bytes!("assets/json_schema_test/expected_schema.json"),
+    )];
+
+    fp_bindgen!(BindingConfig {
+        bindings_type: BindingsType::JsonSchema,
+        path: "bindings/json-schema",
+    });
+
+    for (path, expected) in FILES {
+        tests::assert_file_eq(path, expected)
+    }
+}
+
+#[test]
+fn test_generate_markdown() {
+    static FILES: &[(&str, &[u8])] = &[(
+        "bindings/markdown/index.md",
+        include_bytes!("assets/markdown_test/expected_index.md"),
+    )];
+
+    fp_bindgen!(BindingConfig {
+        bindings_type: BindingsType::Markdown,
+        path: "bindings/markdown",
+    });
+
+    for (path, expected) in FILES {
+        tests::assert_file_eq(path, expected)
+    }
+}
+
 #[test]
 fn test_generate_rust_plugin() {
     static FILES: &[(&str, &[u8])] = &[
@@ -312,6 +531,7 @@ fn test_generate_ts_runtime() {
             TsExtendedRuntimeConfig::new()
                 .with_msgpack_module("https://unpkg.com/@msgpack/msgpack@2.7.2/mod.ts")
                 .with_raw_export_wrappers()
+                .with_time_helpers()
         ),
         path: "bindings/ts-runtime",
     });
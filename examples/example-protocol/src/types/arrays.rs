@@ -0,0 +1,12 @@
+use super::Point;
+use fp_bindgen::prelude::Serializable;
+
+// Fixed-size arrays are serialized as MessagePack arrays of the given length,
+// which become fixed-length tuple types in TypeScript.
+
+#[derive(Serializable)]
+pub struct StructWithArrays {
+    pub checksum: [u8; 4],
+    pub coordinates: [f64; 3],
+    pub segments: Vec<[Point<f64>; 2]>,
+}
@@ -0,0 +1,27 @@
+use fp_bindgen::prelude::Serializable;
+
+// Structs that only consist of primitives may be passed by value using the
+// `#[fp(by_value)]` annotation. When such a struct is used directly as a
+// function argument, every field is passed as a separate Wasm value, which
+// avoids encoding it using MessagePack and allocating memory for it.
+//
+// Return values are still encoded using MessagePack.
+
+/// A position in 2D space.
+#[derive(Serializable)]
+#[fp(by_value)]
+pub struct Position {
+    pub x: f64,
+    pub y: f64,
+}
+
+/// Information about the frame that is being rendered.
+#[derive(Serializable)]
+#[fp(by_value)]
+#[fp(rename_all = "camelCase")]
+pub struct FrameInfo {
+    pub frame_number: u64,
+    pub delta_ms: f32,
+    pub offset: i32,
+    pub is_paused: bool,
+}
@@ -0,0 +1,19 @@
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, TimeDelta, Utc};
+use fp_bindgen::prelude::Serializable;
+
+// The `chrono-compat` feature adds support for types from the `chrono` crate.
+// Dates and times are represented as ISO 8601 strings in TypeScript, just
+// like the types from the `time` crate.
+//
+// Note that `chrono::Duration` is an alias for `TimeDelta`, which is what you
+// should refer to in your protocol. Chrono serializes it as a tuple of seconds
+// and nanoseconds.
+
+#[derive(Serializable)]
+pub struct StructWithChronoTypes {
+    pub utc: DateTime<Utc>,
+    pub fixed_offset: DateTime<FixedOffset>,
+    pub date: NaiveDate,
+    pub date_time: NaiveDateTime,
+    pub delta: TimeDelta,
+}
@@ -0,0 +1,31 @@
+use fp_bindgen::prelude::Serializable;
+use indexmap::IndexMap;
+use std::{
+    borrow::Cow,
+    collections::{BinaryHeap, VecDeque},
+    sync::Arc,
+};
+
+// Besides `Vec`, `Box`, `Rc` and the `HashMap`/`BTreeMap` and
+// `HashSet`/`BTreeSet` families, the following standard containers are
+// supported. They are preserved in the generated Rust types, so plugins and
+// runtimes use exactly the same types. In TypeScript, lists become arrays and
+// maps become records, while `Arc` is transparent.
+//
+// Note that `BinaryHeap` does not implement `PartialEq`, so types that contain
+// it don't derive `PartialEq` in the generated Rust bindings.
+//
+// `IndexMap` requires the `indexmap-compat` feature.
+
+#[derive(Serializable)]
+pub struct StructWithCollections {
+    pub shared: Arc<String>,
+    pub label: Cow<'static, str>,
+    pub queue: VecDeque<u32>,
+    pub ordered: IndexMap<String, u32>,
+}
+
+#[derive(Serializable)]
+pub struct StructWithBinaryHeap {
+    pub priorities: BinaryHeap<u32>,
+}
@@ -0,0 +1,41 @@
+use fp_bindgen::prelude::Serializable;
+
+// Types that are defined elsewhere, or that need a representation that cannot
+// be derived from their definition, can be mapped to custom types through the
+// `#[fp(custom(...))]` annotation. Their definition is then ignored, and the
+// generators refer to them as follows:
+//
+// - `rs_ty` is the path to the type used by the Rust generators.
+// - `ts_ty` is the name of the type used by the TypeScript generator.
+// - `ts_declaration` is an optional declaration of `ts_ty`, for when it does
+//   not refer to a built-in type.
+// - `serde_attrs` are Serde attributes to add to fields of the type.
+// - `dependency` declares a dependency of the generated Rust plugin crate that
+//   is needed to use the type. It takes a `name` and the same `version`,
+//   `features`, `git`, `branch` and `path` options you would use in a
+//   `Cargo.toml` file.
+
+/// A calendar date, such as `"2022-05-17"`.
+#[derive(Serializable)]
+#[fp(custom(
+    rs_ty = "time::Date",
+    ts_ty = "string",
+    dependency(name = "time", version = "0.3", features("serde-human-readable")),
+))]
+pub struct CalendarDate;
+
+/// The day of the week, such as `"Monday"`.
+#[derive(Serializable)]
+#[fp(custom(
+    rs_ty = "time::Weekday",
+    ts_ty = "Weekday",
+    ts_declaration = r#""Monday" | "Tuesday" | "Wednesday" | "Thursday" | "Friday" | "Saturday" | "Sunday""#,
+    dependency(name = "time", version = "0.3", features("serde")),
+))]
+pub struct Weekday;
+
+#[derive(Serializable)]
+pub struct Appointment {
+    pub date: CalendarDate,
+    pub weekday: Weekday,
+}
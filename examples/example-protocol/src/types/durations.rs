@@ -0,0 +1,18 @@
+use fp_bindgen::prelude::Serializable;
+use std::time::{Duration, SystemTime};
+
+// `Duration` and `SystemTime` from `std::time` are supported out of the box.
+// They use Serde's representation, which is a struct with `secs` and `nanos`
+// fields for durations, and a struct with `secs_since_epoch` and
+// `nanos_since_epoch` fields for system times.
+//
+// The TypeScript runtime can generate helpers for converting these to and
+// from milliseconds, by enabling `with_time_helpers()` in the
+// `TsExtendedRuntimeConfig`.
+
+#[derive(Serializable)]
+pub struct StructWithDurations {
+    pub timeout: Duration,
+    pub interval: Option<Duration>,
+    pub created_at: SystemTime,
+}
@@ -0,0 +1,25 @@
+use fp_bindgen::prelude::Serializable;
+use http::{HeaderValue, Request, Response};
+use serde_bytes::ByteBuf;
+
+// The `http-compat` feature also provides ready-made mappings for
+// `http::Request<ByteBuf>` and `http::Response<ByteBuf>`, so you don't need to
+// declare your own types for them (the `serde-bytes-compat` feature is required
+// as well).
+//
+// In the generated bindings, they become the `HttpRequest` and `HttpResponse`
+// newtypes from `fp_bindgen_support::http`, which can also be used directly as
+// function arguments and return values (see `fetch()` and `export_fetch()` in
+// `main.rs`). Because the example protocol has its own `Request` and
+// `Response` types, those functions refer to them as `http::Request<ByteBuf>`
+// and `http::Response<ByteBuf>`.
+
+/// A request that was sent, together with the response that was received.
+#[derive(Serializable)]
+pub struct HttpExchange {
+    pub request: Request<ByteBuf>,
+    pub response: Response<ByteBuf>,
+
+    /// The content type of the response body.
+    pub content_type: HeaderValue,
+}
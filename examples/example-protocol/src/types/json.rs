@@ -0,0 +1,26 @@
+use fp_bindgen::prelude::Serializable;
+use serde_json::{Map as JsonMap, Value as JsonValue};
+
+// The `json-compat` feature adds support for dynamic JSON data through
+// `serde_json`'s `Value` and `Map` types. In TypeScript, these are represented
+// as `JsonValue` and `JsonObject`, respectively.
+//
+// `Value` and `Map` need to be referred to as `JsonValue` and `JsonMap`, or by
+// their full path in the protocol, so they can be distinguished from
+// `rmpv::Value` and other types named `Map`.
+//
+// JSON numbers are serialized as MessagePack integers if they are integral,
+// and as 64-bit floats otherwise. They round-trip without loss between Rust
+// plugins and runtimes, but in TypeScript they become plain `number`s. This
+// means that integral floats, such as `1.0`, will be passed back as integers,
+// and that integers beyond `2^53 - 1` lose precision.
+//
+// AssemblyScript plugins cannot inspect JSON values. They are skipped when
+// decoding, and cannot be encoded.
+
+#[derive(Serializable)]
+pub struct StructWithJson {
+    pub value: JsonValue,
+    pub object: JsonMap<String, JsonValue>,
+    pub optional_value: Option<serde_json::Value>,
+}
@@ -1,6 +1,24 @@
 mod aliases;
 pub use aliases::*;
 
+mod arrays;
+pub use arrays::*;
+
+mod by_value;
+pub use by_value::*;
+
+mod chrono;
+pub use self::chrono::*;
+
+mod collections;
+pub use collections::*;
+
+mod custom_types;
+pub use custom_types::*;
+
+mod durations;
+pub use durations::*;
+
 mod flattening;
 pub use flattening::*;
 
@@ -10,14 +28,41 @@ pub use generics::*;
 mod http;
 pub use self::http::*;
 
+mod http_exchange;
+pub use http_exchange::*;
+
 mod inline_docs;
 pub use inline_docs::*;
 
+mod json;
+pub use json::*;
+
+mod primitives;
+pub use primitives::*;
+
+mod remote;
+pub use remote::*;
+
 mod renaming;
 pub use renaming::*;
 
+mod repr_enums;
+pub use repr_enums::*;
+
+mod serde_attributes;
+pub use serde_attributes::*;
+
 mod tagged_enums;
 pub use tagged_enums::*;
 
+mod tuple_structs;
+pub use tuple_structs::*;
+
+mod tuples;
+pub use tuples::*;
+
+mod unknown_variants;
+pub use unknown_variants::*;
+
 mod use_statements;
 pub use use_statements::*;
@@ -0,0 +1,17 @@
+use fp_bindgen::prelude::Serializable;
+
+// Primitives that don't fit in a WebAssembly value are always serialized
+// using MessagePack, even when passed directly as function arguments.
+//
+// Inside data types, 128-bit integers are encoded as 16 big-endian bytes,
+// which the TypeScript runtime converts to and from a `bigint`. Pointer-sized
+// integers are serialized as 64-bit integers.
+
+#[derive(Serializable)]
+pub struct StructWithWidePrimitives {
+    pub initial: char,
+    pub signed_large: i128,
+    pub unsigned_large: u128,
+    pub offset: isize,
+    pub length: usize,
+}
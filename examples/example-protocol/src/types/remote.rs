@@ -0,0 +1,20 @@
+use fp_bindgen::prelude::Serializable;
+
+// Types from other crates that do not implement `Serializable` can be used by
+// declaring a mirror for them, similar to Serde's remote derive. The mirror
+// must have the same fields or variants, and attributes, as the remote type,
+// and takes on the identity of the remote type in the generated bindings.
+//
+// If the name of the mirror differs from the remote type, the mirror name is
+// generated as an alias for the remote type.
+
+#[derive(Serializable)]
+#[fp(
+    remote = "redux_example::ActionMeta",
+    rename_all = "camelCase",
+    rust_plugin_module = "redux_example"
+)]
+pub struct ActionMetaDef {
+    pub sequence_number: u64,
+    pub origin: String,
+}
@@ -16,6 +16,11 @@ use serde::{Deserialize, Serialize};
 // - https://serde.rs/field-attrs.html#rename
 // - https://serde.rs/variant-attrs.html#rename
 // - https://serde.rs/variant-attrs.html#rename_all
+//
+// All the casings supported by Serde's `rename_all` can be used, including
+// `lowercase`, `UPPERCASE`, `kebab-case` and `SCREAMING-KEBAB-CASE`. Property
+// names that are not valid identifiers, such as those in kebab-case, are
+// quoted in the generated TypeScript types.
 
 // This struct renames its properties using the `fp` attribute namespace.
 #[derive(Serializable)]
@@ -84,3 +89,43 @@ pub enum SerdeVariantRenaming {
         qux_baz: f64,
     },
 }
+
+// This struct uses kebab-case property names.
+#[derive(Serializable, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct KebabCasePropertyRenaming {
+    // Will be renamed to "foo-bar" because of the `rename_all` on the struct.
+    pub foo_bar: String,
+
+    // Raw identifiers are supported and will be processed like any other.
+    pub r#raw_struct: i32,
+}
+
+// The casing may differ between serialization and deserialization, just like
+// with Serde.
+#[derive(Serializable, Serialize, Deserialize)]
+#[serde(rename_all(serialize = "UPPERCASE", deserialize = "lowercase"))]
+pub enum DirectionalVariantRenaming {
+    // Will be serialized as "FOOBAR", and deserialized from "foobar".
+    FooBar,
+    #[serde(rename_all(serialize = "camelCase", deserialize = "SCREAMING-KEBAB-CASE"))]
+    QuxBaz {
+        /// Will be serialized as "fooBar", and deserialized from "FOO-BAR".
+        foo_bar: String,
+    },
+    // Will be serialized as "BAZ_OUT", and deserialized from "baz_in".
+    #[serde(rename(serialize = "BAZ_OUT", deserialize = "baz_in"))]
+    Baz,
+}
+
+// The name of a struct may also differ between serialization and
+// deserialization. It only becomes visible in the serialized format through
+// the `tag` property.
+#[derive(Serializable, Serialize, Deserialize)]
+#[serde(
+    rename(serialize = "SerializedName", deserialize = "DeserializedName"),
+    tag = "type"
+)]
+pub struct DirectionalStructRenaming {
+    pub foo_bar: String,
+}
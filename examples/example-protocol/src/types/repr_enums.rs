@@ -0,0 +1,28 @@
+use fp_bindgen::prelude::Serializable;
+
+// Enums with only unit variants can be serialized as integers instead of as
+// the names of their variants, just like `serde_repr` does. This is done with
+// the `#[fp(repr = "...")]` annotation, which takes the integer type to use.
+//
+// Variants without an explicit discriminant follow the same rules as in Rust:
+// they get the discriminant of the previous variant plus one.
+//
+// The generated Rust bindings derive `Serialize_repr` and `Deserialize_repr`,
+// while the TypeScript runtime uses a numeric `const enum`.
+
+/// Log level, serialized as a single byte.
+#[derive(Serializable)]
+#[fp(repr = "u8")]
+pub enum Level {
+    Debug = 10,
+    Info = 20,
+    /// Something may be wrong.
+    Warning = 30,
+    Error,
+}
+
+#[derive(Serializable)]
+pub struct StructWithReprEnums {
+    pub level: Level,
+    pub history: Vec<Level>,
+}
@@ -0,0 +1,124 @@
+use fp_bindgen::prelude::Serializable;
+use serde::{Deserialize, Serialize};
+
+// Besides renaming, flattening and tagging, fp-bindgen supports most of the
+// other commonly used Serde attributes. They end up in the generated Rust
+// types, and are reflected in the types of the other generators. For instance,
+// skipped fields are left out of the TypeScript types, while fields that may be
+// missing become optional.
+//
+// For more information, see the supported annotations:
+// - https://serde.rs/container-attrs.html#bound
+// - https://serde.rs/container-attrs.html#crate
+// - https://serde.rs/container-attrs.html#default
+// - https://serde.rs/container-attrs.html#deny_unknown_fields
+// - https://serde.rs/container-attrs.html#from
+// - https://serde.rs/container-attrs.html#into
+// - https://serde.rs/container-attrs.html#rename
+// - https://serde.rs/container-attrs.html#tag
+// - https://serde.rs/container-attrs.html#transparent
+// - https://serde.rs/container-attrs.html#try_from
+// - https://serde.rs/field-attrs.html#alias
+// - https://serde.rs/field-attrs.html#rename
+// - https://serde.rs/field-attrs.html#skip
+// - https://serde.rs/field-attrs.html#skip_serializing
+// - https://serde.rs/field-attrs.html#skip_deserializing
+// - https://serde.rs/variant-attrs.html#alias
+// - https://serde.rs/variant-attrs.html#other
+// - https://serde.rs/variant-attrs.html#skip
+// - https://serde.rs/variant-attrs.html#skip_serializing
+// - https://serde.rs/variant-attrs.html#skip_deserializing
+
+// Because of the container-level `default`, every field may be omitted.
+#[derive(Default, Serializable, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct SerdeAttributes {
+    // Will be serialized as "title", but "name" is accepted as well.
+    #[serde(alias = "name")]
+    pub title: String,
+
+    // Uses a different name depending on the direction.
+    #[serde(rename(serialize = "maxItems", deserialize = "max_items"))]
+    pub max_items: u32,
+
+    // Not part of the serialized representation at all. Its type doesn't need
+    // to be `Serializable`, because the field is left out of the generated
+    // types entirely.
+    #[serde(skip)]
+    pub cache: Cache,
+
+    // Can be passed in, but is never passed back out.
+    #[serde(skip_serializing)]
+    pub password: String,
+
+    // Can be passed out, but is ignored when passed in.
+    #[serde(skip_deserializing)]
+    pub revision: u32,
+
+    pub label: Label,
+    pub priority: Priority,
+    pub range: Range,
+    pub email: Email,
+}
+
+// Only used by a skipped field, so it doesn't derive `Serializable`.
+#[derive(Default)]
+pub struct Cache {
+    pub entries: Vec<String>,
+}
+
+// Serialized as a plain string, just like a newtype.
+#[derive(Default, Serializable, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Label {
+    pub value: String,
+}
+
+#[derive(Default, Serializable, Serialize, Deserialize)]
+pub enum Priority {
+    #[default]
+    Low,
+    // Will be serialized as "High", but "Urgent" is accepted as well.
+    #[serde(alias = "Urgent")]
+    High,
+    // Not part of the serialized representation at all.
+    #[serde(skip)]
+    Internal,
+    // Can be passed in, but is never passed back out.
+    #[serde(skip_serializing)]
+    Legacy,
+    // Can be passed out, but is rejected when passed in.
+    #[serde(skip_deserializing)]
+    Pending,
+    // Any variant this enum doesn't know about is deserialized as `Unknown`.
+    #[serde(other)]
+    Unknown,
+}
+
+// Serialized with a "type" property that holds its (renamed) name. The
+// `bound` and `crate` attributes don't affect the serialized representation.
+#[derive(Default, Serializable, Serialize, Deserialize)]
+#[serde(tag = "type", rename = "range", bound = "", crate = "serde")]
+pub struct Range {
+    pub start: u32,
+    pub end: u32,
+}
+
+// Converted from and into a string, so it is serialized as one.
+#[derive(Clone, Default, Serializable, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
+pub struct Email {
+    pub address: String,
+}
+
+impl From<String> for Email {
+    fn from(address: String) -> Self {
+        Self { address }
+    }
+}
+
+impl From<Email> for String {
+    fn from(email: Email) -> Self {
+        email.address
+    }
+}
@@ -0,0 +1,39 @@
+use fp_bindgen::prelude::Serializable;
+
+// Tuple structs and unit structs are serialized the same way Serde does:
+// newtypes (tuple structs with a single field) are serialized transparently as
+// their field, other tuple structs are serialized as arrays, and unit structs
+// are serialized as `null`.
+
+/// Identifier of a user.
+///
+/// In TypeScript, this becomes a branded type, so it cannot be mixed up with
+/// other strings by accident.
+#[derive(Serializable)]
+pub struct UserId(pub String);
+
+/// Newtypes may also wrap primitives.
+#[derive(Serializable)]
+pub struct Meters(pub f64);
+
+/// Newtypes may be generic too.
+#[derive(Serializable)]
+pub struct Wrapper<T>(pub T);
+
+/// Color in RGB format.
+#[derive(Serializable)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+/// Marker without any data.
+#[derive(Serializable)]
+pub struct Marker;
+
+#[derive(Serializable)]
+pub struct StructWithTupleStructs {
+    pub user_id: UserId,
+    pub optional_user_id: Option<UserId>,
+    pub distance: Meters,
+    pub wrapped: Wrapper<u32>,
+    pub color: Rgb,
+    pub marker: Marker,
+}
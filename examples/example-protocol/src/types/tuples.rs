@@ -0,0 +1,12 @@
+use fp_bindgen::prelude::Serializable;
+
+// Anonymous tuples can be used in function signatures, as well as in the fields
+// of other types. They are serialized as arrays, which become tuple types in
+// TypeScript.
+
+#[derive(Serializable)]
+pub struct StructWithTuples {
+    pub pair: (i64, i64),
+    pub nested: Vec<(String, (u8, bool))>,
+    pub single: (String,),
+}
@@ -0,0 +1,28 @@
+use fp_bindgen::prelude::Serializable;
+
+// Enums that may gain new variants in future versions of the protocol can
+// specify an `#[fp(unknown_variant = "...")]` annotation. The generated
+// bindings then get an additional variant with the given name, which captures
+// any variant that is not known to their version of the protocol. This way,
+// hosts and plugins can be upgraded independently of one another.
+//
+// The generated Rust bindings store the serialized value of an unknown variant
+// as an `rmpv::Value`, so it can be passed on unchanged, while the TypeScript
+// types include an explicit case for it.
+
+/// A shape, which may be one we don't know about yet.
+#[derive(Serializable)]
+#[fp(unknown_variant = "Unknown")]
+pub enum Shape {
+    Circle { radius: f64 },
+    Point,
+    Square(f64),
+}
+
+/// Internally tagged equivalent of `Shape`.
+#[derive(Serializable)]
+#[fp(tag = "type", unknown_variant = "Unknown")]
+pub enum TaggedShape {
+    Circle { radius: f64 },
+    Point,
+}
@@ -14,6 +14,17 @@ pub enum ReduxAction {
     UpdateTitle { title: String },
 }
 
+/// Metadata about a dispatched Redux action.
+///
+/// This type does not implement `Serializable` itself. Instead, the example
+/// protocol declares a mirror for it using `#[fp(remote = "...")]`.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ActionMeta {
+    pub sequence_number: u64,
+    pub origin: String,
+}
+
 /// Example for how Redux state could be stored in Rust.
 ///
 /// Any fields that do not implement `Copy` are wrapped in `Rc` so that we can
@@ -0,0 +1,35 @@
+use serde_bytes::ByteBuf;
+
+/// Binary types that are passed across the Wasm boundary as raw buffers.
+///
+/// When these types are used directly as function arguments or return values,
+/// their bytes are copied into linear memory as is, without being encoded as
+/// MessagePack.
+pub trait BinaryValue: Sized {
+    /// Converts the value into a buffer, without copying if possible.
+    fn into_vec(self) -> Vec<u8>;
+
+    /// Creates the value from a buffer, without copying if possible.
+    fn from_vec(buffer: Vec<u8>) -> Self;
+}
+
+impl BinaryValue for ByteBuf {
+    fn into_vec(self) -> Vec<u8> {
+        ByteBuf::into_vec(self)
+    }
+
+    fn from_vec(buffer: Vec<u8>) -> Self {
+        ByteBuf::from(buffer)
+    }
+}
+
+#[cfg(feature = "bytes")]
+impl BinaryValue for bytes::Bytes {
+    fn into_vec(self) -> Vec<u8> {
+        self.into()
+    }
+
+    fn from_vec(buffer: Vec<u8>) -> Self {
+        buffer.into()
+    }
+}
@@ -10,3 +10,141 @@ pub fn to_fat_ptr(ptr: *const u8, len: u32) -> FatPtr {
 pub fn from_fat_ptr(ptr: FatPtr) -> (*const u8, u32) {
     ((ptr >> 32) as *const u8, (ptr & 0xffffffff) as u32)
 }
+
+/// Linear memory of a guest, from which the host imports values.
+#[cfg(any(feature = "host", test))]
+pub(crate) trait GuestMemory {
+    /// Returns the current contents of the guest's memory.
+    ///
+    /// # Safety
+    ///
+    /// The returned slice may only be used for as long as the guest doesn't
+    /// run, since running the guest may grow or mutate its memory.
+    unsafe fn data(&self) -> &[u8];
+
+    /// Frees the memory that is referred to by the given fat pointer.
+    fn free(&self, fat_ptr: FatPtr);
+}
+
+/// Deserializes a value straight from the guest's memory, and only frees the
+/// memory it was read from afterwards.
+#[cfg(any(feature = "host", test))]
+pub(crate) fn import_from_memory<T: serde::de::DeserializeOwned>(
+    memory: &impl GuestMemory,
+    fat_ptr: FatPtr,
+) -> T {
+    let value = {
+        let data = unsafe { memory.data() };
+        let range = to_guest_range(fat_ptr, data.len() as u64);
+        let mut deserializer = rmp_serde::Deserializer::new(&data[range]).with_human_readable();
+        T::deserialize(&mut deserializer).unwrap()
+    };
+
+    memory.free(fat_ptr);
+
+    value
+}
+
+/// Returns the range of bytes in a guest's linear memory of `data_size` bytes
+/// that is referred to by the given fat pointer.
+#[cfg(any(feature = "host", test))]
+pub(crate) fn to_guest_range(fat_ptr: FatPtr, data_size: u64) -> std::ops::Range<usize> {
+    let start = fat_ptr >> 32;
+    let len = fat_ptr & 0xffffffff;
+    if len & 0xff000000 != 0 {
+        panic!("Unknown extension bits");
+    }
+
+    // Both halves of the fat pointer are at most 32 bits, so this cannot
+    // overflow:
+    let end = start + len;
+    if end > data_size {
+        panic!("Pointer out of bounds ({}..{})", start, end);
+    }
+
+    start as usize..end as usize
+}
+
+#[cfg(test)]
+mod test {
+    use super::{import_from_memory, to_guest_range, FatPtr, GuestMemory};
+    use std::cell::RefCell;
+
+    struct TestMemory {
+        data: RefCell<Vec<u8>>,
+        freed: RefCell<Vec<FatPtr>>,
+    }
+
+    impl GuestMemory for TestMemory {
+        unsafe fn data(&self) -> &[u8] {
+            &*self.data.as_ptr()
+        }
+
+        fn free(&self, fat_ptr: FatPtr) {
+            // Clobber the memory, so that reading from it after it has been
+            // freed would be noticed:
+            self.data.borrow_mut().fill(0);
+            self.freed.borrow_mut().push(fat_ptr);
+        }
+    }
+
+    fn fat_ptr(ptr: u32, len: u32) -> FatPtr {
+        (ptr as FatPtr) << 32 | (len as FatPtr)
+    }
+
+    #[test]
+    fn test_to_guest_range() {
+        assert_eq!(to_guest_range(fat_ptr(16, 8), 32), 16..24);
+        assert_eq!(to_guest_range(fat_ptr(16, 16), 32), 16..32);
+        assert_eq!(to_guest_range(fat_ptr(32, 0), 32), 32..32);
+    }
+
+    #[test]
+    #[should_panic(expected = "Pointer out of bounds")]
+    fn test_to_guest_range_out_of_bounds() {
+        to_guest_range(fat_ptr(16, 17), 32);
+    }
+
+    #[test]
+    #[should_panic(expected = "Pointer out of bounds")]
+    fn test_to_guest_range_overflow() {
+        // The end of the range doesn't fit in 32 bits, which is the maximum
+        // size of a guest's memory:
+        to_guest_range(fat_ptr(u32::MAX, 0xffffff), u32::MAX as u64 + 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "Unknown extension bits")]
+    fn test_to_guest_range_extension_bits() {
+        to_guest_range(fat_ptr(0, 0x01000000), u64::MAX);
+    }
+
+    #[test]
+    fn test_import_from_memory_frees_after_deserializing() {
+        let value = (42u32, "Hello, world!".to_owned());
+        let serialized = rmp_serde::to_vec(&value).unwrap();
+        let mut data = vec![0xff; 8];
+        data.extend_from_slice(&serialized);
+
+        let memory = TestMemory {
+            data: RefCell::new(data),
+            freed: RefCell::new(Vec::new()),
+        };
+        let ptr = fat_ptr(8, serialized.len() as u32);
+
+        let imported: (u32, String) = import_from_memory(&memory, ptr);
+        assert_eq!(imported, value);
+        assert_eq!(*memory.freed.borrow(), vec![ptr]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Pointer out of bounds")]
+    fn test_import_from_memory_out_of_bounds() {
+        let memory = TestMemory {
+            data: RefCell::new(vec![0; 8]),
+            freed: RefCell::new(Vec::new()),
+        };
+
+        let _: u32 = import_from_memory(&memory, fat_ptr(0, 16));
+    }
+}
@@ -1,7 +1,6 @@
-use crate::common::mem::*;
+use crate::common::{binary::BinaryValue, mem::*};
 use rmp_serde::{Deserializer, Serializer};
 use serde::{Deserialize, Serialize};
-use std::alloc::Layout;
 
 #[doc(hidden)]
 pub fn export_value_to_host<T: Serialize>(value: &T) -> FatPtr {
@@ -14,6 +13,24 @@ pub fn export_value_to_host<T: Serialize>(value: &T) -> FatPtr {
         )
         .expect("Serialization error");
 
+    export_buffer_to_host(buffer)
+}
+
+/// Passes the value's bytes to the host as is, without MessagePack encoding.
+#[doc(hidden)]
+pub fn export_binary_to_host<T: BinaryValue>(value: T) -> FatPtr {
+    export_buffer_to_host(value.into_vec())
+}
+
+/// Passes the borrowed bytes to the host as is, without MessagePack encoding.
+///
+/// The bytes are copied, because the host frees the buffer once it has read it.
+#[doc(hidden)]
+pub fn export_slice_to_host(slice: &[u8]) -> FatPtr {
+    export_buffer_to_host(slice.to_vec())
+}
+
+fn export_buffer_to_host(mut buffer: Vec<u8>) -> FatPtr {
     let len = buffer.len();
 
     if buffer.capacity() > len {
@@ -61,17 +78,34 @@ pub unsafe fn import_value_from_host<'de, T: Deserialize<'de>>(fat_ptr: FatPtr)
     value
 }
 
-const MALLOC_ALIGNMENT: usize = 16;
+/// # Safety
+///
+/// This function is only safe if passed a valid pointer given to us by the
+/// host. After this call, the pointer is no longer valid.
+#[doc(hidden)]
+pub unsafe fn import_binary_from_host<T: BinaryValue>(fat_ptr: FatPtr) -> T {
+    let (ptr, len) = from_fat_ptr(fat_ptr);
+    if len & 0xff000000 != 0 {
+        panic!("Unknown extension bits");
+    }
+
+    // The buffer was allocated by `__fp_malloc()` as a `Vec<u8>` with an exact
+    // capacity, so we can take ownership of it instead of copying it. This
+    // also means we don't need to call `__fp_free()` ourselves:
+    let buffer = Vec::from_raw_parts(ptr as *mut u8, len as usize, len as usize);
+
+    T::from_vec(buffer)
+}
 
+/// Buffers are allocated as a `Vec<u8>`, so that binary values can take
+/// ownership of the buffers passed to us by the host.
 #[doc(hidden)]
 #[no_mangle]
 pub fn __fp_malloc(len: u32) -> FatPtr {
-    let ptr = unsafe {
-        std::alloc::alloc(
-            Layout::from_size_align(len as usize, MALLOC_ALIGNMENT)
-                .expect("Allocation failed unexpectedly, check requested allocation size"),
-        )
-    };
+    // `Vec::with_capacity()` is guaranteed to allocate the exact capacity:
+    let mut buffer = Vec::<u8>::with_capacity(len as usize);
+    let ptr = buffer.as_mut_ptr();
+    std::mem::forget(buffer);
     to_fat_ptr(ptr, len)
 }
 
@@ -95,9 +129,5 @@ pub unsafe fn __fp_free(ptr: FatPtr) {
         "__fp_free() failed: unknown extension bits"
     );
 
-    std::alloc::dealloc(
-        ptr as *mut u8,
-        Layout::from_size_align(len as usize, MALLOC_ALIGNMENT)
-            .expect("Deallocation failed unexpectedly, check the pointer is valid"),
-    );
+    drop(Vec::from_raw_parts(ptr as *mut u8, 0, len as usize));
 }
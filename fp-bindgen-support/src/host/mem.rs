@@ -1,8 +1,10 @@
-use super::{io::to_wasm_ptr, runtime::RuntimeInstanceData};
-use crate::common::mem::FatPtr;
-use rmp_serde::{decode::ReadReader, Deserializer, Serializer};
-use serde::{Deserialize, Serialize};
-use wasmer::WasmCell;
+use super::runtime::RuntimeInstanceData;
+use crate::common::{
+    binary::BinaryValue,
+    mem::{import_from_memory, to_guest_range, FatPtr, GuestMemory},
+};
+use rmp_serde::Serializer;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
 
 /// Serialize the given value to MessagePack
 pub fn serialize_to_vec<T: Serialize>(value: &T) -> Vec<u8> {
@@ -20,16 +22,16 @@ pub fn deserialize_from_slice<'a, T: Deserialize<'a>>(slice: &'a [u8]) -> T {
     T::deserialize(&mut deserializer).unwrap()
 }
 
-/// Serialize an object from the linear memory and after that free up the memory
-pub fn import_from_guest<'de, T: Deserialize<'de>>(
-    env: &RuntimeInstanceData,
-    fat_ptr: FatPtr,
-) -> T {
-    let value = import_from_guest_raw(env, fat_ptr);
-
-    let mut deserializer =
-        Deserializer::<ReadReader<&[u8]>>::new(value.as_ref()).with_human_readable();
-    T::deserialize(&mut deserializer).unwrap()
+/// Deserialize an object from the linear memory and after that free up the
+/// memory.
+///
+/// The value is deserialized straight from the guest's memory, without first
+/// copying it into an intermediate buffer. This is safe because the guest
+/// cannot run (and thus cannot grow or mutate its memory) while we're
+/// deserializing, and the memory is only freed afterwards. Because of this,
+/// the deserialized value may not borrow from the buffer it was read from.
+pub fn import_from_guest<T: DeserializeOwned>(env: &RuntimeInstanceData, fat_ptr: FatPtr) -> T {
+    import_from_memory(env, fat_ptr)
 }
 
 /// Retrieve a serialized object from the linear memory as a Vec<u8> and free up
@@ -43,16 +45,10 @@ pub fn import_from_guest_raw(env: &RuntimeInstanceData, fat_ptr: FatPtr) -> Vec<
         return Vec::new();
     }
 
-    let memory = unsafe { env.memory.get_unchecked() };
-
-    let (ptr, len) = to_wasm_ptr::<u8>(fat_ptr);
-    if len & 0xff000000 != 0 {
-        panic!("Unknown extension bits");
-    }
-
-    let value: Vec<u8> = {
-        let view = ptr.deref(memory, 0, len).unwrap();
-        view.iter().map(WasmCell::get).collect()
+    let value = {
+        let memory = unsafe { env.memory.get_unchecked() };
+        let range = to_guest_range(fat_ptr, memory.data_size());
+        unsafe { memory.data_unchecked()[range].to_vec() }
     };
 
     env.free(fat_ptr);
@@ -60,6 +56,12 @@ pub fn import_from_guest_raw(env: &RuntimeInstanceData, fat_ptr: FatPtr) -> Vec<
     value
 }
 
+/// Retrieve a binary value from the linear memory, without MessagePack
+/// decoding, and free up the memory it was using.
+pub fn import_binary_from_guest<T: BinaryValue>(env: &RuntimeInstanceData, fat_ptr: FatPtr) -> T {
+    T::from_vec(import_from_guest_raw(env, fat_ptr))
+}
+
 /// Serialize a value and put it in linear memory.
 pub fn export_to_guest<T: Serialize>(env: &RuntimeInstanceData, value: &T) -> FatPtr {
     export_to_guest_raw(env, rmp_serde::to_vec(value).unwrap())
@@ -67,8 +69,6 @@ pub fn export_to_guest<T: Serialize>(env: &RuntimeInstanceData, value: &T) -> Fa
 
 /// Copy the buffer into linear memory.
 pub fn export_to_guest_raw(env: &RuntimeInstanceData, buffer: Vec<u8>) -> FatPtr {
-    let memory = unsafe { env.memory.get_unchecked() };
-
     let len = buffer.len() as u32;
 
     // Make sure the length marker does not run into our extension bits:
@@ -78,12 +78,26 @@ pub fn export_to_guest_raw(env: &RuntimeInstanceData, buffer: Vec<u8>) -> FatPtr
 
     let fat_ptr = env.malloc(len);
 
-    let (ptr, len) = to_wasm_ptr(fat_ptr);
+    // Allocating may have grown the memory, so we may only look up the guest's
+    // memory *after* the call to `malloc()`:
+    let memory = unsafe { env.memory.get_unchecked() };
+    let range = to_guest_range(fat_ptr, memory.data_size());
+    unsafe { memory.data_unchecked_mut()[range].copy_from_slice(&buffer) };
+
+    fat_ptr
+}
+
+/// Put a binary value in linear memory as is, without MessagePack encoding.
+pub fn export_binary_to_guest<T: BinaryValue>(env: &RuntimeInstanceData, value: T) -> FatPtr {
+    export_to_guest_raw(env, value.into_vec())
+}
 
-    let values = ptr.deref(memory, 0, len).unwrap();
-    for (i, val) in buffer.iter().enumerate() {
-        values[i].set(*val);
+impl GuestMemory for RuntimeInstanceData {
+    unsafe fn data(&self) -> &[u8] {
+        self.memory.get_unchecked().data_unchecked()
     }
 
-    fat_ptr
+    fn free(&self, fat_ptr: FatPtr) {
+        RuntimeInstanceData::free(self, fat_ptr)
+    }
 }
@@ -1,8 +1,18 @@
 use http::{
+    header::{GetAll, HeaderName},
     uri::{Scheme, Uri},
-    Method,
+    HeaderMap, HeaderValue, Method, Request, Response, StatusCode, Version,
+};
+use serde::{
+    de::{self, MapAccess, Visitor},
+    ser::SerializeMap,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+use serde_bytes::{ByteBuf, Bytes};
+use std::{
+    fmt,
+    ops::{Deref, DerefMut},
 };
-use serde::{de, Deserialize, Deserializer, Serializer};
 
 pub fn serialize_http_method<S>(method: &Method, serializer: S) -> Result<S::Ok, S::Error>
 where
@@ -100,3 +110,679 @@ impl From<SchemeDef> for Scheme {
         }
     }
 }
+
+/// Serializes a `HeaderMap` as a map from header names to their values. Names
+/// with multiple values are serialized with an array of values.
+pub fn serialize_header_map<S>(headers: &HeaderMap, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    let mut map = serializer.serialize_map(Some(headers.keys_len()))?;
+    for name in headers.keys() {
+        map.serialize_entry(name.as_str(), &HeaderValues(headers.get_all(name)))?;
+    }
+    map.end()
+}
+
+pub fn deserialize_header_map<'de, D>(deserializer: D) -> Result<HeaderMap, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_map(HeaderMapVisitor)
+}
+
+struct HeaderValues<'a>(GetAll<'a, HeaderValue>);
+
+impl Serialize for HeaderValues<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let values: Vec<_> = self.0.iter().map(HeaderValueRef).collect();
+        match values.as_slice() {
+            [value] => value.serialize(serializer),
+            values => serializer.collect_seq(values),
+        }
+    }
+}
+
+struct HeaderValueRef<'a>(&'a HeaderValue);
+
+impl Serialize for HeaderValueRef<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize_header_value(self.0, serializer)
+    }
+}
+
+struct HeaderMapVisitor;
+
+impl<'de> Visitor<'de> for HeaderMapVisitor {
+    type Value = HeaderMap;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a map of HTTP header names to values")
+    }
+
+    fn visit_map<A>(self, mut access: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut headers = HeaderMap::with_capacity(access.size_hint().unwrap_or_default());
+        while let Some((name, values)) = access.next_entry::<String, HeaderValuesDef>()? {
+            let name = HeaderName::from_bytes(name.as_bytes()).map_err(|_| {
+                de::Error::invalid_value(de::Unexpected::Str(&name), &"a valid header name")
+            })?;
+            match values {
+                HeaderValuesDef::One(value) => {
+                    headers.append(name, value.0);
+                }
+                HeaderValuesDef::Many(values) => {
+                    for value in values {
+                        headers.append(&name, value.0);
+                    }
+                }
+            }
+        }
+        Ok(headers)
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum HeaderValuesDef {
+    One(HeaderValueDef),
+    Many(Vec<HeaderValueDef>),
+}
+
+struct HeaderValueDef(HeaderValue);
+
+impl<'de> Deserialize<'de> for HeaderValueDef {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_header_value(deserializer).map(Self)
+    }
+}
+
+/// Serializes a `HeaderValue` as a string, or as bytes if the value contains
+/// characters that are not visible ASCII.
+pub fn serialize_header_value<S>(value: &HeaderValue, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    match value.to_str() {
+        Ok(value) => serializer.serialize_str(value),
+        Err(_) => serializer.serialize_bytes(value.as_bytes()),
+    }
+}
+
+pub fn deserialize_header_value<'de, D>(deserializer: D) -> Result<HeaderValue, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_any(HeaderValueVisitor)
+}
+
+struct HeaderValueVisitor;
+
+impl<'de> Visitor<'de> for HeaderValueVisitor {
+    type Value = HeaderValue;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a valid header value")
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        HeaderValue::from_str(value)
+            .map_err(|_| de::Error::invalid_value(de::Unexpected::Str(value), &self))
+    }
+
+    fn visit_bytes<E>(self, value: &[u8]) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        HeaderValue::from_bytes(value)
+            .map_err(|_| de::Error::invalid_value(de::Unexpected::Bytes(value), &self))
+    }
+}
+
+pub fn serialize_status_code<S>(status: &StatusCode, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_u16(status.as_u16())
+}
+
+pub fn deserialize_status_code<'de, D>(deserializer: D) -> Result<StatusCode, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let code = u16::deserialize(deserializer)?;
+    StatusCode::from_u16(code).map_err(|_| {
+        de::Error::invalid_value(
+            de::Unexpected::Unsigned(code.into()),
+            &"a status code between 100 and 999",
+        )
+    })
+}
+
+pub fn serialize_http_version<S>(version: &Version, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    // The `Debug` implementation formats versions as `HTTP/1.1`, etc.:
+    serializer.serialize_str(&format!("{:?}", version))
+}
+
+pub fn deserialize_http_version<'de, D>(deserializer: D) -> Result<Version, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    VersionDef::deserialize(deserializer).map(Version::from)
+}
+
+#[derive(Deserialize)]
+enum VersionDef {
+    #[serde(rename = "HTTP/0.9")]
+    Http09,
+    #[serde(rename = "HTTP/1.0")]
+    Http10,
+    #[serde(rename = "HTTP/1.1")]
+    Http11,
+    #[serde(rename = "HTTP/2.0")]
+    H2,
+    #[serde(rename = "HTTP/3.0")]
+    H3,
+}
+
+impl From<VersionDef> for Version {
+    fn from(def: VersionDef) -> Version {
+        match def {
+            VersionDef::Http09 => Version::HTTP_09,
+            VersionDef::Http10 => Version::HTTP_10,
+            VersionDef::Http11 => Version::HTTP_11,
+            VersionDef::H2 => Version::HTTP_2,
+            VersionDef::H3 => Version::HTTP_3,
+        }
+    }
+}
+
+/// Serde helpers for `http::Request<ByteBuf>`, for use with
+/// `#[serde(with = "fp_bindgen_support::http::request")]`.
+pub mod request {
+    use super::*;
+
+    #[derive(Serialize)]
+    struct RequestRef<'a> {
+        #[serde(serialize_with = "serialize_http_method")]
+        method: &'a Method,
+        #[serde(serialize_with = "serialize_uri")]
+        uri: &'a Uri,
+        #[serde(serialize_with = "serialize_http_version")]
+        version: Version,
+        #[serde(serialize_with = "serialize_header_map")]
+        headers: &'a HeaderMap,
+        body: &'a Bytes,
+    }
+
+    #[derive(Deserialize)]
+    struct RequestDef {
+        #[serde(deserialize_with = "deserialize_http_method")]
+        method: Method,
+        #[serde(deserialize_with = "deserialize_uri")]
+        uri: Uri,
+        #[serde(default, deserialize_with = "deserialize_http_version")]
+        version: Version,
+        #[serde(default, deserialize_with = "deserialize_header_map")]
+        headers: HeaderMap,
+        #[serde(default)]
+        body: ByteBuf,
+    }
+
+    pub fn serialize<S>(request: &Request<ByteBuf>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        RequestRef {
+            method: request.method(),
+            uri: request.uri(),
+            version: request.version(),
+            headers: request.headers(),
+            body: Bytes::new(request.body()),
+        }
+        .serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Request<ByteBuf>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let def = RequestDef::deserialize(deserializer)?;
+        let mut request = Request::new(def.body);
+        *request.method_mut() = def.method;
+        *request.uri_mut() = def.uri;
+        *request.version_mut() = def.version;
+        *request.headers_mut() = def.headers;
+        Ok(request)
+    }
+}
+
+/// Serde helpers for `http::Response<ByteBuf>`, for use with
+/// `#[serde(with = "fp_bindgen_support::http::response")]`.
+pub mod response {
+    use super::*;
+
+    #[derive(Serialize)]
+    struct ResponseRef<'a> {
+        #[serde(serialize_with = "serialize_status_code")]
+        status: StatusCode,
+        #[serde(serialize_with = "serialize_http_version")]
+        version: Version,
+        #[serde(serialize_with = "serialize_header_map")]
+        headers: &'a HeaderMap,
+        body: &'a Bytes,
+    }
+
+    #[derive(Deserialize)]
+    struct ResponseDef {
+        #[serde(deserialize_with = "deserialize_status_code")]
+        status: StatusCode,
+        #[serde(default, deserialize_with = "deserialize_http_version")]
+        version: Version,
+        #[serde(default, deserialize_with = "deserialize_header_map")]
+        headers: HeaderMap,
+        #[serde(default)]
+        body: ByteBuf,
+    }
+
+    pub fn serialize<S>(response: &Response<ByteBuf>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        ResponseRef {
+            status: response.status(),
+            version: response.version(),
+            headers: response.headers(),
+            body: Bytes::new(response.body()),
+        }
+        .serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Response<ByteBuf>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let def = ResponseDef::deserialize(deserializer)?;
+        let mut response = Response::new(def.body);
+        *response.status_mut() = def.status;
+        *response.version_mut() = def.version;
+        *response.headers_mut() = def.headers;
+        Ok(response)
+    }
+}
+
+/// Wrapper around `http::Request<ByteBuf>` that implements `Serialize` and
+/// `Deserialize`, so that requests can be passed directly as function
+/// arguments and return values, or be wrapped in an `Option`.
+///
+/// Generated bindings use this type wherever a protocol uses
+/// `http::Request<ByteBuf>`.
+#[derive(Debug, Default)]
+pub struct HttpRequest(pub Request<ByteBuf>);
+
+impl HttpRequest {
+    pub fn into_inner(self) -> Request<ByteBuf> {
+        self.0
+    }
+}
+
+impl Deref for HttpRequest {
+    type Target = Request<ByteBuf>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for HttpRequest {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl From<Request<ByteBuf>> for HttpRequest {
+    fn from(request: Request<ByteBuf>) -> Self {
+        Self(request)
+    }
+}
+
+impl From<HttpRequest> for Request<ByteBuf> {
+    fn from(request: HttpRequest) -> Self {
+        request.0
+    }
+}
+
+impl Serialize for HttpRequest {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        request::serialize(&self.0, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for HttpRequest {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        request::deserialize(deserializer).map(Self)
+    }
+}
+
+/// Wrapper around `http::Response<ByteBuf>` that implements `Serialize` and
+/// `Deserialize`, so that responses can be passed directly as function
+/// arguments and return values, or be wrapped in an `Option`.
+///
+/// Generated bindings use this type wherever a protocol uses
+/// `http::Response<ByteBuf>`.
+#[derive(Debug, Default)]
+pub struct HttpResponse(pub Response<ByteBuf>);
+
+impl HttpResponse {
+    pub fn into_inner(self) -> Response<ByteBuf> {
+        self.0
+    }
+}
+
+impl Deref for HttpResponse {
+    type Target = Response<ByteBuf>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for HttpResponse {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl From<Response<ByteBuf>> for HttpResponse {
+    fn from(response: Response<ByteBuf>) -> Self {
+        Self(response)
+    }
+}
+
+impl From<HttpResponse> for Response<ByteBuf> {
+    fn from(response: HttpResponse) -> Self {
+        response.0
+    }
+}
+
+impl Serialize for HttpResponse {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        response::serialize(&self.0, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for HttpResponse {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        response::deserialize(deserializer).map(Self)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::fmt::Debug;
+
+    fn round_trip<T>(value: &T) -> T
+    where
+        T: Serialize + for<'de> Deserialize<'de>,
+    {
+        let bytes = rmp_serde::to_vec_named(value).unwrap();
+        rmp_serde::from_slice(&bytes).unwrap()
+    }
+
+    fn assert_round_trip<T>(value: T)
+    where
+        T: Debug + PartialEq + Serialize + for<'de> Deserialize<'de>,
+    {
+        assert_eq!(round_trip(&value), value);
+    }
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct MethodWrapper(
+        #[serde(
+            serialize_with = "serialize_http_method",
+            deserialize_with = "deserialize_http_method"
+        )]
+        Method,
+    );
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct UriWrapper(
+        #[serde(serialize_with = "serialize_uri", deserialize_with = "deserialize_uri")] Uri,
+    );
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct SchemeWrapper(
+        #[serde(
+            serialize_with = "serialize_uri_scheme",
+            deserialize_with = "deserialize_uri_scheme"
+        )]
+        Scheme,
+    );
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct HeaderMapWrapper(
+        #[serde(
+            serialize_with = "serialize_header_map",
+            deserialize_with = "deserialize_header_map"
+        )]
+        HeaderMap,
+    );
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct HeaderValueWrapper(
+        #[serde(
+            serialize_with = "serialize_header_value",
+            deserialize_with = "deserialize_header_value"
+        )]
+        HeaderValue,
+    );
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct StatusCodeWrapper(
+        #[serde(
+            serialize_with = "serialize_status_code",
+            deserialize_with = "deserialize_status_code"
+        )]
+        StatusCode,
+    );
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct VersionWrapper(
+        #[serde(
+            serialize_with = "serialize_http_version",
+            deserialize_with = "deserialize_http_version"
+        )]
+        Version,
+    );
+
+    #[derive(Deserialize, Serialize)]
+    struct RequestWrapper(#[serde(with = "request")] Request<ByteBuf>);
+
+    #[derive(Deserialize, Serialize)]
+    struct ResponseWrapper(#[serde(with = "response")] Response<ByteBuf>);
+
+    #[test]
+    fn test_method_round_trip() {
+        for method in [
+            Method::GET,
+            Method::POST,
+            Method::PUT,
+            Method::DELETE,
+            Method::HEAD,
+            Method::OPTIONS,
+            Method::CONNECT,
+            Method::PATCH,
+            Method::TRACE,
+        ] {
+            assert_round_trip(MethodWrapper(method));
+        }
+    }
+
+    #[test]
+    fn test_uri_round_trip() {
+        assert_round_trip(UriWrapper(
+            "https://fiberplane.dev/path?query=1".parse().unwrap(),
+        ));
+        assert_round_trip(UriWrapper("/relative".parse().unwrap()));
+    }
+
+    #[test]
+    fn test_scheme_round_trip() {
+        assert_round_trip(SchemeWrapper(Scheme::HTTP));
+        assert_round_trip(SchemeWrapper(Scheme::HTTPS));
+    }
+
+    #[test]
+    fn test_header_value_round_trip() {
+        assert_round_trip(HeaderValueWrapper(HeaderValue::from_static(
+            "application/json",
+        )));
+
+        // Values that are not visible ASCII are serialized as bytes:
+        let value = HeaderValue::from_bytes(b"caf\xc3\xa9").unwrap();
+        let bytes = rmp_serde::to_vec(&HeaderValueWrapper(value.clone())).unwrap();
+        assert_eq!(bytes[0], 0xc4, "expected a MessagePack bin value");
+        assert_round_trip(HeaderValueWrapper(value));
+    }
+
+    #[test]
+    fn test_header_map_round_trip() {
+        let mut headers = HeaderMap::new();
+        headers.insert("content-type", HeaderValue::from_static("text/plain"));
+        headers.append("set-cookie", HeaderValue::from_static("a=1"));
+        headers.append("set-cookie", HeaderValue::from_static("b=2"));
+        headers.insert("x-binary", HeaderValue::from_bytes(b"\xff\xfe").unwrap());
+        assert_round_trip(HeaderMapWrapper(headers));
+        assert_round_trip(HeaderMapWrapper(HeaderMap::new()));
+    }
+
+    #[test]
+    fn test_status_code_round_trip() {
+        for status in [
+            StatusCode::OK,
+            StatusCode::NOT_FOUND,
+            StatusCode::IM_A_TEAPOT,
+        ] {
+            assert_round_trip(StatusCodeWrapper(status));
+        }
+    }
+
+    #[test]
+    fn test_version_round_trip() {
+        for version in [
+            Version::HTTP_09,
+            Version::HTTP_10,
+            Version::HTTP_11,
+            Version::HTTP_2,
+            Version::HTTP_3,
+        ] {
+            assert_round_trip(VersionWrapper(version));
+        }
+    }
+
+    #[test]
+    fn test_request_round_trip() {
+        let mut request = Request::new(ByteBuf::from(b"body".to_vec()));
+        *request.method_mut() = Method::POST;
+        *request.uri_mut() = "https://fiberplane.dev/".parse().unwrap();
+        *request.version_mut() = Version::HTTP_2;
+        request
+            .headers_mut()
+            .insert("content-type", HeaderValue::from_static("text/plain"));
+
+        let actual = round_trip(&RequestWrapper(request)).0;
+        assert_eq!(actual.method(), Method::POST);
+        assert_eq!(actual.uri(), "https://fiberplane.dev/");
+        assert_eq!(actual.version(), Version::HTTP_2);
+        assert_eq!(actual.headers()["content-type"], "text/plain");
+        assert_eq!(actual.body().as_slice(), b"body");
+    }
+
+    #[test]
+    fn test_response_round_trip() {
+        let mut response = Response::new(ByteBuf::from(b"not found".to_vec()));
+        *response.status_mut() = StatusCode::NOT_FOUND;
+        *response.version_mut() = Version::HTTP_10;
+        response
+            .headers_mut()
+            .append("vary", HeaderValue::from_static("accept"));
+        response
+            .headers_mut()
+            .append("vary", HeaderValue::from_static("origin"));
+
+        let actual = round_trip(&ResponseWrapper(response)).0;
+        assert_eq!(actual.status(), StatusCode::NOT_FOUND);
+        assert_eq!(actual.version(), Version::HTTP_10);
+        assert_eq!(
+            actual.headers().get_all("vary").iter().collect::<Vec<_>>(),
+            vec!["accept", "origin"]
+        );
+        assert_eq!(actual.body().as_slice(), b"not found");
+    }
+
+    #[test]
+    fn test_request_newtype_round_trip() {
+        let mut request = Request::new(ByteBuf::from(b"body".to_vec()));
+        *request.method_mut() = Method::PATCH;
+        *request.uri_mut() = "/notebooks/1".parse().unwrap();
+
+        let actual = round_trip(&HttpRequest::from(request));
+        assert_eq!(actual.method(), Method::PATCH);
+        assert_eq!(actual.uri(), "/notebooks/1");
+        assert_eq!(actual.body().as_slice(), b"body");
+
+        let actual = round_trip(&Some(HttpRequest::default()));
+        assert_eq!(
+            actual.map(|request| request.method().clone()),
+            Some(Method::GET)
+        );
+        assert!(round_trip(&None::<HttpRequest>).is_none());
+    }
+
+    #[test]
+    fn test_response_newtype_round_trip() {
+        let mut response = Response::new(ByteBuf::from(b"created".to_vec()));
+        *response.status_mut() = StatusCode::CREATED;
+
+        let actual = round_trip(&HttpResponse::from(response));
+        assert_eq!(actual.status(), StatusCode::CREATED);
+        assert_eq!(actual.body().as_slice(), b"created");
+
+        let actual = round_trip(&Some(HttpResponse::default()));
+        assert_eq!(
+            actual.map(|response| response.status()),
+            Some(StatusCode::OK)
+        );
+        assert!(round_trip(&None::<HttpResponse>).is_none());
+    }
+}
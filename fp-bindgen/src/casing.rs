@@ -1,13 +1,18 @@
 use inflector::Inflector;
 use std::convert::TryFrom;
 
-#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
 pub enum Casing {
+    #[default]
     Original,
     CamelCase,
     PascalCase,
     SnakeCase,
     ScreamingSnakeCase,
+    Lowercase,
+    Uppercase,
+    KebabCase,
+    ScreamingKebabCase,
 }
 
 impl Casing {
@@ -18,6 +23,10 @@ impl Casing {
             Self::PascalCase => Some("PascalCase"),
             Self::SnakeCase => Some("snake_case"),
             Self::ScreamingSnakeCase => Some("SCREAMING_SNAKE_CASE"),
+            Self::Lowercase => Some("lowercase"),
+            Self::Uppercase => Some("UPPERCASE"),
+            Self::KebabCase => Some("kebab-case"),
+            Self::ScreamingKebabCase => Some("SCREAMING-KEBAB-CASE"),
         }
     }
 
@@ -28,16 +37,14 @@ impl Casing {
             Self::PascalCase => string.to_pascal_case(),
             Self::SnakeCase => string.to_snake_case(),
             Self::ScreamingSnakeCase => string.to_screaming_snake_case(),
+            Self::Lowercase => string.to_lowercase(),
+            Self::Uppercase => string.to_uppercase(),
+            Self::KebabCase => string.to_kebab_case(),
+            Self::ScreamingKebabCase => string.to_screaming_snake_case().replace('_', "-"),
         }
     }
 }
 
-impl Default for Casing {
-    fn default() -> Self {
-        Self::Original
-    }
-}
-
 impl TryFrom<&str> for Casing {
     type Error = String;
 
@@ -47,7 +54,35 @@ impl TryFrom<&str> for Casing {
             "PascalCase" => Ok(Self::PascalCase),
             "snake_case" => Ok(Self::SnakeCase),
             "SCREAMING_SNAKE_CASE" => Ok(Self::ScreamingSnakeCase),
+            "lowercase" => Ok(Self::Lowercase),
+            "UPPERCASE" => Ok(Self::Uppercase),
+            "kebab-case" => Ok(Self::KebabCase),
+            "SCREAMING-KEBAB-CASE" => Ok(Self::ScreamingKebabCase),
             other => Err(format!("Unrecognized case format: {}", other)),
         }
     }
 }
+
+/// Formats the `rename_all` Serde attribute for the given casings, where the
+/// casing for deserialization is only given if it differs from the one for
+/// serialization.
+pub(crate) fn format_rename_all_attr(
+    serialize: Casing,
+    deserialize: Option<Casing>,
+) -> Option<String> {
+    match (
+        serialize.as_maybe_str(),
+        deserialize.map(|casing| casing.as_maybe_str()),
+    ) {
+        (None, None) | (None, Some(None)) => None,
+        (Some(casing), None) => Some(format!("rename_all = \"{}\"", casing)),
+        (Some(serialize), Some(None)) => Some(format!("rename_all(serialize = \"{}\")", serialize)),
+        (None, Some(Some(deserialize))) => {
+            Some(format!("rename_all(deserialize = \"{}\")", deserialize))
+        }
+        (Some(serialize), Some(Some(deserialize))) => Some(format!(
+            "rename_all(serialize = \"{}\", deserialize = \"{}\")",
+            serialize, deserialize
+        )),
+    }
+}
@@ -1,4 +1,7 @@
-use crate::{docs::get_doc_lines, types::TypeIdent};
+use crate::{
+    docs::get_doc_lines,
+    types::{Field, Struct, Type, TypeIdent, TypeMap},
+};
 use quote::{format_ident, quote, ToTokens};
 use std::{collections::BTreeSet, convert::TryFrom};
 use syn::{token::Async, FnArg, ForeignItemFn, ReturnType};
@@ -19,6 +22,30 @@ impl FunctionList {
     pub fn new() -> Self {
         Self(BTreeSet::new())
     }
+
+    /// Replaces the identifiers of argument and return types with the ones
+    /// under which the types are stored in the given type map.
+    ///
+    /// See also: [`TypeMap::resolve()`]
+    pub fn resolve_idents(self, types: &TypeMap) -> Self {
+        Self(
+            self.0
+                .into_iter()
+                .map(|function| Function {
+                    args: function
+                        .args
+                        .into_iter()
+                        .map(|arg| FunctionArg {
+                            ty: types.resolve(&arg.ty),
+                            ..arg
+                        })
+                        .collect(),
+                    return_type: function.return_type.map(|ty| types.resolve(&ty)),
+                    ..function
+                })
+                .collect(),
+        )
+    }
 }
 
 impl IntoIterator for FunctionList {
@@ -64,11 +91,28 @@ impl Function {
                     "Methods are not supported. Found `self` in function declaration: {:?}",
                     item
                 ),
-                FnArg::Typed(arg) => FunctionArg {
-                    name: arg.pat.to_token_stream().to_string(),
-                    ty: TypeIdent::try_from(arg.ty.as_ref())
-                        .unwrap_or_else(|_| panic!("Invalid argument type for function {}", name)),
-                },
+                FnArg::Typed(arg) => {
+                    let (ty, is_borrowed) = match arg.ty.as_ref() {
+                        syn::Type::Reference(reference) if reference.mutability.is_none() => {
+                            (get_owned_type_ident(reference.elem.as_ref()), true)
+                        }
+                        ty => (TypeIdent::try_from(ty), false),
+                    };
+                    let ty = ty
+                        .unwrap_or_else(|_| panic!("Invalid argument type for function {}", name));
+                    if is_borrowed && ty.is_primitive() {
+                        panic!(
+                            "Primitives cannot be borrowed. Found argument of type &{} in function {}",
+                            ty, name
+                        );
+                    }
+
+                    FunctionArg {
+                        name: arg.pat.to_token_stream().to_string(),
+                        ty,
+                        is_borrowed,
+                    }
+                }
             })
             .collect();
         let return_type = match &item.sig.output {
@@ -90,6 +134,22 @@ impl Function {
     }
 }
 
+/// Returns the identifier of the owned type that is received for an argument
+/// that borrows the given type.
+fn get_owned_type_ident(ty: &syn::Type) -> Result<TypeIdent, String> {
+    match ty {
+        syn::Type::Path(path) if path.path.is_ident("str") => Ok(TypeIdent::from("String")),
+        syn::Type::Slice(slice) => match TypeIdent::try_from(slice.elem.as_ref())? {
+            item if item.name == "u8" => Ok(TypeIdent::from("ByteBuf")),
+            item => Ok(TypeIdent {
+                name: "Vec".to_owned(),
+                generic_args: vec![item],
+            }),
+        },
+        ty => TypeIdent::try_from(ty),
+    }
+}
+
 impl Ord for Function {
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
         self.name.cmp(&other.name)
@@ -98,7 +158,7 @@ impl Ord for Function {
 
 impl PartialOrd for Function {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        self.name.partial_cmp(&other.name)
+        Some(self.cmp(other))
     }
 }
 
@@ -129,15 +189,81 @@ impl ToTokens for Function {
 #[derive(Debug, Eq, PartialEq)]
 pub struct FunctionArg {
     pub name: String,
+
+    /// The type of the argument. For borrowed arguments, this is the owned
+    /// type that is received on the other side of the bridge.
     pub ty: TypeIdent,
+
+    /// Whether the argument is borrowed by the caller, such as `&str`, `&[u8]`
+    /// or `&T`. The callee always receives an owned value.
+    pub is_borrowed: bool,
+}
+
+impl FunctionArg {
+    /// Formats the type of the argument as it is passed by the caller, using
+    /// the given function to format type identifiers.
+    ///
+    /// Borrowed strings and binary types are formatted as `&str` and `&[u8]`,
+    /// and borrowed vectors as slices.
+    pub fn format_caller_type<F>(&self, types: &TypeMap, format_ident: F) -> String
+    where
+        F: Fn(&TypeIdent) -> String,
+    {
+        self.format_caller_type_with(self.ty.is_binary(types), format_ident)
+    }
+
+    fn format_caller_type_with<F>(&self, is_binary: bool, format_ident: F) -> String
+    where
+        F: Fn(&TypeIdent) -> String,
+    {
+        if !self.is_borrowed {
+            format_ident(&self.ty)
+        } else if self.ty.name == "String" {
+            "&str".to_owned()
+        } else if is_binary {
+            "&[u8]".to_owned()
+        } else if self.ty.name == "Vec" && self.ty.generic_args.len() == 1 {
+            format!("&[{}]", format_ident(&self.ty.generic_args[0]))
+        } else {
+            format!("&{}", format_ident(&self.ty))
+        }
+    }
+
+    /// Returns the argument's type if it is a struct that is passed by value,
+    /// in which case every field is passed as a separate Wasm value.
+    ///
+    /// See also: [`crate::types::StructOptions::by_value`]
+    pub fn by_value_struct<'a>(&self, types: &'a TypeMap) -> Option<&'a Struct> {
+        match types.get(&self.ty) {
+            Some(Type::Struct(ty)) if ty.options.by_value => Some(ty),
+            _ => None,
+        }
+    }
+
+    /// Returns the name of the Wasm parameter through which the given field is
+    /// passed, if the argument is passed by value.
+    pub fn by_value_field_name(&self, field: &Field) -> String {
+        format!(
+            "{}_{}",
+            self.name.trim_start_matches("r#"),
+            field.name.trim_start_matches("r#")
+        )
+    }
 }
 
 impl ToTokens for FunctionArg {
     fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
-        let Self { name, ty } = self;
-        let name = format_ident!("{}", name);
-
-        (quote! { #name: #ty }).to_tokens(tokens)
+        let name = format_ident!("{}", self.name);
+        if self.is_borrowed {
+            // Without a type map, binary types cannot be recognized, so they
+            // are borrowed as their owned type (such as `&ByteBuf`) instead:
+            let ty = self.format_caller_type_with(false, ToString::to_string);
+            let ty = syn::parse_str::<syn::Type>(&ty).expect("Invalid argument type");
+            (quote! { #name: #ty }).to_tokens(tokens)
+        } else {
+            let ty = &self.ty;
+            (quote! { #name: #ty }).to_tokens(tokens)
+        }
     }
 }
 
@@ -152,6 +278,7 @@ mod test {
         let arg = FunctionArg {
             name: "foobar".into(),
             ty: TypeIdent::from("i64"),
+            is_borrowed: false,
         };
 
         let stringified = arg.into_token_stream().to_string();
@@ -159,6 +286,26 @@ mod test {
         pretty_assertions::assert_eq!(&stringified, "foobar : i64");
     }
 
+    #[test]
+    fn test_borrowed_function_args() {
+        let func = Function::new("fn foobar(a: &str, b: &[u8], c: &[u32], d: &Point);");
+
+        let owned_types = func
+            .args
+            .iter()
+            .map(|arg| arg.ty.to_string())
+            .collect::<Vec<_>>();
+        pretty_assertions::assert_eq!(owned_types, vec!["String", "ByteBuf", "Vec<u32>", "Point"]);
+        assert!(func.args.iter().all(|arg| arg.is_borrowed));
+
+        let string = func.into_token_stream().to_string();
+
+        pretty_assertions::assert_eq!(
+            &string,
+            "fn foobar (a : & str , b : & ByteBuf , c : & [u32] , d : & Point) ->"
+        );
+    }
+
     #[test]
     fn test_function_to_tokens() {
         let func = Function {
@@ -169,6 +316,7 @@ mod test {
             args: vec![FunctionArg {
                 name: "a1".into(),
                 ty: TypeIdent::from("u64"),
+                is_borrowed: false,
             }],
         };
 
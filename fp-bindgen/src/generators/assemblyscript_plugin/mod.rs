@@ -0,0 +1,2023 @@
+use super::naming::{
+    get_deserialize_field_name, get_deserialize_variant_name, get_field_name, get_pointer_name,
+    get_variable_name, get_variant_name,
+};
+use crate::{
+    casing::Casing,
+    functions::{Function, FunctionList},
+    primitives::Primitive,
+    types::{
+        CustomType, Enum, EnumOptions, Field, Struct, StructKind, Type, TypeIdent, TypeMap, Variant,
+    },
+    AssemblyScriptPluginConfig,
+};
+use inflector::Inflector;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fs,
+    str::FromStr,
+};
+
+const MSGPACK_MODULE: &str = "@wapc/as-msgpack";
+
+pub(crate) fn generate_bindings(
+    import_functions: FunctionList,
+    export_functions: FunctionList,
+    types: TypeMap,
+    config: AssemblyScriptPluginConfig,
+    path: &str,
+) {
+    let src_path = format!("{}/assembly", path);
+    fs::create_dir_all(&src_path).expect("Could not create output directory");
+
+    let concrete_types = collect_concrete_types(&import_functions, &export_functions, &types);
+    if !config.allow_opaque_types {
+        check_opaque_types(&concrete_types, &types);
+    }
+
+    generate_package_file(config, path);
+
+    generate_runtime_bindings(&src_path);
+    generate_type_bindings(&concrete_types, &types, &src_path);
+    generate_imported_function_bindings(&import_functions, &types, &src_path);
+    generate_exported_function_bindings(&export_functions, &types, &src_path);
+
+    write_bindings_file(
+        format!("{}/index.ts", src_path),
+        "export { __fp_free, __fp_malloc } from \"./fp\";
+export * from \"./export\";
+export * from \"./import\";
+export * from \"./types\";
+",
+    );
+    write_bindings_file(
+        format!("{}/tsconfig.json", src_path),
+        "{
+  \"extends\": \"assemblyscript/std/assembly.json\",
+  \"include\": [\"./**/*.ts\"]
+}
+",
+    );
+}
+
+fn generate_package_file(config: AssemblyScriptPluginConfig, path: &str) {
+    let mut dependencies = BTreeMap::from([(MSGPACK_MODULE, "^0.1.16")]);
+
+    // Inject dependencies passed through the config:
+    for (name, version) in config.dependencies {
+        dependencies.insert(name, version);
+    }
+
+    write_bindings_file(
+        format!("{}/package.json", path),
+        format!(
+            "{{
+  \"name\": \"{}\",
+  \"version\": \"{}\",
+  \"contributors\": {},
+  \"ascMain\": \"assembly/index.ts\",
+  \"types\": \"assembly/index.ts\",
+  \"dependencies\": {{
+{}
+  }},
+  \"devDependencies\": {{
+    \"assemblyscript\": \"^0.20.0\"
+  }}
+}}
+",
+            config.name,
+            config.version,
+            config.authors,
+            dependencies
+                .iter()
+                .map(|(name, version)| format!("    \"{}\": \"{}\"", name, version))
+                .collect::<Vec<_>>()
+                .join(",\n")
+        ),
+    );
+}
+
+fn generate_runtime_bindings(path: &str) {
+    write_bindings_file(
+        format!("{}/fp.ts", path),
+        format!(
+            "// ============================================= //
+// Runtime for AssemblyScript plugins            //
+//                                               //
+// This file is generated. PLEASE DO NOT MODIFY. //
+// ============================================= //
+
+import {{ Decoder, Encoder, Sizer, Writer }} from \"{}\";
+
+export type FatPtr = u64;
+
+/**
+ * Wraps a primitive value, so that it can be made nullable.
+ */
+export class Box<T> {{
+    constructor(public value: T) {{}}
+}}
+
+export function __fp_malloc(len: u32): FatPtr {{
+    return toFatPtr(<u32>heap.alloc(len), len);
+}}
+
+export function __fp_free(fatPtr: FatPtr): void {{
+    heap.free(fatPtrAddr(fatPtr));
+}}
+
+/**
+ * Decodes the given number of fields into the given value.
+ *
+ * Fields that are not known to the value are skipped.
+ */
+export function decodeFields<T>(reader: Decoder, value: T, numFields: u32): T {{
+    for (let i: u32 = 0; i < numFields; i++) {{
+        const key = reader.readString();
+        if (!value.decodeField(key, reader)) {{
+            reader.skip();
+        }}
+    }}
+    return value;
+}}
+
+/**
+ * Serializes the value and copies it into memory that is owned by the host.
+ */
+export function exportToHost<T>(value: T, encode: (writer: Writer, value: T) => void): FatPtr {{
+    const sizer = new Sizer();
+    encode(sizer, value);
+
+    const buffer = new ArrayBuffer(sizer.length);
+    encode(new Encoder(buffer), value);
+    return exportBinaryToHost(buffer);
+}}
+
+/**
+ * Deserializes the value from memory that was passed to us by the host.
+ *
+ * After this call, the pointer is no longer valid.
+ */
+export function importFromHost<T>(fatPtr: FatPtr, decode: (reader: Decoder) => T): T {{
+    return decode(new Decoder(importBinaryFromHost(fatPtr)));
+}}
+
+/**
+ * Copies the buffer as is into memory that is owned by the host.
+ */
+export function exportBinaryToHost(buffer: ArrayBuffer): FatPtr {{
+    const len = <u32>buffer.byteLength;
+    const ptr = heap.alloc(len);
+    memory.copy(ptr, changetype<usize>(buffer), len);
+    return toFatPtr(<u32>ptr, len);
+}}
+
+/**
+ * Copies the buffer from memory that was passed to us by the host.
+ *
+ * After this call, the pointer is no longer valid.
+ */
+export function importBinaryFromHost(fatPtr: FatPtr): ArrayBuffer {{
+    const ptr = fatPtrAddr(fatPtr);
+    const len = fatPtrLen(fatPtr);
+    const buffer = new ArrayBuffer(len);
+    memory.copy(changetype<usize>(buffer), ptr, len);
+    __fp_free(fatPtr);
+    return buffer;
+}}
+
+function fatPtrAddr(fatPtr: FatPtr): u32 {{
+    return <u32>(fatPtr >> 32);
+}}
+
+function fatPtrLen(fatPtr: FatPtr): u32 {{
+    if (fatPtr & 0xff000000) {{
+        throw new Error(\"Unknown extension bits\");
+    }}
+    return <u32>(fatPtr & 0xffffffff);
+}}
+
+function toFatPtr(ptr: u32, len: u32): FatPtr {{
+    if (len & 0xff000000) {{
+        throw new Error(\"Buffer too large\");
+    }}
+    return (<u64>ptr << 32) | <u64>len;
+}}
+",
+            MSGPACK_MODULE
+        ),
+    );
+}
+
+/// Panics if any of the given types is a custom type that cannot be
+/// represented in AssemblyScript.
+fn check_opaque_types(concrete_types: &BTreeSet<TypeIdent>, types: &TypeMap) {
+    let opaque_types = concrete_types
+        .iter()
+        .filter(|ident| {
+            matches!(types.get(ident), Some(Type::Custom(custom)) if as_custom_type(custom) == AsCustomType::Opaque)
+        })
+        .map(ToString::to_string)
+        .collect::<Vec<_>>();
+    if !opaque_types.is_empty() {
+        panic!(
+            "The following custom types cannot be represented in AssemblyScript plugins: {}. \
+                Please set `AssemblyScriptPluginConfig::allow_opaque_types` if you want them to \
+                be skipped when decoding instead.",
+            opaque_types.join(", ")
+        );
+    }
+}
+
+/// Collects all the types that are used by the protocol, with their generic
+/// arguments resolved to concrete types.
+///
+/// AssemblyScript generics cannot be used for (de)serialization, so we
+/// generate a specialized class for every instantiation of a generic type.
+///
+/// The type map already contains an entry for every instantiation, but the
+/// definitions may refer to them through aliases, so we collect the
+/// identifiers as they are used in the definitions instead.
+fn collect_concrete_types(
+    import_functions: &FunctionList,
+    export_functions: &FunctionList,
+    types: &TypeMap,
+) -> BTreeSet<TypeIdent> {
+    let mut concrete_types = BTreeSet::new();
+
+    for function in import_functions.iter().chain(export_functions.iter()) {
+        for arg in function.args.iter() {
+            collect_concrete_type(&arg.ty, types, &mut concrete_types);
+        }
+        if let Some(return_type) = &function.return_type {
+            collect_concrete_type(return_type, types, &mut concrete_types);
+        }
+    }
+
+    // Explicitly imported types may not be referenced by any function:
+    for (ident, ty) in types.iter() {
+        if matches!(ty, Type::Alias(_, _) | Type::Enum(_) | Type::Struct(_)) {
+            collect_concrete_type(ident, types, &mut concrete_types);
+        }
+    }
+
+    concrete_types
+}
+
+fn collect_concrete_type(
+    ident: &TypeIdent,
+    types: &TypeMap,
+    concrete_types: &mut BTreeSet<TypeIdent>,
+) {
+    if concrete_types.contains(ident) {
+        return;
+    }
+
+    let ty = match types.get(ident) {
+        Some(ty) => ty,
+        None => return, // Must be a generic.
+    };
+    concrete_types.insert(ident.clone());
+
+    match ty {
+        Type::Alias(_, target) => collect_concrete_type(target, types, concrete_types),
+        Type::Array(_, _)
+        | Type::Container(_, _)
+        | Type::List(_, _)
+        | Type::Map(_, _, _)
+        | Type::Tuple(_) => {
+            for arg in ident.generic_args.iter() {
+                collect_concrete_type(arg, types, concrete_types);
+            }
+        }
+        Type::Enum(ty) => {
+            for variant in ty
+                .specialize(ident)
+                .variants
+                .into_iter()
+                .filter(|variant| !variant.attrs.is_skipped())
+            {
+                match variant.ty {
+                    Type::Struct(variant) => {
+                        for field in variant.fields.iter() {
+                            collect_concrete_type(&field.ty, types, concrete_types);
+                        }
+                    }
+                    Type::Tuple(items) => {
+                        for item in items.iter() {
+                            collect_concrete_type(item, types, concrete_types);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+        Type::Struct(ty) => {
+            for field in ty.specialize(ident).fields {
+                if !field.attrs.is_skipped() {
+                    collect_concrete_type(&field.ty, types, concrete_types);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+fn generate_type_bindings(concrete_types: &BTreeSet<TypeIdent>, types: &TypeMap, path: &str) {
+    let type_defs = concrete_types
+        .iter()
+        .filter_map(|ident| match types.get(ident)? {
+            Type::Alias(name, target) => Some(format!(
+                "export type {} = {};",
+                name,
+                format_ident(target, types)
+            )),
+            Type::Custom(custom) if as_custom_type(custom) == AsCustomType::Opaque => {
+                Some(create_opaque_definition(custom))
+            }
+            Type::Enum(ty) => Some(create_enum_definition(ty, ident, types)),
+            Type::Struct(ty) => Some(create_struct_definition(ty, ident, types)),
+            Type::Tuple(_) => Some(create_tuple_definition(ident, types)),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    let helpers = concrete_types
+        .iter()
+        .filter_map(|ident| create_helper_functions(ident, types))
+        .collect::<Vec<_>>();
+
+    write_bindings_file(
+        format!("{}/types.ts", path),
+        format!(
+            "// ============================================= //
+// Types for AssemblyScript plugins              //
+//                                               //
+// This file is generated. PLEASE DO NOT MODIFY. //
+// ============================================= //
+
+import {{ Decoder, Writer }} from \"{}\";
+import {{ Box, decodeFields }} from \"./fp\";
+
+{}\n\n{}\n",
+            MSGPACK_MODULE,
+            type_defs.join("\n\n"),
+            helpers.join("\n\n")
+        ),
+    );
+}
+
+/// Creates the class for a custom type that cannot be represented in
+/// AssemblyScript.
+fn create_opaque_definition(custom: &CustomType) -> String {
+    format!(
+        "/**
+ * A value of type `{}`, which cannot be represented in AssemblyScript.
+ *
+ * Its value is skipped when decoding, so it cannot be encoded again.
+ */
+export class {} {{}}",
+        custom.ts_ty,
+        get_class_name(&custom.ident)
+    )
+}
+
+fn create_struct_definition(ty: &Struct, ident: &TypeIdent, types: &TypeMap) -> String {
+    let ty = ty.specialize(ident);
+    let fields = &ty.fields;
+    let name = format_ident(ident, types);
+    let definition = match (ty.transparent_field(), ty.kind) {
+        // Newtypes are serialized transparently, so they become aliases:
+        (Some(field), _) => format!("export type {} = {};", name, format_ident(&field.ty, types)),
+        (None, StructKind::Named) => format!(
+            "export class {} {{\n{}\n    static decode(reader: Decoder): {} {{
+        return decodeFields(reader, new {}(), reader.readMapLength());
+    }}
+
+{}
+
+    encode(writer: Writer): void {{
+        writer.writeMapLength({});{}
+        this.encodeFields(writer);
+    }}
+
+{}
+}}",
+            name,
+            join_lines(&format_class_fields(fields, types), |line| format!(
+                "    {}",
+                line
+            )),
+            name,
+            name,
+            indent(&format_decode_field_method(
+                fields,
+                types,
+                ty.options.deserialize_field_casing()
+            )),
+            count_serialized_fields(fields, types) + usize::from(ty.tag().is_some()),
+            match ty.tag() {
+                Some((tag, name)) => format!(
+                    "\n        writer.writeString(\"{}\");\n        writer.writeString(\"{}\");",
+                    tag, name
+                ),
+                None => String::new(),
+            },
+            indent(&format_encode_fields_method(
+                fields,
+                types,
+                ty.options.field_casing
+            )),
+        ),
+        (None, StructKind::Tuple) => {
+            let items = fields
+                .iter()
+                .map(|field| field.ty.clone())
+                .collect::<Vec<_>>();
+            create_tuple_class(&name, &items, types)
+        }
+        (None, StructKind::Unit) => format!(
+            "export class {} {{
+    static decode(reader: Decoder): {} {{
+        reader.isNextNil();
+        return new {}();
+    }}
+
+    encode(writer: Writer): void {{
+        writer.writeNil();
+    }}
+}}",
+            name, name, name
+        ),
+    };
+
+    format!(
+        "{}{}",
+        join_lines(&format_docs(&ty.doc_lines), String::to_owned),
+        definition
+    )
+}
+
+fn create_tuple_definition(ident: &TypeIdent, types: &TypeMap) -> String {
+    create_tuple_class(&format_ident(ident, types), &ident.generic_args, types)
+}
+
+/// Tuples and tuple structs are represented as classes with a property for
+/// every item, which are serialized as MessagePack arrays.
+fn create_tuple_class(name: &str, items: &[TypeIdent], types: &TypeMap) -> String {
+    let fields = items
+        .iter()
+        .enumerate()
+        .map(|(index, item)| {
+            format!(
+                "    {}{};",
+                get_tuple_item_name(items, index),
+                format_field_type_and_default(item, types)
+            )
+        })
+        .collect::<Vec<_>>();
+    let decode_stmts = items
+        .iter()
+        .enumerate()
+        .map(|(index, item)| {
+            format!(
+                "        result.{} = {};",
+                get_tuple_item_name(items, index),
+                format_decode_expr(item, types)
+            )
+        })
+        .collect::<Vec<_>>();
+    let encode_stmts = items
+        .iter()
+        .enumerate()
+        .map(|(index, item)| {
+            let value = format!("this.{}", get_tuple_item_name(items, index));
+            format!("        {}", format_encode_stmt(item, &value, types))
+        })
+        .collect::<Vec<_>>();
+
+    format!(
+        "export class {} {{
+{}
+
+    static decode(reader: Decoder): {} {{
+        reader.readArrayLength();
+        const result = new {}();
+{}
+        return result;
+    }}
+
+    encode(writer: Writer): void {{
+        writer.writeArrayLength({});
+{}
+    }}
+}}",
+        name,
+        fields.join("\n"),
+        name,
+        name,
+        decode_stmts.join("\n"),
+        items.len(),
+        encode_stmts.join("\n")
+    )
+}
+
+fn create_enum_definition(ty: &Enum, ident: &TypeIdent, types: &TypeMap) -> String {
+    let name = format_ident(ident, types);
+    if ty.options.repr.is_some() {
+        return create_repr_enum_definition(ty, &name);
+    }
+
+    let variants = ty
+        .specialize(ident)
+        .variants
+        .into_iter()
+        .filter(|variant| !variant.attrs.is_skipped())
+        .collect::<Vec<_>>();
+
+    let mut variant_defs = variants
+        .iter()
+        .map(|variant| create_variant_definition(ty, variant, &name, types))
+        .collect::<Vec<_>>();
+    if let Some(unknown_variant) = &ty.options.unknown_variant {
+        variant_defs.push(format!(
+            "/**
+ * A variant that is not known to this version of the protocol.
+ *
+ * Its value is skipped when decoding, so it cannot be encoded again.
+ */
+export class {}{} extends {} {{
+    encode(writer: Writer): void {{
+        throw new Error(\"Unknown variant of enum {} cannot be encoded\");
+    }}
+}}",
+            name,
+            get_variable_name(unknown_variant),
+            name,
+            name
+        ));
+    }
+
+    format!(
+        "{}export abstract class {} {{
+    static decode(reader: Decoder): {} {{
+{}
+    }}
+
+    abstract encode(writer: Writer): void;
+}}
+
+{}",
+        join_lines(&format_docs(&ty.doc_lines), String::to_owned),
+        name,
+        name,
+        join_lines(
+            &format_enum_decode_body(ty, &variants, &name, types),
+            |line| { format!("        {}", line) }
+        )
+        .trim_end(),
+        variant_defs.join("\n\n")
+    )
+}
+
+/// Creates a native enum for enums that are serialized as integers.
+fn create_repr_enum_definition(ty: &Enum, name: &str) -> String {
+    let variants = ty
+        .variants
+        .iter()
+        .filter(|variant| !variant.attrs.is_skipped())
+        .map(|variant| {
+            let mut lines = format_docs(&variant.doc_lines);
+            lines.push(format!(
+                "{} = {},",
+                variant.name,
+                variant
+                    .discriminant
+                    .expect("Variants of enums with a `repr` must have a discriminant")
+            ));
+            join_lines(&lines, |line| format!("    {}", line))
+        })
+        .collect::<Vec<_>>()
+        .join("");
+
+    format!(
+        "{}export enum {} {{\n{}}}",
+        join_lines(&format_docs(&ty.doc_lines), String::to_owned),
+        name,
+        variants
+    )
+}
+
+fn create_variant_definition(ty: &Enum, variant: &Variant, name: &str, types: &TypeMap) -> String {
+    let class_name = get_variant_class_name(name, variant);
+    let variant_name = get_variant_name(variant, &ty.options);
+
+    let (fields, methods) = match &variant.ty {
+        Type::Unit => (Vec::new(), Vec::new()),
+        Type::Struct(struct_variant) => {
+            let casing = variant.attrs.field_casing;
+            let deserialize_casing = variant.attrs.deserialize_field_casing();
+            (
+                format_class_fields(&struct_variant.fields, types),
+                vec![
+                    format_decode_field_method(&struct_variant.fields, types, deserialize_casing),
+                    format_encode_fields_method(&struct_variant.fields, types, casing),
+                ],
+            )
+        }
+        Type::Tuple(items) => (
+            items
+                .iter()
+                .enumerate()
+                .map(|(index, item)| {
+                    format!(
+                        "{}{};",
+                        get_tuple_item_name(items, index),
+                        format_field_type_and_default(item, types)
+                    )
+                })
+                .collect(),
+            Vec::new(),
+        ),
+        other => panic!("Unsupported type for enum variant: {:?}", other),
+    };
+
+    let encode_method = format!(
+        "encode(writer: Writer): void {{\n{}}}",
+        join_lines(
+            &format_variant_encode_body(ty, variant, &variant_name, types),
+            |line| format!("    {}", line)
+        )
+    );
+
+    let body = fields
+        .iter()
+        .map(|line| {
+            if line.is_empty() {
+                line.clone()
+            } else {
+                format!("    {}", line)
+            }
+        })
+        .chain(
+            methods
+                .iter()
+                .chain(std::iter::once(&encode_method))
+                .map(|method| format!("\n{}", indent(method))),
+        )
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    format!(
+        "{}export class {} extends {} {{\n{}\n}}",
+        join_lines(&format_docs(&variant.doc_lines), String::to_owned),
+        class_name,
+        name,
+        body.trim_start_matches('\n')
+    )
+}
+
+fn format_enum_decode_body(
+    ty: &Enum,
+    variants: &[Variant],
+    name: &str,
+    types: &TypeMap,
+) -> Vec<String> {
+    let opts = &ty.options;
+    if opts.untagged {
+        return vec![format!(
+            "throw new Error(\"Untagged enum {} cannot be decoded by AssemblyScript plugins\");",
+            name
+        )];
+    }
+
+    // Unknown variants are decoded as the variant with the `other` attribute,
+    // or as the generated unknown variant, if there is one:
+    let other_variant = variants
+        .iter()
+        .find(|variant| variant.attrs.other)
+        .map(|variant| get_variant_class_name(name, variant))
+        .or_else(|| {
+            opts.unknown_variant
+                .as_ref()
+                .map(|variant| format!("{}{}", name, get_variable_name(variant)))
+        });
+    let unknown_variant_stmt = match &other_variant {
+        Some(class_name) => format!("return new {}();", class_name),
+        None => format!(
+            "throw new Error(\"Unknown variant for enum {}: \" + name);",
+            name
+        ),
+    };
+
+    let mut lines = Vec::new();
+    match &opts.tag_prop_name {
+        Some(tag) => {
+            // Serde always serializes the tag first, so we rely on that here:
+            lines.push("const numFields = reader.readMapLength();".to_owned());
+            lines.push(format!("if (reader.readString() != \"{}\") {{", tag));
+            lines.push(format!(
+                "    throw new Error(\"Expected tag \\\"{}\\\" for enum {}\");",
+                tag, name
+            ));
+            lines.push("}".to_owned());
+            lines.push("const name = reader.readString();".to_owned());
+        }
+        None => {
+            let unit_variants = variants
+                .iter()
+                .filter(|variant| variant.ty == Type::Unit && !variant.attrs.skip_deserializing)
+                .collect::<Vec<_>>();
+            if !unit_variants.is_empty() || opts.unknown_variant.is_some() {
+                lines.push("if (reader.isNextString()) {".to_owned());
+                lines.push("    const name = reader.readString();".to_owned());
+                for variant in unit_variants {
+                    lines.push(format!(
+                        "    if ({}) return new {}();",
+                        format_variant_name_condition(ty, variant),
+                        get_variant_class_name(name, variant)
+                    ));
+                }
+                lines.push(format!("    {}", unknown_variant_stmt));
+                lines.push("}".to_owned());
+            }
+            lines.push("reader.readMapLength();".to_owned());
+            lines.push("const name = reader.readString();".to_owned());
+        }
+    }
+
+    for variant in variants
+        .iter()
+        .filter(|variant| !variant.attrs.skip_deserializing)
+    {
+        let class_name = get_variant_class_name(name, variant);
+        let variant_decl = match (&variant.ty, &opts.tag_prop_name, &opts.content_prop_name) {
+            (Type::Unit, Some(_), _) => vec![format!("return new {}();", class_name)],
+            (Type::Unit, None, _) => continue, // Already handled above.
+            (Type::Struct(_), Some(_), None) => vec![format!(
+                "return decodeFields(reader, new {}(), numFields - 1);",
+                class_name
+            )],
+            (Type::Tuple(items), Some(_), None) => {
+                let item = items.first().unwrap();
+                vec![
+                    format!("const variant = new {}();", class_name),
+                    format!(
+                        "variant.value = decodeFields(reader, new {}(), numFields - 1);",
+                        format_ident(item, types)
+                    ),
+                    "return variant;".to_owned(),
+                ]
+            }
+            (ty, _, content) => {
+                let mut lines = Vec::new();
+                if content.is_some() {
+                    lines.push("reader.readString();".to_owned());
+                }
+                match ty {
+                    Type::Struct(_) => lines.push(format!(
+                        "return decodeFields(reader, new {}(), reader.readMapLength());",
+                        class_name
+                    )),
+                    Type::Tuple(items) => {
+                        lines.push(format!("const variant = new {}();", class_name));
+                        if items.len() > 1 {
+                            lines.push("reader.readArrayLength();".to_owned());
+                        }
+                        for (index, item) in items.iter().enumerate() {
+                            lines.push(format!(
+                                "variant.{} = {};",
+                                get_tuple_item_name(items, index),
+                                format_decode_expr(item, types)
+                            ));
+                        }
+                        lines.push("return variant;".to_owned());
+                    }
+                    other => panic!("Unsupported type for enum variant: {:?}", other),
+                }
+                lines
+            }
+        };
+
+        lines.push(format!(
+            "if ({}) {{",
+            format_variant_name_condition(ty, variant)
+        ));
+        lines.extend(variant_decl.into_iter().map(|line| format!("    {}", line)));
+        lines.push("}".to_owned());
+    }
+
+    match (&other_variant, &opts.tag_prop_name) {
+        (Some(class_name), Some(_)) => {
+            // Skip whatever content the unknown variant may have:
+            lines.push("for (let i: u32 = 1; i < numFields; i++) {".to_owned());
+            lines.push("    reader.skip();".to_owned());
+            lines.push("    reader.skip();".to_owned());
+            lines.push("}".to_owned());
+            lines.push(format!("return new {}();", class_name));
+        }
+        (Some(class_name), None) if opts.unknown_variant.is_some() => {
+            lines.push("reader.skip();".to_owned());
+            lines.push(format!("return new {}();", class_name));
+        }
+        _ => lines.push(format!(
+            "throw new Error(\"Unknown variant for enum {}: \" + name);",
+            name
+        )),
+    }
+    lines
+}
+
+/// Returns the condition under which a decoded variant name matches the given
+/// variant, taking its aliases into account.
+fn format_variant_name_condition(ty: &Enum, variant: &Variant) -> String {
+    std::iter::once(get_deserialize_variant_name(variant, &ty.options))
+        .chain(variant.attrs.alias.iter().cloned())
+        .map(|name| format!("name == \"{}\"", name))
+        .collect::<Vec<_>>()
+        .join(" || ")
+}
+
+fn format_variant_encode_body(
+    ty: &Enum,
+    variant: &Variant,
+    variant_name: &str,
+    types: &TypeMap,
+) -> Vec<String> {
+    let opts = &ty.options;
+    if variant.attrs.skip_serializing {
+        return vec![format!(
+            "throw new Error(\"Variant {} of enum {} cannot be encoded\");",
+            variant.name, ty.ident.name
+        )];
+    }
+
+    let payload = match &variant.ty {
+        Type::Unit => Vec::new(),
+        Type::Struct(struct_variant) => vec![
+            format!(
+                "writer.writeMapLength({});",
+                count_serialized_fields(&struct_variant.fields, types)
+            ),
+            "this.encodeFields(writer);".to_owned(),
+        ],
+        Type::Tuple(items) if items.len() == 1 => {
+            vec![format_encode_stmt(
+                items.first().unwrap(),
+                "this.value",
+                types,
+            )]
+        }
+        Type::Tuple(items) => std::iter::once(format!("writer.writeArrayLength({});", items.len()))
+            .chain(items.iter().enumerate().map(|(index, item)| {
+                format_encode_stmt(
+                    item,
+                    &format!("this.{}", get_tuple_item_name(items, index)),
+                    types,
+                )
+            }))
+            .collect(),
+        other => panic!("Unsupported type for enum variant: {:?}", other),
+    };
+
+    let write_tag = |lines: &mut Vec<String>, tag: &str| {
+        lines.push(format!("writer.writeString(\"{}\");", tag));
+        lines.push(format!("writer.writeString(\"{}\");", variant_name));
+    };
+
+    let mut lines = Vec::new();
+    if opts.untagged {
+        if payload.is_empty() {
+            lines.push("writer.writeNil();".to_owned());
+        } else {
+            lines.extend(payload);
+        }
+    } else {
+        match (&opts.tag_prop_name, &opts.content_prop_name) {
+            (Some(tag), _) if payload.is_empty() => {
+                lines.push("writer.writeMapLength(1);".to_owned());
+                write_tag(&mut lines, tag);
+            }
+            (Some(tag), Some(content)) => {
+                lines.push("writer.writeMapLength(2);".to_owned());
+                write_tag(&mut lines, tag);
+                lines.push(format!("writer.writeString(\"{}\");", content));
+                lines.extend(payload);
+            }
+            (Some(tag), None) => match &variant.ty {
+                Type::Struct(struct_variant) => {
+                    lines.push(format!(
+                        "writer.writeMapLength({});",
+                        count_serialized_fields(&struct_variant.fields, types) + 1
+                    ));
+                    write_tag(&mut lines, tag);
+                    lines.push("this.encodeFields(writer);".to_owned());
+                }
+                Type::Tuple(items) => {
+                    let item = items.first().unwrap();
+                    let fields = match resolve_type(item, types) {
+                        Some(Type::Struct(item_struct)) => item_struct.specialize(item).fields,
+                        _ => panic!(
+                            "Enum {} cannot be serialized, because the variant `{}` has an \
+                                unnamed field that is not a struct and the enum has no \
+                                `content` attribute",
+                            ty.ident, variant.name
+                        ),
+                    };
+                    lines.push(format!(
+                        "writer.writeMapLength({});",
+                        count_serialized_fields(&fields, types) + 1
+                    ));
+                    write_tag(&mut lines, tag);
+                    lines.push("this.value.encodeFields(writer);".to_owned());
+                }
+                other => panic!("Unsupported type for enum variant: {:?}", other),
+            },
+            (None, _) if payload.is_empty() => {
+                lines.push(format!("writer.writeString(\"{}\");", variant_name));
+            }
+            (None, _) => {
+                lines.push("writer.writeMapLength(1);".to_owned());
+                lines.push(format!("writer.writeString(\"{}\");", variant_name));
+                lines.extend(payload);
+            }
+        }
+    }
+    lines
+}
+
+fn format_class_fields(fields: &[Field], types: &TypeMap) -> Vec<String> {
+    let mut lines: Vec<String> = fields
+        .iter()
+        .filter(|field| !field.attrs.is_skipped())
+        .flat_map(|field| {
+            let field_decl = if field.attrs.flatten {
+                let ty = format_ident(&field.ty, types);
+                format!("{}: {} = new {}();", get_property_name(&field.name), ty, ty)
+            } else {
+                format!(
+                    "{}{};",
+                    get_property_name(&field.name),
+                    format_field_type_and_default(&field.ty, types)
+                )
+            };
+            if field.doc_lines.is_empty() {
+                vec![field_decl]
+            } else {
+                let mut lines = vec!["".to_owned()];
+                lines.append(&mut format_docs(&field.doc_lines));
+                lines.push(field_decl);
+                lines
+            }
+        })
+        .collect();
+
+    // Documented fields are separated by an empty line, except at the start:
+    if lines.first().map(String::is_empty).unwrap_or_default() {
+        lines.remove(0);
+    }
+    lines
+}
+
+fn format_decode_field_method(fields: &[Field], types: &TypeMap, casing: Casing) -> String {
+    let (flattened_fields, fields): (Vec<_>, Vec<_>) = fields
+        .iter()
+        .filter(|field| !field.attrs.skip_deserializing)
+        .partition(|field| field.attrs.flatten);
+
+    let mut lines = vec!["decodeField(key: string, reader: Decoder): bool {".to_owned()];
+    for field in fields.iter() {
+        let condition = std::iter::once(get_deserialize_field_name(field, casing))
+            .chain(field.attrs.alias.iter().cloned())
+            .map(|name| format!("key == \"{}\"", name))
+            .collect::<Vec<_>>()
+            .join(" || ");
+        lines.push(format!("    if ({}) {{", condition));
+        lines.push(format!(
+            "        this.{} = {};",
+            get_property_name(&field.name),
+            format_decode_expr(&field.ty, types)
+        ));
+        lines.push("        return true;".to_owned());
+        lines.push("    }".to_owned());
+    }
+    if flattened_fields.is_empty() {
+        lines.push("    return false;".to_owned());
+    } else {
+        lines.push(format!(
+            "    return {};",
+            flattened_fields
+                .iter()
+                .map(|field| format!(
+                    "this.{}.decodeField(key, reader)",
+                    get_property_name(&field.name)
+                ))
+                .collect::<Vec<_>>()
+                .join(" || ")
+        ));
+    }
+    lines.push("}".to_owned());
+    lines.join("\n")
+}
+
+fn format_encode_fields_method(fields: &[Field], types: &TypeMap, casing: Casing) -> String {
+    let mut lines = vec!["encodeFields(writer: Writer): void {".to_owned()];
+    for field in fields.iter().filter(|field| !field.attrs.skip_serializing) {
+        let value = format!("this.{}", get_property_name(&field.name));
+        if field.attrs.flatten {
+            lines.push(format!("    {}.encodeFields(writer);", value));
+        } else {
+            lines.push(format!(
+                "    writer.writeString(\"{}\");",
+                get_field_name(field, casing)
+            ));
+            lines.push(format!(
+                "    {}",
+                format_encode_stmt(&field.ty, &value, types)
+            ));
+        }
+    }
+    lines.push("}".to_owned());
+    lines.join("\n")
+}
+
+/// Returns the number of fields the given fields will be serialized as, after
+/// flattening.
+fn count_serialized_fields(fields: &[Field], types: &TypeMap) -> usize {
+    fields
+        .iter()
+        .filter(|field| !field.attrs.skip_serializing)
+        .map(|field| {
+            if field.attrs.flatten {
+                match resolve_type(&field.ty, types) {
+                    Some(Type::Struct(ty)) => {
+                        count_serialized_fields(&ty.specialize(&field.ty).fields, types)
+                    }
+                    _ => panic!(
+                        "Only structs can be flattened in AssemblyScript plugins. Found: {}",
+                        field.ty
+                    ),
+                }
+            } else {
+                1
+            }
+        })
+        .sum()
+}
+
+/// Creates the `encode*()` and `decode*()` functions for the given type, which
+/// are used for (de)serializing function arguments, return values and
+/// container items.
+fn create_helper_functions(ident: &TypeIdent, types: &TypeMap) -> Option<String> {
+    let ty = types.get(ident)?;
+    let (encode_body, decode_body) = match ty {
+        Type::Alias(_, target) => (
+            format_encode_stmt(target, "value", types),
+            format!("return {};", format_decode_expr(target, types)),
+        ),
+        Type::Array(_, len) => {
+            let arg = first_generic_arg(ident);
+            (
+                format!(
+                    "writer.writeArrayLength({});
+for (let i = 0; i < value.length; i++) {{
+    {}
+}}",
+                    len,
+                    format_encode_stmt(arg, "value[i]", types)
+                ),
+                format!(
+                    "const length = reader.readArrayLength();
+const result = new Array<{}>();
+for (let i: u32 = 0; i < length; i++) {{
+    result.push({});
+}}
+return StaticArray.fromArray(result);",
+                    format_ident(arg, types),
+                    format_decode_expr(arg, types)
+                ),
+            )
+        }
+        Type::Container(name, _) => {
+            let arg = first_generic_arg(ident);
+            if name == "Option" {
+                let (value, decoded) = if is_primitive(arg, types) {
+                    (
+                        "value!.value".to_owned(),
+                        format!("new Box({})", format_decode_expr(arg, types)),
+                    )
+                } else {
+                    ("value!".to_owned(), format_decode_expr(arg, types))
+                };
+                (
+                    format!(
+                        "if (value === null) {{\n    writer.writeNil();\n}} else {{\n    {}\n}}",
+                        format_encode_stmt(arg, &value, types)
+                    ),
+                    format!(
+                        "if (reader.isNextNil()) {{\n    return null;\n}}\nreturn {};",
+                        decoded
+                    ),
+                )
+            } else {
+                (
+                    format_encode_stmt(arg, "value", types),
+                    format!("return {};", format_decode_expr(arg, types)),
+                )
+            }
+        }
+        Type::Custom(custom) => match as_custom_type(custom) {
+            AsCustomType::ArrayBuffer => (
+                "writer.writeByteArray(value);".to_owned(),
+                "return reader.readByteArray();".to_owned(),
+            ),
+            AsCustomType::String => (
+                "writer.writeString(value);".to_owned(),
+                "return reader.readString();".to_owned(),
+            ),
+            AsCustomType::Opaque => (
+                format!(
+                    "throw new Error(\"Value of type {} cannot be encoded\");",
+                    custom.ts_ty
+                ),
+                format!(
+                    "reader.skip();\nreturn new {}();",
+                    get_class_name(&custom.ident)
+                ),
+            ),
+        },
+        Type::Struct(ty) if ty.is_newtype() => {
+            let target = ty.specialize(ident).transparent_field().unwrap().ty.clone();
+            (
+                format_encode_stmt(&target, "value", types),
+                format!("return {};", format_decode_expr(&target, types)),
+            )
+        }
+        Type::Enum(Enum {
+            options: EnumOptions {
+                repr: Some(repr), ..
+            },
+            ..
+        }) => (
+            format!(
+                "writer.write{}(<{}>value);",
+                format_msgpack_primitive(*repr),
+                format_encoded_primitive(*repr)
+            ),
+            format!(
+                "return <{}>reader.read{}();",
+                format_ident(ident, types),
+                format_msgpack_primitive(*repr)
+            ),
+        ),
+        Type::Enum(_) | Type::Struct(_) | Type::Tuple(_) => (
+            "value.encode(writer);".to_owned(),
+            format!("return {}.decode(reader);", format_ident(ident, types)),
+        ),
+        Type::List(_, _) => {
+            let arg = first_generic_arg(ident);
+            (
+                format!(
+                    "writer.writeArrayLength(value.length);
+for (let i = 0; i < value.length; i++) {{
+    {}
+}}",
+                    format_encode_stmt(arg, "value[i]", types)
+                ),
+                format!(
+                    "const length = reader.readArrayLength();
+const result = new {}();
+for (let i: u32 = 0; i < length; i++) {{
+    result.push({});
+}}
+return result;",
+                    format_ident(ident, types),
+                    format_decode_expr(arg, types)
+                ),
+            )
+        }
+        Type::Map(_, _, _) => {
+            let (key, value) = (
+                first_generic_arg(ident),
+                ident
+                    .generic_args
+                    .get(1)
+                    .expect("Identifier was expected to contain two arguments"),
+            );
+            (
+                format!(
+                    "const keys = value.keys();
+writer.writeMapLength(keys.length);
+for (let i = 0; i < keys.length; i++) {{
+    {}
+    {}
+}}",
+                    format_encode_stmt(key, "keys[i]", types),
+                    format_encode_stmt(value, "value.get(keys[i])", types)
+                ),
+                format!(
+                    "const size = reader.readMapLength();
+const result = new {}();
+for (let i: u32 = 0; i < size; i++) {{
+    const key = {};
+    result.set(key, {});
+}}
+return result;",
+                    format_ident(ident, types),
+                    format_decode_expr(key, types),
+                    format_decode_expr(value, types)
+                ),
+            )
+        }
+        // Primitives that don't fit in a Wasm value are passed using
+        // MessagePack, so the function wrappers need helpers for them:
+        Type::Primitive(primitive) if !primitive.fits_wasm_value() => (
+            format_encode_stmt(ident, "value", types),
+            format!("return {};", format_decode_expr(ident, types)),
+        ),
+        Type::String => (
+            "writer.writeString(value);".to_owned(),
+            "return reader.readString();".to_owned(),
+        ),
+        Type::Primitive(_) | Type::Unit => return None,
+    };
+
+    let name = get_helper_suffix(ident);
+    let as_ty = format_ident(ident, types);
+    Some(format!(
+        "export function encode{}(writer: Writer, value: {}): void {{\n{}}}
+
+export function decode{}(reader: Decoder): {} {{\n{}}}",
+        name,
+        as_ty,
+        join_lines(&split_lines(&encode_body), |line| format!("    {}", line)),
+        name,
+        as_ty,
+        join_lines(&split_lines(&decode_body), |line| format!("    {}", line)),
+    ))
+}
+
+fn generate_imported_function_bindings(
+    import_functions: &FunctionList,
+    types: &TypeMap,
+    path: &str,
+) {
+    let mut used_names = BTreeSet::new();
+    let functions = import_functions
+        .iter()
+        .map(|function| {
+            if function.is_async {
+                return format_unsupported_async_function(function);
+            }
+
+            let name = &function.name;
+            let fp_gen_name = format!("__fp_gen_{}", name);
+            let extern_args = function
+                .args
+                .iter()
+                .flat_map(|arg| {
+                    if let Some(ty) = arg.by_value_struct(types) {
+                        ty.fields
+                            .iter()
+                            .map(|field| {
+                                format!(
+                                    "{}: {}",
+                                    arg.by_value_field_name(field),
+                                    format_wasm_type(Some(&field.ty))
+                                )
+                            })
+                            .collect()
+                    } else if let Some(primitive) = arg.ty.as_primitive() {
+                        vec![format!(
+                            "{}: {}",
+                            get_variable_name(&arg.name),
+                            primitive.name()
+                        )]
+                    } else {
+                        vec![format!("{}: FatPtr", get_variable_name(&arg.name))]
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join(", ");
+            let extern_return_type = format_wasm_type(function.return_type.as_ref());
+
+            let export_args = function
+                .args
+                .iter()
+                .filter(|arg| !arg.ty.is_primitive() && arg.by_value_struct(types).is_none())
+                .map(|arg| {
+                    if arg.ty.is_binary(types) {
+                        return format!(
+                            "    const {} = exportBinaryToHost({});\n",
+                            get_pointer_name(&arg.name),
+                            get_variable_name(&arg.name).to_camel_case()
+                        );
+                    }
+
+                    used_names.insert(format_ident(&arg.ty, types));
+                    used_names.insert(format!("encode{}", get_helper_suffix(&arg.ty)));
+                    format!(
+                        "    const {} = exportToHost({}, encode{});\n",
+                        get_pointer_name(&arg.name),
+                        get_variable_name(&arg.name).to_camel_case(),
+                        get_helper_suffix(&arg.ty)
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join("");
+            let call_args = function
+                .args
+                .iter()
+                .flat_map(|arg| {
+                    let name = get_variable_name(&arg.name).to_camel_case();
+                    if let Some(ty) = arg.by_value_struct(types) {
+                        ty.fields
+                            .iter()
+                            .map(|field| format!("{}.{}", name, get_property_name(&field.name)))
+                            .collect()
+                    } else if arg.ty.is_primitive() {
+                        vec![name]
+                    } else {
+                        vec![get_pointer_name(&arg.name)]
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join(", ");
+            let fn_call = format!("{}({})", fp_gen_name, call_args);
+            let fn_call = match &function.return_type {
+                None => format!("{};", fn_call),
+                Some(ty) if ty.is_primitive() => format!("return {};", fn_call),
+                Some(ty) if ty.is_binary(types) => {
+                    format!("return importBinaryFromHost({});", fn_call)
+                }
+                Some(ty) => {
+                    used_names.insert(format!("decode{}", get_helper_suffix(ty)));
+                    format!(
+                        "return importFromHost({}, decode{});",
+                        fn_call,
+                        get_helper_suffix(ty)
+                    )
+                }
+            };
+
+            format!(
+                "// @ts-ignore: decorator
+@external(\"fp\", \"{}\")
+declare function {}({}): {};
+
+{}export function {}({}): {} {{
+{}    {}
+}}",
+                fp_gen_name,
+                fp_gen_name,
+                extern_args,
+                extern_return_type,
+                join_lines(&format_docs(&function.doc_lines), String::to_owned),
+                name.to_camel_case(),
+                format_function_args(function, types, &mut used_names),
+                format_return_type(function.return_type.as_ref(), types, &mut used_names),
+                export_args,
+                fn_call
+            )
+        })
+        .collect::<Vec<_>>();
+
+    write_bindings_file(
+        format!("{}/import.ts", path),
+        format!(
+            "// ============================================= //
+// Imported functions for AssemblyScript plugins //
+//                                               //
+// This file is generated. PLEASE DO NOT MODIFY. //
+// ============================================= //
+
+import {{
+    FatPtr,
+    exportBinaryToHost,
+    exportToHost,
+    importBinaryFromHost,
+    importFromHost,
+}} from \"./fp\";
+{}
+{}\n",
+            format_types_import(&used_names),
+            functions.join("\n\n")
+        ),
+    );
+}
+
+fn generate_exported_function_bindings(
+    export_functions: &FunctionList,
+    types: &TypeMap,
+    path: &str,
+) {
+    let mut used_names = BTreeSet::new();
+    let functions = export_functions
+        .iter()
+        .map(|function| {
+            if function.is_async {
+                return format_unsupported_async_function(function);
+            }
+
+            let name = &function.name;
+            let camel_name = name.to_camel_case();
+            let impl_name = format!("{}Impl", camel_name);
+            let impl_type = format!(
+                "({}) => {}",
+                format_function_args(function, types, &mut used_names),
+                format_return_type(function.return_type.as_ref(), types, &mut used_names)
+            );
+
+            let wasm_args = function
+                .args
+                .iter()
+                .flat_map(|arg| {
+                    if let Some(ty) = arg.by_value_struct(types) {
+                        ty.fields
+                            .iter()
+                            .map(|field| {
+                                format!(
+                                    "{}: {}",
+                                    arg.by_value_field_name(field),
+                                    format_wasm_type(Some(&field.ty))
+                                )
+                            })
+                            .collect()
+                    } else if let Some(primitive) = arg.ty.as_primitive() {
+                        vec![format!(
+                            "{}: {}",
+                            get_variable_name(&arg.name).to_camel_case(),
+                            primitive.name()
+                        )]
+                    } else {
+                        vec![format!("{}: FatPtr", get_pointer_name(&arg.name))]
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join(", ");
+            let import_args = function
+                .args
+                .iter()
+                .filter(|arg| !arg.ty.is_primitive())
+                .map(|arg| {
+                    if let Some(ty) = arg.by_value_struct(types) {
+                        let name = get_variable_name(&arg.name).to_camel_case();
+                        let class_name = format_ident(&arg.ty, types);
+                        used_names.insert(class_name.clone());
+                        return format!(
+                            "    const {} = new {}();\n{}",
+                            name,
+                            class_name,
+                            ty.fields
+                                .iter()
+                                .map(|field| format!(
+                                    "    {}.{} = {};\n",
+                                    name,
+                                    get_property_name(&field.name),
+                                    arg.by_value_field_name(field)
+                                ))
+                                .collect::<Vec<_>>()
+                                .join("")
+                        );
+                    }
+
+                    if arg.ty.is_binary(types) {
+                        return format!(
+                            "    const {} = importBinaryFromHost({});\n",
+                            get_variable_name(&arg.name).to_camel_case(),
+                            get_pointer_name(&arg.name)
+                        );
+                    }
+
+                    used_names.insert(format!("decode{}", get_helper_suffix(&arg.ty)));
+                    format!(
+                        "    const {} = importFromHost({}, decode{});\n",
+                        get_variable_name(&arg.name).to_camel_case(),
+                        get_pointer_name(&arg.name),
+                        get_helper_suffix(&arg.ty)
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join("");
+            let call_args = function
+                .args
+                .iter()
+                .map(|arg| get_variable_name(&arg.name).to_camel_case())
+                .collect::<Vec<_>>()
+                .join(", ");
+            let fn_call = format!("{}({})", impl_name, call_args);
+            let fn_call = match &function.return_type {
+                None => format!("{};", fn_call),
+                Some(ty) if ty.is_primitive() => format!("return {};", fn_call),
+                Some(ty) if ty.is_binary(types) => {
+                    format!("return exportBinaryToHost({});", fn_call)
+                }
+                Some(ty) => {
+                    used_names.insert(format!("encode{}", get_helper_suffix(ty)));
+                    format!(
+                        "return exportToHost({}, encode{});",
+                        fn_call,
+                        get_helper_suffix(ty)
+                    )
+                }
+            };
+
+            format!(
+                "let {}: {} = {};
+
+{}export function implement{}(impl: {}): void {{
+    {} = impl;
+}}
+
+export function __fp_gen_{}({}): {} {{
+{}    {}
+}}",
+                impl_name,
+                impl_type,
+                format_missing_implementation(function, types),
+                join_lines(&format_docs(&function.doc_lines), String::to_owned),
+                camel_name.to_pascal_case(),
+                impl_type,
+                impl_name,
+                name,
+                wasm_args,
+                format_wasm_type(function.return_type.as_ref()),
+                import_args,
+                fn_call
+            )
+        })
+        .collect::<Vec<_>>();
+
+    write_bindings_file(
+        format!("{}/export.ts", path),
+        format!(
+            "// ============================================= //
+// Exported functions for AssemblyScript plugins //
+//                                               //
+// This file is generated. PLEASE DO NOT MODIFY. //
+// ============================================= //
+//
+// Plugins provide their implementation by calling the `implement*()` function
+// for every function they wish to export, and by re-exporting the matching
+// `__fp_gen_*()` wrapper from their entry file.
+
+import {{
+    FatPtr,
+    exportBinaryToHost,
+    exportToHost,
+    importBinaryFromHost,
+    importFromHost,
+}} from \"./fp\";
+{}
+{}\n",
+            format_types_import(&used_names),
+            functions.join("\n\n")
+        ),
+    );
+}
+
+/// Async functions cannot be expressed in AssemblyScript, so we only leave a
+/// note for plugin authors.
+fn format_unsupported_async_function(function: &Function) -> String {
+    format!(
+        "// Async function `{}` is not supported by AssemblyScript plugins.",
+        function.name
+    )
+}
+
+fn format_missing_implementation(function: &Function, types: &TypeMap) -> String {
+    format!(
+        "({}): {} => {{
+    throw new Error(\"Exported function \\\"{}\\\" has not been implemented\");
+}}",
+        function
+            .args
+            .iter()
+            .map(|arg| format!(
+                "_{}: {}",
+                get_variable_name(&arg.name).to_camel_case(),
+                format_ident(&arg.ty, types)
+            ))
+            .collect::<Vec<_>>()
+            .join(", "),
+        function
+            .return_type
+            .as_ref()
+            .map(|ty| format_ident(ty, types))
+            .unwrap_or_else(|| "void".to_owned()),
+        function.name
+    )
+}
+
+fn format_function_args(
+    function: &Function,
+    types: &TypeMap,
+    used_names: &mut BTreeSet<String>,
+) -> String {
+    function
+        .args
+        .iter()
+        .map(|arg| {
+            let ty = format_ident(&arg.ty, types);
+            used_names.insert(ty.clone());
+            format!("{}: {}", get_variable_name(&arg.name).to_camel_case(), ty)
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn format_return_type(
+    return_type: Option<&TypeIdent>,
+    types: &TypeMap,
+    used_names: &mut BTreeSet<String>,
+) -> String {
+    match return_type {
+        Some(ty) => {
+            let ty = format_ident(ty, types);
+            used_names.insert(ty.clone());
+            ty
+        }
+        None => "void".to_owned(),
+    }
+}
+
+fn format_types_import(used_names: &BTreeSet<String>) -> String {
+    // Only named types and helpers need to be imported:
+    let names = used_names
+        .iter()
+        .filter(|name| name.chars().all(|c| c.is_alphanumeric() || c == '_'))
+        .filter(|name| {
+            Primitive::from_str(name).is_err() && *name != "string" && *name != "ArrayBuffer"
+        })
+        .map(|name| format!("    {},\n", name))
+        .collect::<Vec<_>>();
+    if names.is_empty() {
+        "".to_owned()
+    } else {
+        format!("import {{\n{}}} from \"./types\";\n", names.join(""))
+    }
+}
+
+fn format_wasm_type(ty: Option<&TypeIdent>) -> String {
+    match ty {
+        Some(ty) => match ty.as_primitive() {
+            Some(primitive) => primitive.name(),
+            None => "FatPtr".to_owned(),
+        },
+        None => "void".to_owned(),
+    }
+}
+
+fn format_field_type_and_default(ident: &TypeIdent, types: &TypeMap) -> String {
+    let ty = format_ident(ident, types);
+    let default_value = match resolve_type(ident, types) {
+        Some(Type::Container(name, _)) if name == "Option" => Some("null".to_owned()),
+        Some(Type::Container(_, _)) => {
+            return format_field_type_and_default(first_generic_arg(ident), types)
+        }
+        Some(Type::Custom(custom)) => Some(match as_custom_type(custom) {
+            AsCustomType::ArrayBuffer => "new ArrayBuffer(0)".to_owned(),
+            AsCustomType::String => "\"\"".to_owned(),
+            AsCustomType::Opaque => format!("new {}()", ty),
+        }),
+        Some(Type::List(_, _)) | Some(Type::Map(_, _, _)) => Some(format!("new {}()", ty)),
+        Some(Type::Enum(definition)) if definition.options.repr.is_some() => definition
+            .variants
+            .iter()
+            .find(|variant| !variant.attrs.is_skipped())
+            .map(|variant| format!("{}.{}", ty, variant.name)),
+        Some(Type::Primitive(Primitive::Bool)) => Some("false".to_owned()),
+        Some(Type::Primitive(Primitive::Char)) => Some("\"\"".to_owned()),
+        Some(Type::Primitive(Primitive::I128 | Primitive::U128)) => {
+            Some("new ArrayBuffer(16)".to_owned())
+        }
+        Some(Type::Primitive(_)) => Some("0".to_owned()),
+        Some(Type::String) => Some("\"\"".to_owned()),
+        // Classes are not instantiated by default, to avoid infinite recursion
+        // for recursive types:
+        _ => None,
+    };
+    match default_value {
+        Some(default_value) => format!(": {} = {}", ty, default_value),
+        None => format!("!: {}", ty),
+    }
+}
+
+fn format_encode_stmt(ident: &TypeIdent, value: &str, types: &TypeMap) -> String {
+    match resolve_type(ident, types) {
+        Some(Type::Primitive(primitive)) => {
+            format!(
+                "writer.write{}({});",
+                format_msgpack_primitive(*primitive),
+                value
+            )
+        }
+        _ => format!("encode{}(writer, {});", get_helper_suffix(ident), value),
+    }
+}
+
+fn format_decode_expr(ident: &TypeIdent, types: &TypeMap) -> String {
+    match resolve_type(ident, types) {
+        Some(Type::Primitive(primitive)) => {
+            format!("reader.read{}()", format_msgpack_primitive(*primitive))
+        }
+        _ => format!("decode{}(reader)", get_helper_suffix(ident)),
+    }
+}
+
+fn format_msgpack_primitive(primitive: Primitive) -> &'static str {
+    match primitive {
+        Primitive::Bool => "Bool",
+        Primitive::Char => "String",
+        Primitive::F32 => "Float32",
+        Primitive::F64 => "Float64",
+        Primitive::I8 => "Int8",
+        Primitive::I16 => "Int16",
+        Primitive::I32 => "Int32",
+        Primitive::I64 | Primitive::Isize => "Int64",
+        // Serde serializes 128-bit integers as 16 big-endian bytes:
+        Primitive::I128 | Primitive::U128 => "ByteArray",
+        Primitive::U8 => "UInt8",
+        Primitive::U16 => "UInt16",
+        Primitive::U32 => "UInt32",
+        Primitive::U64 | Primitive::Usize => "UInt64",
+    }
+}
+
+/// Formats a primitive as it is represented in AssemblyScript, when it is
+/// part of a MessagePack-encoded type.
+fn format_encoded_primitive(primitive: Primitive) -> String {
+    match primitive {
+        Primitive::Char => "string".to_owned(),
+        Primitive::I128 | Primitive::U128 => "ArrayBuffer".to_owned(),
+        Primitive::Isize => "i64".to_owned(),
+        Primitive::Usize => "u64".to_owned(),
+        primitive => primitive.name(),
+    }
+}
+
+/// Formats a type so it's valid AssemblyScript.
+fn format_ident(ident: &TypeIdent, types: &TypeMap) -> String {
+    match types.get(ident) {
+        Some(ty) => format_type_with_ident(ty, ident, types),
+        None => ident.to_string(), // Must be a generic.
+    }
+}
+
+/// Formats a type so it's valid AssemblyScript.
+fn format_type_with_ident(ty: &Type, ident: &TypeIdent, types: &TypeMap) -> String {
+    match ty {
+        Type::Alias(name, _) => name.clone(),
+        Type::Array(_, _) => format!(
+            "StaticArray<{}>",
+            format_ident(first_generic_arg(ident), types)
+        ),
+        Type::Container(name, _) => {
+            let arg = first_generic_arg(ident);
+            if name != "Option" {
+                format_ident(arg, types)
+            } else if is_primitive(arg, types) {
+                format!("Box<{}> | null", format_ident(arg, types))
+            } else {
+                format!("{} | null", format_ident(arg, types))
+            }
+        }
+        Type::Custom(custom) => match as_custom_type(custom) {
+            AsCustomType::ArrayBuffer => "ArrayBuffer".to_owned(),
+            AsCustomType::String => "string".to_owned(),
+            AsCustomType::Opaque => get_class_name(&custom.ident),
+        },
+        Type::Enum(_) | Type::Struct(_) => get_class_name(ident),
+        Type::List(_, _) => format!("Array<{}>", format_ident(first_generic_arg(ident), types)),
+        Type::Map(_, _, _) => {
+            let arg2 = ident
+                .generic_args
+                .get(1)
+                .expect("Identifier was expected to contain two arguments");
+            format!(
+                "Map<{}, {}>",
+                format_ident(first_generic_arg(ident), types),
+                format_ident(arg2, types)
+            )
+        }
+        Type::Primitive(primitive) => format_encoded_primitive(*primitive),
+        Type::String => "string".to_owned(),
+        Type::Tuple(_) => get_class_name(ident),
+        Type::Unit => "void".to_owned(),
+    }
+}
+
+#[derive(PartialEq)]
+enum AsCustomType {
+    ArrayBuffer,
+    String,
+    /// Types that cannot be represented in AssemblyScript, such as dynamic
+    /// JSON values, are skipped when decoding and cannot be encoded. These are
+    /// only generated if `AssemblyScriptPluginConfig::allow_opaque_types` is
+    /// set.
+    Opaque,
+}
+
+/// Custom types only specify how they are represented in TypeScript, so we
+/// derive the AssemblyScript representation from that.
+fn as_custom_type(custom: &CustomType) -> AsCustomType {
+    let is_string_union = |declaration: &str| {
+        declaration
+            .split('|')
+            .map(str::trim)
+            .filter(|part| !part.is_empty())
+            .all(|part| part.starts_with('"') && part.ends_with('"'))
+    };
+    // Branded strings, such as `string & { readonly __brand: "Uuid" }`:
+    let is_branded_string = |declaration: &str| declaration.trim_start().starts_with("string &");
+
+    match (custom.ts_ty.as_str(), custom.ts_declaration.as_deref()) {
+        ("ArrayBuffer", None) => AsCustomType::ArrayBuffer,
+        ("string", None) => AsCustomType::String,
+        (_, Some(declaration))
+            if is_string_union(declaration) || is_branded_string(declaration) =>
+        {
+            AsCustomType::String
+        }
+        _ => AsCustomType::Opaque,
+    }
+}
+
+/// Resolves aliases, newtypes and transparent containers to the underlying
+/// type.
+fn resolve_type<'a>(ident: &TypeIdent, types: &'a TypeMap) -> Option<&'a Type> {
+    match types.get(ident)? {
+        Type::Alias(_, target) => resolve_type(target, types),
+        Type::Container(name, _) if name != "Option" => {
+            resolve_type(first_generic_arg(ident), types)
+        }
+        Type::Struct(ty) => match ty.specialize(ident).transparent_field() {
+            Some(field) => resolve_type(&field.ty, types),
+            None => types.get(ident),
+        },
+        ty => Some(ty),
+    }
+}
+
+/// Returns whether the type is represented as a value type in AssemblyScript,
+/// which means it needs to be boxed in order to be nullable.
+fn is_primitive(ident: &TypeIdent, types: &TypeMap) -> bool {
+    matches!(
+        resolve_type(ident, types),
+        Some(Type::Primitive(primitive))
+            if !matches!(primitive, Primitive::Char | Primitive::I128 | Primitive::U128)
+    )
+}
+
+fn first_generic_arg(ident: &TypeIdent) -> &TypeIdent {
+    ident
+        .generic_args
+        .first()
+        .expect("Identifier was expected to contain a generic argument")
+}
+
+/// Returns the class name for a struct or enum, including its generic
+/// arguments, since every instantiation results in its own class.
+fn get_class_name(ident: &TypeIdent) -> String {
+    get_helper_suffix(ident)
+}
+
+/// Returns a name that uniquely identifies the type, for use in the names of
+/// generated classes and (de)serialization helpers.
+fn get_helper_suffix(ident: &TypeIdent) -> String {
+    let name = ident.name.rsplit("::").next().unwrap_or(&ident.name);
+    let mut suffix = if let Some(len) = ident.array_len() {
+        format!("Array{}", len)
+    } else if ident.is_tuple() {
+        "Tuple".to_owned()
+    } else if Primitive::from_str(name).is_ok() {
+        name.to_pascal_case()
+    } else {
+        name.to_owned()
+    };
+    for arg in ident.generic_args.iter() {
+        suffix.push_str(&get_helper_suffix(arg));
+    }
+    suffix
+}
+
+fn get_variant_class_name(enum_name: &str, variant: &Variant) -> String {
+    format!("{}{}", enum_name, get_variable_name(&variant.name))
+}
+
+fn get_tuple_item_name(items: &[TypeIdent], index: usize) -> String {
+    if items.len() == 1 {
+        "value".to_owned()
+    } else {
+        format!("value{}", index)
+    }
+}
+
+fn get_property_name(name: &str) -> String {
+    get_variable_name(name).to_camel_case()
+}
+
+fn format_docs(doc_lines: &[String]) -> Vec<String> {
+    if doc_lines.is_empty() {
+        Vec::new()
+    } else {
+        let mut lines = vec!["/**".to_owned()];
+        lines.append(
+            &mut doc_lines
+                .iter()
+                .map(|doc_line| format!(" *{}", doc_line))
+                .collect(),
+        );
+        lines.push(" */".to_owned());
+        lines
+    }
+}
+
+fn indent(code: &str) -> String {
+    code.split('\n')
+        .map(|line| {
+            if line.is_empty() {
+                line.to_owned()
+            } else {
+                format!("    {}", line)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn split_lines(code: &str) -> Vec<String> {
+    code.split('\n').map(str::to_owned).collect()
+}
+
+fn join_lines<F>(lines: &[String], formatter: F) -> String
+where
+    F: Fn(&String) -> String,
+{
+    let lines = lines
+        .iter()
+        .map(|line| {
+            if line.is_empty() {
+                line.clone()
+            } else {
+                formatter(line)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    if lines.is_empty() {
+        lines
+    } else {
+        format!("{}\n", lines)
+    }
+}
+
+fn write_bindings_file<C>(file_path: String, contents: C)
+where
+    C: AsRef<[u8]>,
+{
+    fs::write(&file_path, &contents).expect("Could not write bindings file");
+}
+
+#[cfg(test)]
+mod test {
+    use super::check_opaque_types;
+    use crate::types::{CustomType, Type, TypeIdent, TypeMap};
+    use std::collections::{BTreeMap, BTreeSet};
+
+    fn custom_type(name: &str, ts_ty: &str, ts_declaration: Option<&str>) -> Type {
+        Type::Custom(CustomType {
+            ident: TypeIdent::from(name),
+            binary: false,
+            rs_ty: name.to_owned(),
+            rs_dependencies: BTreeMap::new(),
+            serde_attrs: Vec::new(),
+            ts_ty: ts_ty.to_owned(),
+            ts_declaration: ts_declaration.map(ToOwned::to_owned),
+        })
+    }
+
+    #[test]
+    fn test_representable_custom_types() {
+        let mut types = TypeMap::new();
+        types.insert(TypeIdent::from("Uuid"), custom_type("Uuid", "string", None));
+        types.insert(
+            TypeIdent::from("Level"),
+            custom_type("Level", "Level", Some("\"debug\" | \"info\"")),
+        );
+
+        check_opaque_types(&types.keys().cloned().collect(), &types);
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot be represented in AssemblyScript plugins: JsonValue")]
+    fn test_opaque_custom_types() {
+        let mut types = TypeMap::new();
+        types.insert(
+            TypeIdent::from("JsonValue"),
+            custom_type("JsonValue", "JsonValue", Some("null | boolean | number")),
+        );
+
+        check_opaque_types(&BTreeSet::from([TypeIdent::from("JsonValue")]), &types);
+    }
+}
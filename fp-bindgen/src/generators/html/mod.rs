@@ -0,0 +1,43 @@
+use super::markdown::format_protocol_docs;
+use crate::{functions::FunctionList, types::TypeMap};
+use pulldown_cmark::{html, Options, Parser};
+use std::fs;
+
+pub(crate) fn generate_bindings(
+    import_functions: FunctionList,
+    export_functions: FunctionList,
+    types: TypeMap,
+    path: &str,
+) {
+    let markdown = format_protocol_docs(&import_functions, &export_functions, &types);
+
+    let mut body = String::new();
+    html::push_html(
+        &mut body,
+        Parser::new_ext(&markdown, Options::ENABLE_TABLES),
+    );
+
+    write_bindings_file(
+        format!("{}/index.html", path),
+        format!(
+            "<!DOCTYPE html>
+<html>
+<head>
+<meta charset=\"utf-8\">
+<title>Protocol reference</title>
+</head>
+<body>
+{}</body>
+</html>
+",
+            body
+        ),
+    );
+}
+
+fn write_bindings_file<C>(file_path: String, contents: C)
+where
+    C: AsRef<[u8]>,
+{
+    fs::write(&file_path, &contents).expect("Could not write bindings file");
+}
@@ -0,0 +1,826 @@
+use super::naming::{
+    get_deserialize_field_name, get_deserialize_variant_name, get_field_name, get_variant_name,
+};
+use crate::{
+    functions::FunctionList,
+    primitives::Primitive,
+    types::{
+        CustomType, Enum, EnumOptions, Field, StructKind, StructOptions, Type, TypeIdent, TypeMap,
+        Variant,
+    },
+};
+use inflector::Inflector;
+use serde_json::{json, Map, Value};
+use std::{collections::BTreeMap, fs};
+
+const SCHEMA_DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+pub(crate) fn generate_bindings(
+    import_functions: FunctionList,
+    export_functions: FunctionList,
+    types: TypeMap,
+    path: &str,
+) {
+    let mut generator = SchemaGenerator::new(&types);
+
+    for function in import_functions.iter().chain(export_functions.iter()) {
+        for arg in function.args.iter() {
+            generator.schema_for_ident(&arg.ty);
+        }
+        if let Some(return_type) = &function.return_type {
+            generator.schema_for_ident(return_type);
+        }
+    }
+
+    // JSON Schema has no generics, so every instantiation of a generic type
+    // gets a definition of its own. Types that no function refers to get a
+    // definition as well:
+    for (ident, ty) in types.iter() {
+        if matches!(ty, Type::Alias(_, _) | Type::Enum(_) | Type::Struct(_)) {
+            generator.schema_for_ident(ident);
+        }
+    }
+
+    let schema = json!({
+        "$schema": SCHEMA_DIALECT,
+        "$defs": generator.defs,
+    });
+
+    write_bindings_file(
+        format!("{}/schema.json", path),
+        format!(
+            "{}\n",
+            serde_json::to_string_pretty(&schema).expect("Could not serialize JSON Schema")
+        ),
+    );
+}
+
+struct SchemaGenerator<'a> {
+    types: &'a TypeMap,
+    defs: BTreeMap<String, Value>,
+}
+
+impl<'a> SchemaGenerator<'a> {
+    fn new(types: &'a TypeMap) -> Self {
+        Self {
+            types,
+            defs: BTreeMap::new(),
+        }
+    }
+
+    /// Returns the schema for the given type. Structs, enums and aliases are
+    /// added to the definitions and referenced using `$ref`.
+    fn schema_for_ident(&mut self, ident: &TypeIdent) -> Value {
+        let ty = match self.types.get(ident) {
+            Some(ty) => ty,
+            None => return json!({}), // Must be a generic.
+        };
+
+        match ty {
+            Type::Alias(_, _) | Type::Enum(_) | Type::Struct(_) => {
+                let def_name = get_def_name(ident);
+                if !self.defs.contains_key(&def_name) {
+                    // Insert a placeholder first, so recursive types terminate:
+                    self.defs.insert(def_name.clone(), Value::Null);
+                    let schema = self.create_definition(ty, ident);
+                    self.defs.insert(def_name.clone(), schema);
+                }
+                json!({ "$ref": format!("#/$defs/{}", def_name) })
+            }
+            Type::Array(_, len) => {
+                let item = ident
+                    .generic_args
+                    .first()
+                    .expect("Identifier was expected to contain a generic argument");
+                json!({
+                    "type": "array",
+                    "items": self.schema_for_ident(item),
+                    "minItems": len,
+                    "maxItems": len,
+                })
+            }
+            Type::Container(name, _) => {
+                let arg = ident
+                    .generic_args
+                    .first()
+                    .expect("Identifier was expected to contain a generic argument");
+                let schema = self.schema_for_ident(arg);
+                if name == "Option" {
+                    json!({ "anyOf": [schema, { "type": "null" }] })
+                } else {
+                    schema
+                }
+            }
+            Type::Custom(custom) => create_custom_schema(custom),
+            Type::List(name, _) => {
+                let arg = ident
+                    .generic_args
+                    .first()
+                    .expect("Identifier was expected to contain a generic argument");
+                let mut schema = json!({
+                    "type": "array",
+                    "items": self.schema_for_ident(arg),
+                });
+                if name.ends_with("Set") {
+                    schema["uniqueItems"] = Value::Bool(true);
+                }
+                schema
+            }
+            Type::Map(_, _, _) => {
+                let value = ident
+                    .generic_args
+                    .get(1)
+                    .expect("Identifier was expected to contain two arguments");
+                json!({
+                    "type": "object",
+                    "additionalProperties": self.schema_for_ident(value),
+                })
+            }
+            Type::Primitive(primitive) => create_primitive_schema(*primitive),
+            Type::String => json!({ "type": "string" }),
+            Type::Tuple(_) => self.create_tuple_schema(&ident.generic_args),
+            Type::Unit => json!({ "type": "null" }),
+        }
+    }
+
+    fn create_definition(&mut self, ty: &Type, ident: &TypeIdent) -> Value {
+        match ty {
+            Type::Alias(_, target) => self.schema_for_ident(target),
+            Type::Enum(ty) => self.create_enum_schema(ty, ident),
+            Type::Struct(ty) => {
+                let ty = ty.specialize(ident);
+                let fields = &ty.fields;
+                let mut schema = match (ty.transparent_field(), ty.kind) {
+                    // Newtypes are serialized transparently:
+                    (Some(field), _) => self.schema_for_ident(&field.ty),
+                    (None, StructKind::Named) => self.create_object_schema(fields, &ty.options),
+                    (None, StructKind::Tuple) => {
+                        let items = fields
+                            .iter()
+                            .map(|field| field.ty.clone())
+                            .collect::<Vec<_>>();
+                        self.create_tuple_schema(&items)
+                    }
+                    (None, StructKind::Unit) => json!({ "type": "null" }),
+                };
+                // The tag is always serialized, but is not required when
+                // deserializing:
+                if let (Some((tag, name)), Some(properties)) = (
+                    ty.tag(),
+                    schema.get_mut("properties").and_then(Value::as_object_mut),
+                ) {
+                    properties.insert(tag.to_owned(), json!({ "const": name }));
+                }
+                add_description(&mut schema, &ty.doc_lines);
+                schema
+            }
+            _ => unreachable!("Only aliases, enums and structs have definitions"),
+        }
+    }
+
+    fn create_enum_schema(&mut self, ty: &Enum, ident: &TypeIdent) -> Value {
+        let mut variants = ty
+            .specialize(ident)
+            .variants
+            .iter()
+            .filter(|variant| !variant.attrs.is_skipped())
+            .flat_map(|variant| {
+                // If the variant has a different name when deserializing than
+                // when serializing, either one may be encountered:
+                let mut names = vec![get_variant_name(variant, &ty.options)];
+                let deserialize_name = get_deserialize_variant_name(variant, &ty.options);
+                if ty.options.repr.is_none() && !names.contains(&deserialize_name) {
+                    names.push(deserialize_name);
+                }
+
+                names
+                    .iter()
+                    .map(|name| {
+                        let mut schema = self.create_variant_schema(variant, name, &ty.options);
+                        add_description(&mut schema, &variant.doc_lines);
+                        schema
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        // Variants that are not known to this version of the protocol are
+        // accepted as-is:
+        if ty.options.unknown_variant.is_some() {
+            variants.push(json!({
+                "description": "A variant that is not known to this version of the protocol.",
+                "not": { "anyOf": variants.clone() },
+            }));
+        }
+
+        // Untagged variants may overlap, so we cannot require exactly one of
+        // them to match:
+        let mut schema = if ty.options.untagged {
+            json!({ "anyOf": variants })
+        } else {
+            json!({ "oneOf": variants })
+        };
+        add_description(&mut schema, &ty.doc_lines);
+        schema
+    }
+
+    fn create_variant_schema(
+        &mut self,
+        variant: &Variant,
+        name: &str,
+        opts: &EnumOptions,
+    ) -> Value {
+        // Enums with a `repr` are serialized as their discriminants, so the
+        // variant names only serve as documentation:
+        if let (Some(_), Some(discriminant)) = (opts.repr, variant.discriminant) {
+            return json!({ "title": variant.name, "const": discriminant });
+        }
+
+        let content = match &variant.ty {
+            Type::Unit => None,
+            Type::Struct(ty) => Some(self.create_object_schema(
+                &ty.fields,
+                &StructOptions {
+                    field_casing: variant.attrs.field_casing,
+                    field_casing_deserialize: variant.attrs.field_casing_deserialize,
+                    ..StructOptions::default()
+                },
+            )),
+            Type::Tuple(items) if items.len() == 1 => Some(self.schema_for_ident(&items[0])),
+            Type::Tuple(items) => Some(self.create_tuple_schema(items)),
+            other => panic!("Unsupported type for enum variant: {:?}", other),
+        };
+
+        if opts.untagged {
+            return content.unwrap_or_else(|| json!({ "type": "null" }));
+        }
+
+        match (&opts.tag_prop_name, &opts.content_prop_name, content) {
+            (Some(tag), Some(content_prop_name), Some(content)) => json!({
+                "type": "object",
+                "properties": {
+                    tag: { "const": name },
+                    content_prop_name: content,
+                },
+                "required": [tag, content_prop_name],
+            }),
+            (Some(tag), None, Some(mut content)) => {
+                if content.get("properties").is_some() {
+                    content["properties"][tag] = json!({ "const": name });
+                    match content["required"].as_array_mut() {
+                        Some(required) => required.insert(0, Value::String(tag.clone())),
+                        None => content["required"] = json!([tag]),
+                    }
+                    content
+                } else {
+                    json!({
+                        "allOf": [
+                            {
+                                "type": "object",
+                                "properties": { tag: { "const": name } },
+                                "required": [tag],
+                            },
+                            content,
+                        ]
+                    })
+                }
+            }
+            (Some(tag), _, None) => json!({
+                "type": "object",
+                "properties": { tag: { "const": name } },
+                "required": [tag],
+            }),
+            (None, _, Some(content)) => json!({
+                "type": "object",
+                "properties": { name: content },
+                "required": [name],
+                "additionalProperties": false,
+            }),
+            (None, _, None) => json!({ "const": name }),
+        }
+    }
+
+    fn create_object_schema(&mut self, fields: &[Field], opts: &StructOptions) -> Value {
+        let casing = opts.field_casing;
+        let mut properties = Map::new();
+        let mut required = Vec::new();
+        let mut flattened = Vec::new();
+
+        for field in fields.iter().filter(|field| !field.attrs.is_skipped()) {
+            if field.attrs.flatten {
+                let flattened_schema = match self.types.get(&field.ty) {
+                    Some(Type::Struct(ty)) => {
+                        let fields = ty.specialize(&field.ty).fields;
+                        self.create_object_schema(&fields, &ty.options)
+                    }
+                    _ => self.schema_for_ident(&field.ty),
+                };
+
+                // Properties of flattened structs are merged into the parent,
+                // anything else needs to be matched separately:
+                match flattened_schema
+                    .get("properties")
+                    .and_then(Value::as_object)
+                {
+                    Some(flattened_properties) => {
+                        for (name, schema) in flattened_properties {
+                            properties.insert(name.clone(), schema.clone());
+                        }
+                        if let Some(flattened_required) = flattened_schema["required"].as_array() {
+                            required.extend(flattened_required.iter().cloned());
+                        }
+                    }
+                    None => flattened.push(flattened_schema),
+                }
+                continue;
+            }
+
+            let name = get_field_name(field, casing);
+            let mut schema = self.schema_for_ident(&field.ty);
+            add_description(&mut schema, &field.doc_lines);
+            properties.insert(name.clone(), schema.clone());
+
+            // If the field has a different name when deserializing, either
+            // name may be present:
+            let deserialize_name =
+                get_deserialize_field_name(field, opts.deserialize_field_casing());
+            let has_distinct_names = deserialize_name != name;
+            if has_distinct_names {
+                properties.insert(deserialize_name, schema);
+            }
+
+            let is_optional = field.ty.name == "Option"
+                || has_distinct_names
+                || opts.default.is_some()
+                || field.attrs.is_optional();
+            if !is_optional {
+                required.push(Value::String(name));
+            }
+        }
+
+        let mut schema = json!({
+            "type": "object",
+            "properties": properties,
+        });
+        if !required.is_empty() {
+            schema["required"] = Value::Array(required);
+        }
+        if opts.deny_unknown_fields {
+            schema["additionalProperties"] = Value::Bool(false);
+        }
+        if flattened.is_empty() {
+            schema
+        } else {
+            flattened.insert(0, schema);
+            json!({ "allOf": flattened })
+        }
+    }
+
+    fn create_tuple_schema(&mut self, items: &[TypeIdent]) -> Value {
+        let item_schemas = items
+            .iter()
+            .map(|item| self.schema_for_ident(item))
+            .collect::<Vec<_>>();
+        json!({
+            "type": "array",
+            "prefixItems": item_schemas,
+            "minItems": items.len(),
+            "maxItems": items.len(),
+        })
+    }
+}
+
+/// Creates a schema for a custom type. The custom types that are built into
+/// fp-bindgen have explicit schemas, while others get a schema based on their
+/// TypeScript declaration.
+fn create_custom_schema(custom: &CustomType) -> Value {
+    create_builtin_custom_schema(&custom.rs_ty).unwrap_or_else(|| create_declared_schema(custom))
+}
+
+/// Returns the schema for one of the custom types that are built into
+/// fp-bindgen, if the given Rust type is one of them.
+fn create_builtin_custom_schema(rs_ty: &str) -> Option<Value> {
+    let schema = match rs_ty {
+        "bytes::Bytes" | "serde_bytes::ByteBuf" => create_ts_type_schema("ArrayBuffer"),
+        "chrono::DateTime<chrono::Utc>" | "chrono::DateTime<chrono::FixedOffset>" => {
+            json!({ "type": "string", "format": "date-time" })
+        }
+        "chrono::NaiveDate" => json!({ "type": "string", "format": "date" }),
+        // These are not serialized in RFC 3339 format, because they either lack
+        // an offset or use the human-readable format of the `time` crate:
+        "chrono::NaiveDateTime" | "time::OffsetDateTime" | "time::PrimitiveDateTime" => {
+            json!({ "type": "string" })
+        }
+        "chrono::TimeDelta" => json!({
+            "type": "array",
+            "prefixItems": [
+                create_primitive_schema(Primitive::I64),
+                create_primitive_schema(Primitive::I32),
+            ],
+            "minItems": 2,
+            "maxItems": 2,
+        }),
+        "std::borrow::Cow<'static, str>" => json!({ "type": "string" }),
+        "std::time::Duration" => json!({
+            "type": "object",
+            "properties": {
+                "secs": create_primitive_schema(Primitive::U64),
+                "nanos": create_primitive_schema(Primitive::U32),
+            },
+            "required": ["secs", "nanos"],
+        }),
+        "std::time::SystemTime" => json!({
+            "type": "object",
+            "properties": {
+                "secs_since_epoch": create_primitive_schema(Primitive::U64),
+                "nanos_since_epoch": create_primitive_schema(Primitive::U32),
+            },
+            "required": ["secs_since_epoch", "nanos_since_epoch"],
+        }),
+        "uuid::Uuid" => json!({ "type": "string", "format": "uuid" }),
+        "serde_json::Value" | "rmpv::Value" => json!({}),
+        "serde_json::Map<String, serde_json::Value>" => json!({ "type": "object" }),
+        "http::Method" => create_http_method_schema(),
+        "http::Scheme" => json!({ "type": "string", "enum": ["http", "https"] }),
+        "http::Uri" => json!({ "type": "string", "format": "uri-reference" }),
+        "http::HeaderMap" => create_header_map_schema(),
+        "http::HeaderValue" => create_header_value_schema(),
+        "http::StatusCode" => create_status_code_schema(),
+        "http::Version" => create_http_version_schema(),
+        "fp_bindgen_support::http::HttpRequest" => json!({
+            "type": "object",
+            "properties": {
+                "method": create_http_method_schema(),
+                "uri": { "type": "string", "format": "uri-reference" },
+                "version": create_http_version_schema(),
+                "headers": create_header_map_schema(),
+                "body": create_ts_type_schema("ArrayBuffer"),
+            },
+            "required": ["method", "uri"],
+        }),
+        "fp_bindgen_support::http::HttpResponse" => json!({
+            "type": "object",
+            "properties": {
+                "status": create_status_code_schema(),
+                "version": create_http_version_schema(),
+                "headers": create_header_map_schema(),
+                "body": create_ts_type_schema("ArrayBuffer"),
+            },
+            "required": ["status"],
+        }),
+        _ => return None,
+    };
+    Some(schema)
+}
+
+fn create_http_method_schema() -> Value {
+    json!({
+        "type": "string",
+        "enum": [
+            "GET", "POST", "PUT", "DELETE", "HEAD", "OPTIONS", "CONNECT", "PATCH", "TRACE",
+        ],
+    })
+}
+
+fn create_http_version_schema() -> Value {
+    json!({
+        "type": "string",
+        "enum": ["HTTP/0.9", "HTTP/1.0", "HTTP/1.1", "HTTP/2.0", "HTTP/3.0"],
+    })
+}
+
+fn create_status_code_schema() -> Value {
+    json!({ "type": "integer", "minimum": 100, "maximum": 999 })
+}
+
+/// Header values are strings, unless they contain characters that are not
+/// visible ASCII, in which case they are bytes.
+fn create_header_value_schema() -> Value {
+    json!({
+        "anyOf": [
+            { "type": "string" },
+            create_ts_type_schema("ArrayBuffer"),
+        ],
+    })
+}
+
+/// Headers with multiple values are serialized as an array of values.
+fn create_header_map_schema() -> Value {
+    json!({
+        "type": "object",
+        "additionalProperties": {
+            "anyOf": [
+                create_header_value_schema(),
+                { "type": "array", "items": create_header_value_schema() },
+            ],
+        },
+    })
+}
+
+/// Creates a schema for a custom type, based on its TypeScript declaration.
+fn create_declared_schema(custom: &CustomType) -> Value {
+    if let Some(declaration) = &custom.ts_declaration {
+        // Branded strings, such as `string & { readonly __brand: "Uuid" }`:
+        if declaration.trim_start().starts_with("string &") {
+            return json!({ "type": "string" });
+        }
+        // Index signatures, such as `{ [key: string]: JsonValue }`:
+        if declaration.trim_start().starts_with('{') {
+            return json!({ "type": "object" });
+        }
+
+        let variants = declaration
+            .split('|')
+            .map(str::trim)
+            .filter(|variant| !variant.is_empty())
+            .collect::<Vec<_>>();
+        let is_string_union = variants
+            .iter()
+            .all(|variant| variant.len() > 1 && variant.starts_with('"') && variant.ends_with('"'));
+        return if is_string_union {
+            json!({
+                "type": "string",
+                "enum": variants
+                    .iter()
+                    .map(|variant| variant.trim_matches('"'))
+                    .collect::<Vec<_>>(),
+            })
+        } else {
+            json!({})
+        };
+    }
+
+    create_ts_type_schema(&custom.ts_ty)
+}
+
+/// Creates a schema for a built-in TypeScript type, or a union of them.
+fn create_ts_type_schema(ts_ty: &str) -> Value {
+    match ts_ty.trim() {
+        "string" => json!({ "type": "string" }),
+        "number" => json!({ "type": "number" }),
+        "boolean" => json!({ "type": "boolean" }),
+        "ArrayBuffer" | "Uint8Array" => json!({
+            "type": "array",
+            "items": create_primitive_schema(Primitive::U8),
+        }),
+        union if union.contains('|') => json!({
+            "anyOf": union.split('|').map(create_ts_type_schema).collect::<Vec<_>>(),
+        }),
+        _ => json!({}),
+    }
+}
+
+fn create_primitive_schema(primitive: Primitive) -> Value {
+    match primitive {
+        Primitive::Bool => json!({ "type": "boolean" }),
+        Primitive::Char => json!({ "type": "string", "minLength": 1, "maxLength": 1 }),
+        Primitive::F32 | Primitive::F64 => json!({ "type": "number" }),
+        Primitive::I8 => json!({ "type": "integer", "minimum": i8::MIN, "maximum": i8::MAX }),
+        Primitive::I16 => json!({ "type": "integer", "minimum": i16::MIN, "maximum": i16::MAX }),
+        Primitive::I32 => json!({ "type": "integer", "minimum": i32::MIN, "maximum": i32::MAX }),
+        Primitive::I64 | Primitive::Isize => {
+            json!({ "type": "integer", "minimum": i64::MIN, "maximum": i64::MAX })
+        }
+        // 128-bit bounds cannot be represented by JSON numbers reliably:
+        Primitive::I128 => json!({ "type": "integer" }),
+        Primitive::U8 => json!({ "type": "integer", "minimum": 0, "maximum": u8::MAX }),
+        Primitive::U16 => json!({ "type": "integer", "minimum": 0, "maximum": u16::MAX }),
+        Primitive::U32 => json!({ "type": "integer", "minimum": 0, "maximum": u32::MAX }),
+        Primitive::U64 | Primitive::Usize => {
+            json!({ "type": "integer", "minimum": 0, "maximum": u64::MAX })
+        }
+        Primitive::U128 => json!({ "type": "integer", "minimum": 0 }),
+    }
+}
+
+fn add_description(schema: &mut Value, doc_lines: &[String]) {
+    if doc_lines.is_empty() {
+        return;
+    }
+
+    if let Some(schema) = schema.as_object_mut() {
+        let description = doc_lines
+            .iter()
+            .map(|line| line.strip_prefix(' ').unwrap_or(line))
+            .collect::<Vec<_>>()
+            .join("\n");
+        schema.insert(
+            "description".to_owned(),
+            Value::String(description.trim().to_owned()),
+        );
+    }
+}
+
+/// Returns the name under which a type is defined in the `$defs` of the
+/// schema. Concrete instances of generic types get their arguments appended,
+/// so `Point<f64>` is defined as `PointF64`.
+fn get_def_name(ident: &TypeIdent) -> String {
+    let mut name = ident.name.clone();
+    for arg in ident.generic_args.iter() {
+        name.push_str(&get_def_name(arg).to_pascal_case());
+    }
+    name
+}
+
+fn write_bindings_file<C>(file_path: String, contents: C)
+where
+    C: AsRef<[u8]>,
+{
+    fs::write(&file_path, &contents).expect("Could not write bindings file");
+}
+
+#[cfg(test)]
+mod test {
+    use super::create_custom_schema;
+    use crate::{prelude::Serializable, types::Type};
+    use pretty_assertions::assert_eq;
+    use serde_json::{json, Value};
+
+    fn schema_for<T: Serializable>() -> Value {
+        match T::ty() {
+            Type::Custom(custom) => create_custom_schema(&custom),
+            other => panic!("Expected a custom type, found: {:?}", other),
+        }
+    }
+
+    fn bytes_schema() -> Value {
+        json!({ "type": "array", "items": { "type": "integer", "minimum": 0, "maximum": 255 } })
+    }
+
+    #[test]
+    fn test_std_schemas() {
+        assert_eq!(
+            schema_for::<std::borrow::Cow<'static, str>>(),
+            json!({ "type": "string" })
+        );
+        assert_eq!(
+            schema_for::<std::time::Duration>(),
+            json!({
+                "type": "object",
+                "properties": {
+                    "secs": { "type": "integer", "minimum": 0, "maximum": u64::MAX },
+                    "nanos": { "type": "integer", "minimum": 0, "maximum": u32::MAX },
+                },
+                "required": ["secs", "nanos"],
+            })
+        );
+        assert_eq!(
+            schema_for::<std::time::SystemTime>(),
+            json!({
+                "type": "object",
+                "properties": {
+                    "secs_since_epoch": { "type": "integer", "minimum": 0, "maximum": u64::MAX },
+                    "nanos_since_epoch": { "type": "integer", "minimum": 0, "maximum": u32::MAX },
+                },
+                "required": ["secs_since_epoch", "nanos_since_epoch"],
+            })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "bytes-compat")]
+    fn test_bytes_schema() {
+        assert_eq!(schema_for::<bytes::Bytes>(), bytes_schema());
+    }
+
+    #[test]
+    #[cfg(feature = "serde-bytes-compat")]
+    fn test_serde_bytes_schema() {
+        assert_eq!(schema_for::<serde_bytes::ByteBuf>(), bytes_schema());
+    }
+
+    #[test]
+    #[cfg(feature = "chrono-compat")]
+    fn test_chrono_schemas() {
+        let date_time = json!({ "type": "string", "format": "date-time" });
+        assert_eq!(schema_for::<chrono::DateTime<chrono::Utc>>(), date_time);
+        assert_eq!(
+            schema_for::<chrono::DateTime<chrono::FixedOffset>>(),
+            date_time
+        );
+        assert_eq!(
+            schema_for::<chrono::NaiveDate>(),
+            json!({ "type": "string", "format": "date" })
+        );
+        assert_eq!(
+            schema_for::<chrono::NaiveDateTime>(),
+            json!({ "type": "string" })
+        );
+        assert_eq!(
+            schema_for::<chrono::TimeDelta>(),
+            json!({
+                "type": "array",
+                "prefixItems": [
+                    { "type": "integer", "minimum": i64::MIN, "maximum": i64::MAX },
+                    { "type": "integer", "minimum": i32::MIN, "maximum": i32::MAX },
+                ],
+                "minItems": 2,
+                "maxItems": 2,
+            })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "time-compat")]
+    fn test_time_schemas() {
+        assert_eq!(
+            schema_for::<time::OffsetDateTime>(),
+            json!({ "type": "string" })
+        );
+        assert_eq!(
+            schema_for::<time::PrimitiveDateTime>(),
+            json!({ "type": "string" })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "uuid-compat")]
+    fn test_uuid_schema() {
+        assert_eq!(
+            schema_for::<uuid::Uuid>(),
+            json!({ "type": "string", "format": "uuid" })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "json-compat")]
+    fn test_json_schemas() {
+        assert_eq!(schema_for::<serde_json::Value>(), json!({}));
+        assert_eq!(
+            schema_for::<serde_json::Map<String, serde_json::Value>>(),
+            json!({ "type": "object" })
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "rmpv-compat")]
+    fn test_rmpv_schema() {
+        assert_eq!(schema_for::<rmpv::Value>(), json!({}));
+    }
+
+    #[test]
+    #[cfg(feature = "http-compat")]
+    fn test_http_schemas() {
+        let method = json!({
+            "type": "string",
+            "enum": ["GET", "POST", "PUT", "DELETE", "HEAD", "OPTIONS", "CONNECT", "PATCH", "TRACE"],
+        });
+        let version = json!({
+            "type": "string",
+            "enum": ["HTTP/0.9", "HTTP/1.0", "HTTP/1.1", "HTTP/2.0", "HTTP/3.0"],
+        });
+        let status = json!({ "type": "integer", "minimum": 100, "maximum": 999 });
+        let header_value = json!({ "anyOf": [{ "type": "string" }, bytes_schema()] });
+        let header_map = json!({
+            "type": "object",
+            "additionalProperties": {
+                "anyOf": [header_value, { "type": "array", "items": header_value }],
+            },
+        });
+
+        assert_eq!(schema_for::<http::Method>(), method);
+        assert_eq!(
+            schema_for::<http::uri::Scheme>(),
+            json!({ "type": "string", "enum": ["http", "https"] })
+        );
+        assert_eq!(
+            schema_for::<http::Uri>(),
+            json!({ "type": "string", "format": "uri-reference" })
+        );
+        assert_eq!(schema_for::<http::HeaderMap>(), header_map);
+        assert_eq!(schema_for::<http::HeaderValue>(), header_value);
+        assert_eq!(schema_for::<http::StatusCode>(), status);
+        assert_eq!(schema_for::<http::Version>(), version);
+
+        #[cfg(feature = "serde-bytes-compat")]
+        {
+            assert_eq!(
+                schema_for::<http::Request<serde_bytes::ByteBuf>>(),
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "method": method,
+                        "uri": { "type": "string", "format": "uri-reference" },
+                        "version": version,
+                        "headers": header_map,
+                        "body": bytes_schema(),
+                    },
+                    "required": ["method", "uri"],
+                })
+            );
+            assert_eq!(
+                schema_for::<http::Response<serde_bytes::ByteBuf>>(),
+                json!({
+                    "type": "object",
+                    "properties": {
+                        "status": status,
+                        "version": version,
+                        "headers": header_map,
+                        "body": bytes_schema(),
+                    },
+                    "required": ["status"],
+                })
+            );
+        }
+    }
+}
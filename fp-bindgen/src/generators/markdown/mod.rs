@@ -0,0 +1,527 @@
+use super::naming::{
+    get_deserialize_field_name, get_deserialize_variant_name, get_field_name, get_variable_name,
+    get_variant_name,
+};
+use crate::{
+    casing::Casing,
+    functions::{Function, FunctionList},
+    types::{
+        CustomType, Enum, EnumOptions, Field, Struct, StructKind, Type, TypeIdent, TypeMap, Variant,
+    },
+};
+use std::fs;
+
+pub(crate) fn generate_bindings(
+    import_functions: FunctionList,
+    export_functions: FunctionList,
+    types: TypeMap,
+    path: &str,
+) {
+    write_bindings_file(
+        format!("{}/index.md", path),
+        format_protocol_docs(&import_functions, &export_functions, &types),
+    );
+}
+
+/// Renders reference documentation for the entire protocol as Markdown.
+pub(crate) fn format_protocol_docs(
+    import_functions: &FunctionList,
+    export_functions: &FunctionList,
+    types: &TypeMap,
+) -> String {
+    let mut sections = vec!["# Protocol reference
+
+This file is generated. PLEASE DO NOT MODIFY."
+        .to_owned()];
+
+    sections.push(
+        "## Imported functions
+
+Functions that are implemented by the runtime and that may be called by plugins."
+            .to_owned(),
+    );
+    sections.append(&mut format_functions(import_functions, types));
+
+    sections.push(
+        "## Exported functions
+
+Functions that may be implemented by plugins and that may be called by the runtime."
+            .to_owned(),
+    );
+    sections.append(&mut format_functions(export_functions, types));
+
+    sections.push(
+        "## Types
+
+Data types are serialized using MessagePack. The examples below use a JSON-like
+notation to describe the serialized shape of every type."
+            .to_owned(),
+    );
+    sections.append(
+        &mut types
+            .definitions()
+            .filter_map(|ty| match ty {
+                Type::Alias(name, ty) => Some(format_alias(name, ty)),
+                Type::Custom(ty) => Some(format_custom_type(ty)),
+                Type::Enum(ty) => Some(format_enum(ty)),
+                Type::Struct(ty) => Some(format_struct(ty, types)),
+                _ => None,
+            })
+            .collect(),
+    );
+
+    format!("{}\n", sections.join("\n\n"))
+}
+
+fn format_functions(functions: &FunctionList, types: &TypeMap) -> Vec<String> {
+    if functions.iter().next().is_none() {
+        return vec!["*None.*".to_owned()];
+    }
+
+    functions
+        .iter()
+        .map(|function| format_function(function, types))
+        .collect()
+}
+
+fn format_function(function: &Function, types: &TypeMap) -> String {
+    let args = function
+        .args
+        .iter()
+        .map(|arg| {
+            format!(
+                "{}: {}",
+                arg.name,
+                arg.format_caller_type(types, ToString::to_string)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+    let return_type = match &function.return_type {
+        Some(ty) => format!(" -> {}", ty),
+        None => "".to_owned(),
+    };
+    let modifiers = if function.is_async { "async " } else { "" };
+
+    join_blocks(&[
+        format!("### `{}`", function.name),
+        format_docs(&function.doc_lines),
+        format!(
+            "```rust\n{}fn {}({}){};\n```",
+            modifiers, function.name, args, return_type
+        ),
+    ])
+}
+
+fn format_alias(name: &str, ty: &TypeIdent) -> String {
+    format!("### `{}`\n\nAlias for `{}`.", name, ty)
+}
+
+fn format_custom_type(ty: &CustomType) -> String {
+    let description = format!(
+        "### `{}`\n\nCustom type that is represented as `{}` in Rust and as `{}` in TypeScript.",
+        ty.ident.name, ty.rs_ty, ty.ts_ty
+    );
+    match &ty.ts_declaration {
+        Some(declaration) => {
+            let declaration = declaration.trim_end();
+            let separator = if declaration.starts_with('\n') {
+                ""
+            } else {
+                " "
+            };
+            format!(
+                "{}\n\n```ts\ntype {} ={}{};\n```",
+                description, ty.ts_ty, separator, declaration
+            )
+        }
+        None => description,
+    }
+}
+
+fn format_struct(ty: &Struct, types: &TypeMap) -> String {
+    let (description, example) = match (ty.transparent_field(), ty.kind) {
+        (Some(field), _) => (
+            format!(
+                "Newtype around `{}`, which is serialized transparently.",
+                field.ty
+            ),
+            format!("<{}>", field.ty),
+        ),
+        (None, StructKind::Named) => (
+            format_fields_table(
+                &ty.fields,
+                ty.options.field_casing,
+                ty.options.deserialize_field_casing(),
+            ),
+            format_struct_example(ty, ty.options.field_casing, types),
+        ),
+        (None, StructKind::Tuple) => (
+            "Tuple struct, which is serialized as an array of its fields.".to_owned(),
+            format!(
+                "[{}]",
+                ty.fields
+                    .iter()
+                    .map(|field| format!("<{}>", field.ty))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        ),
+        (None, StructKind::Unit) => (
+            "Unit struct, which is serialized as `null`.".to_owned(),
+            "null".to_owned(),
+        ),
+    };
+
+    let mut notes = Vec::new();
+    match ty.options.default.as_deref() {
+        Some("") => notes.push("Missing properties use their default value.".to_owned()),
+        Some(default) => notes.push(format!(
+            "Missing properties use their value from `{}()`.",
+            default
+        )),
+        None => {}
+    }
+    if ty.options.deny_unknown_fields {
+        notes.push("Unknown properties are rejected.".to_owned());
+    }
+    if let Some((tag, name)) = ty.tag() {
+        notes.push(format!(
+            "The name `{}` is serialized in the `{}` property.",
+            name, tag
+        ));
+    }
+
+    join_blocks(&[
+        format!("### `{}`", ty.ident),
+        format_docs(&ty.doc_lines),
+        description,
+        notes.join(" "),
+        format!("Example:\n\n```\n{}\n```", example),
+    ])
+}
+
+fn format_enum(ty: &Enum) -> String {
+    let opts = &ty.options;
+    let tagging = match (&opts.tag_prop_name, &opts.content_prop_name) {
+        _ if opts.repr.is_some() => format!(
+            "The enum is serialized as an integer (`{}`): every variant is serialized as its \
+            discriminant.",
+            opts.repr.unwrap().name()
+        ),
+        _ if opts.untagged => {
+            "The enum is untagged: variants are serialized without their name, and are \
+            distinguished by their content only."
+                .to_owned()
+        }
+        (Some(tag), Some(content)) => format!(
+            "The enum is adjacently tagged: the variant name is serialized in the `{}` property, \
+            while its content is serialized in the `{}` property.",
+            tag, content
+        ),
+        (Some(tag), None) => format!(
+            "The enum is internally tagged: the variant name is serialized in the `{}` property, \
+            alongside the properties of its content.",
+            tag
+        ),
+        (None, _) => {
+            "The enum is externally tagged: unit variants are serialized as their name, \
+            while other variants are serialized as an object with the variant name as its only key."
+                .to_owned()
+        }
+    };
+
+    let mut blocks = vec![
+        format!("### `{}`", ty.ident),
+        format_docs(&ty.doc_lines),
+        tagging,
+    ];
+    blocks.append(
+        &mut ty
+            .variants
+            .iter()
+            .filter(|variant| !variant.attrs.is_skipped())
+            .map(|variant| format_variant(variant, opts))
+            .collect(),
+    );
+    if let Some(unknown_variant) = &opts.unknown_variant {
+        blocks.push(join_blocks(&[
+            format!("#### `{}`", get_variable_name(unknown_variant)),
+            "Any variant that is not known to this version of the protocol is deserialized as \
+            this variant. It holds the serialized value as-is, so it can be passed on unchanged."
+                .to_owned(),
+        ]));
+    }
+    join_blocks(&blocks)
+}
+
+fn format_variant(variant: &Variant, opts: &EnumOptions) -> String {
+    let fields = match &variant.ty {
+        Type::Struct(ty) => format_fields_table(
+            &ty.fields,
+            variant.attrs.field_casing,
+            variant.attrs.deserialize_field_casing(),
+        ),
+        _ => "".to_owned(),
+    };
+
+    let mut notes = Vec::new();
+    if variant.attrs.other {
+        notes.push("Any unknown variant is deserialized as this variant.".to_owned());
+    }
+    if !variant.attrs.alias.is_empty() {
+        notes.push(format!(
+            "Also accepted as {} when deserializing.",
+            variant
+                .attrs
+                .alias
+                .iter()
+                .map(|alias| format!("`{}`", alias))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ));
+    }
+    if variant.attrs.skip_serializing {
+        notes.push("This variant cannot be serialized.".to_owned());
+    } else if variant.attrs.skip_deserializing {
+        notes.push("This variant cannot be deserialized.".to_owned());
+    }
+
+    let serialized_name = get_variant_name(variant, opts);
+    let deserialized_name = get_deserialize_variant_name(variant, opts);
+    let names = if opts.repr.is_none() && serialized_name != deserialized_name {
+        format!(
+            "Named `{}` when serializing and `{}` when deserializing.",
+            serialized_name, deserialized_name
+        )
+    } else {
+        "".to_owned()
+    };
+
+    join_blocks(&[
+        format!("#### `{}`", get_variable_name(&variant.name)),
+        format_docs(&variant.doc_lines),
+        names,
+        notes.join(" "),
+        fields,
+        format!(
+            "Example:\n\n```\n{}\n```",
+            format_variant_example(variant, opts)
+        ),
+    ])
+}
+
+fn format_fields_table(fields: &[Field], casing: Casing, deserialize_casing: Casing) -> String {
+    if fields.is_empty() {
+        return "".to_owned();
+    }
+
+    let rows = fields
+        .iter()
+        .filter(|field| !field.attrs.is_skipped())
+        .map(|field| {
+            let serialized_name = get_field_name(field, casing);
+            let deserialized_name = get_deserialize_field_name(field, deserialize_casing);
+            let name = if field.attrs.flatten {
+                "*(flattened)*".to_owned()
+            } else {
+                let rust_name = get_variable_name(&field.name);
+                let name = if serialized_name == deserialized_name {
+                    format!("`{}`", serialized_name)
+                } else {
+                    format!("`{}` / `{}`", serialized_name, deserialized_name)
+                };
+                if serialized_name == rust_name && deserialized_name == rust_name {
+                    name
+                } else {
+                    format!("{} (Rust: `{}`)", name, rust_name)
+                }
+            };
+
+            let mut notes = Vec::new();
+            if serialized_name != deserialized_name {
+                notes.push(format!(
+                    "Named `{}` when serializing and `{}` when deserializing.",
+                    serialized_name, deserialized_name
+                ));
+            }
+            if !field.attrs.alias.is_empty() {
+                notes.push(format!(
+                    "Also accepts {} when deserializing.",
+                    field
+                        .attrs
+                        .alias
+                        .iter()
+                        .map(|alias| format!("`{}`", alias))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ));
+            }
+            if field.attrs.flatten {
+                notes.push("Properties are flattened into the parent.".to_owned());
+            }
+            if field.ty.name == "Option" {
+                notes.push("Optional.".to_owned());
+            }
+            if field.attrs.skip_serializing {
+                notes.push("Never serialized.".to_owned());
+            } else if let Some(skip_serializing_if) = &field.attrs.skip_serializing_if {
+                notes.push(format!("Omitted if `{}`.", skip_serializing_if));
+            }
+            if field.attrs.skip_deserializing {
+                notes.push("Ignored when deserializing.".to_owned());
+            }
+            match field.attrs.default.as_deref() {
+                Some("") => notes.push("Uses the default value if missing.".to_owned()),
+                Some(default) => notes.push(format!("Uses `{}()` if missing.", default)),
+                None => {}
+            }
+
+            let description = field
+                .doc_lines
+                .iter()
+                .map(|line| line.trim())
+                .filter(|line| !line.is_empty())
+                .chain(notes.iter().map(String::as_str))
+                .collect::<Vec<_>>()
+                .join(" ");
+
+            format!(
+                "| {} | `{}` | {} |",
+                name,
+                field.ty,
+                escape_table_cell(&description)
+            )
+        })
+        .collect::<Vec<_>>();
+
+    format!(
+        "| Name | Type | Description |\n| ---- | ---- | ----------- |\n{}",
+        rows.join("\n")
+    )
+}
+
+fn format_struct_example(ty: &Struct, casing: Casing, types: &TypeMap) -> String {
+    let mut properties = match ty.tag() {
+        Some((tag, name)) => vec![format!("\"{}\": \"{}\"", tag, name)],
+        None => Vec::new(),
+    };
+    properties.append(&mut format_example_properties(&ty.fields, casing, types));
+    format!("{{ {} }}", properties.join(", "))
+}
+
+fn format_example_properties(fields: &[Field], casing: Casing, types: &TypeMap) -> Vec<String> {
+    fields
+        .iter()
+        .filter(|field| !field.attrs.skip_serializing)
+        .flat_map(|field| {
+            if field.attrs.flatten {
+                if let Some(Type::Struct(ty)) = types.get(&field.ty) {
+                    return format_example_properties(&ty.fields, ty.options.field_casing, types);
+                }
+            }
+
+            vec![format!(
+                "\"{}\": <{}>",
+                get_field_name(field, casing),
+                field.ty
+            )]
+        })
+        .collect()
+}
+
+fn format_variant_example(variant: &Variant, opts: &EnumOptions) -> String {
+    if let (Some(_), Some(discriminant)) = (opts.repr, variant.discriminant) {
+        return discriminant.to_string();
+    }
+
+    let name = get_variant_name(variant, opts);
+    let casing = variant.attrs.field_casing;
+    let (content, properties) = match &variant.ty {
+        Type::Unit => (None, Vec::new()),
+        Type::Struct(ty) => {
+            let properties = ty
+                .fields
+                .iter()
+                .map(|field| format!("\"{}\": <{}>", get_field_name(field, casing), field.ty))
+                .collect::<Vec<_>>();
+            (Some(format!("{{ {} }}", properties.join(", "))), properties)
+        }
+        Type::Tuple(items) => {
+            let content = items
+                .iter()
+                .map(|item| format!("<{}>", item))
+                .collect::<Vec<_>>()
+                .join(", ");
+            let content = if items.len() == 1 {
+                content
+            } else {
+                format!("[{}]", content)
+            };
+            (Some(content.clone()), vec![format!("...{}", content)])
+        }
+        other => panic!("Unsupported type for enum variant: {:?}", other),
+    };
+
+    if opts.untagged {
+        return content.unwrap_or_else(|| "null".to_owned());
+    }
+
+    match (&opts.tag_prop_name, &opts.content_prop_name, content) {
+        (Some(tag), Some(content_prop_name), Some(content)) => format!(
+            "{{ \"{}\": \"{}\", \"{}\": {} }}",
+            tag, name, content_prop_name, content
+        ),
+        (Some(tag), None, Some(_)) => {
+            let mut properties = properties;
+            properties.insert(0, format!("\"{}\": \"{}\"", tag, name));
+            format!("{{ {} }}", properties.join(", "))
+        }
+        (Some(tag), _, None) => format!("{{ \"{}\": \"{}\" }}", tag, name),
+        (None, _, Some(content)) => format!("{{ \"{}\": {} }}", name, content),
+        (None, _, None) => format!("\"{}\"", name),
+    }
+}
+
+/// Formats doc lines as Markdown. Headings inside the docs are demoted, so
+/// they don't interfere with the structure of the generated document.
+fn format_docs(doc_lines: &[String]) -> String {
+    let mut in_code_block = false;
+    doc_lines
+        .iter()
+        .map(|line| {
+            let line = line.strip_prefix(' ').unwrap_or(line);
+            if line.trim_start().starts_with("```") {
+                in_code_block = !in_code_block;
+            }
+            if !in_code_block && line.starts_with('#') {
+                format!("####{}", line)
+            } else {
+                line.to_owned()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+        .trim()
+        .to_owned()
+}
+
+fn escape_table_cell(text: &str) -> String {
+    text.replace('|', "\\|")
+}
+
+fn join_blocks(blocks: &[String]) -> String {
+    blocks
+        .iter()
+        .filter(|block| !block.is_empty())
+        .cloned()
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+fn write_bindings_file<C>(file_path: String, contents: C)
+where
+    C: AsRef<[u8]>,
+{
+    fs::write(&file_path, &contents).expect("Could not write bindings file");
+}
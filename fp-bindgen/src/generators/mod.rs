@@ -1,9 +1,14 @@
 use crate::{
     functions::FunctionList,
-    types::{CargoDependency, TypeMap},
+    types::{CargoDependency, Type, TypeIdent, TypeMap},
 };
 use std::{collections::BTreeMap, fmt::Display, fs};
 
+pub mod assemblyscript_plugin;
+pub mod html;
+pub mod json_schema;
+pub mod markdown;
+mod naming;
 pub mod rust_plugin;
 pub mod rust_wasmer_runtime;
 pub mod ts_runtime;
@@ -11,6 +16,10 @@ pub mod ts_runtime;
 #[non_exhaustive]
 #[derive(Debug, Clone)]
 pub enum BindingsType<'a> {
+    AssemblyScriptPlugin(AssemblyScriptPluginConfig<'a>),
+    Html,
+    JsonSchema,
+    Markdown,
     RustPlugin(RustPluginConfig<'a>),
     RustWasmerRuntime,
     #[deprecated(note = "Please use `BindingsType::TsRuntimeWithExtendedConfig` instead.")]
@@ -19,8 +28,13 @@ pub enum BindingsType<'a> {
 }
 
 impl<'a> Display for BindingsType<'a> {
+    #[allow(deprecated)]
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.write_str(match self {
+            BindingsType::AssemblyScriptPlugin { .. } => "assemblyscript-plugin",
+            BindingsType::Html { .. } => "html",
+            BindingsType::JsonSchema { .. } => "json-schema",
+            BindingsType::Markdown { .. } => "markdown",
             BindingsType::RustPlugin { .. } => "rust-plugin",
             BindingsType::RustWasmerRuntime { .. } => "rust-wasmer-runtime",
             BindingsType::TsRuntime { .. } => "ts-runtime",
@@ -35,6 +49,33 @@ pub struct BindingConfig<'a> {
     pub path: &'a str,
 }
 
+#[derive(Debug, Clone)]
+pub struct AssemblyScriptPluginConfig<'a> {
+    /// Name of the plugin package that will be generated.
+    pub name: &'a str,
+
+    /// Authors to be listed in the plugin package that will be generated.
+    pub authors: &'a str,
+
+    /// Version of the plugin package that will be generated.
+    pub version: &'a str,
+
+    /// *Additional* NPM dependencies to be listed in the plugin package that
+    /// will be generated, mapping package names to version specifiers.
+    ///
+    /// These are merged with the dependencies that are necessary for the
+    /// plugin to work and which will always be included.
+    pub dependencies: BTreeMap<&'a str, &'a str>,
+
+    /// Whether to allow custom types that cannot be represented in
+    /// AssemblyScript, such as JSON values. Such types are generated as opaque
+    /// classes, which are skipped when decoding and which throw when encoded.
+    ///
+    /// If not set, generating bindings for a protocol that uses such types
+    /// panics instead.
+    pub allow_opaque_types: bool,
+}
+
 #[derive(Debug, Clone)]
 pub struct RustPluginConfig<'a> {
     /// Name of the plugin crate that will be generated.
@@ -81,6 +122,31 @@ pub struct TsExtendedRuntimeConfig {
     /// Raw export wrappers are named similarly to the regular wrappers (which
     /// are generated in any case), but with a `Raw` suffix.
     pub generate_raw_export_wrappers: bool,
+
+    /// How 128-bit integers (`i128` and `u128`) are represented when they are
+    /// passed as function arguments or return values, and inside data types.
+    ///
+    /// By default, they are represented as `bigint`.
+    pub int128_representation: Int128Representation,
+
+    /// Whether or not to generate helpers for converting `Duration` and
+    /// `SystemTime` values to and from milliseconds.
+    ///
+    /// Helpers are only generated for the types that are used in the
+    /// protocol.
+    pub generate_time_helpers: bool,
+}
+
+/// Representation of 128-bit integers in TypeScript.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Int128Representation {
+    /// Represents 128-bit integers using the `bigint` type.
+    BigInt,
+
+    /// Represents 128-bit integers as decimal strings, for environments where
+    /// `bigint` is inconvenient, such as when values need to be serialized to
+    /// JSON.
+    String,
 }
 
 impl TsExtendedRuntimeConfig {
@@ -100,12 +166,26 @@ impl TsExtendedRuntimeConfig {
         self.generate_raw_export_wrappers = true;
         self
     }
+
+    /// Enables the `generate_time_helpers` setting.
+    pub fn with_time_helpers(mut self) -> Self {
+        self.generate_time_helpers = true;
+        self
+    }
+
+    /// Sets the `int128_representation` setting.
+    pub fn with_int128_representation(mut self, representation: Int128Representation) -> Self {
+        self.int128_representation = representation;
+        self
+    }
 }
 
 impl Default for TsExtendedRuntimeConfig {
     fn default() -> Self {
         Self {
             generate_raw_export_wrappers: false,
+            generate_time_helpers: false,
+            int128_representation: Int128Representation::BigInt,
             msgpack_module: "@msgpack/msgpack".to_owned(),
         }
     }
@@ -113,6 +193,7 @@ impl Default for TsExtendedRuntimeConfig {
 
 impl TsExtendedRuntimeConfig {}
 
+#[allow(deprecated)]
 pub fn generate_bindings(
     import_functions: FunctionList,
     export_functions: FunctionList,
@@ -121,7 +202,29 @@ pub fn generate_bindings(
 ) {
     fs::create_dir_all(config.path).expect("Could not create output directory");
 
+    let import_functions = import_functions.resolve_idents(&types);
+    let export_functions = export_functions.resolve_idents(&types);
+    check_type_references(&import_functions, &export_functions, &types);
+
     match config.bindings_type {
+        BindingsType::AssemblyScriptPlugin(plugin_config) => {
+            assemblyscript_plugin::generate_bindings(
+                import_functions,
+                export_functions,
+                types,
+                plugin_config,
+                config.path,
+            )
+        }
+        BindingsType::Html => {
+            html::generate_bindings(import_functions, export_functions, types, config.path)
+        }
+        BindingsType::JsonSchema => {
+            json_schema::generate_bindings(import_functions, export_functions, types, config.path)
+        }
+        BindingsType::Markdown => {
+            markdown::generate_bindings(import_functions, export_functions, types, config.path)
+        }
         BindingsType::RustPlugin(plugin_config) => rust_plugin::generate_bindings(
             import_functions,
             export_functions,
@@ -154,3 +257,153 @@ pub fn generate_bindings(
         ),
     };
 }
+
+/// Panics if a function or type in the protocol refers to a type that is not
+/// part of the type map, in which case the bindings would refer to a type that
+/// doesn't exist.
+///
+/// This happens when a type is imported under a different name than the one
+/// it is identified by, such as `serde_json::Value`, which needs to be
+/// referred to as `JsonValue` or by its full path.
+fn check_type_references(
+    import_functions: &FunctionList,
+    export_functions: &FunctionList,
+    types: &TypeMap,
+) {
+    let function_idents = import_functions
+        .iter()
+        .chain(export_functions.iter())
+        .flat_map(|function| {
+            function
+                .args
+                .iter()
+                .map(|arg| &arg.ty)
+                .chain(function.return_type.iter())
+                .map(move |ty| (function.name.clone(), ty, vec![]))
+        });
+    let type_idents = types.definitions().flat_map(|ty| {
+        let (idents, generic_params) = match ty {
+            Type::Alias(_, target) => (vec![target], vec![]),
+            Type::Enum(ty) => (
+                ty.variants
+                    .iter()
+                    .filter(|variant| !variant.attrs.is_skipped())
+                    .flat_map(|variant| match &variant.ty {
+                        Type::Struct(variant) => variant
+                            .fields
+                            .iter()
+                            .filter(|field| !field.attrs.is_skipped())
+                            .map(|field| &field.ty)
+                            .collect(),
+                        Type::Tuple(items) => items.iter().collect(),
+                        _ => vec![],
+                    })
+                    .collect(),
+                ty.ident.generic_args.iter().collect(),
+            ),
+            Type::Struct(ty) => (
+                ty.fields
+                    .iter()
+                    .filter(|field| !field.attrs.is_skipped())
+                    .map(|field| &field.ty)
+                    .collect(),
+                ty.ident.generic_args.iter().collect(),
+            ),
+            _ => (vec![], vec![]),
+        };
+        idents
+            .into_iter()
+            .map(move |ident| (ty.name(), ident, generic_params.clone()))
+    });
+
+    for (user, ident, generic_params) in function_idents.chain(type_idents) {
+        if let Some(missing) = find_missing_type(ident, &generic_params, types) {
+            panic!(
+                "`{}` refers to `{}`, which is not part of the protocol. Please make sure it \
+                    implements `Serializable` and is referred to by the name it has in the \
+                    protocol. Types from `serde_json` may be referred to by their full path, or \
+                    as `JsonValue` and `JsonMap`.",
+                user, missing
+            );
+        }
+    }
+}
+
+/// Returns the first identifier, or generic argument thereof, for which the
+/// type map has no definition, skipping the given generic parameters.
+fn find_missing_type<'a>(
+    ident: &'a TypeIdent,
+    generic_params: &[&TypeIdent],
+    types: &TypeMap,
+) -> Option<&'a TypeIdent> {
+    if generic_params.iter().any(|param| param.name == ident.name) {
+        return None;
+    }
+
+    match types.get(ident) {
+        None => Some(ident),
+        // The generic arguments of custom types, such as the `Utc` in
+        // `DateTime<Utc>`, are not necessarily types of their own:
+        Some(Type::Custom(_)) => None,
+        Some(_) => ident
+            .generic_args
+            .iter()
+            .find_map(|arg| find_missing_type(arg, generic_params, types)),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::check_type_references;
+    use crate::{
+        functions::FunctionList,
+        primitives::Primitive,
+        types::{Type, TypeIdent, TypeMap},
+    };
+    use std::str::FromStr;
+
+    #[test]
+    fn test_generic_params_are_not_missing() {
+        let mut types = TypeMap::new();
+        types.insert(
+            TypeIdent::from_str("Point<f64>").unwrap(),
+            Type::from_item("pub struct Point<T> { pub x: T, pub y: Option<T> }"),
+        );
+        types.insert(
+            TypeIdent::from_str("Option<T>").unwrap(),
+            Type::Container("Option".to_owned(), TypeIdent::from("T")),
+        );
+        types.insert(TypeIdent::from("f64"), Type::Primitive(Primitive::F64));
+
+        check_type_references(&FunctionList::new(), &FunctionList::new(), &types);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "`StructWithJson` refers to `Value`, which is not part of the protocol"
+    )]
+    fn test_missing_type() {
+        let mut types = TypeMap::new();
+        types.insert(
+            TypeIdent::from("StructWithJson"),
+            Type::from_item("pub struct StructWithJson { pub value: Value }"),
+        );
+
+        check_type_references(&FunctionList::new(), &FunctionList::new(), &types);
+    }
+
+    #[test]
+    #[should_panic(expected = "`get_value` refers to `Value`, which is not part of the protocol")]
+    fn test_missing_function_type() {
+        let mut functions = FunctionList::new();
+        functions.add_function("fn get_value() -> Option<Value>;");
+
+        let mut types = TypeMap::new();
+        types.insert(
+            TypeIdent::from_str("Option<Value>").unwrap(),
+            Type::Container("Option".to_owned(), TypeIdent::from("Value")),
+        );
+
+        check_type_references(&functions, &FunctionList::new(), &types);
+    }
+}
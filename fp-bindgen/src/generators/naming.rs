@@ -0,0 +1,59 @@
+//! Helpers for determining the names under which fields and variants are
+//! serialized, shared by the generators.
+
+use crate::{
+    casing::Casing,
+    types::{EnumOptions, Field, Variant},
+};
+
+/// Returns the name of the field when serializing.
+pub(crate) fn get_field_name(field: &Field, casing: Casing) -> String {
+    if let Some(rename) = field.attrs.serialize_name() {
+        rename.to_owned()
+    } else {
+        casing.format_string(get_variable_name(&field.name))
+    }
+}
+
+/// Returns the name of the field when deserializing.
+pub(crate) fn get_deserialize_field_name(field: &Field, casing: Casing) -> String {
+    if let Some(rename) = field.attrs.deserialize_name() {
+        rename.to_owned()
+    } else {
+        casing.format_string(get_variable_name(&field.name))
+    }
+}
+
+/// Returns the name of the variant when serializing.
+pub(crate) fn get_variant_name(variant: &Variant, opts: &EnumOptions) -> String {
+    if let Some(rename) = variant.attrs.serialize_name() {
+        rename.to_owned()
+    } else {
+        opts.variant_casing
+            .format_string(get_variable_name(&variant.name))
+    }
+}
+
+/// Returns the name of the variant when deserializing.
+pub(crate) fn get_deserialize_variant_name(variant: &Variant, opts: &EnumOptions) -> String {
+    if let Some(rename) = variant.attrs.deserialize_name() {
+        rename.to_owned()
+    } else {
+        opts.deserialize_variant_casing()
+            .format_string(get_variable_name(&variant.name))
+    }
+}
+
+/// Strips the `r#` prefix from raw identifiers.
+pub(crate) fn get_variable_name(name: &str) -> &str {
+    if let Some(stripped) = name.strip_prefix("r#") {
+        stripped
+    } else {
+        name
+    }
+}
+
+/// Returns the name of the variable that holds a pointer to the given value.
+pub(crate) fn get_pointer_name(name: &str) -> String {
+    format!("{}_ptr", get_variable_name(name))
+}
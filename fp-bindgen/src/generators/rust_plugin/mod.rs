@@ -1,6 +1,6 @@
 use crate::{
     functions::FunctionList,
-    types::{CargoDependency, Enum, Field, Struct, Type, TypeIdent, TypeMap},
+    types::{CargoDependency, Enum, Field, Struct, StructKind, Type, TypeIdent, TypeMap},
     RustPluginConfig,
 };
 use std::{
@@ -55,6 +55,20 @@ fn generate_cargo_file(
         support_features.insert("async");
     }
 
+    // Untagged unknown variants inside tagged enums require serde 1.0.181:
+    let requires_untagged_variants = types.values().any(has_unknown_variant);
+    let serde_version = if requires_untagged_variants {
+        "1.0.181"
+    } else {
+        "1.0"
+    };
+
+    // Serializing `Arc` and `Rc` requires Serde's `rc` feature:
+    let mut serde_features = BTreeSet::from(["derive"]);
+    if types.values().any(is_rc) {
+        serde_features.insert("rc");
+    }
+
     let mut dependencies = BTreeMap::from([
         (
             "fp-bindgen-support",
@@ -68,10 +82,31 @@ fn generate_cargo_file(
         ("rmp-serde", CargoDependency::with_version("1.0")),
         (
             "serde",
-            CargoDependency::with_version_and_features("1.0", BTreeSet::from(["derive"])),
+            CargoDependency::with_version_and_features(serde_version, serde_features),
         ),
     ]);
 
+    // Enums that are serialized as integers rely on `serde_repr`:
+    if types.values().any(has_repr) {
+        dependencies.insert("serde_repr", CargoDependency::with_version("0.1"));
+    }
+
+    // Unknown variants are captured as `rmpv::Value`:
+    if requires_untagged_variants {
+        dependencies.insert(
+            "rmpv",
+            CargoDependency::with_version_and_features("1.0", BTreeSet::from(["with-serde"])),
+        );
+    }
+
+    // Order-preserving maps rely on `indexmap`:
+    if types.values().any(is_index_map) {
+        dependencies.insert(
+            "indexmap",
+            CargoDependency::with_version_and_features("2", BTreeSet::from(["serde"])),
+        );
+    }
+
     // Inject dependencies from custom types:
     for ty in types.values() {
         if let Type::Custom(custom_type) = ty {
@@ -134,7 +169,7 @@ pub fn generate_type_bindings(types: &TypeMap, path: &str, module_key: &str) {
     };
 
     let type_imports = types
-        .values()
+        .definitions()
         .filter_map(|ty| {
             let (ident, native_modules) = match ty {
                 Type::Enum(Enum { ident, options, .. }) => (ident, &options.native_modules),
@@ -152,8 +187,23 @@ pub fn generate_type_bindings(types: &TypeMap, path: &str, module_key: &str) {
         format!("{}\n\n", type_imports.join("\n"))
     };
 
+    let repr_imports = if types.values().any(has_repr) {
+        "use serde_repr::{Deserialize_repr, Serialize_repr};\n"
+    } else {
+        ""
+    };
+    let index_map_imports = if types.values().any(is_index_map) {
+        "use indexmap::IndexMap;\n"
+    } else {
+        ""
+    };
+
+    let default_types = collect_default_types(types);
+    let non_clone_types = collect_types_lacking_trait(types, lacks_clone);
+    let non_partial_eq_types = collect_types_lacking_trait(types, lacks_partial_eq);
+
     let type_defs = types
-        .values()
+        .definitions()
         .filter_map(|ty| match ty {
             Type::Alias(name, ty) => {
                 Some(format!("pub type {} = {};", name, format_ident(ty, types)))
@@ -162,14 +212,26 @@ pub fn generate_type_bindings(types: &TypeMap, path: &str, module_key: &str) {
                 if ty.options.native_modules.contains_key(module_key) || ty.ident.name == "Result" {
                     None
                 } else {
-                    Some(create_enum_definition(ty, types))
+                    Some(create_enum_definition(
+                        ty,
+                        types,
+                        default_types.contains(&ty.ident.name),
+                        !non_clone_types.contains(&ty.ident.name),
+                        !non_partial_eq_types.contains(&ty.ident.name),
+                    ))
                 }
             }
             Type::Struct(ty) => {
                 if ty.options.native_modules.contains_key(module_key) {
                     None
                 } else {
-                    Some(create_struct_definition(ty, types))
+                    Some(create_struct_definition(
+                        ty,
+                        types,
+                        default_types.contains(&ty.ident.name),
+                        !non_clone_types.contains(&ty.ident.name),
+                        !non_partial_eq_types.contains(&ty.ident.name),
+                    ))
                 }
             }
             _ => None,
@@ -180,7 +242,9 @@ pub fn generate_type_bindings(types: &TypeMap, path: &str, module_key: &str) {
         format!("{}/types.rs", path),
         format!(
             "#![allow(unused_imports)]\n\
-            use serde::{{Deserialize, Serialize}};\n{}\n{}{}\n",
+            use serde::{{Deserialize, Serialize}};\n{}{}{}\n{}{}\n",
+            index_map_imports,
+            repr_imports,
             std_imports,
             type_imports,
             type_defs.join("\n\n")
@@ -188,8 +252,16 @@ pub fn generate_type_bindings(types: &TypeMap, path: &str, module_key: &str) {
     );
 }
 
-fn format_functions(export_functions: FunctionList, types: &TypeMap, macro_path: &str) -> String {
-    export_functions
+/// Formats the function declarations for the given macro. If `borrow_args` is
+/// set, borrowed arguments keep their borrowed type, which is what the plugin
+/// passes when calling an imported function.
+fn format_functions(
+    functions: FunctionList,
+    types: &TypeMap,
+    macro_path: &str,
+    borrow_args: bool,
+) -> String {
+    functions
         .iter()
         .map(|func| {
             let name = &func.name;
@@ -203,23 +275,48 @@ fn format_functions(export_functions: FunctionList, types: &TypeMap, macro_path:
             let args_with_types = func
                 .args
                 .iter()
-                .map(|arg| format!("{}: {}", arg.name, format_ident(&arg.ty, types)))
+                .map(|arg| {
+                    let fp_attr = match arg.by_value_struct(types) {
+                        Some(ty) => format!(
+                            "#[fp(by_value({}))] ",
+                            ty.fields
+                                .iter()
+                                .map(|field| format!("{}: {}", field.name, field.ty))
+                                .collect::<Vec<_>>()
+                                .join(", ")
+                        ),
+                        None if arg.ty.is_binary(types) => "#[fp(binary)] ".to_owned(),
+                        None => "".to_owned(),
+                    };
+                    let ty = if borrow_args {
+                        arg.format_caller_type(types, |ty| format_ident(ty, types))
+                    } else {
+                        format_ident(&arg.ty, types)
+                    };
+                    format!("{}{}: {}", fp_attr, arg.name, ty)
+                })
                 .collect::<Vec<_>>()
                 .join(", ");
             let return_type = match &func.return_type {
                 Some(ty) => format!(" -> {}", format_ident(ty, types)),
                 None => "".to_owned(),
             };
+            // Async functions always return their value serialized, so only
+            // sync functions pass binary return values as raw buffers.
+            let macro_args = match &func.return_type {
+                Some(ty) if !func.is_async && ty.is_binary(types) => "(binary_return)",
+                _ => "",
+            };
             format!(
-                "{}#[{}]\npub {}fn {}({}){};",
-                doc, macro_path, modifiers, name, args_with_types, return_type,
+                "{}#[{}{}]\npub {}fn {}({}){};",
+                doc, macro_path, macro_args, modifiers, name, args_with_types, return_type,
             )
         })
         .collect::<Vec<_>>()
         .join("\n\n")
 }
 
-fn format_ident(ident: &TypeIdent, types: &TypeMap) -> String {
+pub(crate) fn format_ident(ident: &TypeIdent, types: &TypeMap) -> String {
     match types.get(ident) {
         Some(ty) => format_type_with_ident(ty, ident, types),
         None => ident.to_string(), // Must be a generic.
@@ -229,6 +326,13 @@ fn format_ident(ident: &TypeIdent, types: &TypeMap) -> String {
 fn format_type_with_ident(ty: &Type, ident: &TypeIdent, types: &TypeMap) -> String {
     match ty {
         Type::Alias(name, _) => name.clone(),
+        Type::Array(_, len) => {
+            let item = ident
+                .generic_args
+                .first()
+                .expect("Identifier was expected to contain a generic argument");
+            format!("[{}; {}]", format_ident(item, types), len)
+        }
         Type::Container(name, _) | Type::List(name, _) => {
             let arg = ident
                 .generic_args
@@ -253,14 +357,18 @@ fn format_type_with_ident(ty: &Type, ident: &TypeIdent, types: &TypeMap) -> Stri
                 format_ident(arg2, types)
             )
         }
-        Type::Tuple(items) => format!(
-            "[{}]",
-            items
+        Type::Tuple(_) => {
+            let items = ident
+                .generic_args
                 .iter()
                 .map(|item| format_ident(item, types))
-                .collect::<Vec<_>>()
-                .join(", ")
-        ),
+                .collect::<Vec<_>>();
+            if items.len() == 1 {
+                format!("({},)", items[0])
+            } else {
+                format!("({})", items.join(", "))
+            }
+        }
         Type::Unit => "void".to_owned(),
         _ => ident.to_string(),
     }
@@ -278,7 +386,8 @@ fn generate_imported_function_bindings(
             format_functions(
                 import_functions,
                 types,
-                "fp_bindgen_support::fp_import_signature"
+                "fp_bindgen_support::fp_import_signature",
+                true
             )
         ),
     );
@@ -296,7 +405,8 @@ fn generate_exported_function_bindings(
             format_functions(
                 export_functions,
                 types,
-                "fp_bindgen_support::fp_export_signature"
+                "fp_bindgen_support::fp_export_signature",
+                false
             )
         ),
     );
@@ -304,8 +414,14 @@ fn generate_exported_function_bindings(
 
 fn collect_std_types(ty: &Type) -> Option<String> {
     match ty {
+        Type::Container(name, _) if name == "Arc" => Some("sync::Arc".to_owned()),
         Type::Container(name, _) if name == "Rc" => Some("rc::Rc".to_owned()),
-        Type::List(name, _) if (name == "BTreeSet" || name == "HashSet") => {
+        Type::List(name, _)
+            if matches!(
+                name.as_str(),
+                "BinaryHeap" | "BTreeSet" | "HashSet" | "VecDeque"
+            ) =>
+        {
             Some(format!("collections::{}", name))
         }
         Type::Map(name, _, _) if (name == "BTreeMap" || name == "HashMap") => {
@@ -315,14 +431,29 @@ fn collect_std_types(ty: &Type) -> Option<String> {
     }
 }
 
-fn create_enum_definition(ty: &Enum, types: &TypeMap) -> String {
+fn create_enum_definition(
+    ty: &Enum,
+    types: &TypeMap,
+    implements_default: bool,
+    implements_clone: bool,
+    implements_partial_eq: bool,
+) -> String {
     let variants = ty
         .variants
         .iter()
         .flat_map(|variant| {
-            let mut serde_attrs = variant.attrs.to_serde_attrs();
+            // Variants of enums that are serialized as integers are never
+            // referred to by name:
+            let mut serde_attrs = if ty.options.repr.is_some() {
+                vec![]
+            } else {
+                variant.attrs.to_serde_attrs()
+            };
             let mut variant_decl = match &variant.ty {
-                Type::Unit => format!("{},", variant.name),
+                Type::Unit => match variant.discriminant {
+                    Some(discriminant) => format!("{} = {},", variant.name, discriminant),
+                    None => format!("{},", variant.name),
+                },
                 Type::Struct(variant) => {
                     let fields = format_struct_fields(&variant.fields, types);
                     let has_multiple_lines = fields.iter().any(|field| field.contains('\n'));
@@ -362,6 +493,9 @@ fn create_enum_definition(ty: &Enum, types: &TypeMap) -> String {
                 serde_attrs.sort();
                 variant_decl = format!("#[serde({})]\n{}", serde_attrs.join(", "), variant_decl);
             }
+            if implements_default && variant.attrs.default {
+                variant_decl = format!("#[default]\n{}", variant_decl);
+            }
 
             let lines = if variant.doc_lines.is_empty() {
                 variant_decl
@@ -397,29 +531,280 @@ fn create_enum_definition(ty: &Enum, types: &TypeMap) -> String {
         .collect::<Vec<_>>()
         .join("\n");
 
+    // Variants that are not known to this version of the protocol are kept
+    // as-is, so they can be passed on without losing any information:
+    let variants = match &ty.options.unknown_variant {
+        Some(name) => format!(
+            "{}\n    /// A variant that is not known to this version of the protocol.\n    \
+                #[serde(untagged)]\n    {}(rmpv::Value),",
+            variants, name
+        ),
+        None => variants,
+    };
+
     let serde_annotation = {
         let attrs = ty.options.to_serde_attrs();
-        if attrs.is_empty() {
+        if attrs.is_empty() || ty.options.repr.is_some() {
             "".to_owned()
         } else {
             format!("#[serde({})]\n", attrs.join(", "))
         }
     };
 
+    let default = if implements_default {
+        if !ty.variants.iter().any(|variant| variant.attrs.default) {
+            panic!(
+                "Enum {} needs to implement `Default`, because it is used by a type with default \
+                    values. Please mark one of its unit variants with `#[default]`",
+                ty.ident
+            );
+        }
+        "Default, "
+    } else {
+        ""
+    };
+    let derives = match ty.options.repr {
+        Some(repr) => format!(
+            "#[derive(Clone, Copy, Debug, {}Deserialize_repr, PartialEq, Serialize_repr)]\n\
+            #[repr({})]",
+            default,
+            repr.name()
+        ),
+        None => format!(
+            "#[derive({}Debug, {}Deserialize, {}Serialize)]",
+            if implements_clone { "Clone, " } else { "" },
+            default,
+            if implements_partial_eq {
+                "PartialEq, "
+            } else {
+                ""
+            }
+        ),
+    };
+
     format!(
-        "{}#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]\n{}\
+        "{}{}\n{}\
         pub enum {} {{\n\
             {}\n\
         }}",
         format_docs(&ty.doc_lines),
+        derives,
         serde_annotation,
         ty.ident,
         variants
     )
 }
 
-fn create_struct_definition(ty: &Struct, types: &TypeMap) -> String {
-    let fields = format_struct_fields(&ty.fields, types)
+/// Collects the names of the types that need to implement `Default`, because
+/// Serde needs to fill in default values for them or for their fields.
+fn collect_default_types(types: &TypeMap) -> BTreeSet<String> {
+    let mut pending = Vec::new();
+    for ty in types.definitions() {
+        if let Type::Struct(ty) = ty {
+            if ty.options.default.as_deref() == Some("") {
+                pending.push(ty.ident.clone());
+            }
+            for field in ty.fields.iter().filter(|field| !field.attrs.is_skipped()) {
+                let uses_default = match field.attrs.default.as_deref() {
+                    Some(default) => default.is_empty(),
+                    None => field.attrs.skip_deserializing,
+                };
+                if uses_default {
+                    pending.push(field.ty.clone());
+                }
+            }
+        }
+    }
+
+    let mut default_types = BTreeSet::new();
+    while let Some(ident) = pending.pop() {
+        match types.get(&ident) {
+            Some(Type::Alias(_, target)) => pending.push(target.clone()),
+            Some(Type::Enum(ty)) => {
+                default_types.insert(ty.ident.name.clone());
+            }
+            Some(Type::Struct(ty)) => {
+                if !default_types.insert(ty.ident.name.clone()) {
+                    continue;
+                }
+
+                // Deriving `Default` requires all fields to implement it:
+                for field in ty.specialize(&ident).fields {
+                    if !field.attrs.is_skipped() {
+                        pending.push(field.ty);
+                    }
+                }
+            }
+            // Other containers implement `Default` regardless of their items:
+            Some(Type::Array(_, _)) | Some(Type::Tuple(_)) => {
+                pending.extend(ident.generic_args.iter().cloned());
+            }
+            Some(Type::Container(name, _)) if name != "Option" => {
+                pending.extend(ident.generic_args.iter().cloned());
+            }
+            _ => {}
+        }
+    }
+    default_types
+}
+
+/// Collects the names of the types that cannot derive a trait, because they
+/// contain a type for which `lacks_trait()` returns `true`, either directly or
+/// through other types.
+fn collect_types_lacking_trait(
+    types: &TypeMap,
+    lacks_trait: fn(&Type) -> bool,
+) -> BTreeSet<String> {
+    let mut names = BTreeSet::new();
+    loop {
+        let mut has_changes = false;
+        for ty in types.definitions() {
+            let (name, field_types) = match ty {
+                Type::Enum(ty) => (
+                    &ty.ident.name,
+                    ty.variants
+                        .iter()
+                        .flat_map(|variant| match &variant.ty {
+                            Type::Struct(variant) => variant
+                                .fields
+                                .iter()
+                                .filter(|field| !field.attrs.is_skipped())
+                                .map(|field| field.ty.clone())
+                                .collect(),
+                            Type::Tuple(items) => items.clone(),
+                            _ => vec![],
+                        })
+                        .collect::<Vec<_>>(),
+                ),
+                Type::Struct(ty) => (
+                    &ty.ident.name,
+                    ty.fields
+                        .iter()
+                        .filter(|field| !field.attrs.is_skipped())
+                        .map(|field| field.ty.clone())
+                        .collect(),
+                ),
+                _ => continue,
+            };
+
+            if !names.contains(name)
+                && field_types
+                    .iter()
+                    .any(|ident| contains_type_lacking_trait(ident, types, &names, lacks_trait))
+            {
+                names.insert(name.clone());
+                has_changes = true;
+            }
+        }
+
+        if !has_changes {
+            return names;
+        }
+    }
+}
+
+fn contains_type_lacking_trait(
+    ident: &TypeIdent,
+    types: &TypeMap,
+    names: &BTreeSet<String>,
+    lacks_trait: fn(&Type) -> bool,
+) -> bool {
+    let is_excluded = match types.get(ident) {
+        Some(Type::Alias(_, target)) => {
+            contains_type_lacking_trait(target, types, names, lacks_trait)
+        }
+        Some(Type::Enum(ty)) => names.contains(&ty.ident.name),
+        Some(Type::Struct(ty)) => names.contains(&ty.ident.name),
+        Some(ty) => lacks_trait(ty),
+        None => false,
+    };
+    is_excluded
+        || ident
+            .generic_args
+            .iter()
+            .any(|arg| contains_type_lacking_trait(arg, types, names, lacks_trait))
+}
+
+/// `BinaryHeap` doesn't implement `PartialEq`, and neither do the request and
+/// response types from the `http` crate.
+fn lacks_partial_eq(ty: &Type) -> bool {
+    matches!(ty, Type::List(name, _) if name == "BinaryHeap") || lacks_clone(ty)
+}
+
+/// Requests and responses from the `http` crate, and the newtypes around them,
+/// don't implement `Clone`.
+fn lacks_clone(ty: &Type) -> bool {
+    matches!(
+        ty,
+        Type::Custom(custom) if custom.rs_ty == "fp_bindgen_support::http::HttpRequest"
+            || custom.rs_ty == "fp_bindgen_support::http::HttpResponse"
+    )
+}
+
+fn has_repr(ty: &Type) -> bool {
+    matches!(ty, Type::Enum(ty) if ty.options.repr.is_some())
+}
+
+fn is_index_map(ty: &Type) -> bool {
+    matches!(ty, Type::Map(name, _, _) if name == "IndexMap")
+}
+
+fn is_rc(ty: &Type) -> bool {
+    matches!(ty, Type::Container(name, _) if name == "Arc" || name == "Rc")
+}
+
+fn has_unknown_variant(ty: &Type) -> bool {
+    matches!(ty, Type::Enum(ty) if ty.options.unknown_variant.is_some())
+}
+
+fn create_struct_definition(
+    ty: &Struct,
+    types: &TypeMap,
+    implements_default: bool,
+    implements_clone: bool,
+    implements_partial_eq: bool,
+) -> String {
+    let serde_annotation = {
+        let attrs = ty.options.to_serde_attrs();
+        if attrs.is_empty() {
+            "".to_owned()
+        } else {
+            format!("#[serde({})]\n", attrs.join(", "))
+        }
+    };
+
+    let body = match ty.kind {
+        StructKind::Named => format!(
+            " {{\n{}\n}}",
+            format_named_struct_fields(&ty.fields, types).trim_start_matches('\n')
+        ),
+        StructKind::Tuple => format!("({});", format_tuple_struct_fields(&ty.fields, types)),
+        StructKind::Unit => ";".to_owned(),
+    };
+
+    let derives = format!(
+        "{}Debug, {}Deserialize, {}Serialize",
+        if implements_clone { "Clone, " } else { "" },
+        if implements_default { "Default, " } else { "" },
+        if implements_partial_eq {
+            "PartialEq, "
+        } else {
+            ""
+        }
+    );
+
+    format!(
+        "{}#[derive({})]\n{}pub struct {}{}",
+        format_docs(&ty.doc_lines),
+        derives,
+        serde_annotation,
+        ty.ident,
+        body
+    )
+}
+
+fn format_named_struct_fields(fields: &[Field], types: &TypeMap) -> String {
+    format_struct_fields(fields, types)
         .iter()
         .flat_map(|field| field.split('\n'))
         .map(|line| {
@@ -438,27 +823,42 @@ fn create_struct_definition(ty: &Struct, types: &TypeMap) -> String {
             }
         })
         .collect::<Vec<_>>()
-        .join("\n");
+        .join("\n")
+}
 
-    let serde_annotation = {
-        let attrs = ty.options.to_serde_attrs();
-        if attrs.is_empty() {
-            "".to_owned()
-        } else {
-            format!("#[serde({})]\n", attrs.join(", "))
-        }
-    };
+/// Formats the fields of a tuple struct. Only explicit Serde attributes are
+/// included, because the implicit ones we add for `Option`s are not allowed on
+/// unnamed fields.
+///
+/// Skipped fields are not part of the protocol, so their types are not
+/// available. They are kept as skipped unit fields instead, because leaving
+/// them out could turn the struct into a newtype.
+fn format_tuple_struct_fields(fields: &[Field], types: &TypeMap) -> String {
+    fields
+        .iter()
+        .map(|field| {
+            if field.attrs.is_skipped() {
+                return "#[serde(skip)] pub ()".to_owned();
+            }
 
-    format!(
-        "{}#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]\n{}\
-        pub struct {} {{\n\
-            {}\n\
-        }}",
-        format_docs(&ty.doc_lines),
-        serde_annotation,
-        ty.ident,
-        fields.trim_start_matches('\n')
-    )
+            let mut serde_attrs = field.attrs.to_serde_attrs();
+            if let Some(Type::Custom(custom_type)) = types.get(&field.ty) {
+                for attr in custom_type.serde_attrs.iter() {
+                    serde_attrs.push(attr.clone());
+                }
+            }
+
+            let annotations = if serde_attrs.is_empty() {
+                "".to_owned()
+            } else {
+                serde_attrs.sort();
+                format!("#[serde({})] ", serde_attrs.join(", "))
+            };
+
+            format!("{}pub {}", annotations, format_ident(&field.ty, types))
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
 }
 
 fn format_docs(doc_lines: &[String]) -> String {
@@ -469,23 +869,28 @@ fn format_docs(doc_lines: &[String]) -> String {
         .join("")
 }
 
+/// Formats named fields. Skipped fields are left out, because they are not part
+/// of the protocol and their types are not available.
 fn format_struct_fields(fields: &[Field], types: &TypeMap) -> Vec<String> {
     fields
         .iter()
+        .filter(|field| !field.attrs.is_skipped())
         .map(|field| {
             let mut serde_attrs = field.attrs.to_serde_attrs();
 
             match types.get(&field.ty) {
                 Some(Type::Container(name, _)) if name == "Option" => {
-                    if !serde_attrs
-                        .iter()
-                        .any(|attr| attr == "default" || attr.starts_with("default = "))
+                    if !field.attrs.skip_deserializing
+                        && !serde_attrs
+                            .iter()
+                            .any(|attr| attr == "default" || attr.starts_with("default = "))
                     {
                         serde_attrs.push("default".to_owned());
                     }
-                    if !serde_attrs
-                        .iter()
-                        .any(|attr| attr.starts_with("skip_serializing_if ="))
+                    if !field.attrs.skip_serializing
+                        && !serde_attrs
+                            .iter()
+                            .any(|attr| attr.starts_with("skip_serializing_if ="))
                     {
                         serde_attrs.push("skip_serializing_if = \"Option::is_none\"".to_owned());
                     }
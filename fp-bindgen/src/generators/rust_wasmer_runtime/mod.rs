@@ -1,12 +1,11 @@
 use crate::{
     functions::{Function, FunctionArg, FunctionList},
-    generators::rust_plugin::generate_type_bindings,
-    primitives::Primitive,
+    generators::rust_plugin::{format_ident as format_rust_ident, generate_type_bindings},
     types::{TypeIdent, TypeMap},
 };
-use proc_macro2::{Punct, TokenStream};
+use proc_macro2::{Ident, Span, TokenStream};
 use quote::{format_ident, quote, ToTokens};
-use std::{fs, str::FromStr};
+use std::fs;
 use syn::token::Async;
 
 pub(crate) fn generate_bindings(
@@ -15,21 +14,21 @@ pub(crate) fn generate_bindings(
     types: TypeMap,
     path: &str,
 ) {
-    fs::create_dir_all(&path).expect("Could not create output directory");
+    fs::create_dir_all(path).expect("Could not create output directory");
 
     // We use the same type generation as for the Rust plugin, only with the
     // serializable and deserializable types inverted:
     generate_type_bindings(&types, path, "rust_wasmer_runtime");
 
-    generate_function_bindings(import_functions, export_functions, path);
+    generate_function_bindings(import_functions, export_functions, &types, path);
 }
 
 fn generate_create_import_object_func(import_functions: &FunctionList) -> TokenStream {
     // Yes, this is pretty ugly but fortunately *only* required here to get
     // proper formatting with quote!, since rustfmt doesn't format inside macro
     // invocations :(
-    let newline = Punct::new('\n', proc_macro2::Spacing::Alone);
-    let space = Punct::new(' ', proc_macro2::Spacing::Joint);
+    let newline = newline();
+    let space = space();
     let spaces4: Vec<_> = (0..3).map(|_| &space).collect();
     let spaces8: Vec<_> = (0..7).map(|_| &space).collect();
     let spaces8 = quote! {#(#spaces8)*};
@@ -53,7 +52,7 @@ fn generate_create_import_object_func(import_functions: &FunctionList) -> TokenS
                         #spaces8 #fp_gen_names => Function::new_native_with_env(store, env.clone(), #names),
                         #newline
                     )*
-                #(#spaces4)* }
+                #(#spaces4)* #space }
                 #newline
             }
         }
@@ -64,7 +63,7 @@ struct WasmType<'a>(&'a TypeIdent);
 
 impl ToTokens for WasmType<'_> {
     fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
-        if let Ok(p) = Primitive::from_str(&self.0.name) {
+        if let Some(p) = self.0.as_primitive() {
             quote! { #p }
         } else {
             quote! { FatPtr }
@@ -83,11 +82,28 @@ impl ToTokens for WasmArg<'_> {
     }
 }
 
+/// Formats the Wasm arguments through which the given argument is passed.
+/// Arguments that are passed by value get an argument for every field.
+fn format_wasm_args(arg: &FunctionArg, types: &TypeMap) -> Vec<TokenStream> {
+    match arg.by_value_struct(types) {
+        Some(ty) => ty
+            .fields
+            .iter()
+            .map(|field| {
+                let name = format_ident!("{}", arg.by_value_field_name(field));
+                let ty = WasmType(&field.ty);
+                quote! { #name: #ty }
+            })
+            .collect(),
+        None => vec![WasmArg(arg).into_token_stream()],
+    }
+}
+
 struct RawType<'a>(&'a TypeIdent);
 
 impl ToTokens for RawType<'_> {
     fn to_tokens(&self, tokens: &mut TokenStream) {
-        if let Ok(p) = Primitive::from_str(&self.0.name) {
+        if let Some(p) = self.0.as_primitive() {
             quote! { #p }
         } else {
             quote! { Vec<u8> }
@@ -96,21 +112,55 @@ impl ToTokens for RawType<'_> {
     }
 }
 
-struct RawArg<'a>(&'a FunctionArg);
+/// Formats a type the same way it is declared in the generated types, so that
+/// custom types are referred to by their Rust type instead of their identifier.
+struct RustType<'a>(&'a TypeIdent, &'a TypeMap);
 
-impl ToTokens for RawArg<'_> {
+impl ToTokens for RustType<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let ty = format_rust_ident(self.0, self.1);
+        syn::parse_str::<syn::Type>(&ty)
+            .expect("Invalid type")
+            .to_tokens(tokens)
+    }
+}
+
+struct RustArg<'a>(&'a FunctionArg, &'a TypeMap);
+
+impl ToTokens for RustArg<'_> {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         let name = format_ident!("{}", self.0.name);
-        let ty = RawType(&self.0.ty);
+        let ty = self
+            .0
+            .format_caller_type(self.1, |ty| format_rust_ident(ty, self.1));
+        let ty = syn::parse_str::<syn::Type>(&ty).expect("Invalid argument type");
         (quote! { #name: #ty }).to_tokens(tokens)
     }
 }
 
-struct RuntimeImportedFunction<'a>(&'a Function);
+/// Arguments that are passed by value don't need to be serialized, so their raw
+/// representation is the type itself (or a reference to it, if borrowed).
+struct RawArg<'a>(&'a FunctionArg, &'a TypeMap);
+
+impl ToTokens for RawArg<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let name = format_ident!("{}", self.0.name);
+        if self.0.by_value_struct(self.1).is_some() {
+            let ty = &self.0.ty;
+            let reference = self.0.is_borrowed.then(|| quote! { & });
+            (quote! { #name: #reference #ty }).to_tokens(tokens)
+        } else {
+            let ty = RawType(&self.0.ty);
+            (quote! { #name: #ty }).to_tokens(tokens)
+        }
+    }
+}
+
+struct RuntimeImportedFunction<'a>(&'a Function, &'a TypeMap);
 
 impl ToTokens for RuntimeImportedFunction<'_> {
     fn to_tokens(&self, tokens: &mut TokenStream) {
-        let newline = Punct::new('\n', proc_macro2::Spacing::Alone);
+        let newline = newline();
 
         let Function {
             name,
@@ -119,6 +169,7 @@ impl ToTokens for RuntimeImportedFunction<'_> {
             return_type,
             is_async,
         } = self.0;
+        let types = self.1;
 
         let fp_gen_name = format!("__fp_gen_{}", name);
         let raw_name = format_ident!("{}_raw", name);
@@ -128,12 +179,44 @@ impl ToTokens for RuntimeImportedFunction<'_> {
             .iter()
             .map(|arg| format_ident!("{}", arg.name))
             .collect();
-        let serialize_names: Vec<_> = args
+        let serialized_args: Vec<_> = args
+            .iter()
+            .filter(|arg| !arg.ty.is_primitive() && arg.by_value_struct(types).is_none())
+            .collect();
+        let serialize_names: Vec<_> = serialized_args
             .iter()
-            .filter(|arg| !&arg.ty.is_primitive())
             .map(|arg| format_ident!("{}", arg.name))
             .collect();
-        let wasm_arg_types = args.iter().map(|arg| WasmType(&arg.ty));
+        let serialize_args = serialized_args.iter().map(|arg| {
+            let name = format_ident!("{}", arg.name);
+            if arg.ty.is_binary(types) && arg.is_borrowed {
+                quote! { let #name = #name.to_vec(); }
+            } else if arg.ty.is_binary(types) {
+                quote! { let #name = BinaryValue::into_vec(#name); }
+            } else {
+                quote! { let #name = serialize_to_vec(&#name); }
+            }
+        });
+        let wasm_arg_types = args
+            .iter()
+            .flat_map(|arg| match arg.by_value_struct(types) {
+                Some(ty) => ty.fields.iter().map(|field| WasmType(&field.ty)).collect(),
+                None => vec![WasmType(&arg.ty)],
+            });
+        let wasm_arg_values = args.iter().flat_map(|arg| {
+            let name = format_ident!("{}", arg.name);
+            match arg.by_value_struct(types) {
+                Some(ty) => ty
+                    .fields
+                    .iter()
+                    .map(|field| {
+                        let field_name = format_ident!("{}", field.name);
+                        quote! { #name.#field_name }
+                    })
+                    .collect(),
+                None => vec![quote! { #name }],
+            }
+        });
         let wasm_return_type = match return_type {
             Some(ty) => {
                 let ty = WasmType(ty);
@@ -141,7 +224,8 @@ impl ToTokens for RuntimeImportedFunction<'_> {
             }
             None => quote! { () },
         };
-        let raw_format_args = args.iter().map(RawArg);
+        let rust_args = args.iter().map(|arg| RustArg(arg, types));
+        let raw_format_args = args.iter().map(|arg| RawArg(arg, types));
         let raw_format_return_type = match return_type {
             Some(ty) => {
                 let raw = RawType(ty);
@@ -171,8 +255,18 @@ impl ToTokens for RuntimeImportedFunction<'_> {
                 quote! {
                     let result = import_from_guest_raw(&env, result);
                 },
-                quote! {
-                    let result = result.map(|ref data| deserialize_from_slice(data));
+                if return_type
+                    .as_ref()
+                    .map(|ty| ty.is_binary(types))
+                    .unwrap_or_default()
+                {
+                    quote! {
+                        let result = result.map(BinaryValue::from_vec);
+                    }
+                } else {
+                    quote! {
+                        let result = result.map(|ref data| deserialize_from_slice(data));
+                    }
                 },
             )
         } else {
@@ -180,14 +274,17 @@ impl ToTokens for RuntimeImportedFunction<'_> {
         };
 
         let return_type = match return_type {
-            Some(ident) => quote! { #ident },
+            Some(ident) => {
+                let ty = RustType(ident, types);
+                quote! { #ty }
+            }
             None => quote! { () },
         };
 
         (quote! {
             #(#[doc = #doc_lines])*
-            pub #asyncness fn #name(&self #(,#args)*) -> Result<#return_type, InvocationError> {
-                #(let #serialize_names = serialize_to_vec(&#serialize_names);)*
+            pub #asyncness fn #name(&self #(,#rust_args)*) -> Result<#return_type, InvocationError> {
+                #(#serialize_args)*
 
                 let result = self.#raw_name(#(#arg_names),*);
 
@@ -209,7 +306,7 @@ impl ToTokens for RuntimeImportedFunction<'_> {
                     .get_native_function::<(#(#wasm_arg_types),*), #wasm_return_type>(#fp_gen_name)
                     .map_err(|_| InvocationError::FunctionNotExported)?;
 
-                let result = function.call(#(#arg_names),*)?;
+                let result = function.call(#(#wasm_arg_values),*)?;
 
                 #raw_return_wrapper
 
@@ -223,7 +320,7 @@ impl ToTokens for RuntimeImportedFunction<'_> {
     }
 }
 
-struct RuntimeExportedFunction<'a>(&'a Function);
+struct RuntimeExportedFunction<'a>(&'a Function, &'a TypeMap);
 
 impl ToTokens for RuntimeExportedFunction<'_> {
     fn to_tokens(&self, tokens: &mut TokenStream) {
@@ -234,9 +331,10 @@ impl ToTokens for RuntimeExportedFunction<'_> {
             return_type,
             ..
         } = self.0;
+        let types = self.1;
 
         let underscore_name = format_ident!("_{}", name);
-        let input_args = args.iter().map(WasmArg);
+        let input_args = args.iter().flat_map(|arg| format_wasm_args(arg, types));
         let wrapper_return_type = if *is_async {
             quote! { -> FatPtr }
         } else {
@@ -249,11 +347,28 @@ impl ToTokens for RuntimeExportedFunction<'_> {
             }
         };
 
+        let by_value_args = args.iter().filter_map(|arg| {
+            let fields = &arg.by_value_struct(types)?.fields;
+            let name = format_ident!("{}", arg.name);
+            let ty = &arg.ty;
+            let field_names = fields.iter().map(|field| format_ident!("{}", field.name));
+            let field_idents = fields
+                .iter()
+                .map(|field| format_ident!("{}", arg.by_value_field_name(field)));
+            Some(quote! { let #name = #ty { #(#field_names: #field_idents),* }; })
+        });
         let complex_args = args
             .iter()
-            .filter(|arg| !arg.ty.is_primitive())
+            .filter(|arg| !arg.ty.is_primitive() && arg.by_value_struct(types).is_none())
             .collect::<Vec<_>>();
-        let complex_types = complex_args.iter().map(|a| &a.ty);
+        let complex_types = complex_args.iter().map(|a| RustType(&a.ty, types));
+        let import_fns = complex_args.iter().map(|arg| {
+            if arg.ty.is_binary(types) {
+                quote! { import_binary_from_guest }
+            } else {
+                quote! { import_from_guest }
+            }
+        });
         let complex_idents = complex_args
             .iter()
             .map(|arg| format_ident!("{}", arg.name))
@@ -279,15 +394,17 @@ impl ToTokens for RuntimeExportedFunction<'_> {
             match return_type {
                 None => quote! { () },
                 Some(ty) if ty.is_primitive() => quote! { result },
+                Some(ty) if ty.is_binary(types) => quote! { export_binary_to_guest(env, result) },
                 _ => quote! { export_to_guest(env, &result) },
             }
         };
 
-        let newline = Punct::new('\n', proc_macro2::Spacing::Alone);
+        let newline = newline();
 
         (quote! {
             pub fn #underscore_name(env: &RuntimeInstanceData #(,#input_args)*) #wrapper_return_type {
-                #(let #complex_idents = import_from_guest::<#complex_types>(env, #complex_idents);)*
+                #(#by_value_args)*
+                #(let #complex_idents = #import_fns::<#complex_types>(env, #complex_idents);)*
 
                 let result = #func_call;
                 #wrapper
@@ -301,21 +418,29 @@ impl ToTokens for RuntimeExportedFunction<'_> {
 fn generate_function_bindings(
     import_functions: FunctionList,
     export_functions: FunctionList,
+    types: &TypeMap,
     path: &str,
 ) {
-    let newline = Punct::new('\n', proc_macro2::Spacing::Alone);
+    check_placeholder_collisions(&import_functions);
+    check_placeholder_collisions(&export_functions);
+
+    let newline = newline();
     let create_import_object_func = generate_create_import_object_func(&import_functions);
 
-    let imports = import_functions.iter().map(RuntimeExportedFunction);
-    let exports = export_functions.iter().map(RuntimeImportedFunction);
+    let imports = import_functions
+        .iter()
+        .map(|function| RuntimeExportedFunction(function, types));
+    let exports = export_functions
+        .iter()
+        .map(|function| RuntimeImportedFunction(function, types));
 
-    let full = rustfmt_wrapper::rustfmt(quote! {
+    let full = rustfmt_wrapper::rustfmt(replace_whitespace_placeholders(quote! {
         use super::types::*;
         use fp_bindgen_support::{
-            common::mem::FatPtr,
+            common::{binary::BinaryValue, mem::FatPtr},
             host::{
                 errors::{InvocationError, RuntimeError},
-                mem::{export_to_guest, export_to_guest_raw, import_from_guest, import_from_guest_raw, deserialize_from_slice, serialize_to_vec},
+                mem::{export_binary_to_guest, export_to_guest, export_to_guest_raw, import_binary_from_guest, import_from_guest, import_from_guest_raw, deserialize_from_slice, serialize_to_vec},
                 r#async::{create_future_value, future::ModuleRawFuture, resolve_async_value},
                 runtime::RuntimeInstanceData,
             },
@@ -364,12 +489,55 @@ fn generate_function_bindings(
         #newline
         #(#imports)*
 
-    })
+    }))
     .unwrap();
 
     write_bindings_file(format!("{}/bindings.rs", path), full);
 }
 
+// `Punct` doesn't accept whitespace characters, so we insert placeholder idents
+// instead and replace them after the token stream has been stringified.
+//
+// The placeholders are kept outside the `__fp_gen_` namespace used for the
+// generated function names, and only tokens that match a placeholder exactly
+// are replaced, so they cannot affect identifiers that merely contain them.
+const NEWLINE_PLACEHOLDER: &str = "__FP_BINDGEN_NEWLINE__";
+const SPACE_PLACEHOLDER: &str = "__FP_BINDGEN_SPACE__";
+
+fn newline() -> Ident {
+    Ident::new(NEWLINE_PLACEHOLDER, Span::call_site())
+}
+
+fn space() -> Ident {
+    Ident::new(SPACE_PLACEHOLDER, Span::call_site())
+}
+
+/// Panics if any of the given functions would be mistaken for a whitespace
+/// placeholder.
+fn check_placeholder_collisions(functions: &FunctionList) {
+    for function in functions.iter() {
+        if function.name == NEWLINE_PLACEHOLDER || function.name == SPACE_PLACEHOLDER {
+            panic!(
+                "Function name `{}` is reserved by the Rust Wasmer runtime generator",
+                function.name
+            );
+        }
+    }
+}
+
+fn replace_whitespace_placeholders(tokens: TokenStream) -> String {
+    tokens
+        .to_string()
+        .split(' ')
+        .map(|token| match token {
+            NEWLINE_PLACEHOLDER => "\n",
+            SPACE_PLACEHOLDER => "",
+            token => token,
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
 fn write_bindings_file<C>(file_path: String, contents: C)
 where
     C: AsRef<[u8]>,
@@ -379,15 +547,16 @@ where
 
 #[cfg(test)]
 mod test {
-    use super::WasmArg;
+    use super::{newline, replace_whitespace_placeholders, space, WasmArg};
     use crate::{functions::FunctionArg, types::TypeIdent};
-    use quote::ToTokens;
+    use quote::{format_ident, quote, ToTokens};
 
     #[test]
     fn test_function_arg_to_tokens() {
         let arg = FunctionArg {
             name: "foobar".into(),
             ty: TypeIdent::from("String"),
+            is_borrowed: false,
         };
         let arg = WasmArg(&arg);
 
@@ -395,4 +564,18 @@ mod test {
 
         pretty_assertions::assert_eq!(&stringified, "foobar : FatPtr");
     }
+
+    #[test]
+    fn test_replace_whitespace_placeholders() {
+        let newline = newline();
+        let space = space();
+        let space_left = format_ident!("__fp_gen_space_left");
+        let newline_count = format_ident!("newline_count");
+
+        let stringified = replace_whitespace_placeholders(quote! {
+            #space_left #newline #space #space #newline_count
+        });
+
+        pretty_assertions::assert_eq!(&stringified, "__fp_gen_space_left \n   newline_count");
+    }
 }
@@ -1,12 +1,16 @@
+use super::naming::{
+    get_deserialize_field_name, get_deserialize_variant_name, get_field_name, get_pointer_name,
+    get_variable_name, get_variant_name,
+};
 use crate::{
     casing::Casing,
-    functions::{Function, FunctionList},
+    functions::{Function, FunctionArg, FunctionList},
     prelude::Primitive,
-    types::{CustomType, Enum, EnumOptions, Field, Struct, Type, TypeIdent, TypeMap, Variant},
-    TsExtendedRuntimeConfig,
+    types::{CustomType, Enum, Field, Struct, StructKind, Type, TypeIdent, TypeMap, Variant},
+    Int128Representation, TsExtendedRuntimeConfig,
 };
 use inflector::Inflector;
-use std::{fs, str::FromStr};
+use std::fs;
 
 pub(crate) fn generate_bindings(
     import_functions: FunctionList,
@@ -15,14 +19,15 @@ pub(crate) fn generate_bindings(
     config: TsExtendedRuntimeConfig,
     path: &str,
 ) {
-    generate_type_bindings(&types, path);
+    let int128 = config.int128_representation;
+    generate_type_bindings(&types, config.generate_time_helpers, int128, path);
 
     let import_decls =
-        format_function_declarations(&import_functions, &types, FunctionType::Import);
+        format_function_declarations(&import_functions, &types, FunctionType::Import, int128);
     let export_decls =
-        format_function_declarations(&export_functions, &types, FunctionType::Export);
+        format_function_declarations(&export_functions, &types, FunctionType::Export, int128);
     let raw_export_decls = if config.generate_raw_export_wrappers {
-        format_raw_function_declarations(&export_functions, FunctionType::Export)
+        format_raw_function_declarations(&export_functions, &types, FunctionType::Export)
     } else {
         Vec::new()
     };
@@ -35,22 +40,39 @@ pub(crate) fn generate_bindings(
         import_wrappers.push("__fp_host_resolve_async_value: resolvePromise,".to_owned());
     }
 
-    let export_wrappers = format_export_wrappers(&export_functions, &types);
+    let export_wrappers = format_export_wrappers(&export_functions, &types, int128);
     let raw_export_wrappers = if config.generate_raw_export_wrappers {
-        format_raw_export_wrappers(&export_functions)
+        format_raw_export_wrappers(&export_functions, &types)
     } else {
         Vec::new()
     };
 
-    let type_names = types
-        .into_iter()
-        .filter_map(|(_, ty)| match ty {
-            Type::Alias(name, _) => Some(name),
-            Type::Enum(ty) => Some(ty.ident.name),
-            Type::Struct(ty) => Some(ty.ident.name),
+    let int128_converters = format_int128_converters(&types);
+    let has_int128_functions =
+        import_functions
+            .iter()
+            .chain(export_functions.iter())
+            .any(|function| {
+                function
+                    .args
+                    .iter()
+                    .map(|arg| &arg.ty)
+                    .chain(function.return_type.iter())
+                    .any(|ty| needs_int128_conversion(ty, &types))
+            });
+
+    let mut type_names = types
+        .definitions()
+        .filter_map(|ty| match ty {
+            Type::Alias(name, _) => Some(name.clone()),
+            Type::Enum(ty) => Some(ty.ident.name.clone()),
+            Type::Struct(ty) => Some(ty.ident.name.clone()),
             _ => None,
         })
         .collect::<Vec<_>>();
+    if has_int128_type(&types) {
+        type_names.insert(0, "Int128".to_owned());
+    }
 
     let contents = format!(
         "// ============================================= //
@@ -164,6 +186,14 @@ export async function createRuntime(
         return exportToMemory(encode(object));
     }}
 
+    function exportBinaryToMemory(binary: ArrayBuffer | ArrayBufferView): FatPtr {{
+        return exportToMemory(
+            ArrayBuffer.isView(binary)
+                ? new Uint8Array(binary.buffer, binary.byteOffset, binary.byteLength)
+                : new Uint8Array(binary)
+        );
+    }}
+
     function exportToMemory(serialized: Uint8Array): FatPtr {{
         const fatPtr = malloc(serialized.length);
         const [ptr, len] = fromFatPtr(fatPtr);
@@ -212,7 +242,7 @@ function fromFatPtr(fatPtr: FatPtr): [ptr: number, len: number] {{
 function toFatPtr(ptr: number, len: number): FatPtr {{
     return (BigInt(ptr) << 32n) | BigInt(len);
 }}
-",
+{}",
         config.msgpack_module,
         join_lines(&type_names, |line| format!("    {},", line)),
         // HACK: Import paths in TypeScript are a bit of a mess. Usually, you
@@ -235,6 +265,11 @@ function toFatPtr(ptr: number, len: number): FatPtr {{
         },
         join_lines(&export_wrappers, |line| format!("        {}", line)),
         join_lines(&raw_export_wrappers, |line| format!("        {}", line)),
+        if has_int128_functions || !int128_converters.is_empty() {
+            format!("{}{}", format_int128_helpers(int128), int128_converters)
+        } else {
+            String::new()
+        },
     );
     write_bindings_file(format!("{}/index.ts", path), &contents);
 }
@@ -248,6 +283,7 @@ fn format_function_declarations(
     functions: &FunctionList,
     types: &TypeMap,
     function_type: FunctionType,
+    int128: Int128Representation,
 ) -> Vec<String> {
     // Plugins can always omit exports, while runtimes are always expected to provide all imports:
     let optional_marker = match function_type {
@@ -265,7 +301,7 @@ fn format_function_declarations(
                     format!(
                         "{}: {}",
                         arg.name.to_camel_case(),
-                        format_plain_primitive_or_ident(&arg.ty, types)
+                        format_plain_primitive_or_ident(&arg.ty, types, int128)
                     )
                 })
                 .collect::<Vec<_>>()
@@ -274,6 +310,9 @@ fn format_function_declarations(
                 format!(
                     " => Promise<{}>",
                     match &function.return_type {
+                        Some(ty) if get_int128_signedness(ty).is_some() => {
+                            format_int128_type(int128).to_owned()
+                        }
                         Some(ty) => format_ident(ty, types),
                         None => "void".to_owned(),
                     }
@@ -282,7 +321,7 @@ fn format_function_declarations(
                 format!(
                     " => {}",
                     match &function.return_type {
-                        Some(ty) => format_plain_primitive_or_ident(ty, types),
+                        Some(ty) => format_plain_primitive_or_ident(ty, types, int128),
                         None => "void".to_owned(),
                     }
                 )
@@ -300,6 +339,7 @@ fn format_function_declarations(
 
 fn format_raw_function_declarations(
     functions: &FunctionList,
+    types: &TypeMap,
     function_type: FunctionType,
 ) -> Vec<String> {
     // Plugins can always omit exports, while runtimes are always expected to provide all imports:
@@ -315,7 +355,13 @@ fn format_raw_function_declarations(
             let args = function
                 .args
                 .iter()
-                .map(|arg| format!("{}: {}", arg.name.to_camel_case(), format_raw_type(&arg.ty)))
+                .map(|arg| {
+                    format!(
+                        "{}: {}",
+                        arg.name.to_camel_case(),
+                        format_raw_arg_type(arg, types)
+                    )
+                })
                 .collect::<Vec<_>>()
                 .join(", ");
             let return_type = if function.is_async {
@@ -356,27 +402,34 @@ fn format_import_wrappers(import_functions: &FunctionList, types: &TypeMap) -> V
             let args_with_ptr_types = function
                 .args
                 .iter()
-                .map(|arg| {
-                    if let Ok(primitive) = Primitive::from_str(&arg.ty.name) {
-                        format!(
+                .flat_map(|arg| {
+                    if let Some(ty) = arg.by_value_struct(types) {
+                        ty.fields
+                            .iter()
+                            .map(|field| {
+                                format!(
+                                    "{}: {}",
+                                    arg.by_value_field_name(field).to_camel_case(),
+                                    format_wasm_field_type(field)
+                                )
+                            })
+                            .collect()
+                    } else if let Some(primitive) = arg.ty.as_primitive() {
+                        vec![format!(
                             "{}: {}",
                             arg.name.to_camel_case(),
                             format_plain_primitive(primitive)
-                        )
+                        )]
                     } else {
-                        format!("{}: FatPtr", get_pointer_name(&arg.name))
+                        vec![format!("{}: FatPtr", get_pointer_name(&arg.name))]
                     }
                 })
                 .collect::<Vec<_>>()
                 .join(", ");
-            let return_type = match &function
-                .return_type
-                .as_ref()
-                .map(|ty| Primitive::from_str(&ty.name))
-            {
+            let return_type = match function.return_type.as_ref().map(TypeIdent::as_primitive) {
                 None => "".to_owned(),
-                Some(Ok(primitive)) => format!(": {}", format_plain_primitive(*primitive)),
-                Some(_) => ": FatPtr".to_owned(),
+                Some(Some(primitive)) => format!(": {}", format_plain_primitive(primitive)),
+                Some(None) => ": FatPtr".to_owned(),
             };
             let import_args = function
                 .args
@@ -384,6 +437,50 @@ fn format_import_wrappers(import_functions: &FunctionList, types: &TypeMap) -> V
                 .filter_map(|arg| {
                     if arg.ty.is_primitive() {
                         None
+                    } else if let Some(ty) = arg.by_value_struct(types) {
+                        Some(format!(
+                            "const {} = {{ {} }};",
+                            arg.name.to_camel_case(),
+                            ty.fields
+                                .iter()
+                                .map(|field| format!(
+                                    "{}: {}",
+                                    format_property_name(&get_field_name(
+                                        field,
+                                        ty.options.field_casing
+                                    )),
+                                    import_by_value_field(
+                                        field,
+                                        &arg.by_value_field_name(field).to_camel_case()
+                                    )
+                                ))
+                                .collect::<Vec<_>>()
+                                .join(", ")
+                        ))
+                    } else if arg.ty.is_binary(types) {
+                        Some(format!(
+                            "const {} = importFromMemory({}).buffer;",
+                            arg.name.to_camel_case(),
+                            get_pointer_name(&arg.name)
+                        ))
+                    } else if let Some(signed) = get_int128_signedness(&arg.ty) {
+                        Some(format!(
+                            "const {} = int128FromBytes(parseObject<Uint8Array>({}), {});",
+                            arg.name.to_camel_case(),
+                            get_pointer_name(&arg.name),
+                            signed
+                        ))
+                    } else if needs_int128_conversion(&arg.ty, types) {
+                        Some(format!(
+                            "const {} = {};",
+                            arg.name.to_camel_case(),
+                            format_int128_conversion(
+                                &arg.ty,
+                                types,
+                                Int128Conversion::Decode,
+                                &format!("parseObject<any>({})", get_pointer_name(&arg.name))
+                            )
+                        ))
                     } else {
                         Some(format!(
                             "const {} = parseObject<{}>({});",
@@ -402,8 +499,11 @@ fn format_import_wrappers(import_functions: &FunctionList, types: &TypeMap) -> V
                 .join(", ");
             if function.is_async {
                 let async_result = match &function.return_type {
-                    Some(_) => "serializeObject(result)",
-                    None => "0",
+                    Some(ty) => format!(
+                        "serializeObject({})",
+                        format_int128_conversion(ty, types, Int128Conversion::Encode, "result")
+                    ),
+                    None => "0".to_owned(),
                 };
 
                 format!(
@@ -449,11 +549,20 @@ fn format_import_wrappers(import_functions: &FunctionList, types: &TypeMap) -> V
                             )
                         )
                     }
-                    _ => format!(
-                        "return serializeObject(importFunctions.{}({}));",
+                    Some(ty) if ty.is_binary(types) => format!(
+                        "return exportBinaryToMemory(importFunctions.{}({}));",
                         name.to_camel_case(),
                         args
                     ),
+                    Some(ty) => format!(
+                        "return serializeObject({});",
+                        format_int128_conversion(
+                            ty,
+                            types,
+                            Int128Conversion::Encode,
+                            &format!("importFunctions.{}({})", name.to_camel_case(), args)
+                        )
+                    ),
                 };
 
                 format!(
@@ -476,7 +585,11 @@ fn format_import_wrappers(import_functions: &FunctionList, types: &TypeMap) -> V
         .collect()
 }
 
-fn format_export_wrappers(export_functions: &FunctionList, types: &TypeMap) -> Vec<String> {
+fn format_export_wrappers(
+    export_functions: &FunctionList,
+    types: &TypeMap,
+    int128: Int128Representation,
+) -> Vec<String> {
     export_functions
         .into_iter()
         .flat_map(|function| {
@@ -498,7 +611,7 @@ fn format_export_wrappers(export_functions: &FunctionList, types: &TypeMap) -> V
                     format!(
                         "{}: {}",
                         arg.name.to_camel_case(),
-                        format_plain_primitive_or_ident(&arg.ty, types)
+                        format_plain_primitive_or_ident(&arg.ty, types, int128)
                     )
                 })
                 .collect::<Vec<_>>()
@@ -506,38 +619,62 @@ fn format_export_wrappers(export_functions: &FunctionList, types: &TypeMap) -> V
             let export_args = function
                 .args
                 .iter()
-                .filter(|arg| !arg.ty.is_primitive())
+                .filter(|arg| !arg.ty.is_primitive() && arg.by_value_struct(types).is_none())
                 .map(|arg| {
-                    format!(
-                        "const {} = serializeObject({});",
-                        get_pointer_name(&arg.name),
-                        arg.name.to_camel_case()
-                    )
-                })
-                .collect::<Vec<_>>();
-
-            let call_args = function
-                .args
-                .iter()
-                .map(|arg| {
-                    if arg.ty.is_primitive() {
-                        arg.name.to_camel_case()
+                    if arg.ty.is_binary(types) {
+                        format!(
+                            "const {} = exportBinaryToMemory({});",
+                            get_pointer_name(&arg.name),
+                            arg.name.to_camel_case()
+                        )
                     } else {
-                        get_pointer_name(&arg.name)
+                        format!(
+                            "const {} = serializeObject({});",
+                            get_pointer_name(&arg.name),
+                            format_int128_conversion(
+                                &arg.ty,
+                                types,
+                                Int128Conversion::Encode,
+                                &arg.name.to_camel_case()
+                            )
+                        )
                     }
                 })
-                .collect::<Vec<_>>()
-                .join(", ");
+                .collect::<Vec<_>>();
+
+            let call_args = format_export_call_args(function, types);
             let fn_call = if function.is_async {
-                format!(
-                    "return promiseFromPtr(export_fn({})).then((ptr) => parseObject<{}>(ptr));",
-                    call_args,
-                    function
-                        .return_type
-                        .as_ref()
-                        .map(|ty| format_ident(ty, types))
-                        .unwrap_or_else(|| "void".to_owned()),
-                )
+                match function.return_type.as_ref() {
+                    Some(ty) if get_int128_signedness(ty).is_some() => format!(
+                        "return promiseFromPtr(export_fn({})).then((ptr) => {});",
+                        call_args,
+                        format_int128_conversion(
+                            ty,
+                            types,
+                            Int128Conversion::Decode,
+                            "parseObject<Uint8Array>(ptr)"
+                        )
+                    ),
+                    Some(ty) if needs_int128_conversion(ty, types) => format!(
+                        "return promiseFromPtr(export_fn({})).then((ptr) => {});",
+                        call_args,
+                        format_int128_conversion(
+                            ty,
+                            types,
+                            Int128Conversion::Decode,
+                            "parseObject<any>(ptr)"
+                        )
+                    ),
+                    _ => format!(
+                        "return promiseFromPtr(export_fn({})).then((ptr) => parseObject<{}>(ptr));",
+                        call_args,
+                        function
+                            .return_type
+                            .as_ref()
+                            .map(|ty| format_ident(ty, types))
+                            .unwrap_or_else(|| "void".to_owned()),
+                    ),
+                }
             } else {
                 match &function.return_type {
                     None => format!("export_fn({});", call_args),
@@ -545,6 +682,27 @@ fn format_export_wrappers(export_functions: &FunctionList, types: &TypeMap) -> V
                         "return {};",
                         import_primitive(ty, &format!("export_fn({})", call_args))
                     ),
+                    Some(ty) if ty.is_binary(types) => {
+                        format!("return importFromMemory(export_fn({})).buffer;", call_args)
+                    }
+                    Some(ty) if get_int128_signedness(ty).is_some() => format!(
+                        "return {};",
+                        format_int128_conversion(
+                            ty,
+                            types,
+                            Int128Conversion::Decode,
+                            &format!("parseObject<Uint8Array>(export_fn({}))", call_args)
+                        )
+                    ),
+                    Some(ty) if needs_int128_conversion(ty, types) => format!(
+                        "return {};",
+                        format_int128_conversion(
+                            ty,
+                            types,
+                            Int128Conversion::Decode,
+                            &format!("parseObject<any>(export_fn({}))", call_args)
+                        )
+                    ),
                     Some(ty) => format!(
                         "return parseObject<{}>(export_fn({}));",
                         format_ident(ty, types),
@@ -580,7 +738,7 @@ fn format_export_wrappers(export_functions: &FunctionList, types: &TypeMap) -> V
         .collect()
 }
 
-fn format_raw_export_wrappers(export_functions: &FunctionList) -> Vec<String> {
+fn format_raw_export_wrappers(export_functions: &FunctionList, types: &TypeMap) -> Vec<String> {
     export_functions
         .into_iter()
         .filter(|function| !is_primitive_function(function))
@@ -589,13 +747,19 @@ fn format_raw_export_wrappers(export_functions: &FunctionList) -> Vec<String> {
             let args = function
                 .args
                 .iter()
-                .map(|arg| format!("{}: {}", arg.name.to_camel_case(), format_raw_type(&arg.ty)))
+                .map(|arg| {
+                    format!(
+                        "{}: {}",
+                        arg.name.to_camel_case(),
+                        format_raw_arg_type(arg, types)
+                    )
+                })
                 .collect::<Vec<_>>()
                 .join(", ");
             let export_args = function
                 .args
                 .iter()
-                .filter(|arg| !arg.ty.is_primitive())
+                .filter(|arg| !arg.ty.is_primitive() && arg.by_value_struct(types).is_none())
                 .map(|arg| {
                     format!(
                         "const {} = exportToMemory({});",
@@ -605,18 +769,7 @@ fn format_raw_export_wrappers(export_functions: &FunctionList) -> Vec<String> {
                 })
                 .collect::<Vec<_>>();
 
-            let call_args = function
-                .args
-                .iter()
-                .map(|arg| {
-                    if arg.ty.is_primitive() {
-                        arg.name.to_camel_case()
-                    } else {
-                        get_pointer_name(&arg.name)
-                    }
-                })
-                .collect::<Vec<_>>()
-                .join(", ");
+            let call_args = format_export_call_args(function, types);
             let fn_call = if function.is_async {
                 format!(
                     "return promiseFromPtr(export_fn({})).then(importFromMemory);",
@@ -663,9 +816,14 @@ fn format_raw_export_wrappers(export_functions: &FunctionList) -> Vec<String> {
         .collect()
 }
 
-fn generate_type_bindings(types: &TypeMap, path: &str) {
-    let type_defs = types
-        .values()
+fn generate_type_bindings(
+    types: &TypeMap,
+    generate_time_helpers: bool,
+    int128: Int128Representation,
+    path: &str,
+) {
+    let mut type_defs = types
+        .definitions()
         .filter_map(|ty| match ty {
             Type::Alias(name, ty) => Some(format!(
                 "export type {} = {};",
@@ -690,6 +848,19 @@ fn generate_type_bindings(types: &TypeMap, path: &str) {
         })
         .collect::<Vec<_>>();
 
+    if has_int128_type(types) {
+        type_defs.insert(
+            0,
+            format!("export type Int128 = {};", format_int128_type(int128)),
+        );
+    }
+
+    let time_helpers = if generate_time_helpers {
+        format_time_helpers(types)
+    } else {
+        String::new()
+    };
+
     write_bindings_file(
         format!("{}/types.ts", path),
         format!(
@@ -699,12 +870,70 @@ fn generate_type_bindings(types: &TypeMap, path: &str) {
 // This file is generated. PLEASE DO NOT MODIFY. //
 // ============================================= //
 
-{}\n",
-            type_defs.join("\n\n")
+{}\n{}",
+            type_defs.join("\n\n"),
+            time_helpers
         ),
     )
 }
 
+/// Formats the helpers for converting the `Duration` and `SystemTime` types
+/// that are used in the protocol to and from milliseconds.
+fn format_time_helpers(types: &TypeMap) -> String {
+    let has_custom_type = |rs_ty: &str| {
+        types
+            .values()
+            .any(|ty| matches!(ty, Type::Custom(custom) if custom.rs_ty == rs_ty))
+    };
+
+    let mut helpers = String::new();
+    if has_custom_type("std::time::Duration") {
+        helpers.push_str(
+            "
+/**
+ * Converts a `Duration` to a (fractional) number of milliseconds.
+ */
+export function durationToMillis(duration: Duration): number {
+    return duration.secs * 1000 + duration.nanos / 1_000_000;
+}
+
+/**
+ * Converts a non-negative number of milliseconds to a `Duration`.
+ */
+export function durationFromMillis(millis: number): Duration {
+    const secs = Math.floor(millis / 1000);
+    const nanos = Math.round((millis - secs * 1000) * 1_000_000);
+    return { secs, nanos: Math.min(nanos, 999_999_999) };
+}
+",
+        );
+    }
+    if has_custom_type("std::time::SystemTime") {
+        helpers.push_str(
+            "
+/**
+ * Converts a `SystemTime` to a (fractional) number of milliseconds since the
+ * Unix epoch, such as can be passed to the `Date` constructor.
+ */
+export function systemTimeToMillis(time: SystemTime): number {
+    return time.secs_since_epoch * 1000 + time.nanos_since_epoch / 1_000_000;
+}
+
+/**
+ * Converts a number of milliseconds since the Unix epoch, such as returned by
+ * `Date.now()`, to a `SystemTime`. Times before the epoch are not supported.
+ */
+export function systemTimeFromMillis(millis: number): SystemTime {
+    const secs = Math.floor(millis / 1000);
+    const nanos = Math.round((millis - secs * 1000) * 1_000_000);
+    return { secs_since_epoch: secs, nanos_since_epoch: Math.min(nanos, 999_999_999) };
+}
+",
+        );
+    }
+    helpers
+}
+
 fn is_primitive_function(function: &Function) -> bool {
     function
         .args
@@ -719,100 +948,28 @@ fn is_primitive_function(function: &Function) -> bool {
 }
 
 fn create_enum_definition(ty: &Enum, types: &TypeMap) -> String {
+    if ty.options.repr.is_some() {
+        return create_repr_enum_definition(ty);
+    }
+
     let variants = ty
         .variants
         .iter()
+        .filter(|variant| !variant.attrs.is_skipped())
         .map(|variant| {
-            let variant_name = get_variant_name(variant, &ty.options);
-            let variant_decl = match &variant.ty {
-                Type::Unit => {
-                    if let Some(tag) = &ty.options.tag_prop_name {
-                        format!("| {{ {}: \"{}\" }}", tag, variant_name)
-                    } else {
-                        format!("| \"{}\"", variant_name)
-                    }
-                }
-                Type::Struct(struct_variant) => {
-                    if ty.options.untagged {
-                        format!(
-                            "| {{ {} }}",
-                            format_struct_fields(
-                                &struct_variant.fields,
-                                types,
-                                variant.attrs.field_casing
-                            )
-                            .join(" ")
-                        )
-                    } else {
-                        let field_lines = format_struct_fields(
-                            &struct_variant.fields,
-                            types,
-                            variant.attrs.field_casing,
-                        );
-                        let formatted_fields = if field_lines.len() > struct_variant.fields.len() {
-                            format!(
-                                "\n{}",
-                                join_lines(&field_lines, |line| format!("    {}", line))
-                            )
-                        } else {
-                            format!(" {} ", field_lines.join(" ").trim_end_matches(';'))
-                        };
+            // If the variant has a different name when deserializing than when
+            // serializing, we may encounter either one:
+            let mut variant_names = vec![get_variant_name(variant, &ty.options)];
+            let deserialize_name = get_deserialize_variant_name(variant, &ty.options);
+            if !variant_names.contains(&deserialize_name) {
+                variant_names.push(deserialize_name);
+            }
 
-                        match (&ty.options.tag_prop_name, &ty.options.content_prop_name) {
-                            (Some(tag), Some(content)) => {
-                                format!(
-                                    "| {{ {}: \"{}\"; {}: {{{}}} }}",
-                                    tag, variant_name, content, formatted_fields
-                                )
-                            }
-                            (Some(tag), None) => {
-                                let space = if formatted_fields.contains('\n') {
-                                    "\n    "
-                                } else {
-                                    " "
-                                };
-                                format!(
-                                    "| {{{}{}: \"{}\";{}}}",
-                                    space, tag, variant_name, formatted_fields
-                                )
-                            }
-                            (None, _) => {
-                                format!("| {{ {}: {{{}}} }}", variant_name, formatted_fields)
-                            }
-                        }
-                    }
-                }
-                Type::Tuple(items) if items.len() == 1 => {
-                    let item = items.first().unwrap();
-                    if ty.options.untagged {
-                        format!("| {}", format_ident(item, types))
-                    } else {
-                        match (&ty.options.tag_prop_name, &ty.options.content_prop_name) {
-                            (Some(tag), Some(content)) => {
-                                format!(
-                                    "| {{ {}: \"{}\"; {}: {} }}",
-                                    tag,
-                                    variant_name,
-                                    content,
-                                    format_ident(item, types)
-                                )
-                            }
-                            (Some(tag), None) => {
-                                format!(
-                                    "| {{ {}: \"{}\" }} & {}",
-                                    tag,
-                                    variant_name,
-                                    format_ident(item, types)
-                                )
-                            }
-                            (None, _) => {
-                                format!("| {{ {}: {} }}", variant_name, format_ident(item, types))
-                            }
-                        }
-                    }
-                }
-                other => panic!("Unsupported type for enum variant: {:?}", other),
-            };
+            let variant_decl = variant_names
+                .iter()
+                .map(|variant_name| format_variant_decl(ty, variant, variant_name, types))
+                .collect::<Vec<_>>()
+                .join("\n");
 
             let lines = if variant.doc_lines.is_empty() {
                 variant_decl
@@ -835,6 +992,41 @@ fn create_enum_definition(ty: &Enum, types: &TypeMap) -> String {
         .collect::<Vec<_>>()
         .join("");
 
+    // Values of variants that are not known to this version of the protocol
+    // are passed on as-is:
+    let variants = if ty.options.unknown_variant.is_some() {
+        let tag = ty
+            .options
+            .tag_prop_name
+            .as_deref()
+            .map(format_property_name);
+        let content = ty
+            .options
+            .content_prop_name
+            .as_deref()
+            .map(format_property_name);
+        let unknown_decl = match (tag, content) {
+            (Some(tag), Some(content)) => {
+                format!("| {{ {}: string & {{}}; {}?: unknown }}", tag, content)
+            }
+            (Some(tag), None) => {
+                format!("| {{ {}: string & {{}}; [key: string]: unknown }}", tag)
+            }
+            (None, _) => "| (string & {})\n| { [variant: string]: unknown }".to_owned(),
+        };
+        let mut lines = format_docs(&[
+            " A variant that is not known to this version of the protocol.".to_owned(),
+        ]);
+        lines.extend(unknown_decl.split('\n').map(str::to_owned));
+        format!(
+            "{}{}",
+            variants,
+            join_lines(&lines, |line| format!("    {}", line))
+        )
+    } else {
+        variants
+    };
+
     format!(
         "{}export type {} =\n{};",
         join_lines(&format_docs(&ty.doc_lines), String::to_owned),
@@ -843,28 +1035,218 @@ fn create_enum_definition(ty: &Enum, types: &TypeMap) -> String {
     )
 }
 
+fn format_variant_decl(
+    ty: &Enum,
+    variant: &Variant,
+    variant_name: &str,
+    types: &TypeMap,
+) -> String {
+    let tag = ty
+        .options
+        .tag_prop_name
+        .as_deref()
+        .map(format_property_name);
+    let content = ty
+        .options
+        .content_prop_name
+        .as_deref()
+        .map(format_property_name);
+    let casing = variant.attrs.field_casing;
+    let deserialize_casing = variant.attrs.deserialize_field_casing();
+
+    match &variant.ty {
+        Type::Unit => {
+            if let Some(tag) = &tag {
+                format!("| {{ {}: \"{}\" }}", tag, variant_name)
+            } else {
+                format!("| \"{}\"", variant_name)
+            }
+        }
+        Type::Struct(struct_variant) => {
+            if ty.options.untagged {
+                format!(
+                    "| {{ {} }}",
+                    format_struct_fields(
+                        &struct_variant.fields,
+                        types,
+                        casing,
+                        deserialize_casing,
+                        false
+                    )
+                    .join(" ")
+                )
+            } else {
+                let field_lines = format_struct_fields(
+                    &struct_variant.fields,
+                    types,
+                    casing,
+                    deserialize_casing,
+                    false,
+                );
+                let formatted_fields = if field_lines.len() > struct_variant.fields.len() {
+                    format!(
+                        "\n{}",
+                        join_lines(&field_lines, |line| format!("    {}", line))
+                    )
+                } else {
+                    format!(" {} ", field_lines.join(" ").trim_end_matches(';'))
+                };
+
+                match (&tag, &content) {
+                    (Some(tag), Some(content)) => {
+                        format!(
+                            "| {{ {}: \"{}\"; {}: {{{}}} }}",
+                            tag, variant_name, content, formatted_fields
+                        )
+                    }
+                    (Some(tag), None) => {
+                        let space = if formatted_fields.contains('\n') {
+                            "\n    "
+                        } else {
+                            " "
+                        };
+                        format!(
+                            "| {{{}{}: \"{}\";{}}}",
+                            space, tag, variant_name, formatted_fields
+                        )
+                    }
+                    (None, _) => {
+                        format!(
+                            "| {{ {}: {{{}}} }}",
+                            format_property_name(variant_name),
+                            formatted_fields
+                        )
+                    }
+                }
+            }
+        }
+        Type::Tuple(items) if items.len() == 1 => {
+            let item = items.first().unwrap();
+            if ty.options.untagged {
+                format!("| {}", format_ident(item, types))
+            } else {
+                match (&tag, &content) {
+                    (Some(tag), Some(content)) => {
+                        format!(
+                            "| {{ {}: \"{}\"; {}: {} }}",
+                            tag,
+                            variant_name,
+                            content,
+                            format_ident(item, types)
+                        )
+                    }
+                    (Some(tag), None) => {
+                        format!(
+                            "| {{ {}: \"{}\" }} & {}",
+                            tag,
+                            variant_name,
+                            format_ident(item, types)
+                        )
+                    }
+                    (None, _) => {
+                        format!(
+                            "| {{ {}: {} }}",
+                            format_property_name(variant_name),
+                            format_ident(item, types)
+                        )
+                    }
+                }
+            }
+        }
+        other => panic!("Unsupported type for enum variant: {:?}", other),
+    }
+}
+
 fn create_struct_definition(ty: &Struct, types: &TypeMap) -> String {
-    let (flattened_fields, fields): (Vec<_>, Vec<_>) =
-        ty.fields.iter().partition(|field| field.attrs.flatten);
+    let definition = match ty.transparent_field() {
+        // Newtypes are serialized transparently, so we use a branded type to
+        // prevent them from being mixed up with other values of the same type:
+        Some(field) => format!(
+            "{} & {{ readonly __brand: \"{}\" }}",
+            format_ident(&field.ty, types),
+            ty.ident.name
+        ),
+        None => create_struct_body(ty, types),
+    };
 
     format!(
-        "{}export type {} = {{\n{}}}{};",
+        "{}export type {} = {};",
         join_lines(&format_docs(&ty.doc_lines), String::to_owned),
         ty.ident,
-        join_lines(
-            &format_struct_fields(
+        definition
+    )
+}
+
+fn create_struct_body(ty: &Struct, types: &TypeMap) -> String {
+    match ty.kind {
+        StructKind::Named => {
+            let (flattened_fields, fields): (Vec<_>, Vec<_>) = ty
+                .fields
+                .iter()
+                .filter(|field| !field.attrs.is_skipped())
+                .partition(|field| field.attrs.flatten);
+
+            // The tag is always serialized, but is not required when
+            // deserializing:
+            let mut lines = match ty.tag() {
+                Some((tag, name)) => vec![format!("{}?: \"{}\";", format_property_name(tag), name)],
+                None => Vec::new(),
+            };
+            lines.append(&mut format_struct_fields(
                 &fields.into_iter().cloned().collect::<Vec<_>>(),
                 types,
-                ty.options.field_casing
-            ),
-            |line| format!("    {}", line)
-        )
-        .trim_start_matches('\n'),
-        flattened_fields
-            .iter()
-            .map(|field| format!(" & {}", field.ty))
-            .collect::<Vec<_>>()
-            .join("")
+                ty.options.field_casing,
+                ty.options.deserialize_field_casing(),
+                ty.options.default.is_some(),
+            ));
+
+            format!(
+                "{{\n{}}}{}",
+                join_lines(&lines, |line| format!("    {}", line)).trim_start_matches('\n'),
+                flattened_fields
+                    .iter()
+                    .map(|field| format!(" & {}", field.ty))
+                    .collect::<Vec<_>>()
+                    .join("")
+            )
+        }
+        StructKind::Tuple => format!(
+            "[{}]",
+            ty.fields
+                .iter()
+                .map(|field| format_ident(&field.ty, types))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        StructKind::Unit => "null".to_owned(),
+    }
+}
+
+/// Creates a numeric enum for enums that are serialized as integers.
+fn create_repr_enum_definition(ty: &Enum) -> String {
+    let variants = ty
+        .variants
+        .iter()
+        .filter(|variant| !variant.attrs.is_skipped())
+        .map(|variant| {
+            let mut lines = format_docs(&variant.doc_lines);
+            lines.push(format!(
+                "{} = {},",
+                get_variable_name(&variant.name),
+                variant
+                    .discriminant
+                    .expect("Variants of enums with a `repr` must have a discriminant")
+            ));
+            join_lines(&lines, |line| format!("    {}", line))
+        })
+        .collect::<Vec<_>>()
+        .join("");
+
+    format!(
+        "{}export const enum {} {{\n{}}}",
+        join_lines(&format_docs(&ty.doc_lines), String::to_owned),
+        ty.ident,
+        variants
     )
 }
 
@@ -884,51 +1266,125 @@ fn format_docs(doc_lines: &[String]) -> Vec<String> {
     }
 }
 
-fn format_struct_fields(fields: &[Field], types: &TypeMap, casing: Casing) -> Vec<String> {
+/// Formats the fields of a struct or struct variant as TypeScript properties.
+///
+/// Fields that may be omitted in the serialized representation become
+/// optional properties. If `has_default` is `true`, this applies to all fields.
+fn format_struct_fields(
+    fields: &[Field],
+    types: &TypeMap,
+    casing: Casing,
+    deserialize_casing: Casing,
+    has_default: bool,
+) -> Vec<String> {
     fields
         .iter()
+        .filter(|field| !field.attrs.is_skipped())
         .flat_map(|field| {
-            let field_decl = match types.get(&field.ty) {
-                Some(Type::Container(name, _)) => {
-                    let optional = if name == "Option" { "?" } else { "" };
-                    let arg = field
+            let (ty, is_optional) = match types.get(&field.ty) {
+                Some(Type::Container(name, _)) => (
+                    field
                         .ty
                         .generic_args
                         .first()
-                        .expect("Identifier was expected to contain a generic argument");
-                    format!(
-                        "{}{}: {};",
-                        get_field_name(field, casing),
-                        optional,
-                        format_ident(arg, types)
-                    )
-                }
-                _ => format!(
-                    "{}: {};",
-                    get_field_name(field, casing),
-                    format_ident(&field.ty, types)
+                        .expect("Identifier was expected to contain a generic argument"),
+                    name == "Option",
                 ),
+                _ => (&field.ty, false),
             };
-            if field.doc_lines.is_empty() {
-                vec![field_decl]
+            let is_optional = is_optional || has_default || field.attrs.is_optional();
+
+            // If the field has a different name when serializing than when
+            // deserializing, we don't know which one will be present, so both
+            // become optional:
+            let serialize_name = format_property_name(&get_field_name(field, casing));
+            let deserialize_name =
+                format_property_name(&get_deserialize_field_name(field, deserialize_casing));
+            let mut lines = if serialize_name == deserialize_name {
+                vec![format!(
+                    "{}{}: {};",
+                    serialize_name,
+                    if is_optional { "?" } else { "" },
+                    format_ident(ty, types)
+                )]
             } else {
-                let mut lines = vec!["".to_owned()];
-                lines.append(&mut format_docs(&field.doc_lines));
-                lines.push(field_decl);
-                lines
+                vec![
+                    format!("{}?: {};", serialize_name, format_ident(ty, types)),
+                    format!("{}?: {};", deserialize_name, format_ident(ty, types)),
+                ]
+            };
+
+            if !field.doc_lines.is_empty() {
+                let mut doc_lines = vec!["".to_owned()];
+                doc_lines.append(&mut format_docs(&field.doc_lines));
+                lines.splice(0..0, doc_lines);
             }
+            lines
         })
         .collect()
 }
 
 fn format_raw_type(ty: &TypeIdent) -> &str {
-    if let Ok(primitive) = Primitive::from_str(&ty.name) {
+    if let Some(primitive) = ty.as_primitive() {
         format_plain_primitive(primitive)
     } else {
         "Uint8Array"
     }
 }
 
+/// Arguments that are passed by value are not encoded, so their raw type is
+/// the type itself.
+fn format_raw_arg_type(arg: &FunctionArg, types: &TypeMap) -> String {
+    if arg.by_value_struct(types).is_some() {
+        format_ident(&arg.ty, types)
+    } else {
+        format_raw_type(&arg.ty).to_owned()
+    }
+}
+
+/// Formats the type of the Wasm value through which a field of an argument
+/// that is passed by value is passed.
+fn format_wasm_field_type(field: &Field) -> &'static str {
+    match field.ty.as_primitive() {
+        Some(primitive) => format_plain_primitive(primitive),
+        None => unreachable!("Fields of structs passed by value must be primitives"),
+    }
+}
+
+/// Formats the arguments for calling an exported function, where arguments
+/// that are passed by value are spread into their fields.
+fn format_export_call_args(function: &Function, types: &TypeMap) -> String {
+    function
+        .args
+        .iter()
+        .flat_map(|arg| {
+            if let Some(ty) = arg.by_value_struct(types) {
+                ty.fields
+                    .iter()
+                    .map(|field| {
+                        export_by_value_field(
+                            field,
+                            &format!(
+                                "{}{}",
+                                arg.name.to_camel_case(),
+                                format_property_access(&get_field_name(
+                                    field,
+                                    ty.options.field_casing
+                                ))
+                            ),
+                        )
+                    })
+                    .collect()
+            } else if arg.ty.is_primitive() {
+                vec![arg.name.to_camel_case()]
+            } else {
+                vec![get_pointer_name(&arg.name)]
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
 /// Formats a type so it's valid TypeScript.
 fn format_ident(ident: &TypeIdent, types: &TypeMap) -> String {
     match types.get(ident) {
@@ -941,6 +1397,13 @@ fn format_ident(ident: &TypeIdent, types: &TypeMap) -> String {
 fn format_type_with_ident(ty: &Type, ident: &TypeIdent, types: &TypeMap) -> String {
     match ty {
         Type::Alias(name, _) => name.clone(),
+        Type::Array(_, len) => {
+            let item = ident
+                .generic_args
+                .first()
+                .expect("Identifier was expected to contain a generic argument");
+            format!("[{}]", vec![format_ident(item, types); *len].join(", "))
+        }
         Type::Container(name, _) => {
             let arg = ident
                 .generic_args
@@ -990,9 +1453,10 @@ fn format_type_with_ident(ty: &Type, ident: &TypeIdent, types: &TypeMap) -> Stri
         }
         Type::Primitive(primitive) => format_encoded_primitive(*primitive).to_owned(),
         Type::String => "string".to_owned(),
-        Type::Tuple(items) => format!(
+        Type::Tuple(_) => format!(
             "[{}]",
-            items
+            ident
+                .generic_args
                 .iter()
                 .map(|item| format_ident(item, types))
                 .collect::<Vec<_>>()
@@ -1005,22 +1469,33 @@ fn format_type_with_ident(ty: &Type, ident: &TypeIdent, types: &TypeMap) -> Stri
 fn format_plain_primitive(primitive: Primitive) -> &'static str {
     match primitive {
         Primitive::Bool => "boolean",
+        Primitive::Char => "string",
         Primitive::F32 => "number",
         Primitive::F64 => "number",
         Primitive::I8 => "number",
         Primitive::I16 => "number",
         Primitive::I32 => "number",
         Primitive::I64 => "bigint",
+        Primitive::I128 => "bigint",
+        Primitive::Isize => "number",
         Primitive::U8 => "number",
         Primitive::U16 => "number",
         Primitive::U32 => "number",
         Primitive::U64 => "bigint",
+        Primitive::U128 => "bigint",
+        Primitive::Usize => "number",
     }
 }
 
-fn format_plain_primitive_or_ident(ident: &TypeIdent, types: &TypeMap) -> String {
-    if let Ok(primitive) = Primitive::from_str(&ident.name) {
+fn format_plain_primitive_or_ident(
+    ident: &TypeIdent,
+    types: &TypeMap,
+    int128: Int128Representation,
+) -> String {
+    if let Some(primitive) = ident.as_primitive() {
         format_plain_primitive(primitive).to_owned()
+    } else if get_int128_signedness(ident).is_some() {
+        format_int128_type(int128).to_owned()
     } else {
         format_ident(ident, types)
     }
@@ -1030,41 +1505,587 @@ fn format_plain_primitive_or_ident(ident: &TypeIdent, types: &TypeMap) -> String
 // regular numbers rather than BigInt. This effectively limits them to a maximum
 // value of `2^53 - 1`.
 // See: https://github.com/msgpack/msgpack-javascript/issues/115
+//
+// 128-bit numbers are encoded as 16 big-endian bytes, which the runtime
+// converts into the `Int128` type defined in `types.ts`.
 fn format_encoded_primitive(primitive: Primitive) -> &'static str {
     match primitive {
         Primitive::I64 => "number",
+        Primitive::I128 => "Int128",
         Primitive::U64 => "number",
+        Primitive::U128 => "Int128",
         primitive => format_plain_primitive(primitive),
     }
 }
 
-fn get_field_name(field: &Field, casing: Casing) -> String {
-    if let Some(rename) = field.attrs.rename.as_ref() {
-        rename.to_owned()
+fn format_int128_type(int128: Int128Representation) -> &'static str {
+    match int128 {
+        Int128Representation::BigInt => "bigint",
+        Int128Representation::String => "string",
+    }
+}
+
+/// Generates the helpers for converting 128-bit integers from and to the
+/// 16 big-endian bytes they are encoded as.
+fn format_int128_helpers(int128: Int128Representation) -> String {
+    let ty = format_int128_type(int128);
+    let (from_bigint, to_bigint) = match int128 {
+        Int128Representation::BigInt => ("value", "value"),
+        Int128Representation::String => ("value.toString()", "BigInt(value)"),
+    };
+
+    format!(
+        "
+function int128FromBytes(bytes: Uint8Array, signed: boolean): {ty} {{
+    let value = 0n;
+    for (const byte of bytes) {{
+        value = (value << 8n) | BigInt(byte);
+    }}
+    if (signed) {{
+        value = BigInt.asIntN(128, value);
+    }}
+    return {from_bigint};
+}}
+
+function int128ToBytes(value: {ty}): Uint8Array {{
+    const bytes = new Uint8Array(16);
+    let remaining = BigInt.asUintN(128, {to_bigint});
+    for (let i = 15; i >= 0; i--) {{
+        bytes[i] = Number(remaining & 0xffn);
+        remaining >>= 8n;
+    }}
+    return bytes;
+}}
+"
+    )
+}
+
+/// Direction in which the 128-bit integers inside a value are converted.
+#[derive(Clone, Copy)]
+enum Int128Conversion {
+    /// From the 16 big-endian bytes they are encoded as into the configured
+    /// representation.
+    Decode,
+    /// From the configured representation into 16 big-endian bytes.
+    Encode,
+}
+
+impl Int128Conversion {
+    fn prefix(self) -> &'static str {
+        match self {
+            Self::Decode => "decode",
+            Self::Encode => "encode",
+        }
+    }
+}
+
+/// Generates the functions that convert the 128-bit integers inside the
+/// structs and enums of the protocol.
+fn format_int128_converters(types: &TypeMap) -> String {
+    types
+        .iter()
+        .filter(|(ident, _)| needs_int128_conversion(ident, types))
+        .flat_map(|(ident, ty)| {
+            vec![Int128Conversion::Decode, Int128Conversion::Encode]
+                .into_iter()
+                .filter_map(move |conversion| {
+                    let body = match ty {
+                        Type::Enum(ty) => {
+                            format_enum_int128_conversion(&ty.specialize(ident), types, conversion)
+                        }
+                        Type::Struct(ty) => format_struct_int128_conversion(
+                            &ty.specialize(ident),
+                            types,
+                            conversion,
+                        ),
+                        _ => return None,
+                    };
+                    Some(format!(
+                        "\nfunction {}(value: any): any {{\n{}}}\n",
+                        format_int128_converter_name(ident, conversion),
+                        join_lines(&body, |line| format!("    {}", line))
+                    ))
+                })
+        })
+        .collect()
+}
+
+/// Formats the body of the function that converts the 128-bit integers inside
+/// values of the given struct.
+fn format_struct_int128_conversion(
+    ty: &Struct,
+    types: &TypeMap,
+    conversion: Int128Conversion,
+) -> Vec<String> {
+    if let Some(field) = ty.transparent_field() {
+        return vec![format!(
+            "return {};",
+            format_int128_conversion(&field.ty, types, conversion, "value")
+        )];
+    }
+
+    match ty.kind {
+        StructKind::Named => {
+            let mut lines = vec!["const result = { ...value };".to_owned()];
+            for field in ty.fields.iter().filter(|field| {
+                field.attrs.flatten
+                    && !field.attrs.is_skipped()
+                    && needs_int128_conversion(&field.ty, types)
+            }) {
+                lines.push(format!(
+                    "Object.assign(result, {});",
+                    format_int128_conversion(&field.ty, types, conversion, "result")
+                ));
+            }
+            lines.append(&mut format_fields_int128_conversion(
+                &ty.fields,
+                types,
+                ty.options.field_casing,
+                ty.options.deserialize_field_casing(),
+                conversion,
+                "result",
+            ));
+            lines.push("return result;".to_owned());
+            lines
+        }
+        StructKind::Tuple => vec![format!(
+            "return [{}];",
+            ty.fields
+                .iter()
+                .enumerate()
+                .map(|(index, field)| format_int128_conversion(
+                    &field.ty,
+                    types,
+                    conversion,
+                    &format!("value[{}]", index)
+                ))
+                .collect::<Vec<_>>()
+                .join(", ")
+        )],
+        StructKind::Unit => vec!["return value;".to_owned()],
+    }
+}
+
+/// Formats the body of the function that converts the 128-bit integers inside
+/// values of the given enum. Values of variants that don't contain any, or
+/// that are not known to this version of the protocol, are returned as is.
+///
+/// Variants of untagged enums can only be recognized by the presence of their
+/// fields, so only their struct variants are converted.
+fn format_enum_int128_conversion(
+    ty: &Enum,
+    types: &TypeMap,
+    conversion: Int128Conversion,
+) -> Vec<String> {
+    let tag = ty.options.tag_prop_name.as_deref();
+    let content = ty.options.content_prop_name.as_deref();
+
+    let mut lines = Vec::new();
+    for variant in ty
+        .variants
+        .iter()
+        .filter(|variant| !variant.attrs.is_skipped())
+    {
+        let convert_content = |content: &str, wrap: &dyn Fn(&str) -> String| match &variant.ty {
+            Type::Struct(struct_variant) => {
+                let mut lines = vec![format!("const result = {{ ...{} }};", content)];
+                lines.append(&mut format_fields_int128_conversion(
+                    &struct_variant.fields,
+                    types,
+                    variant.attrs.field_casing,
+                    variant.attrs.deserialize_field_casing(),
+                    conversion,
+                    "result",
+                ));
+                lines.push(format!("return {};", wrap("result")));
+                lines
+            }
+            Type::Tuple(items) => vec![format!(
+                "return {};",
+                wrap(&format_int128_conversion(
+                    &items[0], types, conversion, content
+                ))
+            )],
+            _ => Vec::new(),
+        };
+
+        let needs_conversion = match &variant.ty {
+            Type::Struct(struct_variant) => struct_variant.fields.iter().any(|field| {
+                !field.attrs.is_skipped() && needs_int128_conversion(&field.ty, types)
+            }),
+            Type::Tuple(items) if !ty.options.untagged => {
+                items.len() == 1 && needs_int128_conversion(&items[0], types)
+            }
+            _ => false,
+        };
+        if !needs_conversion {
+            continue;
+        }
+
+        let mut conditions = Vec::new();
+        if ty.options.untagged {
+            let struct_variant = match &variant.ty {
+                Type::Struct(struct_variant) => struct_variant,
+                _ => continue,
+            };
+            let mut condition = "typeof value === \"object\" && value !== null".to_owned();
+            for field in struct_variant.fields.iter().filter(|field| {
+                !field.attrs.is_skipped()
+                    && !field.attrs.flatten
+                    && !field.attrs.is_optional()
+                    && !matches!(types.get(&field.ty), Some(Type::Container(name, _)) if name == "Option")
+            }) {
+                let names = get_field_names(
+                    field,
+                    variant.attrs.field_casing,
+                    variant.attrs.deserialize_field_casing(),
+                );
+                let presence = names
+                    .iter()
+                    .map(|name| format!("\"{}\" in value", name))
+                    .collect::<Vec<_>>()
+                    .join(" || ");
+                if names.len() > 1 {
+                    condition.push_str(&format!(" && ({})", presence));
+                } else {
+                    condition.push_str(&format!(" && {}", presence));
+                }
+            }
+            conditions.push((
+                condition,
+                convert_content("value", &|value| value.to_owned()),
+            ));
+        } else {
+            let mut variant_names = vec![get_variant_name(variant, &ty.options)];
+            let deserialize_name = get_deserialize_variant_name(variant, &ty.options);
+            if !variant_names.contains(&deserialize_name) {
+                variant_names.push(deserialize_name);
+            }
+
+            for variant_name in variant_names {
+                let condition_and_lines = match (tag, content) {
+                    (Some(tag), Some(content)) => (
+                        format!(
+                            "value{} === \"{}\"",
+                            format_optional_property_access(tag),
+                            variant_name
+                        ),
+                        convert_content(
+                            &format!("value{}", format_property_access(content)),
+                            &|value| {
+                                format!(
+                                    "{{ ...value, {}: {} }}",
+                                    format_property_name(content),
+                                    value
+                                )
+                            },
+                        ),
+                    ),
+                    (Some(tag), None) => (
+                        format!(
+                            "value{} === \"{}\"",
+                            format_optional_property_access(tag),
+                            variant_name
+                        ),
+                        convert_content("value", &|value| value.to_owned()),
+                    ),
+                    (None, _) => (
+                        format!(
+                            "value{} !== undefined",
+                            format_optional_property_access(&variant_name)
+                        ),
+                        convert_content(
+                            &format!("value{}", format_property_access(&variant_name)),
+                            &|value| {
+                                format!("{{ {}: {} }}", format_property_name(&variant_name), value)
+                            },
+                        ),
+                    ),
+                };
+                conditions.push(condition_and_lines);
+            }
+        }
+
+        for (condition, content_lines) in conditions {
+            lines.push(format!("if ({}) {{", condition));
+            lines.extend(
+                content_lines
+                    .into_iter()
+                    .map(|line| format!("    {}", line)),
+            );
+            lines.push("}".to_owned());
+        }
+    }
+    lines.push("return value;".to_owned());
+    lines
+}
+
+/// Formats the statements that convert the 128-bit integers inside the fields
+/// of the object in `target`, using any name the fields may have.
+fn format_fields_int128_conversion(
+    fields: &[Field],
+    types: &TypeMap,
+    casing: Casing,
+    deserialize_casing: Casing,
+    conversion: Int128Conversion,
+    target: &str,
+) -> Vec<String> {
+    fields
+        .iter()
+        .filter(|field| {
+            !field.attrs.is_skipped()
+                && !field.attrs.flatten
+                && needs_int128_conversion(&field.ty, types)
+        })
+        .flat_map(|field| {
+            // Missing and `null` values are skipped, so optional fields are
+            // converted like their inner type:
+            let ty = match types.get(&field.ty) {
+                Some(Type::Container(name, _)) if name == "Option" => field
+                    .ty
+                    .generic_args
+                    .first()
+                    .expect("Identifier was expected to contain a generic argument"),
+                _ => &field.ty,
+            };
+
+            get_field_names(field, casing, deserialize_casing)
+                .into_iter()
+                .flat_map(move |name| {
+                    let value = format!("{}{}", target, format_property_access(&name));
+                    vec![
+                        format!("if ({} != null) {{", value),
+                        format!(
+                            "    {} = {};",
+                            value,
+                            format_int128_conversion(ty, types, conversion, &value)
+                        ),
+                        "}".to_owned(),
+                    ]
+                })
+        })
+        .collect()
+}
+
+/// Returns the names a field may have in its serialized representation.
+fn get_field_names(field: &Field, casing: Casing, deserialize_casing: Casing) -> Vec<String> {
+    let mut names = vec![get_field_name(field, casing)];
+    let deserialize_name = get_deserialize_field_name(field, deserialize_casing);
+    if !names.contains(&deserialize_name) {
+        names.push(deserialize_name);
+    }
+    names
+}
+
+/// Formats an expression that converts the 128-bit integers inside the given
+/// value of the given type. Values of types without them are returned as is.
+fn format_int128_conversion(
+    ident: &TypeIdent,
+    types: &TypeMap,
+    conversion: Int128Conversion,
+    value: &str,
+) -> String {
+    if let Some(signed) = get_int128_signedness(ident) {
+        return match conversion {
+            Int128Conversion::Decode => format!("int128FromBytes({}, {})", value, signed),
+            Int128Conversion::Encode => format!("int128ToBytes({})", value),
+        };
+    }
+    if !needs_int128_conversion(ident, types) {
+        return value.to_owned();
+    }
+
+    let convert =
+        |ident: &TypeIdent, value: &str| format_int128_conversion(ident, types, conversion, value);
+    let item = || {
+        ident
+            .generic_args
+            .first()
+            .expect("Identifier was expected to contain a generic argument")
+    };
+    match types.get(ident) {
+        Some(Type::Alias(_, target)) => convert(target, value),
+        Some(Type::Array(_, _) | Type::List(_, _)) => {
+            format!("{}.map((item: any) => {})", value, convert(item(), "item"))
+        }
+        Some(Type::Container(name, _)) if name == "Option" => with_reusable_value(value, |value| {
+            format!("{} == null ? {} : {}", value, value, convert(item(), value))
+        }),
+        Some(Type::Container(_, _)) => convert(item(), value),
+        Some(Type::Enum(_) | Type::Struct(_)) => format!(
+            "{}({})",
+            format_int128_converter_name(ident, conversion),
+            value
+        ),
+        // Only the values of maps are converted, because MessagePack maps
+        // with binary keys cannot be decoded into objects:
+        Some(Type::Map(_, _, _)) => format!(
+            "Object.fromEntries(Object.entries({}).map(([key, item]: [string, any]) => [key, {}]))",
+            value,
+            convert(
+                ident
+                    .generic_args
+                    .get(1)
+                    .expect("Identifier was expected to contain two arguments"),
+                "item"
+            )
+        ),
+        Some(Type::Tuple(_)) => with_reusable_value(value, |value| {
+            format!(
+                "[{}]",
+                ident
+                    .generic_args
+                    .iter()
+                    .enumerate()
+                    .map(|(index, item)| convert(item, &format!("{}[{}]", value, index)))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )
+        }),
+        _ => value.to_owned(),
+    }
+}
+
+/// Calls `f` with an expression for the given value that may be evaluated more
+/// than once. Values that are not a plain variable or property access are
+/// passed to an arrow function first.
+fn with_reusable_value<F>(value: &str, f: F) -> String
+where
+    F: FnOnce(&str) -> String,
+{
+    if value.contains('(') {
+        format!("((value: any) => {})({})", f("value"), value)
     } else {
-        casing.format_string(get_variable_name(&field.name))
+        f(value)
+    }
+}
+
+/// Formats the name of the function that converts the 128-bit integers inside
+/// values of the given struct or enum.
+fn format_int128_converter_name(ident: &TypeIdent, conversion: Int128Conversion) -> String {
+    fn format_name(ident: &TypeIdent) -> String {
+        let name = match ident.array_len() {
+            Some(len) => format!("Array{}", len),
+            None if ident.is_tuple() => "Tuple".to_owned(),
+            None => ident.name.to_pascal_case(),
+        };
+        ident
+            .generic_args
+            .iter()
+            .fold(name, |name, arg| name + &format_name(arg))
     }
+
+    format!("{}{}", conversion.prefix(), format_name(ident))
 }
 
-fn get_variant_name(variant: &Variant, opts: &EnumOptions) -> String {
-    if let Some(rename) = variant.attrs.rename.as_ref() {
-        rename.to_owned()
+/// Returns whether values of the given type contain 128-bit integers, which
+/// need to be converted from and to the bytes they are encoded as.
+fn needs_int128_conversion(ident: &TypeIdent, types: &TypeMap) -> bool {
+    fn contains_int128(ident: &TypeIdent, types: &TypeMap, visited: &mut Vec<TypeIdent>) -> bool {
+        if get_int128_signedness(ident).is_some() {
+            return true;
+        }
+        // Recursive types only contain 128-bit integers if some other part of
+        // them does:
+        if visited.contains(ident) {
+            return false;
+        }
+        visited.push(ident.clone());
+
+        match types.get(ident) {
+            Some(Type::Alias(_, target)) => contains_int128(target, types, visited),
+            Some(Type::Array(_, _) | Type::Container(_, _) | Type::List(_, _) | Type::Tuple(_)) => {
+                ident
+                    .generic_args
+                    .iter()
+                    .any(|arg| contains_int128(arg, types, visited))
+            }
+            Some(Type::Enum(ty)) => ty
+                .specialize(ident)
+                .variants
+                .iter()
+                .filter(|variant| !variant.attrs.is_skipped())
+                .any(|variant| match &variant.ty {
+                    Type::Struct(variant) => fields_contain_int128(&variant.fields, types, visited),
+                    Type::Tuple(items) => items
+                        .iter()
+                        .any(|item| contains_int128(item, types, visited)),
+                    _ => false,
+                }),
+            Some(Type::Map(_, _, _)) => ident
+                .generic_args
+                .get(1)
+                .map(|value| contains_int128(value, types, visited))
+                .unwrap_or(false),
+            Some(Type::Struct(ty)) => {
+                fields_contain_int128(&ty.specialize(ident).fields, types, visited)
+            }
+            _ => false,
+        }
+    }
+
+    fn fields_contain_int128(
+        fields: &[Field],
+        types: &TypeMap,
+        visited: &mut Vec<TypeIdent>,
+    ) -> bool {
+        fields
+            .iter()
+            .filter(|field| !field.attrs.is_skipped())
+            .any(|field| contains_int128(&field.ty, types, visited))
+    }
+
+    contains_int128(ident, types, &mut Vec::new())
+}
+
+/// Returns whether the protocol uses 128-bit integers, for which `types.ts`
+/// defines the `Int128` type.
+fn has_int128_type(types: &TypeMap) -> bool {
+    types
+        .values()
+        .any(|ty| matches!(ty, Type::Primitive(Primitive::I128 | Primitive::U128)))
+}
+
+/// Returns `Some(signed)` if the given type is a 128-bit integer.
+fn get_int128_signedness(ty: &TypeIdent) -> Option<bool> {
+    match ty.name.as_str() {
+        "i128" if ty.generic_args.is_empty() => Some(true),
+        "u128" if ty.generic_args.is_empty() => Some(false),
+        _ => None,
+    }
+}
+
+/// Formats the name of a property, quoting it if it's not a valid identifier.
+fn format_property_name(name: &str) -> String {
+    let is_identifier = name
+        .chars()
+        .next()
+        .map(|first| first.is_ascii_alphabetic() || first == '_' || first == '$')
+        .unwrap_or(false)
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$');
+    if is_identifier {
+        name.to_owned()
     } else {
-        opts.variant_casing
-            .format_string(get_variable_name(&variant.name))
+        format!("\"{}\"", name)
     }
 }
 
-fn get_variable_name(name: &str) -> &str {
-    if let Some(stripped) = name.strip_prefix("r#") {
-        stripped
+/// Formats an expression for accessing the property with the given name.
+fn format_property_access(name: &str) -> String {
+    let property = format_property_name(name);
+    if property.starts_with('"') {
+        format!("[{}]", property)
     } else {
-        name
+        format!(".{}", property)
     }
 }
 
-fn get_pointer_name(name: &str) -> String {
-    format!("{}_ptr", get_variable_name(name))
+/// Formats an expression for accessing the property with the given name
+/// using optional chaining.
+fn format_optional_property_access(name: &str) -> String {
+    format!("?.{}", format_property_access(name).trim_start_matches('.'))
 }
 
 fn import_primitive(ty: &TypeIdent, value: &str) -> String {
@@ -1078,6 +2099,26 @@ fn import_primitive(ty: &TypeIdent, value: &str) -> String {
     }
 }
 
+/// Converts a Wasm value into the value of a field of an argument that is
+/// passed by value. 64-bit fields are represented as regular numbers inside
+/// objects, just like when they are encoded using MessagePack.
+fn import_by_value_field(field: &Field, value: &str) -> String {
+    let value = import_primitive(&field.ty, value);
+    match field.ty.as_primitive() {
+        Some(Primitive::I64 | Primitive::U64) => format!("Number({})", value),
+        _ => value,
+    }
+}
+
+/// Converts the value of a field of an argument that is passed by value into
+/// a Wasm value.
+fn export_by_value_field(field: &Field, value: &str) -> String {
+    match field.ty.as_primitive() {
+        Some(Primitive::I64 | Primitive::U64) => format!("BigInt({})", value),
+        _ => value.to_owned(),
+    }
+}
+
 fn needs_primitive_cast(ty: &TypeIdent) -> bool {
     matches!(ty.name.as_str(), "bool" | "i8" | "i16" | "i32" | "i64")
 }
@@ -1110,3 +2151,146 @@ where
 {
     fs::write(&file_path, &contents).expect("Could not write bindings file");
 }
+
+#[cfg(test)]
+mod test {
+    use super::{format_int128_conversion, format_int128_converters, Int128Conversion};
+    use crate::{
+        prelude::Serializable,
+        types::{Type, TypeIdent, TypeMap},
+    };
+    use std::str::FromStr;
+
+    #[test]
+    fn test_int128_container_conversion() {
+        let mut types = TypeMap::new();
+        <Option<Vec<u128>>>::collect_types(&mut types);
+
+        let ident = TypeIdent::from_str("Option<Vec<u128>>").unwrap();
+        pretty_assertions::assert_eq!(
+            format_int128_conversion(
+                &ident,
+                &types,
+                Int128Conversion::Decode,
+                "parseObject<any>(ptr)"
+            ),
+            "((value: any) => value == null ? value : value.map((item: any) => int128FromBytes(item, false)))(parseObject<any>(ptr))"
+        );
+        pretty_assertions::assert_eq!(
+            format_int128_conversion(&ident, &types, Int128Conversion::Encode, "values"),
+            "values == null ? values : values.map((item: any) => int128ToBytes(item))"
+        );
+    }
+
+    #[test]
+    fn test_int128_struct_converters() {
+        let mut types = TypeMap::new();
+        <i128>::collect_types(&mut types);
+        <Option<u128>>::collect_types(&mut types);
+        <u64>::collect_types(&mut types);
+        types.insert(
+            TypeIdent::from("Wide"),
+            Type::from_item(
+                "pub struct Wide { pub small: u64, pub large: i128, #[serde(rename = \"max-id\")] pub max_id: Option<u128> }",
+            ),
+        );
+
+        pretty_assertions::assert_eq!(
+            format_int128_converters(&types),
+            r#"
+function decodeWide(value: any): any {
+    const result = { ...value };
+    if (result.large != null) {
+        result.large = int128FromBytes(result.large, true);
+    }
+    if (result["max-id"] != null) {
+        result["max-id"] = int128FromBytes(result["max-id"], false);
+    }
+    return result;
+}
+
+function encodeWide(value: any): any {
+    const result = { ...value };
+    if (result.large != null) {
+        result.large = int128ToBytes(result.large);
+    }
+    if (result["max-id"] != null) {
+        result["max-id"] = int128ToBytes(result["max-id"]);
+    }
+    return result;
+}
+"#
+        );
+    }
+
+    #[test]
+    fn test_int128_enum_converters() {
+        let mut types = TypeMap::new();
+        <u64>::collect_types(&mut types);
+        <u128>::collect_types(&mut types);
+        types.insert(
+            TypeIdent::from("Wide"),
+            Type::from_item(
+                "#[serde(tag = \"type\", content = \"payload\")] pub enum Wide { Small(u64), Large(u128), Range { start: u128, end: u128 } }",
+            ),
+        );
+
+        pretty_assertions::assert_eq!(
+            format_int128_converters(&types),
+            r#"
+function decodeWide(value: any): any {
+    if (value?.type === "Large") {
+        return { ...value, payload: int128FromBytes(value.payload, false) };
+    }
+    if (value?.type === "Range") {
+        const result = { ...value.payload };
+        if (result.start != null) {
+            result.start = int128FromBytes(result.start, false);
+        }
+        if (result.end != null) {
+            result.end = int128FromBytes(result.end, false);
+        }
+        return { ...value, payload: result };
+    }
+    return value;
+}
+
+function encodeWide(value: any): any {
+    if (value?.type === "Large") {
+        return { ...value, payload: int128ToBytes(value.payload) };
+    }
+    if (value?.type === "Range") {
+        const result = { ...value.payload };
+        if (result.start != null) {
+            result.start = int128ToBytes(result.start);
+        }
+        if (result.end != null) {
+            result.end = int128ToBytes(result.end);
+        }
+        return { ...value, payload: result };
+    }
+    return value;
+}
+"#
+        );
+    }
+
+    #[test]
+    fn test_int128_untagged_enum_converters() {
+        let mut types = TypeMap::new();
+        <u128>::collect_types(&mut types);
+        <Option<u128>>::collect_types(&mut types);
+        types.insert(
+            TypeIdent::from("Wide"),
+            Type::from_item(
+                "#[serde(untagged)] pub enum Wide { Large(u128), Range { start: u128, end: Option<u128> } }",
+            ),
+        );
+
+        let converters = format_int128_converters(&types);
+        assert!(converters.contains(
+            "    if (typeof value === \"object\" && value !== null && \"start\" in value) {\n"
+        ));
+        assert!(!converters.contains("Uint8Array"));
+    }
+}
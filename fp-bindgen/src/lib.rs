@@ -9,6 +9,14 @@ pub mod prelude;
 pub mod primitives;
 pub mod types;
 
+/// Re-exports used by the code generated by the `fp_import!` and `fp_export!`
+/// macros. Not meant to be used directly.
+#[doc(hidden)]
+pub mod __private {
+    #[cfg(feature = "serde-bytes-compat")]
+    pub use serde_bytes::ByteBuf;
+}
+
 use fp_bindgen_macros::primitive_impls;
 use prelude::*;
 
@@ -16,6 +24,6 @@ primitive_impls!();
 
 #[cfg(feature = "generators")]
 pub use generators::{
-    generate_bindings, BindingConfig, BindingsType, RustPluginConfig, TsExtendedRuntimeConfig,
-    TsRuntimeConfig,
+    generate_bindings, AssemblyScriptPluginConfig, BindingConfig, BindingsType,
+    Int128Representation, RustPluginConfig, TsExtendedRuntimeConfig, TsRuntimeConfig,
 };
@@ -4,6 +4,7 @@ pub use crate::serializable::Serializable;
 pub use crate::types::{CustomType, Type, TypeIdent, TypeMap};
 #[cfg(feature = "generators")]
 pub use crate::{
-    BindingConfig, BindingsType, RustPluginConfig, TsExtendedRuntimeConfig, TsRuntimeConfig,
+    AssemblyScriptPluginConfig, BindingConfig, BindingsType, Int128Representation,
+    RustPluginConfig, TsExtendedRuntimeConfig, TsRuntimeConfig,
 };
 pub use fp_bindgen_macros::*;
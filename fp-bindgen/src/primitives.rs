@@ -5,16 +5,21 @@ use std::str::FromStr;
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 pub enum Primitive {
     Bool,
+    Char,
     F32,
     F64,
     I8,
     I16,
     I32,
     I64,
+    I128,
+    Isize,
     U8,
     U16,
     U32,
     U64,
+    U128,
+    Usize,
 }
 
 impl Primitive {
@@ -22,19 +27,40 @@ impl Primitive {
         use Primitive::*;
         let string = match self {
             Bool => "bool",
+            Char => "char",
             F32 => "f32",
             F64 => "f64",
             I8 => "i8",
             I16 => "i16",
             I32 => "i32",
             I64 => "i64",
+            I128 => "i128",
+            Isize => "isize",
             U8 => "u8",
             U16 => "u16",
             U32 => "u32",
             U64 => "u64",
+            U128 => "u128",
+            Usize => "usize",
         };
         string.to_owned()
     }
+
+    /// Returns whether the primitive is an integer type.
+    pub fn is_integer(&self) -> bool {
+        use Primitive::*;
+        !matches!(self, Bool | Char | F32 | F64)
+    }
+
+    /// Returns whether the primitive can be passed directly as a Wasm value.
+    ///
+    /// Other primitives either don't fit in an `i64`, or their size differs
+    /// between the host and the plugin, so they are passed using MessagePack
+    /// serialization, just like complex types.
+    pub fn fits_wasm_value(&self) -> bool {
+        use Primitive::*;
+        !matches!(self, Char | I128 | Isize | U128 | Usize)
+    }
 }
 
 impl FromStr for Primitive {
@@ -43,16 +69,21 @@ impl FromStr for Primitive {
     fn from_str(string: &str) -> Result<Self, Self::Err> {
         let primitive = match string {
             "bool" => Primitive::Bool,
+            "char" => Primitive::Char,
             "f32" => Primitive::F32,
             "f64" => Primitive::F64,
             "i8" => Primitive::I8,
             "i16" => Primitive::I16,
             "i32" => Primitive::I32,
             "i64" => Primitive::I64,
+            "i128" => Primitive::I128,
+            "isize" => Primitive::Isize,
             "u8" => Primitive::U8,
             "u16" => Primitive::U16,
             "u32" => Primitive::U32,
             "u64" => Primitive::U64,
+            "u128" => Primitive::U128,
+            "usize" => Primitive::Usize,
             string => return Err(format!("Unknown primitive type: \"{}\"", string)),
         };
         Ok(primitive)
@@ -63,16 +94,21 @@ impl ToTokens for Primitive {
     fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
         (match self {
             Primitive::Bool => quote! {bool},
+            Primitive::Char => quote! {char},
             Primitive::F32 => quote! {f32},
             Primitive::F64 => quote! {f64},
             Primitive::I8 => quote! {i8},
             Primitive::I16 => quote! {i16},
             Primitive::I32 => quote! {i32},
             Primitive::I64 => quote! {i64},
+            Primitive::I128 => quote! {i128},
+            Primitive::Isize => quote! {isize},
             Primitive::U8 => quote! {u8},
             Primitive::U16 => quote! {u16},
             Primitive::U32 => quote! {u32},
             Primitive::U64 => quote! {u64},
+            Primitive::U128 => quote! {u128},
+            Primitive::Usize => quote! {usize},
         })
         .to_tokens(tokens)
     }
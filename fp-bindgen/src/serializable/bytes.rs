@@ -0,0 +1,32 @@
+use super::{fp_bindgen_support_dependency, Serializable};
+use crate::types::{CargoDependency, CustomType, Type, TypeIdent};
+use std::collections::{BTreeMap, BTreeSet};
+
+impl Serializable for bytes::Bytes {
+    fn ident() -> TypeIdent {
+        TypeIdent::from("Bytes")
+    }
+
+    fn ty() -> Type {
+        Type::Custom(CustomType {
+            ident: Self::ident(),
+            binary: true,
+            rs_ty: "bytes::Bytes".to_owned(),
+            rs_dependencies: BTreeMap::from([
+                (
+                    "bytes",
+                    CargoDependency::with_version_and_features("1", BTreeSet::from(["serde"])),
+                ),
+                // Passing `Bytes` as a raw buffer requires support for it in
+                // `fp-bindgen-support`:
+                (
+                    "fp-bindgen-support",
+                    fp_bindgen_support_dependency(BTreeSet::from(["bytes"])),
+                ),
+            ]),
+            serde_attrs: vec![],
+            ts_ty: "ArrayBuffer".to_owned(),
+            ts_declaration: None,
+        })
+    }
+}
@@ -0,0 +1,115 @@
+use super::Serializable;
+use crate::types::{CargoDependency, CustomType, Type, TypeIdent};
+use std::collections::{BTreeMap, BTreeSet};
+
+fn chrono_dependency() -> BTreeMap<&'static str, CargoDependency> {
+    BTreeMap::from([(
+        "chrono",
+        CargoDependency {
+            branch: None,
+            git: None,
+            path: None,
+            // Serde support for `TimeDelta` was added in 0.4.39:
+            version: Some("0.4.39"),
+            features: BTreeSet::from(["serde"]),
+        },
+    )])
+}
+
+impl Serializable for chrono::DateTime<chrono::Utc> {
+    fn ident() -> TypeIdent {
+        TypeIdent {
+            name: "DateTime".to_owned(),
+            generic_args: vec![TypeIdent::from("Utc")],
+        }
+    }
+
+    fn ty() -> Type {
+        Type::Custom(CustomType {
+            ident: Self::ident(),
+            binary: false,
+            rs_ty: "chrono::DateTime<chrono::Utc>".to_owned(),
+            rs_dependencies: chrono_dependency(),
+            serde_attrs: vec![],
+            ts_ty: "string".to_owned(),
+            ts_declaration: None,
+        })
+    }
+}
+
+impl Serializable for chrono::DateTime<chrono::FixedOffset> {
+    fn ident() -> TypeIdent {
+        TypeIdent {
+            name: "DateTime".to_owned(),
+            generic_args: vec![TypeIdent::from("FixedOffset")],
+        }
+    }
+
+    fn ty() -> Type {
+        Type::Custom(CustomType {
+            ident: Self::ident(),
+            binary: false,
+            rs_ty: "chrono::DateTime<chrono::FixedOffset>".to_owned(),
+            rs_dependencies: chrono_dependency(),
+            serde_attrs: vec![],
+            ts_ty: "string".to_owned(),
+            ts_declaration: None,
+        })
+    }
+}
+
+impl Serializable for chrono::NaiveDate {
+    fn ident() -> TypeIdent {
+        TypeIdent::from("NaiveDate")
+    }
+
+    fn ty() -> Type {
+        Type::Custom(CustomType {
+            ident: Self::ident(),
+            binary: false,
+            rs_ty: "chrono::NaiveDate".to_owned(),
+            rs_dependencies: chrono_dependency(),
+            serde_attrs: vec![],
+            ts_ty: "string".to_owned(),
+            ts_declaration: None,
+        })
+    }
+}
+
+impl Serializable for chrono::NaiveDateTime {
+    fn ident() -> TypeIdent {
+        TypeIdent::from("NaiveDateTime")
+    }
+
+    fn ty() -> Type {
+        Type::Custom(CustomType {
+            ident: Self::ident(),
+            binary: false,
+            rs_ty: "chrono::NaiveDateTime".to_owned(),
+            rs_dependencies: chrono_dependency(),
+            serde_attrs: vec![],
+            ts_ty: "string".to_owned(),
+            ts_declaration: None,
+        })
+    }
+}
+
+/// `chrono::Duration` is an alias for `TimeDelta`, which Chrono serializes as
+/// a tuple of seconds and nanoseconds.
+impl Serializable for chrono::TimeDelta {
+    fn ident() -> TypeIdent {
+        TypeIdent::from("TimeDelta")
+    }
+
+    fn ty() -> Type {
+        Type::Custom(CustomType {
+            ident: Self::ident(),
+            binary: false,
+            rs_ty: "chrono::TimeDelta".to_owned(),
+            rs_dependencies: chrono_dependency(),
+            serde_attrs: vec![],
+            ts_ty: "TimeDelta".to_owned(),
+            ts_declaration: Some("[secs: number, nanos: number]".to_owned()),
+        })
+    }
+}
@@ -1,5 +1,5 @@
-use super::Serializable;
-use crate::types::{CargoDependency, CustomType, Type, TypeIdent};
+use super::{fp_bindgen_support_dependency, Serializable};
+use crate::types::{CargoDependency, CustomType, Type, TypeIdent, TypeMap};
 use std::collections::{BTreeMap, BTreeSet};
 
 impl Serializable for http::Method {
@@ -10,6 +10,7 @@ impl Serializable for http::Method {
     fn ty() -> Type {
         Type::Custom(CustomType {
             ident: Self::ident(),
+            binary: false,
             rs_ty: "http::Method".to_owned(),
             rs_dependencies: http_dependencies(),
             serde_attrs: vec![
@@ -43,6 +44,7 @@ impl Serializable for http::uri::Scheme {
     fn ty() -> Type {
         Type::Custom(CustomType {
             ident: Self::ident(),
+            binary: false,
             rs_ty: "http::Scheme".to_owned(),
             rs_dependencies: http_dependencies(),
             serde_attrs: vec![
@@ -64,6 +66,7 @@ impl Serializable for http::uri::Uri {
     fn ty() -> Type {
         Type::Custom(CustomType {
             ident: Self::ident(),
+            binary: false,
             rs_ty: "http::Uri".to_owned(),
             rs_dependencies: http_dependencies(),
             serde_attrs: vec![
@@ -76,18 +79,191 @@ impl Serializable for http::uri::Uri {
     }
 }
 
+impl Serializable for http::HeaderMap {
+    fn ident() -> TypeIdent {
+        TypeIdent::from("HeaderMap")
+    }
+
+    fn ty() -> Type {
+        Type::Custom(CustomType {
+            ident: Self::ident(),
+            binary: false,
+            rs_ty: "http::HeaderMap".to_owned(),
+            rs_dependencies: http_dependencies(),
+            serde_attrs: vec![
+                "serialize_with = \"fp_bindgen_support::http::serialize_header_map\"".to_owned(),
+                "deserialize_with = \"fp_bindgen_support::http::deserialize_header_map\""
+                    .to_owned(),
+            ],
+            ts_ty: "HeaderMap".to_owned(),
+            ts_declaration: Some(
+                "{ [name: string]: string | ArrayBuffer | Array<string | ArrayBuffer> }".to_owned(),
+            ),
+        })
+    }
+}
+
+impl Serializable for http::HeaderValue {
+    fn ident() -> TypeIdent {
+        TypeIdent::from("HeaderValue")
+    }
+
+    fn ty() -> Type {
+        Type::Custom(CustomType {
+            ident: Self::ident(),
+            binary: false,
+            rs_ty: "http::HeaderValue".to_owned(),
+            rs_dependencies: http_dependencies(),
+            serde_attrs: vec![
+                "serialize_with = \"fp_bindgen_support::http::serialize_header_value\"".to_owned(),
+                "deserialize_with = \"fp_bindgen_support::http::deserialize_header_value\""
+                    .to_owned(),
+            ],
+            // Values that are not visible ASCII are serialized as bytes:
+            ts_ty: "string | ArrayBuffer".to_owned(),
+            ts_declaration: None,
+        })
+    }
+}
+
+impl Serializable for http::StatusCode {
+    fn ident() -> TypeIdent {
+        TypeIdent::from("StatusCode")
+    }
+
+    fn ty() -> Type {
+        Type::Custom(CustomType {
+            ident: Self::ident(),
+            binary: false,
+            rs_ty: "http::StatusCode".to_owned(),
+            rs_dependencies: http_dependencies(),
+            serde_attrs: vec![
+                "serialize_with = \"fp_bindgen_support::http::serialize_status_code\"".to_owned(),
+                "deserialize_with = \"fp_bindgen_support::http::deserialize_status_code\""
+                    .to_owned(),
+            ],
+            ts_ty: "number".to_owned(),
+            ts_declaration: None,
+        })
+    }
+}
+
+impl Serializable for http::Version {
+    fn ident() -> TypeIdent {
+        TypeIdent::from("Version")
+    }
+
+    fn ty() -> Type {
+        Type::Custom(CustomType {
+            ident: Self::ident(),
+            binary: false,
+            rs_ty: "http::Version".to_owned(),
+            rs_dependencies: http_dependencies(),
+            serde_attrs: vec![
+                "serialize_with = \"fp_bindgen_support::http::serialize_http_version\"".to_owned(),
+                "deserialize_with = \"fp_bindgen_support::http::deserialize_http_version\""
+                    .to_owned(),
+            ],
+            ts_ty: "HttpVersion".to_owned(),
+            ts_declaration: Some(
+                r#""HTTP/0.9" | "HTTP/1.0" | "HTTP/1.1" | "HTTP/2.0" | "HTTP/3.0""#.to_owned(),
+            ),
+        })
+    }
+}
+
+// `Request` and `Response` don't implement `Serialize` and `Deserialize`, so
+// the bindings use newtypes from `fp-bindgen-support` that do. This way, they
+// can be used anywhere, not just inside other types.
+#[cfg(feature = "serde-bytes-compat")]
+impl Serializable for http::Request<serde_bytes::ByteBuf> {
+    fn ident() -> TypeIdent {
+        TypeIdent {
+            name: "Request".to_owned(),
+            generic_args: vec![serde_bytes::ByteBuf::ident()],
+        }
+    }
+
+    fn ty() -> Type {
+        Type::Custom(CustomType {
+            ident: Self::ident(),
+            binary: false,
+            rs_ty: "fp_bindgen_support::http::HttpRequest".to_owned(),
+            rs_dependencies: http_body_dependencies(),
+            serde_attrs: Vec::new(),
+            ts_ty: "HttpRequest".to_owned(),
+            ts_declaration: Some(
+                r#"{
+    method: Method;
+    uri: string;
+    version?: HttpVersion;
+    headers?: HeaderMap;
+    body?: ArrayBuffer;
+}"#
+                .to_owned(),
+            ),
+        })
+    }
+
+    fn collect_types(types: &mut TypeMap) {
+        if types.insert(Self::ident(), Self::ty()) {
+            http::Method::collect_types(types);
+            http::Version::collect_types(types);
+            http::HeaderMap::collect_types(types);
+        }
+    }
+}
+
+#[cfg(feature = "serde-bytes-compat")]
+impl Serializable for http::Response<serde_bytes::ByteBuf> {
+    fn ident() -> TypeIdent {
+        TypeIdent {
+            name: "Response".to_owned(),
+            generic_args: vec![serde_bytes::ByteBuf::ident()],
+        }
+    }
+
+    fn ty() -> Type {
+        Type::Custom(CustomType {
+            ident: Self::ident(),
+            binary: false,
+            rs_ty: "fp_bindgen_support::http::HttpResponse".to_owned(),
+            rs_dependencies: http_body_dependencies(),
+            serde_attrs: Vec::new(),
+            ts_ty: "HttpResponse".to_owned(),
+            ts_declaration: Some(
+                r#"{
+    status: number;
+    version?: HttpVersion;
+    headers?: HeaderMap;
+    body?: ArrayBuffer;
+}"#
+                .to_owned(),
+            ),
+        })
+    }
+
+    fn collect_types(types: &mut TypeMap) {
+        if types.insert(Self::ident(), Self::ty()) {
+            http::Version::collect_types(types);
+            http::HeaderMap::collect_types(types);
+        }
+    }
+}
+
 fn http_dependencies() -> BTreeMap<&'static str, CargoDependency> {
     BTreeMap::from([
         (
             "fp-bindgen-support",
-            CargoDependency {
-                git: Some("ssh://git@github.com/fiberplane/fp-bindgen.git"),
-                branch: Some("main"),
-                path: None,
-                version: None,
-                features: BTreeSet::from(["http"]),
-            },
+            fp_bindgen_support_dependency(BTreeSet::from(["http"])),
         ),
         ("http", CargoDependency::with_version("0.2")),
     ])
 }
+
+#[cfg(feature = "serde-bytes-compat")]
+fn http_body_dependencies() -> BTreeMap<&'static str, CargoDependency> {
+    let mut dependencies = http_dependencies();
+    dependencies.insert("serde_bytes", CargoDependency::with_version("0.11"));
+    dependencies
+}
@@ -0,0 +1,29 @@
+use super::Serializable;
+use crate::types::{Type, TypeIdent, TypeMap};
+
+impl<K, V> Serializable for indexmap::IndexMap<K, V>
+where
+    K: Serializable,
+    V: Serializable,
+{
+    fn ident() -> TypeIdent {
+        TypeIdent {
+            name: "IndexMap".to_owned(),
+            generic_args: vec![K::ident(), V::ident()],
+        }
+    }
+
+    fn ty() -> Type {
+        Type::Map(
+            "IndexMap".to_owned(),
+            TypeIdent::from("K"),
+            TypeIdent::from("V"),
+        )
+    }
+
+    fn collect_types(types: &mut TypeMap) {
+        types.insert(Self::ident(), Self::ty());
+        K::collect_types(types);
+        V::collect_types(types);
+    }
+}
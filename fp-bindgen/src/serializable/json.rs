@@ -0,0 +1,69 @@
+use super::Serializable;
+use crate::types::{CargoDependency, CustomType, Type, TypeIdent, TypeMap};
+use std::collections::{BTreeMap, BTreeSet};
+
+fn serde_json_dependency() -> BTreeMap<&'static str, CargoDependency> {
+    BTreeMap::from([(
+        "serde_json",
+        CargoDependency {
+            branch: None,
+            git: None,
+            path: None,
+            version: Some("1.0"),
+            features: BTreeSet::new(),
+        },
+    )])
+}
+
+/// JSON values are identified as `JsonValue`, so they don't clash with
+/// `rmpv::Value`. Protocols should refer to them by that name, for instance
+/// through `use serde_json::Value as JsonValue`.
+impl Serializable for serde_json::Value {
+    fn ident() -> TypeIdent {
+        TypeIdent::from("JsonValue")
+    }
+
+    fn ty() -> Type {
+        Type::Custom(CustomType {
+            ident: Self::ident(),
+            binary: false,
+            rs_ty: "serde_json::Value".to_owned(),
+            rs_dependencies: serde_json_dependency(),
+            serde_attrs: vec![],
+            ts_ty: "JsonValue".to_owned(),
+            ts_declaration: Some(
+                "null | boolean | number | string | JsonValue[] | { [key: string]: JsonValue }"
+                    .to_owned(),
+            ),
+        })
+    }
+}
+
+/// JSON objects are identified as `JsonMap`, so they don't clash with types
+/// that are named `Map` in the protocol.
+impl Serializable for serde_json::Map<String, serde_json::Value> {
+    fn ident() -> TypeIdent {
+        TypeIdent {
+            name: "JsonMap".to_owned(),
+            generic_args: vec![String::ident(), serde_json::Value::ident()],
+        }
+    }
+
+    fn ty() -> Type {
+        Type::Custom(CustomType {
+            ident: Self::ident(),
+            binary: false,
+            rs_ty: "serde_json::Map<String, serde_json::Value>".to_owned(),
+            rs_dependencies: serde_json_dependency(),
+            serde_attrs: vec![],
+            ts_ty: "JsonObject".to_owned(),
+            ts_declaration: Some("{ [key: string]: JsonValue }".to_owned()),
+        })
+    }
+
+    fn collect_types(types: &mut TypeMap) {
+        if types.insert(Self::ident(), Self::ty()) {
+            serde_json::Value::collect_types(types);
+        }
+    }
+}
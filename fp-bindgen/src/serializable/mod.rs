@@ -1,26 +1,60 @@
 use crate::{
-    types::{Enum, EnumOptions, TypeIdent, TypeMap, Variant, VariantAttrs},
+    types::{CustomType, Enum, EnumOptions, TypeIdent, TypeMap, Variant, VariantAttrs},
     Type,
 };
 use std::{
-    collections::{BTreeMap, BTreeSet, HashMap, HashSet},
+    borrow::Cow,
+    collections::{BTreeMap, BTreeSet, BinaryHeap, HashMap, HashSet, VecDeque},
     rc::Rc,
+    sync::Arc,
+    time::{Duration, SystemTime},
 };
 
+#[cfg(feature = "bytes-compat")]
+mod bytes;
+#[cfg(feature = "chrono-compat")]
+mod chrono;
 #[cfg(feature = "http-compat")]
 mod http;
+#[cfg(feature = "indexmap-compat")]
+mod indexmap;
+#[cfg(feature = "json-compat")]
+mod json;
 #[cfg(feature = "rmpv-compat")]
 mod rmpv;
 #[cfg(feature = "serde-bytes-compat")]
 mod serde_bytes;
 #[cfg(feature = "time-compat")]
 mod time;
+#[cfg(feature = "uuid-compat")]
+mod uuid;
+
+/// Returns the dependency on `fp-bindgen-support` with the given features,
+/// for custom types that rely on support for them that isn't published yet.
+#[cfg(any(feature = "bytes-compat", feature = "http-compat"))]
+fn fp_bindgen_support_dependency(
+    features: BTreeSet<&'static str>,
+) -> crate::types::CargoDependency {
+    crate::types::CargoDependency {
+        git: Some("ssh://git@github.com/fiberplane/fp-bindgen.git"),
+        branch: Some("main"),
+        path: None,
+        version: None,
+        features,
+    }
+}
 
 pub trait Serializable: 'static {
     /// The identifier of the type as defined in the protocol.
+    ///
+    /// For instantiations of generic types, this includes the identifiers of
+    /// the concrete generic arguments, such as `Point<f64>`.
     fn ident() -> TypeIdent;
 
     /// The type definition.
+    ///
+    /// For generic types, this is the generic definition, such as
+    /// `Point<T>`, which is shared between all instantiations.
     fn ty() -> Type;
 
     /// Whether this type is a primitive.
@@ -33,7 +67,67 @@ pub trait Serializable: 'static {
     /// The default implementation is only suitable for types without
     /// dependencies.
     fn collect_types(types: &mut TypeMap) {
-        types.entry(Self::ident()).or_insert_with(Self::ty);
+        types.insert(Self::ident(), Self::ty());
+    }
+}
+
+impl<T, const N: usize> Serializable for [T; N]
+where
+    T: Serializable,
+{
+    fn ident() -> TypeIdent {
+        TypeIdent::array(T::ident(), N)
+    }
+
+    fn ty() -> Type {
+        Type::Array(TypeIdent::from("T"), N)
+    }
+
+    fn collect_types(types: &mut TypeMap) {
+        types.insert(Self::ident(), Self::ty());
+        T::collect_types(types);
+    }
+}
+
+impl<T> Serializable for Arc<T>
+where
+    T: Serializable,
+{
+    fn ident() -> TypeIdent {
+        TypeIdent {
+            name: "Arc".to_owned(),
+            generic_args: vec![T::ident()],
+        }
+    }
+
+    fn ty() -> Type {
+        Type::Container("Arc".to_owned(), TypeIdent::from("T"))
+    }
+
+    fn collect_types(types: &mut TypeMap) {
+        types.insert(Self::ident(), Self::ty());
+        T::collect_types(types);
+    }
+}
+
+impl<T> Serializable for BinaryHeap<T>
+where
+    T: Serializable,
+{
+    fn ident() -> TypeIdent {
+        TypeIdent {
+            name: "BinaryHeap".to_owned(),
+            generic_args: vec![T::ident()],
+        }
+    }
+
+    fn ty() -> Type {
+        Type::List("BinaryHeap".to_owned(), TypeIdent::from("T"))
+    }
+
+    fn collect_types(types: &mut TypeMap) {
+        types.insert(Self::ident(), Self::ty());
+        T::collect_types(types);
     }
 }
 
@@ -44,7 +138,7 @@ where
     fn ident() -> TypeIdent {
         TypeIdent {
             name: "Box".to_owned(),
-            generic_args: vec![TypeIdent::from("T")],
+            generic_args: vec![T::ident()],
         }
     }
 
@@ -53,7 +147,7 @@ where
     }
 
     fn collect_types(types: &mut TypeMap) {
-        types.entry(Self::ident()).or_insert_with(Self::ty);
+        types.insert(Self::ident(), Self::ty());
         T::collect_types(types);
     }
 }
@@ -66,7 +160,7 @@ where
     fn ident() -> TypeIdent {
         TypeIdent {
             name: "BTreeMap".to_owned(),
-            generic_args: vec![TypeIdent::from("K"), TypeIdent::from("V")],
+            generic_args: vec![K::ident(), V::ident()],
         }
     }
 
@@ -79,7 +173,7 @@ where
     }
 
     fn collect_types(types: &mut TypeMap) {
-        types.entry(Self::ident()).or_insert_with(Self::ty);
+        types.insert(Self::ident(), Self::ty());
         K::collect_types(types);
         V::collect_types(types);
     }
@@ -92,7 +186,7 @@ where
     fn ident() -> TypeIdent {
         TypeIdent {
             name: "BTreeSet".to_owned(),
-            generic_args: vec![TypeIdent::from("T")],
+            generic_args: vec![T::ident()],
         }
     }
 
@@ -101,11 +195,53 @@ where
     }
 
     fn collect_types(types: &mut TypeMap) {
-        types.entry(Self::ident()).or_insert_with(Self::ty);
+        types.insert(Self::ident(), Self::ty());
         T::collect_types(types);
     }
 }
 
+/// Borrowed strings are always deserialized into owned ones, so only
+/// `Cow<'static, str>` is supported.
+impl Serializable for Cow<'static, str> {
+    fn ident() -> TypeIdent {
+        TypeIdent {
+            name: "Cow".to_owned(),
+            generic_args: vec![TypeIdent::from("str")],
+        }
+    }
+
+    fn ty() -> Type {
+        Type::Custom(CustomType {
+            ident: Self::ident(),
+            binary: false,
+            rs_ty: "std::borrow::Cow<'static, str>".to_owned(),
+            rs_dependencies: BTreeMap::new(),
+            serde_attrs: vec![],
+            ts_ty: "string".to_owned(),
+            ts_declaration: None,
+        })
+    }
+}
+
+/// Serde serializes durations as a struct with `secs` and `nanos` fields.
+impl Serializable for Duration {
+    fn ident() -> TypeIdent {
+        TypeIdent::from("Duration")
+    }
+
+    fn ty() -> Type {
+        Type::Custom(CustomType {
+            ident: Self::ident(),
+            binary: false,
+            rs_ty: "std::time::Duration".to_owned(),
+            rs_dependencies: BTreeMap::new(),
+            serde_attrs: vec![],
+            ts_ty: "Duration".to_owned(),
+            ts_declaration: Some("{ secs: number; nanos: number }".to_owned()),
+        })
+    }
+}
+
 impl<K, V> Serializable for HashMap<K, V>
 where
     K: Serializable,
@@ -114,7 +250,7 @@ where
     fn ident() -> TypeIdent {
         TypeIdent {
             name: "HashMap".to_owned(),
-            generic_args: vec![TypeIdent::from("K"), TypeIdent::from("V")],
+            generic_args: vec![K::ident(), V::ident()],
         }
     }
 
@@ -127,7 +263,7 @@ where
     }
 
     fn collect_types(types: &mut TypeMap) {
-        types.entry(Self::ident()).or_insert_with(Self::ty);
+        types.insert(Self::ident(), Self::ty());
         K::collect_types(types);
         V::collect_types(types);
     }
@@ -140,7 +276,7 @@ where
     fn ident() -> TypeIdent {
         TypeIdent {
             name: "HashSet".to_owned(),
-            generic_args: vec![TypeIdent::from("T")],
+            generic_args: vec![T::ident()],
         }
     }
 
@@ -149,7 +285,7 @@ where
     }
 
     fn collect_types(types: &mut TypeMap) {
-        types.entry(Self::ident()).or_insert_with(Self::ty);
+        types.insert(Self::ident(), Self::ty());
         T::collect_types(types);
     }
 }
@@ -161,7 +297,7 @@ where
     fn ident() -> TypeIdent {
         TypeIdent {
             name: "Option".to_owned(),
-            generic_args: vec![TypeIdent::from("T")],
+            generic_args: vec![T::ident()],
         }
     }
 
@@ -170,7 +306,7 @@ where
     }
 
     fn collect_types(types: &mut TypeMap) {
-        types.entry(Self::ident()).or_insert_with(Self::ty);
+        types.insert(Self::ident(), Self::ty());
         T::collect_types(types);
     }
 }
@@ -182,7 +318,7 @@ where
     fn ident() -> TypeIdent {
         TypeIdent {
             name: "Rc".to_owned(),
-            generic_args: vec![TypeIdent::from("T")],
+            generic_args: vec![T::ident()],
         }
     }
 
@@ -191,7 +327,7 @@ where
     }
 
     fn collect_types(types: &mut TypeMap) {
-        types.entry(Self::ident()).or_insert_with(Self::ty);
+        types.insert(Self::ident(), Self::ty());
         T::collect_types(types);
     }
 }
@@ -204,25 +340,30 @@ where
     fn ident() -> TypeIdent {
         TypeIdent {
             name: "Result".to_owned(),
-            generic_args: vec![TypeIdent::from("T"), TypeIdent::from("E")],
+            generic_args: vec![T::ident(), E::ident()],
         }
     }
 
     fn ty() -> Type {
         Type::Enum(Enum {
-            ident: Self::ident(),
+            ident: TypeIdent {
+                name: "Result".to_owned(),
+                generic_args: vec![TypeIdent::from("T"), TypeIdent::from("E")],
+            },
             variants: vec![
                 Variant {
                     name: "Ok".to_owned(),
                     ty: Type::Tuple(vec![TypeIdent::from("T")]),
                     doc_lines: vec![" Represents a succesful result.".to_owned()],
                     attrs: VariantAttrs::default(),
+                    discriminant: None,
                 },
                 Variant {
                     name: "Err".to_owned(),
                     ty: Type::Tuple(vec![TypeIdent::from("E")]),
                     doc_lines: vec![" Represents an error.".to_owned()],
                     attrs: VariantAttrs::default(),
+                    discriminant: None,
                 },
             ],
             doc_lines: vec![
@@ -234,7 +375,7 @@ where
     }
 
     fn collect_types(types: &mut TypeMap) {
-        types.entry(Self::ident()).or_insert_with(Self::ty);
+        types.insert(Self::ident(), Self::ty());
         T::collect_types(types);
         E::collect_types(types);
     }
@@ -250,6 +391,28 @@ impl Serializable for String {
     }
 }
 
+/// Serde serializes system times as a struct with the `secs_since_epoch` and
+/// `nanos_since_epoch` fields.
+impl Serializable for SystemTime {
+    fn ident() -> TypeIdent {
+        TypeIdent::from("SystemTime")
+    }
+
+    fn ty() -> Type {
+        Type::Custom(CustomType {
+            ident: Self::ident(),
+            binary: false,
+            rs_ty: "std::time::SystemTime".to_owned(),
+            rs_dependencies: BTreeMap::new(),
+            serde_attrs: vec![],
+            ts_ty: "SystemTime".to_owned(),
+            ts_declaration: Some(
+                "{ secs_since_epoch: number; nanos_since_epoch: number }".to_owned(),
+            ),
+        })
+    }
+}
+
 impl<T> Serializable for Vec<T>
 where
     T: Serializable,
@@ -257,7 +420,7 @@ where
     fn ident() -> TypeIdent {
         TypeIdent {
             name: "Vec".to_owned(),
-            generic_args: vec![TypeIdent::from("T")],
+            generic_args: vec![T::ident()],
         }
     }
 
@@ -266,7 +429,62 @@ where
     }
 
     fn collect_types(types: &mut TypeMap) {
-        types.entry(Self::ident()).or_insert_with(Self::ty);
+        types.insert(Self::ident(), Self::ty());
         T::collect_types(types);
     }
 }
+
+impl<T> Serializable for VecDeque<T>
+where
+    T: Serializable,
+{
+    fn ident() -> TypeIdent {
+        TypeIdent {
+            name: "VecDeque".to_owned(),
+            generic_args: vec![T::ident()],
+        }
+    }
+
+    fn ty() -> Type {
+        Type::List("VecDeque".to_owned(), TypeIdent::from("T"))
+    }
+
+    fn collect_types(types: &mut TypeMap) {
+        types.insert(Self::ident(), Self::ty());
+        T::collect_types(types);
+    }
+}
+
+// Generators should take the types of the items from the generic arguments of
+// the identifier, rather than from the `Type::Tuple` in the map, because the
+// latter only contains the generic parameters.
+macro_rules! tuple_impls {
+    ($($name:ident)+) => {
+        impl<$($name),+> Serializable for ($($name,)+)
+        where
+            $($name: Serializable),+
+        {
+            fn ident() -> TypeIdent {
+                TypeIdent::tuple(vec![$($name::ident()),+])
+            }
+
+            fn ty() -> Type {
+                Type::Tuple(vec![$(TypeIdent::from(stringify!($name))),+])
+            }
+
+            fn collect_types(types: &mut TypeMap) {
+                types.insert(Self::ident(), Self::ty());
+                $($name::collect_types(types);)+
+            }
+        }
+    };
+}
+
+tuple_impls! { A }
+tuple_impls! { A B }
+tuple_impls! { A B C }
+tuple_impls! { A B C D }
+tuple_impls! { A B C D E }
+tuple_impls! { A B C D E F }
+tuple_impls! { A B C D E F G }
+tuple_impls! { A B C D E F G H }
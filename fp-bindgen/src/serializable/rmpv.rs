@@ -10,6 +10,7 @@ impl Serializable for rmpv::Value {
     fn ty() -> Type {
         Type::Custom(CustomType {
             ident: Self::ident(),
+            binary: false,
             rs_ty: "rmpv::Value".to_owned(),
             rs_dependencies: BTreeMap::from([(
                 "rmpv",
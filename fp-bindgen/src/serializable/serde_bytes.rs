@@ -10,6 +10,7 @@ impl Serializable for serde_bytes::ByteBuf {
     fn ty() -> Type {
         Type::Custom(CustomType {
             ident: Self::ident(),
+            binary: true,
             rs_ty: "serde_bytes::ByteBuf".to_owned(),
             rs_dependencies: BTreeMap::from([(
                 "serde_bytes",
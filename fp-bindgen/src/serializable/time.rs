@@ -10,6 +10,7 @@ impl Serializable for time::OffsetDateTime {
     fn ty() -> Type {
         Type::Custom(CustomType {
             ident: Self::ident(),
+            binary: false,
             rs_ty: "time::OffsetDateTime".to_owned(),
             rs_dependencies: BTreeMap::from([(
                 "time",
@@ -36,6 +37,7 @@ impl Serializable for time::PrimitiveDateTime {
     fn ty() -> Type {
         Type::Custom(CustomType {
             ident: Self::ident(),
+            binary: false,
             rs_ty: "time::PrimitiveDateTime".to_owned(),
             rs_dependencies: BTreeMap::from([(
                 "time",
@@ -0,0 +1,30 @@
+use super::Serializable;
+use crate::types::{CargoDependency, CustomType, Type, TypeIdent};
+use std::collections::{BTreeMap, BTreeSet};
+
+impl Serializable for uuid::Uuid {
+    fn ident() -> TypeIdent {
+        TypeIdent::from("Uuid")
+    }
+
+    fn ty() -> Type {
+        Type::Custom(CustomType {
+            ident: Self::ident(),
+            binary: false,
+            rs_ty: "uuid::Uuid".to_owned(),
+            rs_dependencies: BTreeMap::from([(
+                "uuid",
+                CargoDependency {
+                    branch: None,
+                    git: None,
+                    path: None,
+                    version: Some("1.0"),
+                    features: BTreeSet::from(["serde"]),
+                },
+            )]),
+            serde_attrs: vec![],
+            ts_ty: "Uuid".to_owned(),
+            ts_declaration: Some(r#"string & { readonly __brand: "Uuid" }"#.to_owned()),
+        })
+    }
+}
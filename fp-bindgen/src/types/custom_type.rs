@@ -7,6 +7,14 @@ use std::{collections::BTreeMap, hash::Hash};
 pub struct CustomType {
     pub ident: TypeIdent,
 
+    /// Whether this is a binary type that is passed as a raw buffer, instead
+    /// of being serialized using MessagePack, when used directly as a function
+    /// argument or return value. The type must implement `BinaryValue` from
+    /// `fp-bindgen-support`.
+    ///
+    /// This is set for `bytes::Bytes` and `serde_bytes::ByteBuf`.
+    pub binary: bool,
+
     /// Qualified path to refer to the type in the Rust generators.
     pub rs_ty: String,
 
@@ -1,13 +1,22 @@
 use super::{
-    structs::{Field, Struct, StructOptions},
+    get_remote_name,
+    structs::{
+        parse_directional_values, parse_rename_all, specialize_fields, Field, Struct, StructKind,
+        StructOptions,
+    },
     Type, TypeIdent,
 };
-use crate::{casing::Casing, docs::get_doc_lines, primitives::Primitive, types::FieldAttrs};
+use crate::{
+    casing::{format_rename_all_attr, Casing},
+    docs::get_doc_lines,
+    primitives::Primitive,
+    types::FieldAttrs,
+};
 use quote::ToTokens;
-use std::{collections::BTreeMap, convert::TryFrom, str::FromStr};
+use std::{collections::BTreeMap, convert::TryFrom, ops::RangeInclusive, str::FromStr};
 use syn::{
-    ext::IdentExt, parenthesized, parse::Parse, parse::ParseStream, Attribute, Error, GenericParam,
-    Ident, ItemEnum, LitStr, Result, Token, TypePath,
+    ext::IdentExt, parenthesized, parse::Parse, parse::ParseStream, token, Attribute, Error, Expr,
+    GenericParam, Ident, ItemEnum, Lit, LitStr, Result, Token, TypePath, UnOp,
 };
 
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
@@ -18,9 +27,49 @@ pub struct Enum {
     pub options: EnumOptions,
 }
 
+impl Enum {
+    /// Returns the enum with its generic parameters substituted by the generic
+    /// arguments of the given instantiation.
+    pub fn specialize(&self, ident: &TypeIdent) -> Self {
+        let variants = self
+            .variants
+            .iter()
+            .map(|variant| Variant {
+                ty: match &variant.ty {
+                    Type::Struct(variant) => Type::Struct(Struct {
+                        fields: specialize_fields(&variant.fields, &self.ident, ident),
+                        ..variant.clone()
+                    }),
+                    Type::Tuple(items) => Type::Tuple(
+                        items
+                            .iter()
+                            .map(|item| item.specialize(&self.ident, ident))
+                            .collect(),
+                    ),
+                    other => other.clone(),
+                },
+                ..variant.clone()
+            })
+            .collect();
+
+        Self {
+            ident: ident.clone(),
+            variants,
+            ..self.clone()
+        }
+    }
+}
+
 pub(crate) fn parse_enum_item(item: ItemEnum) -> Enum {
+    let options = EnumOptions::from_attrs(&item.attrs);
+
+    // Enums that mirror a remote type take on the identity of that type:
+    let name = match &options.remote {
+        Some(remote) => get_remote_name(remote).to_owned(),
+        None => item.ident.to_string(),
+    };
     let ident = TypeIdent {
-        name: item.ident.to_string(),
+        name,
         generic_args: item
             .generics
             .params
@@ -31,16 +80,90 @@ pub(crate) fn parse_enum_item(item: ItemEnum) -> Enum {
             })
             .collect(),
     };
-    let options = EnumOptions::from_attrs(&item.attrs);
+
+    // Enums that are serialized as integers, or that specify any explicit
+    // discriminant, get a discriminant for every variant, following the same
+    // rules Rust uses for implicit discriminants.
+    let has_discriminants = options.repr.is_some()
+        || item
+            .variants
+            .iter()
+            .any(|variant| variant.discriminant.is_some());
+    if has_discriminants {
+        if let Some(variant) = item
+            .variants
+            .iter()
+            .find(|variant| !variant.fields.is_empty())
+        {
+            panic!(
+                "Enum {} cannot have discriminants, because the variant `{}` is not a unit \
+                    variant",
+                ident, variant.ident
+            );
+        }
+        if options.repr.is_some() && (options.tag_prop_name.is_some() || options.untagged) {
+            panic!(
+                "Enum {} is serialized as an integer, so it cannot be tagged or untagged",
+                ident
+            );
+        }
+    }
+
+    if let Some(unknown_variant) = &options.unknown_variant {
+        if options.repr.is_some() || options.untagged {
+            panic!(
+                "Enum {} cannot have an `unknown_variant`, because it is {}",
+                ident,
+                if options.untagged {
+                    "untagged"
+                } else {
+                    "serialized as an integer"
+                }
+            );
+        }
+        if let Some(variant) = item
+            .variants
+            .iter()
+            .find(|variant| variant.ident == unknown_variant)
+        {
+            panic!(
+                "Enum {} cannot have an `unknown_variant` named `{}`, because it already has a \
+                    variant with that name",
+                ident, variant.ident
+            );
+        }
+    }
+
+    let mut next_discriminant = 0;
     let variants = item
         .variants
         .iter()
         .map(|variant| {
-            if variant.discriminant.is_some() {
-                panic!(
-                    "Discriminants in enum variants are not supported. Found: {:?}",
-                    item
-                );
+            let discriminant = match &variant.discriminant {
+                Some((_, expr)) => Some(parse_discriminant(expr).unwrap_or_else(|| {
+                    panic!(
+                        "Unsupported discriminant for variant `{}` of enum {}: {}. Only integer \
+                            literals are supported",
+                        variant.ident,
+                        ident,
+                        expr.to_token_stream()
+                    )
+                })),
+                None if has_discriminants => Some(next_discriminant),
+                None => None,
+            };
+            if let Some(discriminant) = discriminant {
+                let repr = options.repr.unwrap_or(Primitive::Isize);
+                if !get_discriminant_range(repr).contains(&discriminant) {
+                    panic!(
+                        "Discriminant {} of variant `{}` of enum {} is out of range for `{}`",
+                        discriminant,
+                        variant.ident,
+                        ident,
+                        repr.name()
+                    );
+                }
+                next_discriminant = discriminant + 1;
             }
 
             // Variants with inline tags may result in unserializable types.
@@ -79,6 +202,7 @@ pub(crate) fn parse_enum_item(item: ItemEnum) -> Enum {
                     .collect();
                 Type::Struct(Struct {
                     ident: TypeIdent::from(name.clone()),
+                    kind: StructKind::Named,
                     fields,
                     doc_lines: Vec::new(),
                     options: StructOptions::default(),
@@ -115,13 +239,32 @@ pub(crate) fn parse_enum_item(item: ItemEnum) -> Enum {
                 Type::Tuple(item_types)
             };
             let doc_lines = get_doc_lines(&variant.attrs);
-            let attrs = VariantAttrs::from_attrs(&variant.attrs);
+            let mut attrs = VariantAttrs::from_attrs(&variant.attrs);
+            if attrs.field_casing == Casing::default() && attrs.field_casing_deserialize.is_none() {
+                attrs.field_casing = options.field_casing;
+                attrs.field_casing_deserialize = options.field_casing_deserialize;
+            }
+            if attrs.other && ty != Type::Unit {
+                panic!(
+                    "Enum {} cannot be serialized, because the variant `{}` has the `other` \
+                        attribute, but is not a unit variant",
+                    ident, variant.ident
+                );
+            }
+            if attrs.other && options.unknown_variant.is_some() {
+                panic!(
+                    "Enum {} cannot have both an `unknown_variant` and a variant with the `other` \
+                        attribute",
+                    ident
+                );
+            }
 
             Variant {
                 name,
                 ty,
                 doc_lines,
                 attrs,
+                discriminant,
             }
         })
         .collect();
@@ -136,7 +279,26 @@ pub(crate) fn parse_enum_item(item: ItemEnum) -> Enum {
 
 #[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct EnumOptions {
+    /// Casing to use for the names of variants, unless they are renamed
+    /// explicitly.
     pub variant_casing: Casing,
+
+    /// Casing to use for the names of variants when deserializing, if it
+    /// differs from the casing used when serializing.
+    ///
+    /// See also: https://serde.rs/container-attrs.html#rename_all
+    pub variant_casing_deserialize: Option<Casing>,
+
+    /// Casing to use for the names of fields of struct variants, unless the
+    /// variant has a `rename_all` of its own.
+    ///
+    /// See also: https://serde.rs/container-attrs.html#rename_all_fields
+    pub field_casing: Casing,
+
+    /// Casing to use for the names of fields of struct variants when
+    /// deserializing, if it differs from the casing used when serializing.
+    pub field_casing_deserialize: Option<Casing>,
+
     pub content_prop_name: Option<String>,
     pub tag_prop_name: Option<String>,
 
@@ -165,9 +327,52 @@ pub struct EnumOptions {
     ///
     /// Instead of generating the enum definition itself.
     pub native_modules: BTreeMap<String, String>,
+
+    /// If set, the enum is serialized as the integer discriminant of its
+    /// variants, using the given integer type, like `serde_repr` does.
+    ///
+    /// Only enums with exclusively unit variants can be serialized this way.
+    ///
+    /// ## Example:
+    ///
+    /// ```rs
+    /// #[fp(repr = "u8")]
+    /// enum Level { Debug = 10, Info = 20 }
+    /// ```
+    pub repr: Option<Primitive>,
+
+    /// If set, the generated bindings get an additional variant with the given
+    /// name, which captures any variant that is not known to this version of
+    /// the protocol. This allows hosts and plugins to be upgraded
+    /// independently of one another.
+    ///
+    /// The Rust bindings store the serialized value as an `rmpv::Value`, so
+    /// that it can be passed on unchanged.
+    ///
+    /// ## Example:
+    ///
+    /// ```rs
+    /// #[fp(unknown_variant = "Unknown")]
+    /// enum Shape { Circle { radius: f64 }, Square { size: f64 } }
+    /// ```
+    pub unknown_variant: Option<String>,
+
+    /// Path of the type from another crate that this enum mirrors, if any.
+    /// The enum then takes on the identity of the remote type, so that types
+    /// which cannot derive `Serializable` themselves can still be used in a
+    /// protocol.
+    ///
+    /// See also: https://serde.rs/remote-derive.html
+    pub remote: Option<String>,
 }
 
 impl EnumOptions {
+    /// Returns the casing to use for the names of variants when deserializing.
+    pub fn deserialize_variant_casing(&self) -> Casing {
+        self.variant_casing_deserialize
+            .unwrap_or(self.variant_casing)
+    }
+
     pub fn from_attrs(attrs: &[Attribute]) -> Self {
         let mut opts = Self::default();
         for attr in attrs {
@@ -181,8 +386,13 @@ impl EnumOptions {
     }
 
     fn merge_with(&mut self, other: &EnumOptions) {
-        if other.variant_casing != Casing::default() {
+        if other.variant_casing != Casing::default() || other.variant_casing_deserialize.is_some() {
             self.variant_casing = other.variant_casing;
+            self.variant_casing_deserialize = other.variant_casing_deserialize;
+        }
+        if other.field_casing != Casing::default() || other.field_casing_deserialize.is_some() {
+            self.field_casing = other.field_casing;
+            self.field_casing_deserialize = other.field_casing_deserialize;
         }
         if other.content_prop_name.is_some() {
             self.content_prop_name = other.content_prop_name.clone();
@@ -196,6 +406,15 @@ impl EnumOptions {
         for (key, value) in other.native_modules.iter() {
             self.native_modules.insert(key.clone(), value.clone());
         }
+        if other.repr.is_some() {
+            self.repr = other.repr;
+        }
+        if other.unknown_variant.is_some() {
+            self.unknown_variant = other.unknown_variant.clone();
+        }
+        if other.remote.is_some() {
+            self.remote = other.remote.clone();
+        }
     }
 
     pub fn to_serde_attrs(&self) -> Vec<String> {
@@ -209,8 +428,10 @@ impl EnumOptions {
                 serde_attrs.push(format!("content = \"{}\"", prop_name));
             }
         }
-        if let Some(casing) = &self.variant_casing.as_maybe_str() {
-            serde_attrs.push(format!("rename_all = \"{}\"", casing));
+        if let Some(rename_all) =
+            format_rename_all_attr(self.variant_casing, self.variant_casing_deserialize)
+        {
+            serde_attrs.push(rename_all);
         }
         serde_attrs
     }
@@ -238,10 +459,62 @@ impl Parse for EnumOptions {
                 "content" => result.content_prop_name = Some(parse_value()?),
                 "tag" => result.tag_prop_name = Some(parse_value()?),
                 "rename_all" => {
-                    result.variant_casing = Casing::try_from(parse_value()?.as_ref())
-                        .map_err(|err| Error::new(content.span(), err))?
+                    let (casing, deserialize_casing) = parse_rename_all(&content)?;
+                    result.variant_casing = casing;
+                    result.variant_casing_deserialize = deserialize_casing;
+                }
+                "rename_all_fields" => {
+                    let (casing, deserialize_casing) = parse_rename_all(&content)?;
+                    result.field_casing = casing;
+                    result.field_casing_deserialize = deserialize_casing;
                 }
                 "untagged" => result.untagged = true,
+                "unknown_variant" => result.unknown_variant = Some(parse_value()?),
+                "remote" => result.remote = Some(parse_value()?),
+                // Types that are converted from and into another type are
+                // handled by the derive macro, while the others have no
+                // effect on the serialized representation:
+                "bound" | "rename" => {
+                    if content.peek(token::Paren) {
+                        parse_directional_values(&content)?;
+                    } else {
+                        parse_value()?;
+                    }
+                }
+                "crate" | "expecting" | "from" | "into" | "try_from" => {
+                    parse_value()?;
+                }
+                "field_identifier" | "variant_identifier" => {
+                    return Err(Error::new(
+                        key.span(),
+                        format!(
+                            "The `{}` attribute is not supported, because it changes how the \
+                                enum is deserialized",
+                            key
+                        ),
+                    ))
+                }
+                "repr" => {
+                    let repr = parse_value()?;
+                    result.repr = match Primitive::from_str(&repr) {
+                        Ok(primitive)
+                            if primitive.is_integer()
+                                && !matches!(primitive, Primitive::I128 | Primitive::U128) =>
+                        {
+                            Some(primitive)
+                        }
+                        _ => {
+                            return Err(Error::new(
+                                content.span(),
+                                format!(
+                                    "Expected an integer type of at most 64 bits for `repr`, \
+                                        found: {}",
+                                    repr
+                                ),
+                            ))
+                        }
+                    }
+                }
                 module if module.ends_with("_module") => {
                     result
                         .native_modules
@@ -272,24 +545,103 @@ pub struct Variant {
     pub ty: Type,
     pub doc_lines: Vec<String>,
     pub attrs: VariantAttrs,
+
+    /// The discriminant of a unit variant, if the enum has any explicit
+    /// discriminants or is serialized as an integer.
+    pub discriminant: Option<i128>,
 }
 
 #[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct VariantAttrs {
+    /// Additional names that are accepted for the variant during
+    /// deserialization.
+    ///
+    /// See also: https://serde.rs/variant-attrs.html#alias
+    pub alias: Vec<String>,
+
+    /// Determines whether this (unit) variant is marked with `#[default]`, so
+    /// it can be used if the enum needs to implement `Default`.
+    pub default: bool,
+
+    /// Casing to use for the names of fields of a struct variant, unless they
+    /// are renamed explicitly.
     pub field_casing: Casing,
 
+    /// Casing to use for the names of fields of a struct variant when
+    /// deserializing, if it differs from the casing used when serializing.
+    pub field_casing_deserialize: Option<Casing>,
+
     /// Optional name to use in the serialized format
     /// (only used if different than the variant name itself).
     ///
     /// See also: https://serde.rs/variant-attrs.html#rename
     pub rename: Option<String>,
+
+    /// Optional name to use when deserializing, if it should differ from the
+    /// name used when serializing. Takes precedence over `rename`.
+    ///
+    /// See also: https://serde.rs/variant-attrs.html#rename
+    pub rename_deserialize: Option<String>,
+
+    /// Optional name to use when serializing, if it should differ from the
+    /// name used when deserializing. Takes precedence over `rename`.
+    ///
+    /// See also: https://serde.rs/variant-attrs.html#rename
+    pub rename_serialize: Option<String>,
+
+    /// Determines whether this (unit) variant is used when deserializing a
+    /// variant that is not known to the enum.
+    ///
+    /// See also: https://serde.rs/variant-attrs.html#other
+    pub other: bool,
+
+    /// Determines whether the variant is rejected during deserialization.
+    ///
+    /// See also: https://serde.rs/variant-attrs.html#skip_deserializing
+    pub skip_deserializing: bool,
+
+    /// Determines whether the variant fails to serialize.
+    ///
+    /// Variants that are skipped during both serialization and
+    /// deserialization (using `#[serde(skip)]`) are not part of the serialized
+    /// representation at all.
+    ///
+    /// See also: https://serde.rs/variant-attrs.html#skip_serializing
+    pub skip_serializing: bool,
 }
 
 impl VariantAttrs {
+    /// Returns the casing to use for the names of fields when deserializing.
+    pub fn deserialize_field_casing(&self) -> Casing {
+        self.field_casing_deserialize.unwrap_or(self.field_casing)
+    }
+
+    /// Returns whether the variant is skipped during both serialization and
+    /// deserialization, so it is not part of the serialized representation.
+    pub fn is_skipped(&self) -> bool {
+        self.skip_serializing && self.skip_deserializing
+    }
+
+    /// Returns the name the variant should get when deserializing, if it was
+    /// renamed.
+    pub fn deserialize_name(&self) -> Option<&str> {
+        self.rename_deserialize
+            .as_deref()
+            .or(self.rename.as_deref())
+    }
+
+    /// Returns the name the variant should get when serializing, if it was
+    /// renamed.
+    pub fn serialize_name(&self) -> Option<&str> {
+        self.rename_serialize.as_deref().or(self.rename.as_deref())
+    }
+
     pub fn from_attrs(attrs: &[Attribute]) -> Self {
         let mut opts = Self::default();
         for attr in attrs {
-            if attr.path.is_ident("fp") || attr.path.is_ident("serde") {
+            if attr.path.is_ident("default") {
+                opts.default = true;
+            } else if attr.path.is_ident("fp") || attr.path.is_ident("serde") {
                 opts.merge_with(
                     &syn::parse2::<Self>(attr.tokens.clone())
                         .expect("Could not parse variant attributes"),
@@ -300,21 +652,66 @@ impl VariantAttrs {
     }
 
     fn merge_with(&mut self, other: &Self) {
-        if other.field_casing != Casing::default() {
+        self.alias.extend(other.alias.iter().cloned());
+        if other.field_casing != Casing::default() || other.field_casing_deserialize.is_some() {
             self.field_casing = other.field_casing;
+            self.field_casing_deserialize = other.field_casing_deserialize;
         }
         if other.rename.is_some() {
             self.rename = other.rename.clone();
         }
+        if other.rename_deserialize.is_some() {
+            self.rename_deserialize = other.rename_deserialize.clone();
+        }
+        if other.rename_serialize.is_some() {
+            self.rename_serialize = other.rename_serialize.clone();
+        }
+        if other.other {
+            self.other = true;
+        }
+        if other.skip_deserializing {
+            self.skip_deserializing = true;
+        }
+        if other.skip_serializing {
+            self.skip_serializing = true;
+        }
     }
 
     pub fn to_serde_attrs(&self) -> Vec<String> {
         let mut serde_attrs = vec![];
-        if let Some(rename) = self.rename.as_ref() {
-            serde_attrs.push(format!("rename = \"{}\"", rename));
+        match (self.serialize_name(), self.deserialize_name()) {
+            (Some(serialize), Some(deserialize)) if serialize == deserialize => {
+                serde_attrs.push(format!("rename = \"{}\"", serialize));
+            }
+            (Some(serialize), Some(deserialize)) => serde_attrs.push(format!(
+                "rename(serialize = \"{}\", deserialize = \"{}\")",
+                serialize, deserialize
+            )),
+            (Some(serialize), None) => {
+                serde_attrs.push(format!("rename(serialize = \"{}\")", serialize));
+            }
+            (None, Some(deserialize)) => {
+                serde_attrs.push(format!("rename(deserialize = \"{}\")", deserialize));
+            }
+            (None, None) => {}
+        }
+        for alias in self.alias.iter() {
+            serde_attrs.push(format!("alias = \"{}\"", alias));
+        }
+        if let Some(rename_all) =
+            format_rename_all_attr(self.field_casing, self.field_casing_deserialize)
+        {
+            serde_attrs.push(rename_all);
+        }
+        if self.other {
+            serde_attrs.push("other".to_owned());
         }
-        if let Some(casing) = &self.field_casing.as_maybe_str() {
-            serde_attrs.push(format!("rename_all = \"{}\"", casing));
+        if self.is_skipped() {
+            serde_attrs.push("skip".to_owned());
+        } else if self.skip_serializing {
+            serde_attrs.push("skip_serializing".to_owned());
+        } else if self.skip_deserializing {
+            serde_attrs.push("skip_deserializing".to_owned());
         }
         serde_attrs
     }
@@ -339,10 +736,47 @@ impl Parse for VariantAttrs {
         loop {
             let key: Ident = content.call(IdentExt::parse_any)?;
             match key.to_string().as_ref() {
+                "alias" => result.alias.push(parse_value()?),
+                "other" => result.other = true,
+                "rename" if content.peek(token::Paren) => {
+                    let (serialize, deserialize) = parse_directional_values(&content)?;
+                    result.rename_serialize = serialize;
+                    result.rename_deserialize = deserialize;
+                }
                 "rename" => result.rename = Some(parse_value()?),
+                "skip" => {
+                    result.skip_deserializing = true;
+                    result.skip_serializing = true;
+                }
+                "skip_deserializing" => result.skip_deserializing = true,
+                "skip_serializing" => result.skip_serializing = true,
                 "rename_all" => {
-                    result.field_casing = Casing::try_from(parse_value()?.as_ref())
-                        .map_err(|err| Error::new(content.span(), err))?
+                    let (casing, deserialize_casing) = parse_rename_all(&content)?;
+                    result.field_casing = casing;
+                    result.field_casing_deserialize = deserialize_casing;
+                }
+                // These have no effect on the serialized representation:
+                "borrow" => {
+                    if content.peek(Token![=]) {
+                        parse_value()?;
+                    }
+                }
+                "bound" => {
+                    if content.peek(token::Paren) {
+                        parse_directional_values(&content)?;
+                    } else {
+                        parse_value()?;
+                    }
+                }
+                "deserialize_with" | "serialize_with" | "untagged" | "with" => {
+                    return Err(Error::new(
+                        key.span(),
+                        format!(
+                            "The `{}` attribute is not supported on enum variants, because it \
+                                changes how the variant is serialized",
+                            key
+                        ),
+                    ))
                 }
                 other => {
                     return Err(Error::new(
@@ -363,6 +797,39 @@ impl Parse for VariantAttrs {
     }
 }
 
+fn parse_discriminant(expr: &Expr) -> Option<i128> {
+    match expr {
+        Expr::Group(group) => parse_discriminant(&group.expr),
+        Expr::Lit(lit) => match &lit.lit {
+            Lit::Int(int) => int.base10_parse().ok(),
+            _ => None,
+        },
+        Expr::Paren(paren) => parse_discriminant(&paren.expr),
+        Expr::Unary(unary) if matches!(unary.op, UnOp::Neg(_)) => {
+            parse_discriminant(&unary.expr).map(|value| -value)
+        }
+        _ => None,
+    }
+}
+
+/// Returns the range of discriminants that fit in the given `repr`.
+///
+/// Enums without a `repr` use `isize`, which is limited to 32 bits because
+/// that is its size in plugins.
+fn get_discriminant_range(repr: Primitive) -> RangeInclusive<i128> {
+    match repr {
+        Primitive::I8 => i8::MIN as i128..=i8::MAX as i128,
+        Primitive::I16 => i16::MIN as i128..=i16::MAX as i128,
+        Primitive::I32 | Primitive::Isize => i32::MIN as i128..=i32::MAX as i128,
+        Primitive::I64 => i64::MIN as i128..=i64::MAX as i128,
+        Primitive::U8 => 0..=u8::MAX as i128,
+        Primitive::U16 => 0..=u16::MAX as i128,
+        Primitive::U32 | Primitive::Usize => 0..=u32::MAX as i128,
+        Primitive::U64 => 0..=u64::MAX as i128,
+        other => unreachable!("Unsupported `repr` for enum: {}", other.name()),
+    }
+}
+
 fn is_path_to_primitive(ty: &syn::Type) -> bool {
     matches!(
         ty,
@@ -375,3 +842,154 @@ fn is_path_to_primitive(ty: &syn::Type) -> bool {
                     .unwrap_or(false)
     )
 }
+
+#[cfg(test)]
+mod test {
+    use super::{EnumOptions, VariantAttrs};
+    use crate::{casing::Casing, types::Type};
+    use syn::{parse_quote, Attribute};
+
+    fn parse_variant_attrs(attr: Attribute) -> VariantAttrs {
+        VariantAttrs::from_attrs(&[attr])
+    }
+
+    #[test]
+    fn test_ignored_container_attrs() {
+        let attrs: Vec<Attribute> = vec![
+            parse_quote!(#[serde(rename = "shape")]),
+            parse_quote!(#[serde(rename(serialize = "ser_shape", deserialize = "de_shape"))]),
+            parse_quote!(#[serde(bound = "T: MyTrait")]),
+            parse_quote!(#[serde(crate = "my_serde")]),
+            parse_quote!(#[serde(from = "String", into = "String")]),
+            parse_quote!(#[serde(try_from = "String")]),
+            parse_quote!(#[serde(expecting = "a shape")]),
+        ];
+        for attr in attrs {
+            assert_eq!(EnumOptions::from_attrs(&[attr]), EnumOptions::default());
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Discriminant 300 of variant `A` of enum L is out of range for `u8`")]
+    fn test_discriminant_out_of_range() {
+        Type::from_item(r#"#[fp(repr = "u8")] pub enum L { A = 300 }"#);
+    }
+
+    #[test]
+    #[should_panic(expected = "Discriminant 256 of variant `B` of enum L is out of range for `u8`")]
+    fn test_implicit_discriminant_out_of_range() {
+        Type::from_item(r#"#[fp(repr = "u8")] pub enum L { A = 255, B }"#);
+    }
+
+    #[test]
+    fn test_u64_discriminant() {
+        let ty = Type::from_item(r#"#[fp(repr = "u64")] pub enum L { A = 18446744073709551615 }"#);
+        let variants = match ty {
+            Type::Enum(ty) => ty.variants,
+            _ => panic!("Expected an enum"),
+        };
+        assert_eq!(variants[0].discriminant, Some(u64::MAX as i128));
+    }
+
+    #[test]
+    #[should_panic(expected = "Discriminant -1 of variant `A` of enum L is out of range for `u64`")]
+    fn test_negative_unsigned_discriminant() {
+        Type::from_item(r#"#[fp(repr = "u64")] pub enum L { A = -1 }"#);
+    }
+
+    #[test]
+    #[should_panic(expected = "The `field_identifier` attribute is not supported")]
+    fn test_field_identifier() {
+        EnumOptions::from_attrs(&[parse_quote!(#[serde(field_identifier)])]);
+    }
+
+    #[test]
+    fn test_rename_all_fields() {
+        let ty = Type::from_item(
+            r#"#[serde(rename_all_fields = "camelCase")]
+            pub enum Shape {
+                Circle { radius_px: f64 },
+                #[serde(rename_all = "UPPERCASE")]
+                Square { size_px: f64 },
+            }"#,
+        );
+        let variants = match ty {
+            Type::Enum(ty) => ty.variants,
+            _ => panic!("Expected an enum"),
+        };
+        assert_eq!(variants[0].attrs.field_casing, Casing::CamelCase);
+        assert_eq!(variants[1].attrs.field_casing, Casing::Uppercase);
+    }
+
+    #[test]
+    fn test_ignored_variant_attrs() {
+        let attrs: Vec<Attribute> = vec![
+            parse_quote!(#[serde(bound = "T: MyTrait")]),
+            parse_quote!(#[serde(borrow)]),
+            parse_quote!(#[serde(borrow = "'a")]),
+        ];
+        for attr in attrs {
+            assert_eq!(parse_variant_attrs(attr), VariantAttrs::default());
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "The `untagged` attribute is not supported on enum variants")]
+    fn test_untagged_variant() {
+        parse_variant_attrs(parse_quote!(#[serde(untagged)]));
+    }
+
+    #[test]
+    fn test_alias() {
+        let attrs = VariantAttrs::from_attrs(&[
+            parse_quote!(#[serde(alias = "Circle")]),
+            parse_quote!(#[serde(alias = "Round")]),
+        ]);
+        assert_eq!(attrs.alias, vec!["Circle", "Round"]);
+        assert_eq!(
+            attrs.to_serde_attrs(),
+            vec!["alias = \"Circle\"", "alias = \"Round\""]
+        );
+    }
+
+    #[test]
+    fn test_directional_rename() {
+        let attrs = parse_variant_attrs(parse_quote!(
+            #[serde(rename(serialize = "ser_circle", deserialize = "de_circle"))]
+        ));
+        assert_eq!(attrs.serialize_name(), Some("ser_circle"));
+        assert_eq!(attrs.deserialize_name(), Some("de_circle"));
+        assert_eq!(
+            attrs.to_serde_attrs(),
+            vec!["rename(serialize = \"ser_circle\", deserialize = \"de_circle\")"]
+        );
+
+        let attrs = parse_variant_attrs(parse_quote!(#[serde(rename(deserialize = "circle"))]));
+        assert_eq!(attrs.serialize_name(), None);
+        assert_eq!(
+            attrs.to_serde_attrs(),
+            vec!["rename(deserialize = \"circle\")"]
+        );
+    }
+
+    #[test]
+    fn test_skip() {
+        let attrs = parse_variant_attrs(parse_quote!(#[serde(skip)]));
+        assert!(attrs.is_skipped());
+        assert_eq!(attrs.to_serde_attrs(), vec!["skip"]);
+    }
+
+    #[test]
+    fn test_skip_serializing() {
+        let attrs = parse_variant_attrs(parse_quote!(#[serde(skip_serializing)]));
+        assert!(attrs.skip_serializing && !attrs.is_skipped());
+        assert_eq!(attrs.to_serde_attrs(), vec!["skip_serializing"]);
+    }
+
+    #[test]
+    fn test_skip_deserializing() {
+        let attrs = parse_variant_attrs(parse_quote!(#[serde(skip_deserializing)]));
+        assert!(attrs.skip_deserializing && !attrs.is_skipped());
+        assert_eq!(attrs.to_serde_attrs(), vec!["skip_deserializing"]);
+    }
+}
@@ -1,6 +1,6 @@
 use crate::primitives::Primitive;
 use quote::{quote, ToTokens};
-use std::{collections::BTreeMap, hash::Hash};
+use std::hash::Hash;
 use syn::Item;
 
 mod cargo_dependency;
@@ -8,18 +8,25 @@ mod custom_type;
 mod enums;
 mod structs;
 mod type_ident;
+mod type_map;
 
 pub use cargo_dependency::CargoDependency;
 pub use custom_type::CustomType;
 pub use enums::{Enum, EnumOptions, Variant, VariantAttrs};
-pub use structs::{Field, FieldAttrs, Struct, StructOptions};
+pub use structs::{Field, FieldAttrs, Struct, StructKind, StructOptions};
 pub use type_ident::TypeIdent;
+pub use type_map::TypeMap;
 
-pub type TypeMap = BTreeMap<TypeIdent, Type>;
+/// Returns the name of the type referred to by the given path, such as the
+/// `remote` path of a struct or enum.
+pub(crate) fn get_remote_name(path: &str) -> &str {
+    path.rsplit("::").next().unwrap_or(path).trim()
+}
 
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub enum Type {
     Alias(String, TypeIdent),
+    Array(TypeIdent, usize),
     Container(String, TypeIdent),
     Custom(CustomType),
     Enum(Enum),
@@ -48,6 +55,7 @@ impl Type {
     pub fn name(&self) -> String {
         match self {
             Self::Alias(name, _) => name.clone(),
+            Self::Array(ident, len) => format!("[{}; {}]", ident, len),
             Self::Container(name, ident) => format!("{}<{}>", name, ident),
             Self::Custom(custom) => custom.ident.to_string(),
             Self::Enum(Enum { ident, .. }) => ident.to_string(),
@@ -76,6 +84,10 @@ impl ToTokens for Type {
                 let ty = syn::parse_str::<syn::Type>(name).unwrap();
                 quote! { #ty }
             }
+            Type::Array(ident, len) => {
+                let len = proc_macro2::Literal::usize_unsuffixed(*len);
+                quote! { [#ident; #len] }
+            }
             Type::Container(name, ident) | Type::List(name, ident) => {
                 let name = syn::parse_str::<syn::Type>(name).unwrap();
                 quote! { #name<#ident> }
@@ -1,23 +1,111 @@
-use super::TypeIdent;
-use crate::{casing::Casing, docs::get_doc_lines};
+use super::{get_remote_name, TypeIdent};
+use crate::{
+    casing::{format_rename_all_attr, Casing},
+    docs::get_doc_lines,
+};
 use quote::ToTokens;
 use std::{collections::BTreeMap, convert::TryFrom};
 use syn::{
-    ext::IdentExt, parenthesized, parse::Parse, parse::ParseStream, Attribute, Error, GenericParam,
-    Ident, ItemStruct, LitStr, Result, Token,
+    ext::IdentExt, parenthesized, parse::Parse, parse::ParseStream, token, Attribute, Error,
+    Fields, GenericParam, Ident, ItemStruct, LitStr, Result, Token,
 };
 
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub struct Struct {
     pub ident: TypeIdent,
+    pub kind: StructKind,
     pub fields: Vec<Field>,
     pub doc_lines: Vec<String>,
     pub options: StructOptions,
 }
 
+impl Struct {
+    /// Returns whether this is a newtype struct, which Serde serializes
+    /// transparently as its only field. Structs with the `transparent`
+    /// attribute are serialized the same way.
+    pub fn is_newtype(&self) -> bool {
+        self.transparent_field().is_some()
+    }
+
+    /// Returns the field as which the struct is serialized, if it is a newtype
+    /// or has the `transparent` attribute.
+    pub fn transparent_field(&self) -> Option<&Field> {
+        if self.kind == StructKind::Tuple && self.fields.len() == 1 {
+            self.fields.first()
+        } else if self.options.transparent {
+            let mut fields = self.fields.iter().filter(|field| !field.attrs.is_skipped());
+            match (fields.next(), fields.next()) {
+                (Some(field), None) => Some(field),
+                _ => None,
+            }
+        } else {
+            None
+        }
+    }
+
+    /// Returns the name of the property in which the struct's name is
+    /// serialized, together with the name, if the struct is tagged.
+    pub fn tag(&self) -> Option<(&str, &str)> {
+        self.options.tag.as_deref().map(|tag| {
+            (
+                tag,
+                self.options.serialize_name().unwrap_or(&self.ident.name),
+            )
+        })
+    }
+
+    /// Returns the struct with its generic parameters substituted by the
+    /// generic arguments of the given instantiation.
+    pub fn specialize(&self, ident: &TypeIdent) -> Self {
+        Self {
+            ident: ident.clone(),
+            fields: specialize_fields(&self.fields, &self.ident, ident),
+            ..self.clone()
+        }
+    }
+}
+
+/// Substitutes the generic parameters of the given definition in the types of
+/// the fields with the generic arguments of the given instantiation.
+pub(crate) fn specialize_fields(
+    fields: &[Field],
+    definition: &TypeIdent,
+    instantiation: &TypeIdent,
+) -> Vec<Field> {
+    fields
+        .iter()
+        .map(|field| Field {
+            ty: field.ty.specialize(definition, instantiation),
+            ..field.clone()
+        })
+        .collect()
+}
+
+/// The shape of a struct, which determines how it is serialized.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum StructKind {
+    /// Struct with named fields, serialized as a map.
+    #[default]
+    Named,
+    /// Tuple struct, such as `struct Meters(f64);`. Its fields are named
+    /// after their index. Newtypes (tuple structs with a single field) are
+    /// serialized as their field, while other tuple structs are serialized as
+    /// an array.
+    Tuple,
+    /// Unit struct, such as `struct Nothing;`, which is serialized as `null`.
+    Unit,
+}
+
 pub(crate) fn parse_struct_item(item: ItemStruct) -> Struct {
+    let options = StructOptions::from_attrs(&item.attrs);
+
+    // Structs that mirror a remote type take on the identity of that type:
+    let name = match &options.remote {
+        Some(remote) => get_remote_name(remote).to_owned(),
+        None => item.ident.to_string(),
+    };
     let ident = TypeIdent {
-        name: item.ident.to_string(),
+        name,
         generic_args: item
             .generics
             .params
@@ -28,15 +116,21 @@ pub(crate) fn parse_struct_item(item: ItemStruct) -> Struct {
             })
             .collect(),
     };
+    let kind = match &item.fields {
+        Fields::Named(_) => StructKind::Named,
+        Fields::Unnamed(_) => StructKind::Tuple,
+        Fields::Unit => StructKind::Unit,
+    };
     let fields = item
         .fields
         .iter()
-        .map(|field| Field {
+        .enumerate()
+        .map(|(index, field)| Field {
             name: field
                 .ident
                 .as_ref()
-                .unwrap_or_else(|| panic!("Unnamed field in struct {}", ident))
-                .to_string(),
+                .map(ToString::to_string)
+                .unwrap_or_else(|| index.to_string()),
             ty: TypeIdent::try_from(&field.ty)
                 .unwrap_or_else(|_| panic!("Invalid field type in struct {}", ident)),
             doc_lines: get_doc_lines(&field.attrs),
@@ -44,18 +138,71 @@ pub(crate) fn parse_struct_item(item: ItemStruct) -> Struct {
         })
         .collect();
 
-    Struct {
+    let ty = Struct {
         ident,
+        kind,
         fields,
         doc_lines: get_doc_lines(&item.attrs),
-        options: StructOptions::from_attrs(&item.attrs),
+        options,
+    };
+    if ty.options.transparent && !ty.is_newtype() {
+        panic!(
+            "Struct {} has the `transparent` attribute, so it must have exactly one field that \
+                is not skipped",
+            ty.ident
+        );
+    }
+    if ty.options.by_value {
+        validate_by_value_struct(&ty);
+    }
+    ty
+}
+
+/// Panics if the struct cannot be passed by value, because not all of its
+/// fields can be passed as a separate Wasm value.
+fn validate_by_value_struct(ty: &Struct) {
+    if ty.kind != StructKind::Named || !ty.ident.generic_args.is_empty() {
+        panic!(
+            "Struct {} has the `by_value` attribute, so it must be a non-generic struct with \
+                named fields",
+            ty.ident
+        );
+    }
+
+    for field in &ty.fields {
+        if field.ty.as_primitive().is_none() {
+            panic!(
+                "Struct {} has the `by_value` attribute, so its fields must be primitives that \
+                    fit in a Wasm value. Found field `{}` of type {}",
+                ty.ident, field.name, field.ty
+            );
+        }
+        if field.attrs.flatten
+            || field.attrs.is_optional()
+            || field.attrs.serialize_with.is_some()
+            || field.attrs.deserialize_with.is_some()
+        {
+            panic!(
+                "Struct {} has the `by_value` attribute, so its field `{}` cannot be flattened, \
+                    skipped, defaulted or use custom (de)serialization",
+                ty.ident, field.name
+            );
+        }
     }
 }
 
 #[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct StructOptions {
+    /// Casing to use for the names of fields, unless they are renamed
+    /// explicitly.
     pub field_casing: Casing,
 
+    /// Casing to use for the names of fields when deserializing, if it differs
+    /// from the casing used when serializing.
+    ///
+    /// See also: https://serde.rs/container-attrs.html#rename_all
+    pub field_casing_deserialize: Option<Casing>,
+
     /// Rust module paths where the type can be found for the given generator.
     /// If present, the generator can use this type instead of generating it.
     ///
@@ -76,9 +223,98 @@ pub struct StructOptions {
     ///
     /// Instead of generating the struct definition itself.
     pub native_modules: BTreeMap<String, String>,
+
+    /// Optional name to use in the serialized format, which is only visible
+    /// as the value of the `tag` property.
+    ///
+    /// See also: https://serde.rs/container-attrs.html#rename
+    pub rename: Option<String>,
+
+    /// Optional name to use when deserializing, if it should differ from the
+    /// name used when serializing. Takes precedence over `rename`.
+    ///
+    /// See also: https://serde.rs/container-attrs.html#rename
+    pub rename_deserialize: Option<String>,
+
+    /// Optional name to use when serializing, if it should differ from the
+    /// name used when deserializing. Takes precedence over `rename`.
+    ///
+    /// See also: https://serde.rs/container-attrs.html#rename
+    pub rename_serialize: Option<String>,
+
+    /// Name of the property in which the name of the struct is serialized,
+    /// alongside its fields.
+    ///
+    /// See also: https://serde.rs/container-attrs.html#tag
+    pub tag: Option<String>,
+
+    /// Optional path to a function that produces the default value of the
+    /// struct, used for any fields that are omitted from the serialized
+    /// representation.
+    ///
+    /// An empty string may be used as value, in which case `Default::default`
+    /// is assumed. See also: https://serde.rs/container-attrs.html#default
+    pub default: Option<String>,
+
+    /// Determines whether deserialization fails when encountering unknown
+    /// fields.
+    ///
+    /// See also: https://serde.rs/container-attrs.html#deny_unknown_fields
+    pub deny_unknown_fields: bool,
+
+    /// Determines whether the struct is serialized as its only field, just
+    /// like a newtype.
+    ///
+    /// See also: https://serde.rs/container-attrs.html#transparent
+    pub transparent: bool,
+
+    /// Path of the type from another crate that this struct mirrors, if any.
+    /// The struct then takes on the identity of the remote type, so that
+    /// types which cannot derive `Serializable` themselves can still be used
+    /// in a protocol.
+    ///
+    /// See also: https://serde.rs/remote-derive.html
+    pub remote: Option<String>,
+
+    /// Determines whether the struct is passed by value when used directly as
+    /// a function argument. Instead of being serialized using MessagePack,
+    /// every field is then passed as a separate Wasm value. This is only
+    /// allowed for structs with named fields that are all primitives that
+    /// fit in a Wasm value.
+    ///
+    /// Return values are always serialized, because Rust does not support
+    /// returning multiple values from Wasm functions.
+    ///
+    /// ## Example:
+    ///
+    /// ```rs
+    /// #[derive(Serializable)]
+    /// #[fp(by_value)]
+    /// struct Point { x: f64, y: f64 }
+    /// ```
+    pub by_value: bool,
 }
 
 impl StructOptions {
+    /// Returns the casing to use for the names of fields when deserializing.
+    pub fn deserialize_field_casing(&self) -> Casing {
+        self.field_casing_deserialize.unwrap_or(self.field_casing)
+    }
+
+    /// Returns the name the struct should get when deserializing, if it was
+    /// renamed.
+    pub fn deserialize_name(&self) -> Option<&str> {
+        self.rename_deserialize
+            .as_deref()
+            .or(self.rename.as_deref())
+    }
+
+    /// Returns the name the struct should get when serializing, if it was
+    /// renamed.
+    pub fn serialize_name(&self) -> Option<&str> {
+        self.rename_serialize.as_deref().or(self.rename.as_deref())
+    }
+
     pub fn from_attrs(attrs: &[Attribute]) -> Self {
         let mut opts = Self::default();
         for attr in attrs {
@@ -92,18 +328,80 @@ impl StructOptions {
     }
 
     fn merge_with(&mut self, other: &Self) {
-        if other.field_casing != Casing::default() {
+        if other.field_casing != Casing::default() || other.field_casing_deserialize.is_some() {
             self.field_casing = other.field_casing;
+            self.field_casing_deserialize = other.field_casing_deserialize;
         }
         for (key, value) in other.native_modules.iter() {
             self.native_modules.insert(key.clone(), value.clone());
         }
+        if other.rename.is_some() {
+            self.rename = other.rename.clone();
+        }
+        if other.rename_deserialize.is_some() {
+            self.rename_deserialize = other.rename_deserialize.clone();
+        }
+        if other.rename_serialize.is_some() {
+            self.rename_serialize = other.rename_serialize.clone();
+        }
+        if other.tag.is_some() {
+            self.tag = other.tag.clone();
+        }
+        if other.default.is_some() {
+            self.default = other.default.clone();
+        }
+        if other.deny_unknown_fields {
+            self.deny_unknown_fields = true;
+        }
+        if other.transparent {
+            self.transparent = true;
+        }
+        if other.remote.is_some() {
+            self.remote = other.remote.clone();
+        }
+        if other.by_value {
+            self.by_value = true;
+        }
     }
 
     pub fn to_serde_attrs(&self) -> Vec<String> {
         let mut serde_attrs = vec![];
-        if let Some(casing) = &self.field_casing.as_maybe_str() {
-            serde_attrs.push(format!("rename_all = \"{}\"", casing));
+        match (self.serialize_name(), self.deserialize_name()) {
+            (Some(serialize), Some(deserialize)) if serialize == deserialize => {
+                serde_attrs.push(format!("rename = \"{}\"", serialize));
+            }
+            (Some(serialize), Some(deserialize)) => serde_attrs.push(format!(
+                "rename(serialize = \"{}\", deserialize = \"{}\")",
+                serialize, deserialize
+            )),
+            (Some(serialize), None) => {
+                serde_attrs.push(format!("rename(serialize = \"{}\")", serialize));
+            }
+            (None, Some(deserialize)) => {
+                serde_attrs.push(format!("rename(deserialize = \"{}\")", deserialize));
+            }
+            (None, None) => {}
+        }
+        if let Some(tag) = self.tag.as_ref() {
+            serde_attrs.push(format!("tag = \"{}\"", tag));
+        }
+        if let Some(default) = self.default.as_ref() {
+            if default.is_empty() {
+                serde_attrs.push("default".to_owned());
+            } else {
+                serde_attrs.push(format!("default = \"{}\"", default));
+            }
+        }
+        if self.deny_unknown_fields {
+            serde_attrs.push("deny_unknown_fields".to_owned());
+        }
+        if let Some(rename_all) =
+            format_rename_all_attr(self.field_casing, self.field_casing_deserialize)
+        {
+            serde_attrs.push(rename_all);
+        }
+        if self.transparent {
+            serde_attrs.push("transparent".to_owned());
         }
         serde_attrs
     }
@@ -124,13 +422,47 @@ impl Parse for StructOptions {
                 .to_owned())
         };
 
+        let parse_optional_value = || -> Result<String> {
+            if content.peek(Token![=]) {
+                parse_value()
+            } else {
+                Ok(String::new())
+            }
+        };
+
         let mut result = Self::default();
         loop {
             let key: Ident = content.call(IdentExt::parse_any)?;
             match key.to_string().as_ref() {
+                "by_value" => result.by_value = true,
+                "default" => result.default = Some(parse_optional_value()?),
+                "deny_unknown_fields" => result.deny_unknown_fields = true,
+                "transparent" => result.transparent = true,
+                "remote" => result.remote = Some(parse_value()?),
+                "rename" if content.peek(token::Paren) => {
+                    let (serialize, deserialize) = parse_directional_values(&content)?;
+                    result.rename_serialize = serialize;
+                    result.rename_deserialize = deserialize;
+                }
+                "rename" => result.rename = Some(parse_value()?),
+                "tag" => result.tag = Some(parse_value()?),
+                // Types that are converted from and into another type are
+                // handled by the derive macro, while the others have no
+                // effect on the serialized representation:
+                "bound" => {
+                    if content.peek(token::Paren) {
+                        parse_directional_values(&content)?;
+                    } else {
+                        parse_value()?;
+                    }
+                }
+                "crate" | "expecting" | "from" | "into" | "try_from" => {
+                    parse_value()?;
+                }
                 "rename_all" => {
-                    result.field_casing = Casing::try_from(parse_value()?.as_ref())
-                        .map_err(|err| Error::new(content.span(), err))?
+                    let (casing, deserialize_casing) = parse_rename_all(&content)?;
+                    result.field_casing = casing;
+                    result.field_casing_deserialize = deserialize_casing;
                 }
                 module if module.ends_with("_module") => {
                     result
@@ -166,6 +498,12 @@ pub struct Field {
 
 #[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct FieldAttrs {
+    /// Additional names that are accepted for the field during
+    /// deserialization.
+    ///
+    /// See also: https://serde.rs/field-attrs.html#alias
+    pub alias: Vec<String>,
+
     /// Optional path to a function that will produce the default value in case
     /// the field is omitted from the serialized representation.
     ///
@@ -191,6 +529,18 @@ pub struct FieldAttrs {
     /// See also: https://serde.rs/field-attrs.html#rename
     pub rename: Option<String>,
 
+    /// Optional name to use when deserializing, if it should differ from the
+    /// name used when serializing. Takes precedence over `rename`.
+    ///
+    /// See also: https://serde.rs/field-attrs.html#rename
+    pub rename_deserialize: Option<String>,
+
+    /// Optional name to use when serializing, if it should differ from the
+    /// name used when deserializing. Takes precedence over `rename`.
+    ///
+    /// See also: https://serde.rs/field-attrs.html#rename
+    pub rename_serialize: Option<String>,
+
     /// Optional Serde dependency used for serialization.
     ///
     /// See also: https://serde.rs/field-attrs.html#serialize_with
@@ -204,6 +554,21 @@ pub struct FieldAttrs {
     ///
     /// See also: https://serde.rs/field-attrs.html#skip_serializing_if
     pub skip_serializing_if: Option<String>,
+
+    /// Determines whether the field is ignored during deserialization, in
+    /// which case its default value is used.
+    ///
+    /// See also: https://serde.rs/field-attrs.html#skip_deserializing
+    pub skip_deserializing: bool,
+
+    /// Determines whether the field is omitted during serialization.
+    ///
+    /// Fields that are skipped during both serialization and deserialization
+    /// (using `#[serde(skip)]`) are not part of the serialized representation
+    /// at all.
+    ///
+    /// See also: https://serde.rs/field-attrs.html#skip_serializing
+    pub skip_serializing: bool,
 }
 
 impl FieldAttrs {
@@ -220,7 +585,37 @@ impl FieldAttrs {
         opts
     }
 
+    /// Returns whether the field is skipped during both serialization and
+    /// deserialization, so it is not part of the serialized representation.
+    pub fn is_skipped(&self) -> bool {
+        self.skip_serializing && self.skip_deserializing
+    }
+
+    /// Returns whether the field may be omitted from the serialized
+    /// representation, in at least one direction.
+    pub fn is_optional(&self) -> bool {
+        self.default.is_some()
+            || self.skip_deserializing
+            || self.skip_serializing
+            || self.skip_serializing_if.is_some()
+    }
+
+    /// Returns the name the field should get when deserializing, if it was
+    /// renamed.
+    pub fn deserialize_name(&self) -> Option<&str> {
+        self.rename_deserialize
+            .as_deref()
+            .or(self.rename.as_deref())
+    }
+
+    /// Returns the name the field should get when serializing, if it was
+    /// renamed.
+    pub fn serialize_name(&self) -> Option<&str> {
+        self.rename_serialize.as_deref().or(self.rename.as_deref())
+    }
+
     fn merge_with(&mut self, other: &Self) {
+        self.alias.extend(other.alias.iter().cloned());
         if other.default.is_some() {
             self.default = other.default.clone();
         }
@@ -233,16 +628,31 @@ impl FieldAttrs {
         if other.rename.is_some() {
             self.rename = other.rename.clone();
         }
+        if other.rename_deserialize.is_some() {
+            self.rename_deserialize = other.rename_deserialize.clone();
+        }
+        if other.rename_serialize.is_some() {
+            self.rename_serialize = other.rename_serialize.clone();
+        }
         if other.serialize_with.is_some() {
             self.serialize_with = other.serialize_with.clone();
         }
         if other.skip_serializing_if.is_some() {
             self.skip_serializing_if = other.skip_serializing_if.clone();
         }
+        if other.skip_deserializing {
+            self.skip_deserializing = true;
+        }
+        if other.skip_serializing {
+            self.skip_serializing = true;
+        }
     }
 
     pub fn to_serde_attrs(&self) -> Vec<String> {
         let mut serde_attrs = vec![];
+        for alias in self.alias.iter() {
+            serde_attrs.push(format!("alias = \"{}\"", alias));
+        }
         if let Some(default) = self.default.as_ref() {
             if default.is_empty() {
                 serde_attrs.push("default".to_owned());
@@ -271,8 +681,28 @@ impl FieldAttrs {
         if self.flatten {
             serde_attrs.push("flatten".to_owned());
         }
-        if let Some(rename) = self.rename.as_ref() {
-            serde_attrs.push(format!("rename = \"{}\"", rename));
+        match (self.serialize_name(), self.deserialize_name()) {
+            (Some(serialize), Some(deserialize)) if serialize == deserialize => {
+                serde_attrs.push(format!("rename = \"{}\"", serialize));
+            }
+            (Some(serialize), Some(deserialize)) => serde_attrs.push(format!(
+                "rename(serialize = \"{}\", deserialize = \"{}\")",
+                serialize, deserialize
+            )),
+            (Some(serialize), None) => {
+                serde_attrs.push(format!("rename(serialize = \"{}\")", serialize));
+            }
+            (None, Some(deserialize)) => {
+                serde_attrs.push(format!("rename(deserialize = \"{}\")", deserialize));
+            }
+            (None, None) => {}
+        }
+        if self.is_skipped() {
+            serde_attrs.push("skip".to_owned());
+        } else if self.skip_deserializing {
+            serde_attrs.push("skip_deserializing".to_owned());
+        } else if self.skip_serializing {
+            serde_attrs.push("skip_serializing".to_owned());
         }
         if let Some(skip_serializing_if) = self.skip_serializing_if.as_ref() {
             serde_attrs.push(format!("skip_serializing_if = \"{}\"", skip_serializing_if));
@@ -308,17 +738,43 @@ impl Parse for FieldAttrs {
         loop {
             let key: Ident = content.call(IdentExt::parse_any)?;
             match key.to_string().as_ref() {
+                "alias" => result.alias.push(parse_value()?),
                 "default" => result.default = Some(parse_optional_value()?),
                 "deserialize_with" => result.deserialize_with = Some(parse_value()?),
                 "flatten" => result.flatten = true,
+                "rename" if content.peek(token::Paren) => {
+                    let (serialize, deserialize) = parse_directional_values(&content)?;
+                    result.rename_serialize = serialize;
+                    result.rename_deserialize = deserialize;
+                }
                 "rename" => result.rename = Some(parse_value()?),
                 "serialize_with" => result.serialize_with = Some(parse_value()?),
+                "skip" => {
+                    result.skip_deserializing = true;
+                    result.skip_serializing = true;
+                }
+                "skip_deserializing" => result.skip_deserializing = true,
+                "skip_serializing" => result.skip_serializing = true,
                 "skip_serializing_if" => result.skip_serializing_if = Some(parse_value()?),
                 "with" => {
                     let value = parse_value()?;
                     result.deserialize_with = Some(value.clone());
                     result.serialize_with = Some(value);
                 }
+                // These have no effect on the serialized representation:
+                "borrow" => {
+                    parse_optional_value()?;
+                }
+                "bound" => {
+                    if content.peek(token::Paren) {
+                        parse_directional_values(&content)?;
+                    } else {
+                        parse_value()?;
+                    }
+                }
+                "getter" => {
+                    parse_value()?;
+                }
                 other => {
                     return Err(Error::new(
                         content.span(),
@@ -337,3 +793,148 @@ impl Parse for FieldAttrs {
         Ok(result)
     }
 }
+
+/// Parses attribute values that can differ between serialization and
+/// deserialization, such as `rename(serialize = "a", deserialize = "b")`.
+///
+/// Returns the values for serialization and deserialization, respectively.
+pub(crate) fn parse_directional_values(
+    input: ParseStream,
+) -> Result<(Option<String>, Option<String>)> {
+    let content;
+    parenthesized!(content in input);
+
+    let mut serialize = None;
+    let mut deserialize = None;
+    loop {
+        let key: Ident = content.call(IdentExt::parse_any)?;
+        content.parse::<Token![=]>()?;
+        let value = content.parse::<LitStr>()?.value();
+        match key.to_string().as_ref() {
+            "serialize" => serialize = Some(value),
+            "deserialize" => deserialize = Some(value),
+            other => {
+                return Err(Error::new(
+                    content.span(),
+                    format!("Expected `serialize` or `deserialize`, found: {}", other),
+                ))
+            }
+        }
+
+        if content.is_empty() {
+            break;
+        }
+
+        content.parse::<Token![,]>()?;
+    }
+
+    Ok((serialize, deserialize))
+}
+
+/// Parses the value of a `rename_all` attribute, which may either be a single
+/// casing, or separate casings for serialization and deserialization.
+///
+/// Returns the casing for serialization, and the casing for deserialization if
+/// it differs.
+pub(crate) fn parse_rename_all(input: ParseStream) -> Result<(Casing, Option<Casing>)> {
+    let parse_casing = |value: Option<String>| match value {
+        Some(value) => {
+            Casing::try_from(value.as_ref()).map_err(|err| Error::new(input.span(), err))
+        }
+        None => Ok(Casing::Original),
+    };
+
+    if input.peek(token::Paren) {
+        let (serialize, deserialize) = parse_directional_values(input)?;
+        let serialize = parse_casing(serialize)?;
+        let deserialize = parse_casing(deserialize)?;
+        Ok((
+            serialize,
+            Some(deserialize).filter(|casing| *casing != serialize),
+        ))
+    } else {
+        input.parse::<Token![=]>()?;
+        let value = input.parse::<LitStr>()?.value();
+        Ok((parse_casing(Some(value))?, None))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{FieldAttrs, StructOptions};
+    use syn::{parse_quote, Attribute};
+
+    fn parse_options(attr: Attribute) -> StructOptions {
+        StructOptions::from_attrs(&[attr])
+    }
+
+    #[test]
+    fn test_rename() {
+        let opts = parse_options(parse_quote!(#[serde(rename = "point")]));
+        assert_eq!(opts.rename.as_deref(), Some("point"));
+        assert_eq!(opts.to_serde_attrs(), vec!["rename = \"point\""]);
+
+        let opts = parse_options(parse_quote!(
+            #[serde(rename(serialize = "ser_point", deserialize = "de_point"))]
+        ));
+        assert_eq!(opts.serialize_name(), Some("ser_point"));
+        assert_eq!(opts.deserialize_name(), Some("de_point"));
+        assert_eq!(
+            opts.to_serde_attrs(),
+            vec!["rename(serialize = \"ser_point\", deserialize = \"de_point\")"]
+        );
+    }
+
+    #[test]
+    fn test_tag() {
+        let opts = parse_options(parse_quote!(#[serde(tag = "type")]));
+        assert_eq!(opts.tag.as_deref(), Some("type"));
+        assert_eq!(opts.to_serde_attrs(), vec!["tag = \"type\""]);
+    }
+
+    #[test]
+    fn test_bound_is_ignored() {
+        let opts = parse_options(parse_quote!(#[serde(bound = "T: MyTrait")]));
+        assert_eq!(opts, StructOptions::default());
+
+        let opts = parse_options(parse_quote!(
+            #[serde(bound(serialize = "T: Serialize", deserialize = "T: Deserialize<'de>"))]
+        ));
+        assert_eq!(opts, StructOptions::default());
+    }
+
+    #[test]
+    fn test_conversions_are_ignored() {
+        let opts = parse_options(parse_quote!(#[serde(from = "String", into = "String")]));
+        assert_eq!(opts, StructOptions::default());
+
+        let opts = parse_options(parse_quote!(#[serde(try_from = "String")]));
+        assert_eq!(opts, StructOptions::default());
+    }
+
+    #[test]
+    fn test_crate_is_ignored() {
+        let opts = parse_options(parse_quote!(#[serde(crate = "my_serde")]));
+        assert_eq!(opts, StructOptions::default());
+    }
+
+    #[test]
+    fn test_expecting_is_ignored() {
+        let opts = parse_options(parse_quote!(#[serde(expecting = "a point")]));
+        assert_eq!(opts, StructOptions::default());
+    }
+
+    #[test]
+    fn test_ignored_field_attrs() {
+        let attrs: Vec<Attribute> = vec![
+            parse_quote!(#[serde(bound = "T: MyTrait")]),
+            parse_quote!(#[serde(bound(serialize = "T: Serialize"))]),
+            parse_quote!(#[serde(borrow)]),
+            parse_quote!(#[serde(borrow = "'a")]),
+            parse_quote!(#[serde(getter = "Point::x")]),
+        ];
+        for attr in attrs {
+            assert_eq!(FieldAttrs::from_attrs(&[attr]), FieldAttrs::default());
+        }
+    }
+}
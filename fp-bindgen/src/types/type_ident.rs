@@ -1,5 +1,6 @@
+use super::{Type, TypeMap};
 use crate::primitives::Primitive;
-use proc_macro2::TokenStream;
+use proc_macro2::{Literal, TokenStream};
 use quote::{quote, ToTokens};
 use std::{
     convert::{Infallible, TryFrom},
@@ -14,15 +15,110 @@ pub struct TypeIdent {
     pub generic_args: Vec<TypeIdent>,
 }
 
+/// Name used for identifiers of anonymous tuple types. The types of the tuple
+/// items are stored as the generic arguments of the identifier.
+const TUPLE_NAME: &str = "()";
+
 impl TypeIdent {
+    /// Creates an identifier for a fixed-size array of the given length. The
+    /// length is encoded in the name, so arrays of different lengths end up as
+    /// separate entries in the type map. The type of the items is stored as
+    /// the generic argument of the identifier.
+    pub fn array(item: TypeIdent, len: usize) -> Self {
+        Self {
+            name: format!("[{}]", len),
+            generic_args: vec![item],
+        }
+    }
+
+    /// Returns the length of the array if this is the identifier of a
+    /// fixed-size array.
+    pub fn array_len(&self) -> Option<usize> {
+        self.name
+            .strip_prefix('[')
+            .and_then(|name| name.strip_suffix(']'))
+            .and_then(|len| len.parse().ok())
+    }
+
+    /// Creates an identifier for an anonymous tuple with the given items.
+    pub fn tuple(items: Vec<TypeIdent>) -> Self {
+        Self {
+            name: TUPLE_NAME.to_owned(),
+            generic_args: items,
+        }
+    }
+
+    /// Returns the primitive if this is a primitive that can be passed
+    /// directly as a Wasm value.
+    ///
+    /// See also: [`Primitive::fits_wasm_value()`]
+    pub fn as_primitive(&self) -> Option<Primitive> {
+        Primitive::from_str(&self.name)
+            .ok()
+            .filter(Primitive::fits_wasm_value)
+    }
+
+    /// Returns whether this identifies a binary type that is passed as a raw
+    /// buffer when used directly as a function argument or return value,
+    /// rather than being serialized using MessagePack.
+    ///
+    /// The identifier is resolved using the given type map, so aliases of
+    /// binary types are still serialized. See also: [`CustomType::is_binary`]
+    pub fn is_binary(&self, types: &TypeMap) -> bool {
+        matches!(types.get(self), Some(Type::Custom(custom)) if custom.binary)
+    }
+
     pub fn is_primitive(&self) -> bool {
-        Primitive::from_str(&self.name).is_ok()
+        self.as_primitive().is_some()
+    }
+
+    pub fn is_tuple(&self) -> bool {
+        self.name == TUPLE_NAME
+    }
+
+    /// Substitutes the generic parameters of the given definition with the
+    /// generic arguments of the given instantiation.
+    ///
+    /// E.g. for a field of type `Vec<T>` inside the definition of `Point<T>`,
+    /// this returns `Vec<f64>` for the instantiation `Point<f64>`.
+    pub fn specialize(&self, definition: &TypeIdent, instantiation: &TypeIdent) -> Self {
+        if let Some(index) = definition
+            .generic_args
+            .iter()
+            .position(|param| param.name == self.name)
+        {
+            if let Some(arg) = instantiation.generic_args.get(index) {
+                return arg.clone();
+            }
+        }
+
+        Self {
+            name: self.name.clone(),
+            generic_args: self
+                .generic_args
+                .iter()
+                .map(|arg| arg.specialize(definition, instantiation))
+                .collect(),
+        }
     }
 }
 
 impl Display for TypeIdent {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        if self.generic_args.is_empty() {
+        if let Some(len) = self.array_len() {
+            f.write_fmt(format_args!("[{}; {}]", self.generic_args[0], len))
+        } else if self.is_tuple() {
+            let items = self
+                .generic_args
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>();
+            if items.len() == 1 {
+                f.write_fmt(format_args!("({},)", items[0]))
+            } else {
+                f.write_fmt(format_args!("({})", items.join(", ")))
+            }
+        } else if self.generic_args.is_empty() {
             f.write_str(&self.name)
         } else {
             f.write_fmt(format_args!(
@@ -48,16 +144,36 @@ impl FromStr for TypeIdent {
     type Err = Infallible;
 
     fn from_str(string: &str) -> Result<Self, Self::Err> {
-        if let Some(start_index) = string.find('<') {
+        let string = string.trim();
+        if let Some(array) = string
+            .strip_prefix('[')
+            .and_then(|string| string.strip_suffix(']'))
+        {
+            let (item, len) = array
+                .rsplit_once(';')
+                .unwrap_or_else(|| panic!("Invalid array type: {}", string));
+            let len = len
+                .trim()
+                .parse()
+                .unwrap_or_else(|_| panic!("Invalid array length in type: {}", string));
+            Ok(Self::array(Self::from_str(item)?, len))
+        } else if let Some(items) = string
+            .strip_prefix('(')
+            .and_then(|string| string.strip_suffix(')'))
+        {
+            Ok(Self::tuple(
+                split_top_level_args(items)
+                    .map(Self::from_str)
+                    .collect::<Result<Vec<Self>, Self::Err>>()?,
+            ))
+        } else if let Some(start_index) = string.find('<') {
             let end_index = string.rfind('>').unwrap_or(string.len());
             Ok(Self {
                 name: string[0..start_index]
                     .trim_end_matches(|c: char| c.is_whitespace() || c == ':')
                     .to_owned(),
-                generic_args: string[start_index + 1..end_index]
-                    .split(',')
-                    .into_iter()
-                    .map(|arg| Self::from_str(arg.trim()))
+                generic_args: split_top_level_args(&string[start_index + 1..end_index])
+                    .map(Self::from_str)
                     .collect::<Result<Vec<Self>, Self::Err>>()?,
             })
         } else {
@@ -66,6 +182,31 @@ impl FromStr for TypeIdent {
     }
 }
 
+/// Splits a comma-separated list of type arguments, without splitting the
+/// arguments that are nested inside them.
+fn split_top_level_args(args: &str) -> impl Iterator<Item = &str> {
+    let mut depth = 0;
+    let mut start_index = 0;
+    let mut parts = Vec::new();
+    for (index, c) in args.char_indices() {
+        match c {
+            '<' | '(' | '[' => depth += 1,
+            '>' | ')' | ']' => depth -= 1,
+            ',' if depth == 0 => {
+                parts.push(&args[start_index..index]);
+                start_index = index + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(&args[start_index..]);
+
+    parts
+        .into_iter()
+        .map(str::trim)
+        .filter(|part| !part.is_empty())
+}
+
 impl From<String> for TypeIdent {
     fn from(name: String) -> Self {
         Self {
@@ -77,24 +218,31 @@ impl From<String> for TypeIdent {
 
 impl Ord for TypeIdent {
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        // We only compare the name so that any type is only included once in
-        // a map, regardless of how many concrete instances are used with
-        // different generic arguments.
-        self.name.cmp(&other.name)
+        // We compare the name first, so that all instantiations of a generic
+        // type are adjacent to one another in a `TypeMap`.
+        self.name
+            .cmp(&other.name)
+            .then_with(|| self.generic_args.cmp(&other.generic_args))
     }
 }
 
 impl PartialOrd for TypeIdent {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        // We only compare the name so that any type is only included once in
-        // a map, regardless of how many concrete instances are used with
-        // different generic arguments.
-        self.name.partial_cmp(&other.name)
+        Some(self.cmp(other))
     }
 }
 
 impl ToTokens for TypeIdent {
     fn to_tokens(&self, tokens: &mut TokenStream) {
+        if let Some(len) = self.array_len() {
+            let item = &self.generic_args[0];
+            let len = Literal::usize_unsuffixed(len);
+            return quote! { [#item; #len] }.to_tokens(tokens);
+        } else if self.is_tuple() {
+            let items = &self.generic_args;
+            return quote! { (#(#items,)*) }.to_tokens(tokens);
+        }
+
         let name = syn::parse_str::<syn::Type>(&self.name).unwrap();
         if self.generic_args.is_empty() {
             quote! { #name }
@@ -112,12 +260,13 @@ impl TryFrom<&syn::Type> for TypeIdent {
     fn try_from(ty: &syn::Type) -> Result<Self, Self::Error> {
         match ty {
             syn::Type::Path(TypePath { path, qself }) if qself.is_none() => Ok(Self {
-                name: path
-                    .segments
-                    .iter()
-                    .map(|segment| segment.ident.to_string())
-                    .collect::<Vec<_>>()
-                    .join("::"),
+                name: resolve_json_path(
+                    path.segments
+                        .iter()
+                        .map(|segment| segment.ident.to_string())
+                        .collect::<Vec<_>>()
+                        .join("::"),
+                ),
                 generic_args: path
                     .segments
                     .last()
@@ -135,7 +284,142 @@ impl TryFrom<&syn::Type> for TypeIdent {
                     })
                     .unwrap_or_default(),
             }),
+            syn::Type::Array(array) => match &array.len {
+                syn::Expr::Lit(syn::ExprLit {
+                    lit: syn::Lit::Int(len),
+                    ..
+                }) => Ok(Self::array(
+                    TypeIdent::try_from(array.elem.as_ref())?,
+                    len.base10_parse().map_err(|err| err.to_string())?,
+                )),
+                len => Err(format!(
+                    "Array length must be an integer literal, found: {}",
+                    len.to_token_stream()
+                )),
+            },
+            syn::Type::Tuple(tuple) if !tuple.elems.is_empty() => Ok(Self::tuple(
+                tuple
+                    .elems
+                    .iter()
+                    .map(TypeIdent::try_from)
+                    .collect::<Result<Vec<_>, _>>()?,
+            )),
             ty => Err(format!("Unsupported type: {:?}", ty)),
         }
     }
 }
+
+/// `serde_json::Value` is identified as `JsonValue`, so it doesn't clash with
+/// `rmpv::Value`, and `serde_json::Map` as `JsonMap`. Types that refer to them
+/// by their full path are resolved to these identifiers.
+fn resolve_json_path(name: String) -> String {
+    match name.as_str() {
+        "serde_json::Value" => "JsonValue".to_owned(),
+        "serde_json::Map" => "JsonMap".to_owned(),
+        _ => name,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::TypeIdent;
+    use crate::types::{CustomType, Type, TypeMap};
+    use quote::ToTokens;
+    use std::{collections::BTreeMap, convert::TryFrom, str::FromStr};
+
+    #[test]
+    fn test_tuple_from_str() {
+        let ident = TypeIdent::from_str("(u32 , BTreeMap < String , (i64 , i64) >)").unwrap();
+
+        pretty_assertions::assert_eq!(ident.to_string(), "(u32, BTreeMap<String, (i64, i64)>)");
+        pretty_assertions::assert_eq!(
+            ident,
+            TypeIdent::tuple(vec![
+                TypeIdent::from("u32"),
+                TypeIdent {
+                    name: "BTreeMap".to_owned(),
+                    generic_args: vec![
+                        TypeIdent::from("String"),
+                        TypeIdent::tuple(vec![TypeIdent::from("i64"), TypeIdent::from("i64")])
+                    ]
+                }
+            ])
+        );
+    }
+
+    #[test]
+    fn test_array_from_str() {
+        let ident = TypeIdent::from_str("[ (u8, [f64; 3]) ; 32 ]").unwrap();
+
+        pretty_assertions::assert_eq!(ident.to_string(), "[(u8, [f64; 3]); 32]");
+        pretty_assertions::assert_eq!(ident.array_len(), Some(32));
+        pretty_assertions::assert_eq!(
+            ident.generic_args[0].generic_args[1],
+            TypeIdent::array(TypeIdent::from("f64"), 3)
+        );
+    }
+
+    #[test]
+    fn test_array_from_syn_type() {
+        let ty = syn::parse_str::<syn::Type>("[u8; 32]").unwrap();
+        let ident = TypeIdent::try_from(&ty).unwrap();
+
+        pretty_assertions::assert_eq!(ident, TypeIdent::array(TypeIdent::from("u8"), 32));
+        pretty_assertions::assert_eq!(ident.into_token_stream().to_string(), "[u8 ; 32]");
+    }
+
+    #[test]
+    fn test_json_value_from_syn_type() {
+        let ty = syn::parse_str::<syn::Type>("Option<serde_json::Map<String, serde_json::Value>>")
+            .unwrap();
+        let ident = TypeIdent::try_from(&ty).unwrap();
+
+        pretty_assertions::assert_eq!(ident.to_string(), "Option<JsonMap<String, JsonValue>>");
+    }
+
+    #[test]
+    fn test_tuple_from_syn_type() {
+        let ty = syn::parse_str::<syn::Type>("(String,)").unwrap();
+        let ident = TypeIdent::try_from(&ty).unwrap();
+
+        pretty_assertions::assert_eq!(ident.to_string(), "(String,)");
+        pretty_assertions::assert_eq!(ident.into_token_stream().to_string(), "(String ,)");
+    }
+
+    #[test]
+    fn test_is_binary() {
+        let custom_type = |name: &str, rs_ty: &str, binary: bool| {
+            Type::Custom(CustomType {
+                ident: TypeIdent::from(name),
+                binary,
+                rs_ty: rs_ty.to_owned(),
+                rs_dependencies: BTreeMap::new(),
+                serde_attrs: vec![],
+                ts_ty: "ArrayBuffer".to_owned(),
+                ts_declaration: None,
+            })
+        };
+
+        let mut types = TypeMap::new();
+        // Binary types are recognized by their marker, however they are
+        // spelled:
+        types.insert(
+            TypeIdent::from("Bytes"),
+            custom_type("Bytes", "::bytes::Bytes", true),
+        );
+        types.insert(
+            TypeIdent::from("ByteBuf"),
+            custom_type("ByteBuf", "serde_bytes::ByteBuf", false),
+        );
+        types.insert(
+            TypeIdent::from("Body"),
+            Type::Alias("Body".to_owned(), TypeIdent::from("Bytes")),
+        );
+
+        assert!(TypeIdent::from("Bytes").is_binary(&types));
+        assert!(!TypeIdent::from("Body").is_binary(&types));
+        assert!(!TypeIdent::from("ByteBuf").is_binary(&types));
+        assert!(!TypeIdent::from("Buffer").is_binary(&types));
+        assert!(!TypeIdent::from_str("Vec<u8>").unwrap().is_binary(&types));
+    }
+}
@@ -0,0 +1,353 @@
+use super::{get_remote_name, Type, TypeIdent};
+use std::collections::{btree_map, BTreeMap};
+
+/// Map with the definitions of all the types used in a protocol.
+///
+/// Every instantiation of a generic type gets its own entry, keyed by its full
+/// identifier. So if a protocol uses both `Point<f64>` and `Point<u64>`, there
+/// will be an entry for each, but both entries share the same definition of
+/// `Point<T>`.
+///
+/// Types that derive `Serializable` are inserted together with the module in
+/// which they are defined. Types are not namespaced by their module, but the
+/// module is used to report conflicting definitions of types with the same
+/// name.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct TypeMap {
+    types: BTreeMap<TypeIdent, Type>,
+    module_paths: BTreeMap<String, String>,
+}
+
+impl TypeMap {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Merges all the types from `other` into this map, leaving `other` empty.
+    ///
+    /// Panics if both maps contain conflicting definitions for the same type.
+    pub fn append(&mut self, other: &mut Self) {
+        let module_paths = std::mem::take(&mut other.module_paths);
+        for (ident, ty) in std::mem::take(&mut other.types) {
+            let module_path = module_paths.get(&ident.name).map(String::as_str);
+            self.insert_with_module_path(ident, ty, module_path);
+        }
+    }
+
+    /// Returns whether the map has an entry for exactly the given identifier.
+    pub fn contains_key(&self, ident: &TypeIdent) -> bool {
+        self.types.contains_key(ident)
+    }
+
+    /// Returns the definitions of all the types in the map, without returning
+    /// the definition of a generic type once for every instantiation.
+    ///
+    /// This is what generators for languages with support for generics should
+    /// use to emit their type definitions.
+    pub fn definitions(&self) -> impl Iterator<Item = &Type> {
+        let mut previous: Option<&Type> = None;
+        self.types.values().filter(move |ty| {
+            // Instantiations of the same type are adjacent in the map, because
+            // identifiers are ordered by name first:
+            let is_duplicate = previous == Some(*ty);
+            previous = Some(*ty);
+            !is_duplicate
+        })
+    }
+
+    /// Returns the definition of the type with the given identifier.
+    ///
+    /// Identifiers that are used inside generic definitions, such as the
+    /// `Vec<T>` in the definition of `Point<T>`, don't have an entry of their
+    /// own. For those, the definition of another instantiation of the same
+    /// type is returned.
+    pub fn get(&self, ident: &TypeIdent) -> Option<&Type> {
+        self.types.get(ident).or_else(|| {
+            self.types
+                .range(TypeIdent::from(ident.name.as_str())..)
+                .take_while(|(key, _)| key.name == ident.name)
+                .find(|(key, _)| key.generic_args.len() == ident.generic_args.len())
+                .map(|(_, ty)| ty)
+        })
+    }
+
+    /// Inserts the type with the given identifier, unless an entry for it
+    /// exists already.
+    ///
+    /// Returns `true` if the type was inserted, in which case the caller
+    /// should also insert the types it depends on.
+    ///
+    /// Panics if the map already contains a different definition for the same
+    /// type, or for another instantiation of the same generic type. This
+    /// happens, for instance, when two distinct types have the same name.
+    pub fn insert(&mut self, ident: TypeIdent, ty: Type) -> bool {
+        self.insert_with_module_path(ident, ty, None)
+    }
+
+    /// Inserts the type with the given identifier, like [`Self::insert()`],
+    /// and records the path of the Rust module in which it is defined.
+    ///
+    /// Also panics if a type with the same name was inserted from a different
+    /// module, even if both definitions are identical.
+    pub fn insert_from_module(&mut self, ident: TypeIdent, ty: Type, module_path: &str) -> bool {
+        self.insert_with_module_path(ident, ty, Some(module_path))
+    }
+
+    fn insert_with_module_path(
+        &mut self,
+        ident: TypeIdent,
+        ty: Type,
+        module_path: Option<&str>,
+    ) -> bool {
+        // Custom types don't have a generic definition, so instantiations
+        // such as `DateTime<Utc>` and `DateTime<FixedOffset>` are distinct:
+        let existing = match &ty {
+            Type::Custom(_) => self.types.get(&ident),
+            _ => self.get(&ident),
+        };
+        let is_inserted = existing.is_some() && self.contains_key(&ident);
+        if let Some(existing) = existing {
+            let existing_module_path = self.module_paths.get(&ident.name).map(String::as_str);
+            let is_from_other_module = matches!(
+                (existing_module_path, module_path),
+                (Some(existing_path), Some(path)) if existing_path != path
+            );
+            if existing != &ty || is_from_other_module {
+                panic!(
+                    "Conflicting definitions for type `{}`. Please make sure every type in the \
+                        protocol has a unique name.\nFirst definition: {}\nConflicting \
+                        definition: {}",
+                    ident,
+                    format_definition(existing, existing_module_path),
+                    format_definition(&ty, module_path)
+                );
+            }
+        }
+
+        if let Some(module_path) = module_path {
+            self.module_paths
+                .entry(ident.name.clone())
+                .or_insert_with(|| module_path.to_owned());
+        }
+        if is_inserted {
+            return false;
+        }
+
+        self.types.insert(ident, ty);
+        true
+    }
+
+    pub fn into_values(self) -> impl Iterator<Item = Type> {
+        self.types.into_values()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.types.is_empty()
+    }
+
+    pub fn iter(&self) -> btree_map::Iter<'_, TypeIdent, Type> {
+        self.types.iter()
+    }
+
+    pub fn keys(&self) -> btree_map::Keys<'_, TypeIdent, Type> {
+        self.types.keys()
+    }
+
+    /// Returns the identifier under which the given type is stored in the map.
+    ///
+    /// Function declarations may refer to types using a module path, such as
+    /// `http::Request<ByteBuf>`, while the map identifies types by their name.
+    /// Identifiers that cannot be resolved are returned as is.
+    pub fn resolve(&self, ident: &TypeIdent) -> TypeIdent {
+        let ident = TypeIdent {
+            name: ident.name.clone(),
+            generic_args: ident
+                .generic_args
+                .iter()
+                .map(|arg| self.resolve(arg))
+                .collect(),
+        };
+        if self.get(&ident).is_some() {
+            return ident;
+        }
+
+        let resolved = TypeIdent {
+            name: get_remote_name(&ident.name).to_owned(),
+            generic_args: ident.generic_args.clone(),
+        };
+        if self.get(&resolved).is_some() {
+            resolved
+        } else {
+            ident
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.types.len()
+    }
+
+    pub fn values(&self) -> btree_map::Values<'_, TypeIdent, Type> {
+        self.types.values()
+    }
+}
+
+/// Formats a definition for use in error messages, preferably using the module
+/// in which it is defined.
+fn format_definition(ty: &Type, module_path: Option<&str>) -> String {
+    match module_path {
+        Some(module_path) => format!("`{}` in module `{}`", ty.name(), module_path),
+        None => format!("{:?}", ty),
+    }
+}
+
+impl IntoIterator for TypeMap {
+    type Item = (TypeIdent, Type);
+    type IntoIter = btree_map::IntoIter<TypeIdent, Type>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.types.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a TypeMap {
+    type Item = (&'a TypeIdent, &'a Type);
+    type IntoIter = btree_map::Iter<'a, TypeIdent, Type>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.types.iter()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::TypeMap;
+    use crate::types::{CustomType, Type, TypeIdent};
+    use std::{collections::BTreeMap, str::FromStr};
+
+    #[test]
+    fn test_instantiations_share_definition() {
+        let definition = Type::from_item("pub struct Point<T> { pub value: T }");
+
+        let mut types = TypeMap::new();
+        assert!(types.insert(
+            TypeIdent::from_str("Point<f64>").unwrap(),
+            definition.clone()
+        ));
+        assert!(types.insert(
+            TypeIdent::from_str("Point<u64>").unwrap(),
+            definition.clone()
+        ));
+        assert!(!types.insert(
+            TypeIdent::from_str("Point<u64>").unwrap(),
+            definition.clone()
+        ));
+
+        assert_eq!(types.len(), 2);
+        assert_eq!(types.definitions().count(), 1);
+        assert_eq!(
+            types.get(&TypeIdent::from_str("Point<T>").unwrap()),
+            Some(&definition)
+        );
+        assert_eq!(types.get(&TypeIdent::from("Point")), None);
+    }
+
+    #[test]
+    fn test_resolve_module_path() {
+        let mut types = TypeMap::new();
+        types.insert(
+            TypeIdent::from_str("Request<ByteBuf>").unwrap(),
+            Type::from_item("pub struct Request<T> { pub body: T }"),
+        );
+        types.insert(
+            TypeIdent::from_str("Option<Request<ByteBuf>>").unwrap(),
+            Type::Container("Option".to_owned(), TypeIdent::from("T")),
+        );
+
+        assert_eq!(
+            types.resolve(&TypeIdent::from_str("Option<http::Request<ByteBuf>>").unwrap()),
+            TypeIdent::from_str("Option<Request<ByteBuf>>").unwrap()
+        );
+        assert_eq!(
+            types.resolve(&TypeIdent::from("http::Method")),
+            TypeIdent::from("http::Method")
+        );
+    }
+
+    #[test]
+    fn test_custom_instantiations() {
+        let custom_type = |ident: &str, rs_ty: &str| {
+            let ident = TypeIdent::from_str(ident).unwrap();
+            let ty = Type::Custom(CustomType {
+                ident: ident.clone(),
+                binary: false,
+                rs_ty: rs_ty.to_owned(),
+                rs_dependencies: BTreeMap::new(),
+                serde_attrs: vec![],
+                ts_ty: "string".to_owned(),
+                ts_declaration: None,
+            });
+            (ident, ty)
+        };
+
+        let mut types = TypeMap::new();
+        let (ident, ty) = custom_type("DateTime<Utc>", "chrono::DateTime<chrono::Utc>");
+        assert!(types.insert(ident, ty));
+        let (ident, ty) = custom_type(
+            "DateTime<FixedOffset>",
+            "chrono::DateTime<chrono::FixedOffset>",
+        );
+        assert!(types.insert(ident, ty));
+
+        assert_eq!(types.len(), 2);
+        assert_eq!(types.definitions().count(), 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "Conflicting definitions for type `Point`")]
+    fn test_conflicting_definitions() {
+        let mut types = TypeMap::new();
+        types.insert(
+            TypeIdent::from("Point"),
+            Type::from_item("pub struct Point { pub x: f64 }"),
+        );
+        types.insert(
+            TypeIdent::from("Point"),
+            Type::from_item("pub struct Point { pub x: f64, pub y: f64 }"),
+        );
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "`Config` in module `protocol::client`\nConflicting definition: \
+        `Config` in module `protocol::server`"
+    )]
+    fn test_conflicting_modules() {
+        let mut types = TypeMap::new();
+        types.insert_from_module(
+            TypeIdent::from("Config"),
+            Type::from_item("pub struct Config { pub timeout: u32 }"),
+            "protocol::client",
+        );
+        types.insert_from_module(
+            TypeIdent::from("Config"),
+            Type::from_item("pub struct Config { pub timeout: u32 }"),
+            "protocol::server",
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "json-compat", feature = "rmpv-compat"))]
+    fn test_json_and_rmpv_values() {
+        use crate::prelude::Serializable;
+
+        let mut types = TypeMap::new();
+        <rmpv::Value as Serializable>::collect_types(&mut types);
+        <serde_json::Map<String, serde_json::Value> as Serializable>::collect_types(&mut types);
+
+        let names = types.keys().map(ToString::to_string).collect::<Vec<_>>();
+        pretty_assertions::assert_eq!(
+            names,
+            vec!["JsonMap<String, JsonValue>", "JsonValue", "Value"]
+        );
+    }
+}
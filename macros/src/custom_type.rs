@@ -0,0 +1,205 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Attribute, Lit, Meta, NestedMeta};
+
+/// Custom type mapping, as declared through `#[fp(custom(...))]`.
+///
+/// ## Example:
+///
+/// ```rs
+/// #[derive(Serializable)]
+/// #[fp(custom(
+///     rs_ty = "time::Date",
+///     ts_ty = "string",
+///     dependency(name = "time", version = "0.3", features("serde-human-readable")),
+/// ))]
+/// pub struct CalendarDate;
+/// ```
+#[derive(Default)]
+pub(crate) struct CustomTypeAttrs {
+    rs_ty: Option<String>,
+    rs_dependencies: Vec<Dependency>,
+    serde_attrs: Vec<String>,
+    ts_ty: Option<String>,
+    ts_declaration: Option<String>,
+}
+
+#[derive(Default)]
+struct Dependency {
+    name: Option<String>,
+    git: Option<String>,
+    branch: Option<String>,
+    path: Option<String>,
+    version: Option<String>,
+    features: Vec<String>,
+}
+
+impl CustomTypeAttrs {
+    /// Parses the `custom` mapping from the given attributes, if there is one.
+    pub(crate) fn from_attrs(attrs: &[Attribute]) -> Option<Self> {
+        let mut result = None;
+        for attr in attrs.iter().filter(|attr| attr.path.is_ident("fp")) {
+            let nested = match attr.parse_meta() {
+                Ok(Meta::List(list)) => list.nested,
+                _ => continue,
+            };
+            let has_custom = nested.iter().any(|meta| {
+                matches!(meta, NestedMeta::Meta(Meta::List(list)) if list.path.is_ident("custom"))
+            });
+            if !has_custom {
+                continue;
+            }
+            if nested.len() > 1 {
+                panic!("The `custom` attribute cannot be combined with other attributes");
+            }
+
+            if let Some(NestedMeta::Meta(Meta::List(list))) = nested.first() {
+                let mut attrs = Self::default();
+                for meta in list.nested.iter() {
+                    attrs.parse_meta(meta);
+                }
+                result = Some(attrs);
+            }
+        }
+
+        if let Some(attrs) = &result {
+            if attrs.rs_ty.is_none() || attrs.ts_ty.is_none() {
+                panic!("Custom types require both an `rs_ty` and a `ts_ty` attribute");
+            }
+        }
+        result
+    }
+
+    fn parse_meta(&mut self, meta: &NestedMeta) {
+        match meta {
+            NestedMeta::Meta(Meta::NameValue(name_value)) => {
+                let value = Some(parse_string(&name_value.lit));
+                match get_key(&name_value.path).as_str() {
+                    "rs_ty" => self.rs_ty = value,
+                    "ts_ty" => self.ts_ty = value,
+                    "ts_declaration" => self.ts_declaration = value,
+                    other => panic!("Unexpected custom type attribute: {}", other),
+                }
+            }
+            NestedMeta::Meta(Meta::List(list)) => match get_key(&list.path).as_str() {
+                "dependency" => {
+                    let mut dependency = Dependency::default();
+                    for meta in list.nested.iter() {
+                        dependency.parse_meta(meta);
+                    }
+                    if dependency.name.is_none() {
+                        panic!("Dependencies of custom types require a `name`");
+                    }
+                    self.rs_dependencies.push(dependency);
+                }
+                "serde_attrs" => self.serde_attrs.extend(parse_string_list(&list.nested)),
+                other => panic!("Unexpected custom type attribute: {}", other),
+            },
+            other => panic!("Unexpected custom type attribute: {:?}", other),
+        }
+    }
+
+    /// Returns the expression that constructs the `Type::Custom`.
+    pub(crate) fn to_type_expr(&self) -> TokenStream {
+        let rs_ty = self.rs_ty.as_ref().unwrap();
+        let ts_ty = self.ts_ty.as_ref().unwrap();
+        let ts_declaration = to_option_expr(self.ts_declaration.as_deref(), |value| {
+            quote! { #value.to_owned() }
+        });
+        let serde_attrs = self.serde_attrs.iter();
+        let dependencies = self.rs_dependencies.iter().map(Dependency::to_tuple_expr);
+
+        quote! {
+            fp_bindgen::prelude::Type::Custom(fp_bindgen::prelude::CustomType {
+                ident: Self::ident(),
+                binary: false,
+                rs_ty: #rs_ty.to_owned(),
+                rs_dependencies: std::collections::BTreeMap::from([#( #dependencies ),*]),
+                serde_attrs: vec![#( #serde_attrs.to_owned() ),*],
+                ts_ty: #ts_ty.to_owned(),
+                ts_declaration: #ts_declaration,
+            })
+        }
+    }
+}
+
+impl Dependency {
+    fn parse_meta(&mut self, meta: &NestedMeta) {
+        match meta {
+            NestedMeta::Meta(Meta::NameValue(name_value)) => {
+                let value = Some(parse_string(&name_value.lit));
+                match get_key(&name_value.path).as_str() {
+                    "name" => self.name = value,
+                    "git" => self.git = value,
+                    "branch" => self.branch = value,
+                    "path" => self.path = value,
+                    "version" => self.version = value,
+                    other => panic!("Unexpected dependency attribute: {}", other),
+                }
+            }
+            NestedMeta::Meta(Meta::List(list)) if list.path.is_ident("features") => {
+                self.features.extend(parse_string_list(&list.nested));
+            }
+            other => panic!("Unexpected dependency attribute: {:?}", other),
+        }
+    }
+
+    fn to_tuple_expr(&self) -> TokenStream {
+        let name = self.name.as_ref().unwrap();
+        let quote_str = |value: &str| quote! { #value };
+        let git = to_option_expr(self.git.as_deref(), quote_str);
+        let branch = to_option_expr(self.branch.as_deref(), quote_str);
+        let path = to_option_expr(self.path.as_deref(), quote_str);
+        let version = to_option_expr(self.version.as_deref(), quote_str);
+        let features = self.features.iter();
+
+        quote! {
+            (
+                #name,
+                fp_bindgen::types::CargoDependency {
+                    git: #git,
+                    branch: #branch,
+                    path: #path,
+                    version: #version,
+                    features: std::collections::BTreeSet::from([#( #features ),*]),
+                },
+            )
+        }
+    }
+}
+
+fn get_key(path: &syn::Path) -> String {
+    path.get_ident()
+        .map(ToString::to_string)
+        .unwrap_or_else(|| panic!("Unexpected custom type attribute: {:?}", path))
+}
+
+fn parse_string(lit: &Lit) -> String {
+    match lit {
+        Lit::Str(value) => value.value(),
+        other => panic!("Expected a string literal, found: {:?}", other),
+    }
+}
+
+fn parse_string_list<'a>(nested: impl IntoIterator<Item = &'a NestedMeta>) -> Vec<String> {
+    nested
+        .into_iter()
+        .map(|meta| match meta {
+            NestedMeta::Lit(lit) => parse_string(lit),
+            other => panic!("Expected a string literal, found: {:?}", other),
+        })
+        .collect()
+}
+
+fn to_option_expr<F>(value: Option<&str>, to_expr: F) -> TokenStream
+where
+    F: Fn(&str) -> TokenStream,
+{
+    match value {
+        Some(value) => {
+            let expr = to_expr(value);
+            quote! { Some(#expr) }
+        }
+        None => quote! { None },
+    }
+}
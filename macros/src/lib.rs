@@ -1,4 +1,7 @@
-use crate::{primitives::Primitive, utils::extract_path_from_type};
+use crate::{
+    primitives::Primitive,
+    utils::{extract_arg_type, extract_value_type},
+};
 use proc_macro::{TokenStream, TokenTree};
 use proc_macro_error::{abort, proc_macro_error, ResultExt};
 use quote::{format_ident, quote, ToTokens};
@@ -7,11 +10,12 @@ use std::{
     iter::once,
 };
 use syn::{
-    AttributeArgs, FnArg, ForeignItemFn, GenericParam, ItemFn, ItemType, ItemUse, Pat, PatPath,
-    Path, PathArguments, PathSegment, ReturnType,
+    AttributeArgs, FnArg, ForeignItemFn, GenericParam, ItemFn, ItemType, ItemUse, ReturnType, Type,
+    TypePath,
 };
 use utils::flatten_using_statement;
 
+mod custom_type;
 mod primitives;
 mod serializable;
 mod typing;
@@ -25,6 +29,7 @@ pub fn derive_serializable(item: TokenStream) -> TokenStream {
 
 /// Declares functions the plugin can import from the host runtime.
 #[proc_macro]
+#[proc_macro_error]
 pub fn fp_import(token_stream: TokenStream) -> TokenStream {
     let ParsedStatements {
         functions,
@@ -40,7 +45,7 @@ pub fn fp_import(token_stream: TokenStream) -> TokenStream {
     let replacement = quote! {
         fn __fp_declare_import_fns() -> (fp_bindgen::prelude::FunctionList, fp_bindgen::prelude::TypeMap) {
             let mut import_types = fp_bindgen::prelude::TypeMap::new();
-            #( #type_paths::collect_types(&mut import_types); )*
+            #( <#type_paths as fp_bindgen::prelude::Serializable>::collect_types(&mut import_types); )*
             #( import_types.insert(TypeIdent::from(#alias_keys), Type::Alias(#alias_keys.to_owned(), std::str::FromStr::from_str(#alias_paths).unwrap())); )*
 
             let mut list = fp_bindgen::prelude::FunctionList::new();
@@ -54,6 +59,7 @@ pub fn fp_import(token_stream: TokenStream) -> TokenStream {
 
 /// Declares functions the plugin may export to the host runtime.
 #[proc_macro]
+#[proc_macro_error]
 pub fn fp_export(token_stream: TokenStream) -> TokenStream {
     let ParsedStatements {
         functions,
@@ -69,7 +75,7 @@ pub fn fp_export(token_stream: TokenStream) -> TokenStream {
     let replacement = quote! {
         fn __fp_declare_export_fns() -> (fp_bindgen::prelude::FunctionList, fp_bindgen::prelude::TypeMap) {
             let mut export_types = fp_bindgen::prelude::TypeMap::new();
-            #( #type_paths::collect_types(&mut export_types); )*
+            #( <#type_paths as fp_bindgen::prelude::Serializable>::collect_types(&mut export_types); )*
             #( export_types.insert(TypeIdent::from(#alias_keys), Type::Alias(#alias_keys.to_owned(), std::str::FromStr::from_str(#alias_paths).unwrap())); )*
 
             let mut list = fp_bindgen::prelude::FunctionList::new();
@@ -85,8 +91,8 @@ pub fn fp_export(token_stream: TokenStream) -> TokenStream {
 /// macros.
 struct ParsedStatements {
     pub functions: Vec<String>,
-    pub type_paths: HashSet<Path>,
-    pub aliases: HashMap<String, Path>,
+    pub type_paths: HashSet<Type>,
+    pub aliases: HashMap<String, Type>,
 }
 
 /// Parses statements like function declarations and 'use Foobar;' and returns them in a list.
@@ -116,9 +122,9 @@ fn parse_statements(token_stream: TokenStream) -> ParsedStatements {
                             ),
                             FnArg::Typed(arg) => {
                                 type_paths.insert(
-                                    extract_path_from_type(arg.ty.as_ref()).unwrap_or_else(|| {
+                                    extract_arg_type(arg.ty.as_ref()).unwrap_or_else(|| {
                                         panic!(
-                                            "Only value types are supported. \
+                                            "Only value types and shared references are supported. \
                                                 Incompatible argument type in function declaration: {:?}",
                                             function.sig
                                         )
@@ -131,7 +137,7 @@ fn parse_statements(token_stream: TokenStream) -> ParsedStatements {
                     match &function.sig.output {
                         ReturnType::Default => { /* No return value. */ }
                         ReturnType::Type(_, ty) => {
-                            type_paths.insert(extract_path_from_type(ty.as_ref()).unwrap_or_else(
+                            type_paths.insert(extract_value_type(ty.as_ref()).unwrap_or_else(
                                 || {
                                     panic!(
                                         "Only value types are supported. \
@@ -146,12 +152,12 @@ fn parse_statements(token_stream: TokenStream) -> ParsedStatements {
                     functions.push(function.into_token_stream().to_string());
                 } else if let Ok(using) = syn::parse::<ItemUse>(stream.clone()) {
                     for path in flatten_using_statement(using) {
-                        type_paths.insert(path);
+                        type_paths.insert(Type::Path(TypePath { qself: None, path }));
                     }
                 } else if let Ok(type_alias) = syn::parse::<ItemType>(stream) {
                     aliases.insert(
                         type_alias.ident.to_string(),
-                        extract_path_from_type(type_alias.ty.as_ref()).unwrap_or_else(|| {
+                        extract_value_type(type_alias.ty.as_ref()).unwrap_or_else(|| {
                             panic!(
                                 "Only value types are supported. \
                                     Incompatible type in alias: {:?}",
@@ -200,21 +206,26 @@ pub fn fp_bindgen(args: TokenStream) -> TokenStream {
 pub fn primitive_impls(_: TokenStream) -> TokenStream {
     let primitives = [
         Primitive::Bool,
+        Primitive::Char,
         Primitive::F32,
         Primitive::F64,
         Primitive::I8,
         Primitive::I16,
         Primitive::I32,
         Primitive::I64,
+        Primitive::I128,
+        Primitive::Isize,
         Primitive::U8,
         Primitive::U16,
         Primitive::U32,
         Primitive::U64,
+        Primitive::U128,
+        Primitive::Usize,
     ];
 
     let mut token_stream = TokenStream::new();
     for primitive in primitives {
-        token_stream.extend(primitive.gen_impl().into_iter());
+        token_stream.extend(primitive.gen_impl());
     }
     token_stream
 }
@@ -223,16 +234,29 @@ pub fn primitive_impls(_: TokenStream) -> TokenStream {
 /// This is not meant to be used directly.
 #[proc_macro_attribute]
 #[proc_macro_error]
-pub fn fp_export_signature(_attributes: TokenStream, input: TokenStream) -> TokenStream {
+pub fn fp_export_signature(attributes: TokenStream, input: TokenStream) -> TokenStream {
     proc_macro_error::set_dummy(input.clone().into());
 
+    let is_ret_type_binary = typing::has_binary_return(attributes.into());
     let func = syn::parse_macro_input::parse::<ForeignItemFn>(input.clone()).unwrap_or_abort();
     let args = typing::extract_args(&func.sig).collect::<Vec<_>>();
 
     let mut sig = func.sig.clone();
+    typing::morph_signature(&mut sig, &quote! { fp_bindgen_support });
+
+    // The signature of the `__fp_gen_*` function that is exported by the
+    // plugin, which is declared by `fp_export_impl` through the macro below:
+    let mut export_sig = func.sig.clone();
+    export_sig.ident = format_ident!("__fp_gen_{}", export_sig.ident);
+    typing::morph_signature(&mut export_sig, &quote! { $crate });
+    let export_args = export_sig
+        .inputs
+        .iter()
+        .map(|arg| typing::get_pat_type(arg).pat.as_ref())
+        .collect::<Vec<_>>();
+
     //Massage the signature into what we wish to export
     {
-        typing::morph_signature(&mut sig, "fp_bindgen_support");
         sig.inputs = sig
             .inputs
             .into_iter()
@@ -262,16 +286,29 @@ pub fn fp_export_signature(_attributes: TokenStream, input: TokenStream) -> Toke
         }
     }
 
-    let (complex_names, complex_types): (Vec<_>, Vec<_>) = args
+    let import_args = args
         .iter()
-        .filter_map(|&(_, pt, is_complex)| {
-            if is_complex {
-                Some((pt.pat.as_ref(), pt.ty.as_ref()))
+        .filter(|&&(_, _, is_complex)| is_complex)
+        .map(|&(_, pt, _)| {
+            let (name, ty) = (pt.pat.as_ref(), pt.ty.as_ref());
+            if let Some(fields) = typing::get_by_value_fields(pt) {
+                let field_names = fields.iter().map(|field| &field.name);
+                let field_idents = fields
+                    .iter()
+                    .map(|field| typing::get_by_value_field_ident(pt, field));
+                quote! {
+                    let #name = #ty { #(#field_names: #field_idents),* };
+                }
+            } else if typing::is_arg_binary(pt) {
+                quote! {
+                    let #name = unsafe { fp_bindgen_support::guest::io::import_binary_from_host::<#ty>(#name) };
+                }
             } else {
-                None
+                quote! {
+                    let #name = unsafe { fp_bindgen_support::guest::io::import_value_from_host::<#ty>(#name) };
+                }
             }
-        })
-        .unzip();
+        });
 
     let names = args.iter().map(|(_, pt, _)| pt.pat.as_ref());
     let func_call = quote! {(fptr)(#(#names),*)};
@@ -282,7 +319,9 @@ pub fn fp_export_signature(_attributes: TokenStream, input: TokenStream) -> Toke
         }
     } else {
         // Check the output type and replace complex ones with FatPtr
-        let return_wrapper = if typing::is_ret_type_complex(&func.sig.output) {
+        let return_wrapper = if is_ret_type_binary {
+            quote! {let ret = fp_bindgen_support::guest::io::export_binary_to_host(ret);}
+        } else if typing::is_ret_type_complex(&func.sig.output) {
             quote! {let ret = fp_bindgen_support::guest::io::export_value_to_host(&ret);}
         } else {
             Default::default()
@@ -293,15 +332,32 @@ pub fn fp_export_signature(_attributes: TokenStream, input: TokenStream) -> Toke
         }
     };
 
+    let fn_name = &func.sig.ident;
+    let export_macro_name = format_ident!("__fp_gen_{}", fn_name);
+
     //build the actual exported wrapper function
     (quote! {
         /// This is a implementation detail an should not be called directly
         #[inline(always)]
         pub #sig {
-            #(let #complex_names = unsafe { fp_bindgen_support::guest::io::import_value_from_host::<#complex_types>(#complex_names) };)*
+            #(#import_args)*
             #func_wrapper
             ret
         }
+
+        // Declares the exported function for an implementation. This way, the
+        // Wasm parameters are derived from the protocol's signature, rather
+        // than from the signature of the implementation.
+        #[doc(hidden)]
+        #[macro_export]
+        macro_rules! #export_macro_name {
+            ($impl_fn:path) => {
+                #[no_mangle]
+                pub #export_sig {
+                    $crate::#fn_name(#(#export_args,)* $impl_fn)
+                }
+            };
+        }
     })
     .into()
 }
@@ -326,7 +382,7 @@ pub fn fp_export_impl(attributes: TokenStream, input: TokenStream) -> TokenStrea
         syn::parse_macro_input::parse::<AttributeArgs>(attributes.clone()).unwrap_or_abort();
 
     let protocol_path = attrs
-        .get(0)
+        .first()
         .map(|om| match om {
             syn::NestedMeta::Meta(meta) => match meta {
                 syn::Meta::Path(path) => path,
@@ -336,43 +392,19 @@ pub fn fp_export_impl(attributes: TokenStream, input: TokenStream) -> TokenStrea
         })
         .unwrap_or_else(|| abort!(func, "missing attribute. Must name which provider is being implemented eg: #[fp_export_impl(foobar)]"));
 
-    let args = typing::extract_args(&func.sig).collect::<Vec<_>>();
-
-    let mut sig = func.sig.clone();
-    //Massage the signature into what we wish to export
-    {
-        typing::morph_signature(
-            &mut sig,
-            protocol_path.to_token_stream().to_string().as_str(),
-        );
-        sig.ident = format_ident!("__fp_gen_{}", sig.ident);
-    }
-
     let fn_name = &func.sig.ident;
+    let export_macro_name = format_ident!("__fp_gen_{}", fn_name);
 
-    let impl_fn_pat = Pat::Path(PatPath {
-        attrs: vec![],
-        qself: None,
-        path: PathSegment {
-            ident: func.sig.ident.clone(),
-            arguments: PathArguments::None,
-        }
-        .into(),
-    });
-    let call_args = args
-        .iter()
-        .map(|&(_, pt, _)| pt.pat.as_ref())
-        .chain(once(&impl_fn_pat))
-        .collect::<Vec<_>>();
+    // The `#[fp(...)]` attributes are not needed on implementations, because
+    // the exported function is declared using the protocol's signature. They
+    // are still accepted for compatibility:
+    let mut impl_func = func.clone();
+    typing::strip_fp_attrs(&mut impl_func.sig);
 
-    let ts: proc_macro2::TokenStream = input.clone().into();
-    //build the actual exported wrapper function
+    //declare the actual exported wrapper function
     (quote! {
-        #[no_mangle]
-        pub #sig {
-            #protocol_path::#fn_name(#(#call_args),*)
-        }
-        #ts
+        #protocol_path::#export_macro_name!(#fn_name);
+        #impl_func
     })
     .into()
 }
@@ -381,32 +413,54 @@ pub fn fp_export_impl(attributes: TokenStream, input: TokenStream) -> TokenStrea
 /// This is not meant to be used directly.
 #[proc_macro_attribute]
 #[proc_macro_error]
-pub fn fp_import_signature(_attributes: TokenStream, input: TokenStream) -> TokenStream {
+pub fn fp_import_signature(attributes: TokenStream, input: TokenStream) -> TokenStream {
     proc_macro_error::set_dummy(input.clone().into());
 
+    let is_ret_type_binary = typing::has_binary_return(attributes.into());
     let func = syn::parse_macro_input::parse::<ForeignItemFn>(input.clone()).unwrap_or_abort();
     let args = typing::extract_args(&func.sig).collect::<Vec<_>>();
 
-    let wrapper_sig = func.sig.clone();
-    let mut extern_sig = wrapper_sig.clone();
+    let mut wrapper_sig = func.sig.clone();
+    typing::strip_fp_attrs(&mut wrapper_sig);
+    let mut extern_sig = func.sig.clone();
     //Massage the signature into what we wish to export
     {
         extern_sig.ident = format_ident!("__fp_gen_{}", extern_sig.ident);
-        typing::morph_signature(&mut extern_sig, "fp_bindgen_support");
+        typing::morph_signature(&mut extern_sig, &quote! { fp_bindgen_support });
     }
 
-    let complex_names: Vec<_> = args
+    let export_args = args
         .iter()
-        .filter_map(|&(_, pt, is_complex)| {
-            if is_complex {
-                Some(pt.pat.as_ref())
+        .filter(|&&(_, _, is_complex)| is_complex)
+        .map(|&(_, pt, _)| {
+            let name = pt.pat.as_ref();
+            if let Some(fields) = typing::get_by_value_fields(pt) {
+                let field_names = fields.iter().map(|field| &field.name);
+                let field_idents = fields
+                    .iter()
+                    .map(|field| typing::get_by_value_field_ident(pt, field));
+                quote! {
+                    #(let #field_idents = #name.#field_names;)*
+                }
+            } else if typing::is_arg_borrowed_binary(pt) {
+                quote! {
+                    let #name = fp_bindgen_support::guest::io::export_slice_to_host(#name);
+                }
+            } else if typing::is_arg_binary(pt) {
+                quote! {
+                    let #name = fp_bindgen_support::guest::io::export_binary_to_host(#name);
+                }
             } else {
-                None
+                quote! {
+                    let #name = fp_bindgen_support::guest::io::export_value_to_host(&#name);
+                }
             }
-        })
-        .collect();
+        });
 
-    let names = args.iter().map(|(_, pt, _)| pt.pat.as_ref());
+    let names = extern_sig
+        .inputs
+        .iter()
+        .map(|arg| typing::get_pat_type(arg).pat.as_ref());
     let extern_ident = &extern_sig.ident;
     let func_call = quote! {#extern_ident(#(#names),*)};
 
@@ -418,7 +472,11 @@ pub fn fp_import_signature(_attributes: TokenStream, input: TokenStream) -> Toke
         }
     } else {
         // Check the output type and replace complex ones with FatPtr
-        if typing::is_ret_type_complex(&func.sig.output) {
+        if is_ret_type_binary {
+            quote! {
+                let ret = unsafe { fp_bindgen_support::guest::io::import_binary_from_host(ret) };
+            }
+        } else if typing::is_ret_type_complex(&func.sig.output) {
             quote! {
                 let ret = unsafe { fp_bindgen_support::guest::io::import_value_from_host(ret) };
             }
@@ -437,7 +495,7 @@ pub fn fp_import_signature(_attributes: TokenStream, input: TokenStream) -> Toke
         #[inline(always)]
         #(#attrs)*
         pub #wrapper_sig {
-            #(let #complex_names = fp_bindgen_support::guest::io::export_value_to_host(&#complex_names);)*
+            #(#export_args)*
             let ret = unsafe { #func_call };
             #ret_wrapper
             ret
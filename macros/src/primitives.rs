@@ -7,22 +7,28 @@ use syn::Type;
 /// Type of primitive that is supported out-of-the-box.
 pub enum Primitive {
     Bool,
+    Char,
     F32,
     F64,
     I8,
     I16,
     I32,
     I64,
+    I128,
+    Isize,
     U8,
     U16,
     U32,
     U64,
+    U128,
+    Usize,
 }
 
 impl Primitive {
     pub fn gen_impl(&self) -> TokenStream {
         let ty = self.ty();
         let ty_str = ty.to_token_stream().to_string();
+        let is_primitive = self.fits_wasm_value();
 
         let implementation = quote! {
             impl Serializable for #ty {
@@ -31,7 +37,7 @@ impl Primitive {
                 }
 
                 fn is_primitive() -> bool {
-                    true
+                    #is_primitive
                 }
 
                 fn ty() -> Type {
@@ -46,19 +52,31 @@ impl Primitive {
         use Primitive::*;
         let string = match self {
             Bool => "bool",
+            Char => "char",
             F32 => "f32",
             F64 => "f64",
             I8 => "i8",
             I16 => "i16",
             I32 => "i32",
             I64 => "i64",
+            I128 => "i128",
+            Isize => "isize",
             U8 => "u8",
             U16 => "u16",
             U32 => "u32",
             U64 => "u64",
+            U128 => "u128",
+            Usize => "usize",
         };
         Type::Path(parse_str(string))
     }
+
+    /// Primitives that don't fit in a Wasm value are passed using
+    /// serialization instead.
+    fn fits_wasm_value(&self) -> bool {
+        use Primitive::*;
+        !matches!(self, Char | I128 | Isize | U128 | Usize)
+    }
 }
 
 impl ToTokens for Primitive {
@@ -66,19 +84,24 @@ impl ToTokens for Primitive {
         use Primitive::*;
         let ident_str = match self {
             Bool => "Bool",
+            Char => "Char",
             F32 => "F32",
             F64 => "F64",
             I8 => "I8",
             I16 => "I16",
             I32 => "I32",
             I64 => "I64",
+            I128 => "I128",
+            Isize => "Isize",
             U8 => "U8",
             U16 => "U16",
             U32 => "U32",
             U64 => "U64",
+            U128 => "U128",
+            Usize => "Usize",
         };
         let ident = Ident::new(ident_str, Span::call_site());
-        tokens.extend(vec![TokenTree::Ident(ident)].into_iter());
+        tokens.extend(vec![TokenTree::Ident(ident)]);
     }
 }
 
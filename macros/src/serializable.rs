@@ -1,20 +1,101 @@
-use crate::utils::{extract_path_from_type, parse_type_item};
+use crate::{
+    custom_type::CustomTypeAttrs,
+    utils::{extract_value_type, parse_type_item},
+};
 use proc_macro::TokenStream;
 use quote::quote;
 use std::collections::HashSet;
-use syn::Path;
+use syn::{Attribute, Lit, Meta, NestedMeta, Type};
 
 pub(crate) fn impl_derive_serializable(item: TokenStream) -> TokenStream {
     let item_str = item.to_string();
     let (item_name, item, generics) = parse_type_item(item);
 
-    let field_types: HashSet<Path> = match item {
+    // Types with a custom mapping are not constructed from their definition,
+    // so their fields don't need to be serializable:
+    let attrs = match &item {
+        syn::Item::Enum(ty) => ty.attrs.as_slice(),
+        syn::Item::Struct(ty) => ty.attrs.as_slice(),
+        _ => &[],
+    };
+    if let Some(custom_type) = CustomTypeAttrs::from_attrs(attrs) {
+        if !generics.params.is_empty() {
+            panic!("Custom type {} cannot be generic", item_name);
+        }
+
+        let item_name_str = item_name.to_string();
+        let ty = custom_type.to_type_expr();
+        return quote! {
+            impl fp_bindgen::prelude::Serializable for #item_name {
+                fn ident() -> fp_bindgen::prelude::TypeIdent {
+                    fp_bindgen::prelude::TypeIdent::from(#item_name_str)
+                }
+
+                fn ty() -> fp_bindgen::prelude::Type {
+                    #ty
+                }
+            }
+        }
+        .into();
+    }
+
+    // Types that are converted from and into another type are serialized as
+    // that type, so they become an alias for it:
+    if let Some(target) = get_conversion_target(attrs) {
+        if !generics.params.is_empty() {
+            panic!(
+                "Generic type {} cannot be converted from or into another type",
+                item_name
+            );
+        }
+
+        let item_name_str = item_name.to_string();
+        return quote! {
+            impl fp_bindgen::prelude::Serializable for #item_name {
+                fn ident() -> fp_bindgen::prelude::TypeIdent {
+                    fp_bindgen::prelude::TypeIdent::from(#item_name_str)
+                }
+
+                fn ty() -> fp_bindgen::prelude::Type {
+                    fp_bindgen::prelude::Type::Alias(
+                        #item_name_str.to_owned(),
+                        <#target as fp_bindgen::prelude::Serializable>::ident(),
+                    )
+                }
+
+                fn collect_types(types: &mut fp_bindgen::prelude::TypeMap) {
+                    if types.insert(Self::ident(), Self::ty()) {
+                        <#target as fp_bindgen::prelude::Serializable>::collect_types(types);
+                    }
+                }
+            }
+        }
+        .into();
+    }
+
+    // Mirrors of remote types take on the identity of the remote type:
+    let remote_name = get_remote_name(attrs);
+    let is_mirror_alias = remote_name
+        .as_ref()
+        .map(|name| item_name != name)
+        .unwrap_or_default();
+    if is_mirror_alias && !generics.params.is_empty() {
+        panic!(
+            "Generic type {} must have the same name as the remote type it mirrors",
+            item_name
+        );
+    }
+
+    // Skipped fields are not part of the protocol, so their types don't need to
+    // be serializable:
+    let field_types: HashSet<Type> = match item {
         syn::Item::Enum(ty) => ty
             .variants
             .into_iter()
             .flat_map(|variant| variant.fields)
+            .filter(|field| !is_skipped(&field.attrs))
             .map(|field| {
-                extract_path_from_type(&field.ty).unwrap_or_else(|| {
+                extract_value_type(&field.ty).unwrap_or_else(|| {
                     panic!(
                         "Only value types are supported. Incompatible type in enum variant field: {:?}",
                         field
@@ -25,8 +106,9 @@ pub(crate) fn impl_derive_serializable(item: TokenStream) -> TokenStream {
         syn::Item::Struct(ty) => ty
             .fields
             .into_iter()
+            .filter(|field| !is_skipped(&field.attrs))
             .map(|field| {
-                extract_path_from_type(&field.ty).unwrap_or_else(|| {
+                extract_value_type(&field.ty).unwrap_or_else(|| {
                     panic!(
                         "Only value types are supported. Incompatible type in struct field: {:?}",
                         field
@@ -37,31 +119,47 @@ pub(crate) fn impl_derive_serializable(item: TokenStream) -> TokenStream {
         _ => HashSet::default(),
     };
 
+    let mirror_alias = if is_mirror_alias {
+        // Allow the mirror to be referenced by its own name as well:
+        let item_name = item_name.to_string();
+        quote! {
+            types.insert(
+                fp_bindgen::prelude::TypeIdent::from(#item_name),
+                fp_bindgen::prelude::Type::Alias(#item_name.to_owned(), Self::ident()),
+            );
+        }
+    } else {
+        quote! {}
+    };
+
     let collect_types = if field_types.is_empty() {
-        quote! { types.entry(Self::ident()).or_insert_with(Self::ty); }
+        quote! {
+            types.insert_from_module(Self::ident(), Self::ty(), module_path!());
+            #mirror_alias
+        }
     } else {
         let field_types = field_types.iter();
         let generic_params = generics.type_params();
         quote! {
-            if let std::collections::btree_map::Entry::Vacant(entry) = types.entry(Self::ident()) {
-                entry.insert(Self::ty());
-                #( #field_types::collect_types(types); )*
+            if types.insert_from_module(Self::ident(), Self::ty(), module_path!()) {
+                #( <#field_types as fp_bindgen::prelude::Serializable>::collect_types(types); )*
             }
+            #mirror_alias
 
             #( #generic_params::collect_types(types); )*
         }
     };
 
     let ident = {
-        let item_name = item_name.to_string();
+        let item_name = remote_name.unwrap_or_else(|| item_name.to_string());
         if generics.params.is_empty() {
             quote! { fp_bindgen::prelude::TypeIdent::from(#item_name) }
         } else {
-            let params = generics.type_params().map(|param| param.ident.to_string());
+            let params = generics.type_params().map(|param| &param.ident);
             quote! {
                 fp_bindgen::prelude::TypeIdent {
                     name: #item_name.to_owned(),
-                    generic_args: vec![#( fp_bindgen::prelude::TypeIdent::from(#params) ),*],
+                    generic_args: vec![#( <#params as fp_bindgen::prelude::Serializable>::ident() ),*],
                 }
             }
         }
@@ -94,3 +192,85 @@ pub(crate) fn impl_derive_serializable(item: TokenStream) -> TokenStream {
     };
     implementation.into()
 }
+
+/// Returns the type the item is serialized as, if it is converted from and into
+/// another type using `#[serde(from = "...", into = "...")]`. `try_from` may be
+/// used instead of `from`.
+fn get_conversion_target(attrs: &[Attribute]) -> Option<Type> {
+    let from = get_attr_value(attrs, "from").or_else(|| get_attr_value(attrs, "try_from"));
+    let into = get_attr_value(attrs, "into");
+    let target = match (from, into) {
+        (None, None) => return None,
+        (Some(from), Some(into)) if from == into => from,
+        (from, into) => panic!(
+            "Types that are converted using `from` or `try_from` must be converted `into` the \
+                same type, found: {} and {}",
+            from.as_deref().unwrap_or("(none)"),
+            into.as_deref().unwrap_or("(none)")
+        ),
+    };
+
+    Some(
+        syn::parse_str(&target)
+            .unwrap_or_else(|_| panic!("Invalid type to convert from and into: {}", target)),
+    )
+}
+
+/// Returns the name of the remote type, if the item is a mirror declared
+/// using `#[fp(remote = "...")]` or `#[serde(remote = "...")]`.
+fn get_remote_name(attrs: &[Attribute]) -> Option<String> {
+    get_attr_value(attrs, "remote").map(|path| {
+        path.rsplit("::")
+            .next()
+            .map(|name| name.trim().to_owned())
+            .unwrap_or(path)
+    })
+}
+
+/// Returns whether a field is skipped during both serialization and
+/// deserialization, using `#[serde(skip)]` or both `skip_serializing` and
+/// `skip_deserializing`.
+fn is_skipped(attrs: &[Attribute]) -> bool {
+    has_attr_flag(attrs, "skip")
+        || (has_attr_flag(attrs, "skip_serializing") && has_attr_flag(attrs, "skip_deserializing"))
+}
+
+/// Returns whether there is an `#[fp(key)]` or `#[serde(key)]` attribute with
+/// the given key.
+fn has_attr_flag(attrs: &[Attribute], key: &str) -> bool {
+    attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("fp") || attr.path.is_ident("serde"))
+        .filter_map(|attr| match attr.parse_meta() {
+            Ok(Meta::List(list)) => Some(list.nested),
+            _ => None,
+        })
+        .flatten()
+        .any(|meta| matches!(meta, NestedMeta::Meta(Meta::Path(path)) if path.is_ident(key)))
+}
+
+/// Returns the value of the last `#[fp(key = "...")]` or `#[serde(key = "...")]`
+/// attribute with the given key.
+fn get_attr_value(attrs: &[Attribute], key: &str) -> Option<String> {
+    let mut value = None;
+    for attr in attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("fp") || attr.path.is_ident("serde"))
+    {
+        let nested = match attr.parse_meta() {
+            Ok(Meta::List(list)) => list.nested,
+            _ => continue,
+        };
+        for meta in nested {
+            if let NestedMeta::Meta(Meta::NameValue(name_value)) = meta {
+                if name_value.path.is_ident(key) {
+                    match name_value.lit {
+                        Lit::Str(lit) => value = Some(lit.value()),
+                        other => panic!("Expected a string literal, found: {:?}", other),
+                    }
+                }
+            }
+        }
+    }
+    value
+}
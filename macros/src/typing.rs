@@ -1,6 +1,13 @@
+use proc_macro2::TokenStream;
 use proc_macro_error::{abort, ResultExt};
-use quote::ToTokens;
-use syn::{spanned::Spanned, FnArg, PatType, ReturnType, Signature, Type};
+use quote::{format_ident, quote, ToTokens};
+use syn::{
+    parenthesized,
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    spanned::Spanned,
+    FnArg, Ident, PatType, ReturnType, Signature, Token, Type,
+};
 
 pub(crate) fn get_pat_type(arg: &FnArg) -> &PatType {
     match arg {
@@ -39,14 +46,40 @@ pub(crate) fn is_type_complex(ty: &Type) -> bool {
                     | "u16"
                     | "u32"
                     | "u64"
-                    | "usize"
             )
         }
-        Type::Tuple(_) => true,
+        Type::Array(_) | Type::Reference(_) | Type::Tuple(_) => true,
         t => abort!(t, "unsupported type"),
     }
 }
 
+/// Returns whether the signature macro was invoked with `binary_return`, which
+/// the bindings generator adds to functions that return a binary type. The
+/// return value is then passed as a raw buffer, rather than being serialized
+/// using MessagePack.
+///
+/// Binary types cannot be recognized from the signature alone, because the
+/// generator resolves them through their `Serializable` implementation.
+pub(crate) fn has_binary_return(attributes: TokenStream) -> bool {
+    let attrs =
+        syn::parse::Parser::parse2(Punctuated::<Ident, Token![,]>::parse_terminated, attributes)
+            .unwrap_or_abort();
+    attrs.iter().any(|attr| attr == "binary_return")
+}
+
+/// Returns whether the argument is annotated with `#[fp(binary)]`, which the
+/// bindings generator adds to arguments of a binary type. Such arguments are
+/// passed as a raw buffer, rather than being serialized using MessagePack.
+pub(crate) fn is_arg_binary(pt: &PatType) -> bool {
+    matches!(get_arg_attr(pt), Some(ArgAttr::Binary))
+}
+
+/// Returns whether the argument is a borrowed binary type, such as `&[u8]`,
+/// which is passed as a raw buffer copied straight from the borrow.
+pub(crate) fn is_arg_borrowed_binary(pt: &PatType) -> bool {
+    is_arg_binary(pt) && matches!(pt.ty.as_ref(), Type::Reference(_))
+}
+
 pub(crate) fn get_output_type(output: &ReturnType) -> &Type {
     match output {
         ReturnType::Default => abort!(output, "FIXME"),
@@ -54,24 +87,123 @@ pub(crate) fn get_output_type(output: &ReturnType) -> &Type {
     }
 }
 
-pub(crate) fn replace_complex_type(ty: &mut Type, crate_path: &str) {
+pub(crate) fn replace_complex_type(ty: &mut Type, crate_path: &TokenStream) {
     if is_type_complex(ty) {
-        *ty = syn::parse_str::<Type>(format!("{}::common::mem::FatPtr", crate_path).as_str())
-            .unwrap_or_abort();
+        // The crate path may be `$crate` inside a `macro_rules!` definition,
+        // which cannot be parsed as a regular path:
+        *ty = Type::Verbatim(quote! { #crate_path::common::mem::FatPtr });
+    }
+}
+
+/// An `#[fp(...)]` attribute on a function argument, as added by the bindings
+/// generator.
+enum ArgAttr {
+    /// The argument is of a binary type.
+    Binary,
+    /// The argument is a struct that is passed by value.
+    ByValue(Vec<ByValueField>),
+}
+
+impl Parse for ArgAttr {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let key: Ident = input.parse()?;
+        if key == "binary" {
+            return Ok(Self::Binary);
+        } else if key != "by_value" {
+            return Err(syn::Error::new(
+                key.span(),
+                "expected `binary` or `by_value`",
+            ));
+        }
+
+        let content;
+        parenthesized!(content in input);
+        let fields = Punctuated::<ByValueField, Token![,]>::parse_terminated(&content)?;
+        Ok(Self::ByValue(fields.into_iter().collect()))
+    }
+}
+
+fn get_arg_attr(pt: &PatType) -> Option<ArgAttr> {
+    pt.attrs
+        .iter()
+        .find(|attr| attr.path.is_ident("fp"))
+        .map(|attr| attr.parse_args::<ArgAttr>().unwrap_or_abort())
+}
+
+/// A field of a struct that is passed by value, as declared by the
+/// `#[fp(by_value(...))]` attribute on a function argument.
+pub(crate) struct ByValueField {
+    pub name: Ident,
+    pub ty: Type,
+}
+
+impl Parse for ByValueField {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let name = input.parse()?;
+        input.parse::<Token![:]>()?;
+        let ty = input.parse()?;
+        Ok(Self { name, ty })
+    }
+}
+
+/// Returns the fields of the argument if it is a struct that is passed by
+/// value, in which case every field is passed as a separate Wasm value.
+pub(crate) fn get_by_value_fields(pt: &PatType) -> Option<Vec<ByValueField>> {
+    match get_arg_attr(pt) {
+        Some(ArgAttr::ByValue(fields)) => Some(fields),
+        _ => None,
+    }
+}
+
+/// Returns the name of the Wasm parameter through which the given field of an
+/// argument that is passed by value is passed. This needs to match the logic of
+/// `FunctionArg::by_value_field_name()` in the `fp-bindgen` crate.
+pub(crate) fn get_by_value_field_ident(pt: &PatType, field: &ByValueField) -> Ident {
+    format_ident!(
+        "{}_{}",
+        pt.pat
+            .to_token_stream()
+            .to_string()
+            .trim_start_matches("r#"),
+        field.name.to_string().trim_start_matches("r#")
+    )
+}
+
+/// Removes the `#[fp(...)]` attributes from the arguments of a function
+/// signature, since they are only meaningful to our macros.
+pub(crate) fn strip_fp_attrs(sig: &mut Signature) {
+    for arg in sig.inputs.iter_mut() {
+        get_pat_type_mut(arg)
+            .attrs
+            .retain(|attr| !attr.path.is_ident("fp"));
     }
 }
 
-/// Replaces complex types in the input and output of a function signature and makes it non-async
-pub(crate) fn morph_signature(sig: &mut Signature, crate_path: &str) {
+/// Replaces complex types in the input and output of a function signature and makes it non-async.
+/// Arguments that are passed by value are replaced with one argument for every field.
+pub(crate) fn morph_signature(sig: &mut Signature, crate_path: &TokenStream) {
     sig.asyncness = None;
     sig.inputs = sig
         .inputs
         .iter()
         .cloned()
-        .map(|mut arg| {
+        .flat_map(|mut arg| {
             let pt = get_pat_type_mut(&mut arg);
-            replace_complex_type(&mut pt.ty, crate_path);
-            arg
+            match get_by_value_fields(pt) {
+                Some(fields) => fields
+                    .iter()
+                    .map(|field| {
+                        let name = get_by_value_field_ident(pt, field);
+                        let ty = &field.ty;
+                        syn::parse2::<FnArg>(quote! { #name: #ty }).unwrap_or_abort()
+                    })
+                    .collect(),
+                None => {
+                    pt.attrs.retain(|attr| !attr.path.is_ident("fp"));
+                    replace_complex_type(&mut pt.ty, crate_path);
+                    vec![arg]
+                }
+            }
         })
         .collect();
 
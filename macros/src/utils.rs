@@ -2,28 +2,77 @@ use std::collections::VecDeque;
 
 use proc_macro::TokenStream;
 use proc_macro2::Ident;
-use syn::{
-    punctuated::Punctuated, Generics, Item, ItemUse, Path, PathArguments, PathSegment, Type,
-};
+use proc_macro_error::abort;
+use syn::{punctuated::Punctuated, Generics, Item, ItemUse, Path, PathSegment, Type};
 
-pub(crate) fn extract_path_from_type(ty: &Type) -> Option<Path> {
+/// Returns the given type if it is a value type that can be passed across the
+/// bridge. Paths, as well as fixed-size arrays and anonymous tuples of such
+/// types are supported.
+pub(crate) fn extract_value_type(ty: &Type) -> Option<Type> {
     match ty {
-        Type::Path(path) if path.qself.is_none() => {
-            let mut path = path.path.clone();
-            for segment in &mut path.segments {
-                if let PathArguments::AngleBracketed(args) = &mut segment.arguments {
-                    // When calling a static function on `Vec<T>`, it gets invoked
-                    // as `Vec::<T>::some_func()`, so we need to insert extra colons
-                    // to make the famed Rust "turbofish":
-                    args.colon2_token = Some(syn::parse_quote!(::));
-                }
+        Type::Path(path) if path.qself.is_none() => Some(ty.clone()),
+        Type::Paren(paren) => extract_value_type(paren.elem.as_ref()),
+        Type::Array(array) => extract_value_type(array.elem.as_ref()).map(|_| ty.clone()),
+        Type::Tuple(tuple) if !tuple.elems.is_empty() => {
+            if tuple
+                .elems
+                .iter()
+                .all(|elem| extract_value_type(elem).is_some())
+            {
+                Some(ty.clone())
+            } else {
+                None
             }
-            Some(path)
         }
         _ => None,
     }
 }
 
+/// Returns the owned type for the given argument type if it can be passed
+/// across the bridge. Besides value types, shared references are supported:
+/// `&str` is received as a `String`, `&[u8]` as a `ByteBuf`, `&[T]` as a
+/// `Vec<T>` and any other `&T` as a `T`, except for primitives.
+pub(crate) fn extract_arg_type(ty: &Type) -> Option<Type> {
+    match ty {
+        Type::Reference(reference) if reference.mutability.is_none() => {
+            match reference.elem.as_ref() {
+                Type::Path(path) if path.path.is_ident("str") => Some(syn::parse_quote!(String)),
+                Type::Path(path) if is_primitive(&path.path) => abort!(
+                    ty,
+                    "Primitives cannot be borrowed, please pass `{}` by value instead",
+                    path.path.get_ident().unwrap()
+                ),
+                Type::Slice(slice) => match slice.elem.as_ref() {
+                    Type::Path(path) if path.path.is_ident("u8") => {
+                        if cfg!(feature = "serde-bytes-compat") {
+                            Some(syn::parse_quote!(fp_bindgen::__private::ByteBuf))
+                        } else {
+                            abort!(
+                                ty,
+                                "`&[u8]` arguments are received as a `ByteBuf`, which requires the \
+                                    `serde-bytes-compat` feature of fp-bindgen"
+                            )
+                        }
+                    }
+                    elem => extract_value_type(elem).map(|elem| syn::parse_quote!(Vec<#elem>)),
+                },
+                elem => extract_value_type(elem),
+            }
+        }
+        ty => extract_value_type(ty),
+    }
+}
+
+/// Returns whether the path refers to one of the primitives, which are always
+/// passed by value.
+fn is_primitive(path: &Path) -> bool {
+    const PRIMITIVES: [&str; 16] = [
+        "bool", "char", "f32", "f64", "i8", "i16", "i32", "i64", "i128", "isize", "u8", "u16",
+        "u32", "u64", "u128", "usize",
+    ];
+    PRIMITIVES.iter().any(|primitive| path.is_ident(primitive))
+}
+
 pub(crate) fn parse_type_item(item: TokenStream) -> (Ident, Item, Generics) {
     let item = syn::parse::<Item>(item).unwrap();
     match item {